@@ -87,6 +87,11 @@ pub async fn gc_range(
 		beg.sprint(),
 		end.sprint()
 	);
+	// Store the last change which is removed, so that readers know which changes are gone
+	if let Some(k) = tx.keysr(beg.clone()..end.clone(), 1).await?.pop() {
+		let vs = change::Cf::decode(&k)?.vs;
+		tx.set(crate::key::database::cg::new(ns, db), vs.to_vec(), None).await?;
+	}
 	// Delete the entire range in grouped batches
 	tx.delr(beg..end).await?;
 	// Ok all good
@@ -85,3 +85,39 @@ pub async fn read(
 	// Return the results
 	Ok(res)
 }
+
+// Returns the versionstamp of the latest change in the change feed
+// of a database, if the change feed contains any changes.
+//
+// As this is read with the given transaction, the change feed entries up
+// to and including this versionstamp are the ones visible to it.
+pub async fn latest(
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+) -> Result<Option<vs::Versionstamp>, Error> {
+	// Calculate the changefeed range
+	let beg = change::prefix(ns, db);
+	let end = change::suffix(ns, db);
+	// Fetch the last changefeed entry key
+	match tx.keysr(beg..end, 1).await?.pop() {
+		Some(k) => Ok(Some(change::Cf::decode(&k)?.vs)),
+		None => Ok(None),
+	}
+}
+
+// Returns the versionstamp of the latest change which has been garbage
+// collected from the change feed of a database, if any ever has been.
+pub async fn watermark(
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+) -> Result<Option<vs::Versionstamp>, Error> {
+	let key = crate::key::database::cg::new(ns, db);
+	match tx.get(key, None).await? {
+		Some(v) => Ok(Some(<vs::Versionstamp>::try_from(v.as_slice()).map_err(|_| {
+			Error::Internal("The change feed watermark is not a versionstamp".to_string())
+		})?)),
+		None => Ok(None),
+	}
+}
@@ -25,6 +25,13 @@ pub static MAX_COMPUTATION_DEPTH: Lazy<u32> =
 pub static TRANSACTION_CACHE_SIZE: Lazy<usize> =
 	lazy_env_parse!("SURREAL_TRANSACTION_CACHE_SIZE", usize, 10_000);
 
+/// Specifies the number of policy sets of custom role grants which are cached.
+pub static POLICY_CACHE_SIZE: Lazy<usize> =
+	lazy_env_parse!("SURREAL_POLICY_CACHE_SIZE", usize, 1_000);
+
+/// Specifies the number of machine learning model sessions which are kept loaded.
+pub static MODEL_CACHE_SIZE: Lazy<usize> = lazy_env_parse!("SURREAL_MODEL_CACHE_SIZE", usize, 100);
+
 /// The maximum number of keys that should be scanned at once in general queries.
 pub static NORMAL_FETCH_SIZE: Lazy<u32> = lazy_env_parse!("SURREAL_NORMAL_FETCH_SIZE", u32, 50);
 
@@ -39,6 +46,9 @@ pub static MAX_STREAM_BATCH_SIZE: Lazy<u32> =
 pub static INDEXING_BATCH_SIZE: Lazy<u32> =
 	lazy_env_parse!("SURREAL_INDEXING_BATCH_SIZE", u32, 250);
 
+/// The maximum number of expired records that should be deleted at once per table.
+pub static EXPIRY_BATCH_SIZE: Lazy<u32> = lazy_env_parse!("SURREAL_EXPIRY_BATCH_SIZE", u32, 1000);
+
 /// Forward all signup/signin/authenticate query errors to a client performing authentication. Do not use in production.
 pub static INSECURE_FORWARD_ACCESS_ERRORS: Lazy<bool> =
 	lazy_env_parse!("SURREAL_INSECURE_FORWARD_ACCESS_ERRORS", bool, false);
@@ -10,6 +10,7 @@ use crate::idx::planner::{IterationStage, QueryPlanner};
 use crate::idx::trees::store::IndexStores;
 #[cfg(not(target_arch = "wasm32"))]
 use crate::kvs::IndexBuilder;
+use crate::kvs::Sequences;
 use crate::kvs::Transaction;
 use crate::sql::value::Value;
 use channel::Sender;
@@ -68,6 +69,8 @@ pub struct MutableContext {
 	// The index concurrent builders
 	#[cfg(not(target_arch = "wasm32"))]
 	index_builder: Option<IndexBuilder>,
+	// The sequence value allocator
+	sequences: Option<Sequences>,
 	// Capabilities
 	capabilities: Arc<Capabilities>,
 	#[cfg(any(
@@ -116,6 +119,7 @@ impl MutableContext {
 		capabilities: Capabilities,
 		index_stores: IndexStores,
 		#[cfg(not(target_arch = "wasm32"))] index_builder: IndexBuilder,
+		sequences: Sequences,
 		#[cfg(any(
 			feature = "kv-mem",
 			feature = "kv-surrealkv",
@@ -138,6 +142,7 @@ impl MutableContext {
 			index_stores,
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: Some(index_builder),
+			sequences: Some(sequences),
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -169,6 +174,7 @@ impl MutableContext {
 			index_stores: IndexStores::default(),
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: None,
+			sequences: None,
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -196,6 +202,7 @@ impl MutableContext {
 			index_stores: parent.index_stores.clone(),
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: parent.index_builder.clone(),
+			sequences: parent.sequences.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -234,6 +241,7 @@ impl MutableContext {
 			index_stores: parent.index_stores.clone(),
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: parent.index_builder.clone(),
+			sequences: parent.sequences.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -262,6 +270,7 @@ impl MutableContext {
 			index_stores: from.index_stores.clone(),
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: from.index_builder.clone(),
+			sequences: from.sequences.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -375,6 +384,11 @@ impl MutableContext {
 		self.index_builder.as_ref()
 	}
 
+	/// Get the sequence value allocator for this context/ds
+	pub(crate) fn get_sequences(&self) -> Option<&Sequences> {
+		self.sequences.as_ref()
+	}
+
 	/// Check if the context is done. If it returns `None` the operation may
 	/// proceed, otherwise the operation should be stopped.
 	pub fn done(&self) -> Option<Reason> {
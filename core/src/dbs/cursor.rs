@@ -0,0 +1,154 @@
+use crate::err::Error;
+use crate::kvs::Key;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use revision::revisioned;
+use revision::Revisioned;
+use serde::{Deserialize, Serialize};
+
+/// The position of a paginated iteration, from which a following query can resume
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Cursor {
+	/// The position of the iterable within the iterator
+	pub(crate) pos: u32,
+	/// The number of records which were processed from the iterable
+	pub(crate) count: u64,
+	/// The key of the last record which was processed from the iterable
+	pub(crate) key: Option<Key>,
+}
+
+impl Cursor {
+	/// Encodes the cursor as an opaque string
+	pub(crate) fn encode(&self) -> Result<String, Error> {
+		let mut buf = Vec::new();
+		self.serialize_revisioned(&mut buf)?;
+		Ok(URL_SAFE_NO_PAD.encode(buf))
+	}
+	/// Decodes a cursor from an opaque string
+	pub(crate) fn decode(val: &str) -> Result<Self, Error> {
+		let buf = URL_SAFE_NO_PAD.decode(val).map_err(|_| Error::InvalidCursor {
+			value: val.to_owned(),
+		})?;
+		Self::deserialize_revisioned(&mut buf.as_slice()).map_err(|_| Error::InvalidCursor {
+			value: val.to_owned(),
+		})
+	}
+}
+
+/// Tracks the position of a paginated iteration
+#[derive(Default)]
+pub(crate) struct Paginator {
+	/// The position to resume the iteration after
+	after: Option<Cursor>,
+	/// The position of the record being processed
+	current: Cursor,
+	/// The position of the last record which was output
+	last: Option<Cursor>,
+	/// The number of records still to skip in the current iterable
+	skip: u64,
+	/// The key to resume the current iterable from
+	resume: Option<Key>,
+}
+
+impl Paginator {
+	pub(crate) fn new(after: Option<Cursor>) -> Self {
+		Self {
+			after,
+			..Default::default()
+		}
+	}
+
+	/// Moves to the iterable at the given position, returning
+	/// false if it was entirely output by a previous query
+	pub(crate) fn seek(&mut self, pos: u32) -> bool {
+		self.current = Cursor {
+			pos,
+			..Default::default()
+		};
+		self.skip = 0;
+		self.resume = None;
+		match &self.after {
+			Some(after) if after.pos > pos => false,
+			Some(after) if after.pos == pos => {
+				self.skip = after.count;
+				self.resume.clone_from(&after.key);
+				true
+			}
+			_ => true,
+		}
+	}
+
+	/// Takes the key to resume the current iterable from. The
+	/// caller must then start the iteration after this key.
+	pub(crate) fn resume(&mut self) -> Option<Key> {
+		let key = self.resume.take();
+		if key.is_some() {
+			// The skipped records are not processed again
+			self.current.count = self.skip;
+			self.skip = 0;
+		}
+		key
+	}
+
+	/// Tracks a record from the current iterable, returning false
+	/// if it was already output by a previous query
+	pub(crate) fn track(&mut self, key: Option<Key>) -> bool {
+		self.current.count += 1;
+		self.current.key = key;
+		if self.skip > 0 {
+			self.skip -= 1;
+			return false;
+		}
+		true
+	}
+
+	/// Marks the current record as output
+	pub(crate) fn output(&mut self) {
+		self.last = Some(self.current.clone());
+	}
+
+	/// Marks the iteration as complete, as there are no more records
+	pub(crate) fn done(&mut self) {
+		self.last = None;
+	}
+
+	/// The position of the last record which was output
+	pub(crate) fn last(&self) -> Option<&Cursor> {
+		self.last.as_ref()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cursor_roundtrip() {
+		let val = Cursor {
+			pos: 2,
+			count: 100,
+			key: Some(b"/*ns*db*tb*test".to_vec()),
+		};
+		let enc = val.encode().unwrap();
+		assert_eq!(Cursor::decode(&enc).unwrap(), val);
+		assert!(Cursor::decode("not a cursor").is_err());
+	}
+
+	#[test]
+	fn paginator_skips_output_records() {
+		let mut pag = Paginator::new(Some(Cursor {
+			pos: 1,
+			count: 2,
+			key: None,
+		}));
+		assert!(!pag.seek(0));
+		assert!(pag.seek(1));
+		assert!(!pag.track(None));
+		assert!(!pag.track(None));
+		assert!(pag.track(None));
+		pag.output();
+		assert_eq!(pag.last().map(|c| c.count), Some(3));
+		assert!(pag.seek(2));
+		assert!(pag.track(None));
+	}
+}
@@ -16,7 +16,7 @@ use crate::sql::query::Query;
 use crate::sql::statement::Statement;
 use crate::sql::value::Value;
 use crate::sql::Base;
-use channel::Receiver;
+use channel::{Receiver, Sender};
 use futures::StreamExt;
 use reblessive::TreeStack;
 use std::sync::Arc;
@@ -31,6 +31,7 @@ pub(crate) struct Executor<'a> {
 	err: bool,
 	kvs: &'a Datastore,
 	txn: Option<Arc<Transaction>>,
+	interactive: Option<Sender<Notification>>,
 }
 
 impl<'a> Executor<'a> {
@@ -39,6 +40,24 @@ impl<'a> Executor<'a> {
 			kvs,
 			txn: None,
 			err: false,
+			interactive: None,
+		}
+	}
+
+	/// Creates an executor which runs every statement within an interactive
+	/// transaction, which is neither committed nor cancelled by the executor.
+	/// The live query notifications are sent to the given channel, so that
+	/// they are only delivered once the transaction is committed.
+	pub(crate) fn new_interactive(
+		kvs: &'a Datastore,
+		txn: Arc<Transaction>,
+		notifications: Sender<Notification>,
+	) -> Executor<'a> {
+		Executor {
+			kvs,
+			txn: Some(txn),
+			err: false,
+			interactive: Some(notifications),
 		}
 	}
 
@@ -196,7 +215,7 @@ impl<'a> Executor<'a> {
 		// Create a notification channel
 		let (send, recv) = channel::unbounded();
 		// Set the notification channel
-		let mut opt = opt.new_with_sender(send);
+		let mut opt = opt.new_with_sender(self.interactive.clone().unwrap_or(send));
 		// Initialise buffer of responses
 		let mut buf: Vec<Response> = vec![];
 		// Initialise array of responses
@@ -229,6 +248,12 @@ impl<'a> Executor<'a> {
 			}
 			// Process a single statement
 			let res = match stm {
+				// Interactive transactions are committed or cancelled by the client
+				Statement::Begin(_) | Statement::Cancel(_) | Statement::Commit(_)
+					if self.interactive.is_some() =>
+				{
+					Err(Error::TxInteractive)
+				}
 				// Specify runtime options
 				Statement::Option(mut stm) => {
 					// Allowed to run?
@@ -457,7 +482,7 @@ impl<'a> Executor<'a> {
 				},
 			};
 			// Output the response
-			if self.txn.is_some() {
+			if self.txn.is_some() && self.interactive.is_none() {
 				if is_stm_output || has_returned {
 					buf.clear();
 					ff_txn = true;
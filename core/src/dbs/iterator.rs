@@ -7,10 +7,14 @@ use crate::dbs::plan::Plan;
 use crate::dbs::result::Results;
 use crate::dbs::Options;
 use crate::dbs::Statement;
+use crate::dbs::{Cursor, Paginator};
 use crate::doc::Document;
 use crate::err::Error;
+use crate::idx::planner::aggregate::AggregatePlan;
 use crate::idx::planner::iterators::{IteratorRecord, IteratorRef};
 use crate::idx::planner::IterationStage;
+use crate::key::thing;
+use crate::kvs::Key;
 use crate::sql::edges::Edges;
 use crate::sql::table::Table;
 use crate::sql::thing::Thing;
@@ -33,6 +37,7 @@ pub(crate) enum Iterable {
 	Mergeable(Thing, Value),
 	Relatable(Thing, Thing, Thing, Option<Value>),
 	Index(Table, IteratorRef),
+	Aggregate(AggregatePlan),
 }
 
 pub(crate) struct Processed {
@@ -69,6 +74,8 @@ pub(crate) struct Iterator {
 	entries: Vec<Iterable>,
 	// Set if the iterator can be cancelled once it reaches start/limit
 	cancel_on_limit: Option<u32>,
+	// Iterator pagination state
+	paginator: Option<Paginator>,
 }
 
 impl Clone for Iterator {
@@ -81,6 +88,7 @@ impl Clone for Iterator {
 			results: Results::default(),
 			entries: self.entries.clone(),
 			cancel_on_limit: None,
+			paginator: None,
 		}
 	}
 }
@@ -91,6 +99,21 @@ impl Iterator {
 		Self::default()
 	}
 
+	/// Paginates the iteration, resuming after the position of a cursor
+	pub(crate) fn paginate(&mut self, after: Option<Cursor>) {
+		self.paginator = Some(Paginator::new(after));
+	}
+
+	/// Returns the cursor of the next page, if there are any more results
+	pub(crate) fn cursor(&self) -> Option<&Cursor> {
+		self.paginator.as_ref().and_then(Paginator::last)
+	}
+
+	/// Takes the key to resume a paginated iterable after
+	pub(crate) fn resume(&mut self) -> Option<Key> {
+		self.paginator.as_mut().and_then(Paginator::resume)
+	}
+
 	/// Ingests an iterable for processing
 	pub fn ingest(&mut self, val: Iterable) {
 		self.entries.push(val)
@@ -308,25 +331,36 @@ impl Iterator {
 			ctx,
 			stm,
 		)?;
+		// Aggregates which are answered from the keys are already grouped
+		if let [Iterable::Aggregate(_)] = self.entries.as_slice() {
+			self.results = Results::Memory(Default::default());
+		}
 		// Extract the expected behaviour depending on the presence of EXPLAIN with or without FULL
 		let mut plan = Plan::new(ctx, stm, &self.entries, &self.results);
 		if plan.do_iterate {
-			// Process prepared values
-			if let Some(qp) = ctx.get_query_planner() {
-				while let Some(s) = qp.next_iteration_stage().await {
-					let is_last = matches!(s, IterationStage::Iterate(_));
-					let mut c = MutableContext::unfreeze(cancel_ctx)?;
-					c.set_iteration_stage(s);
-					cancel_ctx = c.freeze();
-					if !is_last {
-						self.clone().iterate(stk, &cancel_ctx, opt, stm).await?;
-					};
+			if let [Iterable::Aggregate(a)] = self.entries.as_slice() {
+				// Compute the aggregates without iterating the records
+				if let Some(v) = a.compute(stk, &cancel_ctx, opt).await? {
+					self.results.push(stk, ctx, opt, stm, v).await?;
+				}
+			} else {
+				// Process prepared values
+				if let Some(qp) = ctx.get_query_planner() {
+					while let Some(s) = qp.next_iteration_stage().await {
+						let is_last = matches!(s, IterationStage::Iterate(_));
+						let mut c = MutableContext::unfreeze(cancel_ctx)?;
+						c.set_iteration_stage(s);
+						cancel_ctx = c.freeze();
+						if !is_last {
+							self.clone().iterate(stk, &cancel_ctx, opt, stm).await?;
+						};
+					}
+				}
+				self.iterate(stk, &cancel_ctx, opt, stm).await?;
+				// Return any document errors
+				if let Some(e) = self.error.take() {
+					return Err(e);
 				}
-			}
-			self.iterate(stk, &cancel_ctx, opt, stm).await?;
-			// Return any document errors
-			if let Some(e) = self.error.take() {
-				return Err(e);
 			}
 			// Process any SPLIT clause
 			self.output_split(stk, ctx, opt, stm).await?;
@@ -343,6 +377,13 @@ impl Iterator {
 			// Process any START & LIMIT clause
 			self.results.start_limit(self.start, self.limit);
 
+			// There is no next page unless the LIMIT was reached
+			if let Some(p) = &mut self.paginator {
+				if self.limit.map_or(true, |l| self.results.len() < l as usize) {
+					p.done();
+				}
+			}
+
 			if let Some(e) = &mut plan.explanation {
 				e.add_fetch(self.results.len());
 			} else {
@@ -383,19 +424,26 @@ impl Iterator {
 	}
 
 	#[inline]
-	async fn setup_start(
+	pub(crate) async fn setup_start(
 		&mut self,
 		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
 		stm: &Statement<'_>,
 	) -> Result<(), Error> {
-		if let Some(v) = stm.start() {
-			self.start = Some(v.process(stk, ctx, opt, None).await?);
+		if self.start.is_none() {
+			if let Some(v) = stm.start() {
+				self.start = Some(v.process(stk, ctx, opt, None).await?);
+			}
 		}
 		Ok(())
 	}
 
+	/// The number of records which the query can output, with the records skipped by its start
+	pub(crate) fn start_limit(&self) -> Option<u32> {
+		self.limit.map(|l| l.saturating_add(self.start.unwrap_or(0)))
+	}
+
 	/// Check if the iteration can be limited per iterator
 	fn check_set_start_limit(&mut self, ctx: &Context, stm: &Statement<'_>) -> bool {
 		// If there are groups we can't
@@ -517,7 +565,11 @@ impl Iterator {
 		// If any iterator requires distinct, we new to create a global distinct instance
 		let mut distinct = SyncDistinct::new(ctx);
 		// Process all prepared values
-		for v in mem::take(&mut self.entries) {
+		for (pos, v) in mem::take(&mut self.entries).into_iter().enumerate() {
+			// Skip the iterables which were output by a previous page
+			if !self.seek(pos) {
+				continue;
+			}
 			v.iterate(stk, ctx, opt, stm, self, distinct.as_mut()).await?;
 		}
 		// Everything processed ok
@@ -543,7 +595,11 @@ impl Iterator {
 				// If any iterator requires distinct, we need to create a global distinct instance
 				let mut distinct = SyncDistinct::new(ctx);
 				// Process all prepared values
-				for v in mem::take(&mut self.entries) {
+				for (pos, v) in mem::take(&mut self.entries).into_iter().enumerate() {
+					// Skip the iterables which were output by a previous page
+					if !self.seek(pos) {
+						continue;
+					}
 					v.iterate(stk, ctx, opt, stm, self, distinct.as_mut()).await?;
 				}
 				// Everything processed ok
@@ -633,10 +689,44 @@ impl Iterator {
 		stm: &Statement<'_>,
 		pro: Processed,
 	) {
+		// Skip the records which were output by a previous page
+		if let Some(p) = &mut self.paginator {
+			if !p.track(Self::cursor_key(opt, &pro)) {
+				return;
+			}
+		}
+		// Count the results before this document
+		let len = self.results.len();
 		// Process the document
 		let res = stk.run(|stk| Document::process(stk, ctx, opt, stm, pro)).await;
 		// Process the result
 		self.result(stk, ctx, opt, stm, res).await;
+		// Track the position of the last output record
+		if let Some(p) = &mut self.paginator {
+			let out = self.results.len();
+			if out > len && self.cancel_on_limit.map_or(true, |l| out <= l as usize) {
+				p.output();
+			}
+		}
+	}
+
+	/// Moves a paginated iteration to the iterable at the given position
+	fn seek(&mut self, pos: usize) -> bool {
+		match &mut self.paginator {
+			Some(p) => p.seek(pos as u32),
+			None => true,
+		}
+	}
+
+	/// Returns the key which a paginated iteration can be resumed after
+	fn cursor_key(opt: &Options, pro: &Processed) -> Option<Key> {
+		// Records from an index are resumed from the index key
+		if let Some(key) = pro.ir.as_ref().and_then(|ir| ir.key()) {
+			return Some(key.clone());
+		}
+		// Records from a table are resumed from the record key
+		let rid = pro.rid.as_ref()?;
+		thing::new(opt.ns().ok()?, opt.db().ok()?, &rid.tb, &rid.id).encode().ok()
 	}
 
 	/// Accept a processed record result
@@ -2,6 +2,7 @@
 //! In this module we essentially manage the entire lifecycle of a database request acting as the
 //! glue between the API and the response. In this module we use channels as a transport layer
 //! and executors to process the operations. This module also gives a `context` to the transaction.
+mod cursor;
 mod distinct;
 mod executor;
 mod group;
@@ -28,6 +29,7 @@ pub use self::options::*;
 pub use self::response::*;
 pub use self::session::*;
 
+pub(crate) use self::cursor::*;
 pub(crate) use self::executor::*;
 pub(crate) use self::iterator::*;
 pub(crate) use self::statement::*;
@@ -25,7 +25,7 @@ impl Display for Action {
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Notification {
@@ -33,8 +33,16 @@ pub struct Notification {
 	pub id: Uuid,
 	/// The CREATE / UPDATE / DELETE action which caused this notification
 	pub action: Action,
+	/// The id of the record which caused this notification
+	#[revision(start = 2)]
+	#[serde(default)]
+	pub record: Value,
 	/// The resulting notification content, usually the altered record content
 	pub result: Value,
+	/// The record content before the change, if requested using WITH BEFORE
+	#[revision(start = 2)]
+	#[serde(default)]
+	pub before: Option<Value>,
 }
 
 impl Display for Notification {
@@ -42,7 +50,9 @@ impl Display for Notification {
 		let obj: Object = map! {
 			"id".to_string() => self.id.to_string().into(),
 			"action".to_string() => self.action.to_string().into(),
+			"record".to_string() => self.record.clone(),
 			"result".to_string() => self.result.clone(),
+			"before".to_string(), if let Some(v) = &self.before => v.clone(),
 		}
 		.into();
 		write!(f, "{}", obj)
@@ -51,18 +61,28 @@ impl Display for Notification {
 
 impl Notification {
 	/// Construct a new notification
-	pub const fn new(id: Uuid, action: Action, result: Value) -> Self {
+	pub const fn new(id: Uuid, action: Action, record: Value, result: Value) -> Self {
 		Self {
 			id,
 			action,
+			record,
 			result,
+			before: None,
 		}
 	}
+	/// Set the record content before the change
+	pub fn with_before(mut self, before: Value) -> Self {
+		self.before = Some(before);
+		self
+	}
 }
 
 #[cfg(test)]
 impl FuzzyEq for Notification {
 	fn fuzzy_eq(&self, other: &Self) -> bool {
-		self.action == other.action && self.result == other.result
+		self.action == other.action
+			&& self.record == other.record
+			&& self.result == other.result
+			&& self.before == other.before
 	}
 }
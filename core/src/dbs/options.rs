@@ -464,16 +464,16 @@ impl Options {
 				&& self.auth.level().db().unwrap() == self.db()?;
 
 		// Is the actor allowed to do the action on the selected database?
-		let is_allowed = match action {
-			Action::View => {
-				// Today all users have at least View permissions, so if the target database belongs to the user's level, don't check permissions
-				can_view && db_in_actor_level
-			}
-			Action::Edit => {
-				// Editor and Owner roles are allowed to edit, but only if the target database belongs to the user's level
-				can_edit && db_in_actor_level
-			}
+		let is_allowed = if action.is_in(&Action::View) {
+			// Today all users have at least View permissions, so if the target database belongs to the user's level, don't check permissions
+			can_view && db_in_actor_level
+		} else {
+			// Editor and Owner roles are allowed to edit, but only if the target database belongs to the user's level
+			can_edit && db_in_actor_level
 		};
+		// Custom roles can grant the action on the records of the selected database
+		let is_allowed =
+			is_allowed || db_in_actor_level && self.auth.has_grant(&action, &ResourceKind::Record);
 
 		// Check permissions if the author is not already allowed to do the action
 		Ok(!is_allowed)
@@ -146,6 +146,13 @@ impl ExplainItem {
 					details,
 				}
 			}
+			Iterable::Aggregate(a) => Self {
+				name: "Iterate Aggregate".into(),
+				details: vec![
+					("table", Value::from(a.table().0.to_owned())),
+					("aggregates", a.explain()),
+				],
+			},
 		}
 	}
 
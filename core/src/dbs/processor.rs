@@ -8,7 +8,7 @@ use crate::err::Error;
 use crate::idx::planner::iterators::{CollectorRecord, IteratorRef, ThingIterator};
 use crate::idx::planner::IterationStage;
 use crate::key::{graph, thing};
-use crate::kvs::Transaction;
+use crate::kvs::{Key, Transaction};
 use crate::sql::dir::Dir;
 use crate::sql::id::range::IdRange;
 use crate::sql::{Edges, Table, Thing, Value};
@@ -111,6 +111,15 @@ impl<'a> Processor<'a> {
 		Ok(())
 	}
 
+	/// Takes the key to resume a paginated iteration after
+	fn resume(&mut self) -> Option<Key> {
+		match self {
+			Processor::Iterator(_, ite) => ite.resume(),
+			#[cfg(not(target_arch = "wasm32"))]
+			Processor::Channel(_, _) => None,
+		}
+	}
+
 	async fn process_iterable(
 		&mut self,
 		stk: &mut Stk,
@@ -160,6 +169,8 @@ impl<'a> Processor<'a> {
 				Iterable::Relatable(f, v, w, o) => {
 					self.process_relatable(stk, ctx, opt, stm, (f, v, w, o)).await?
 				}
+				// Aggregates are computed from the keys by the iterator
+				Iterable::Aggregate(_) => {}
 			}
 		}
 		Ok(())
@@ -311,8 +322,13 @@ impl<'a> Processor<'a> {
 		// Check that the table exists
 		txn.check_ns_db_tb(opt.ns()?, opt.db()?, v, opt.strict).await?;
 		// Prepare the start and end keys
-		let beg = thing::prefix(opt.ns()?, opt.db()?, v);
+		let mut beg = thing::prefix(opt.ns()?, opt.db()?, v);
 		let end = thing::suffix(opt.ns()?, opt.db()?, v);
+		// Resume after the last key of a previous page
+		if let Some(mut key) = self.resume() {
+			key.push(0x00);
+			beg = beg.max(key).min(end.clone());
+		}
 		// Create a new iterable range
 		let mut stream = txn.stream(beg..end, opt.version);
 		// Loop until no more entries
@@ -354,7 +370,7 @@ impl<'a> Processor<'a> {
 		// Check that the table exists
 		txn.check_ns_db_tb(opt.ns()?, opt.db()?, &tb, opt.strict).await?;
 		// Prepare the range start key
-		let beg = match &r.beg {
+		let mut beg = match &r.beg {
 			Bound::Unbounded => thing::prefix(opt.ns()?, opt.db()?, &tb),
 			Bound::Included(v) => thing::new(opt.ns()?, opt.db()?, &tb, v).encode().unwrap(),
 			Bound::Excluded(v) => {
@@ -373,6 +389,11 @@ impl<'a> Processor<'a> {
 				key
 			}
 		};
+		// Resume after the last key of a previous page
+		if let Some(mut key) = self.resume() {
+			key.push(0x00);
+			beg = beg.max(key).min(end.clone());
+		}
 		// Create a new iterable range
 		let mut stream = txn.stream(beg..end, None);
 		// Loop until no more entries
@@ -529,6 +550,12 @@ impl<'a> Processor<'a> {
 		ctx.tx().check_ns_db_tb(opt.ns()?, opt.db()?, &table.0, opt.strict).await?;
 		if let Some(exe) = ctx.get_query_executor() {
 			if let Some(mut iterator) = exe.new_iterator(opt, irf).await? {
+				// Resume after the last key of a previous page
+				if iterator.can_resume() {
+					if let Some(key) = self.resume() {
+						iterator.resume(key);
+					}
+				}
 				// Get the first batch
 				let mut to_process = Self::next_batch(ctx, opt, &mut iterator).await?;
 
@@ -3,6 +3,7 @@ use crate::dbs::Options;
 use crate::dbs::Statement;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::iam::Action;
 use crate::sql::permission::Permission;
 use reblessive::tree::Stk;
 
@@ -16,8 +17,18 @@ impl Document {
 	) -> Result<(), Error> {
 		// Check if this record exists
 		if self.id.is_some() {
+			// Writes which may create or update a record, like UPSERT or
+			// INSERT ... ON DUPLICATE KEY UPDATE, are authorised by what
+			// they actually do to this record
+			let action = match Action::from(stm) {
+				Action::Create | Action::Update if self.is_new() => Action::Create,
+				Action::Create | Action::Update if self.initial.doc.as_ref().is_some() => {
+					Action::Update
+				}
+				action => action,
+			};
 			// Should we run permissions checks?
-			if opt.check_perms(stm.into())? {
+			if opt.check_perms(action)? {
 				// Check that record authentication matches session
 				if opt.auth.is_record() {
 					let ns = opt.ns()?;
@@ -24,6 +24,10 @@ impl Document {
 		self.index(stk, ctx, opt, stm).await?;
 		// Purge record data
 		self.purge(stk, ctx, opt, stm).await?;
+		// Purge expiry data
+		self.expire(ctx, opt, stm).await?;
+		// Purge reference data
+		self.refs(ctx, opt, stm).await?;
 		// Run table queries
 		self.table(stk, ctx, opt, stm).await?;
 		// Run lives queries
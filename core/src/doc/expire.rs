@@ -0,0 +1,55 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Statement;
+use crate::doc::Document;
+use crate::err::Error;
+
+impl Document {
+	pub async fn expire(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_stm: &Statement<'_>,
+	) -> Result<(), Error> {
+		// Check if changed
+		if !self.changed() {
+			return Ok(());
+		}
+		// Get the table definition
+		let tb = self.tb(ctx, opt).await?;
+		// Check if the table is a view
+		if tb.drop {
+			return Ok(());
+		}
+		// Get the transaction
+		let txn = ctx.tx();
+		// Get the record id
+		let rid = self.id.as_ref().unwrap();
+		// Get the namespace and database
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		// Remove any previous expiry time
+		let key = crate::key::table::ei::new(ns, db, &rid.tb, &rid.id);
+		if let Some(v) = txn.get(key.clone(), None).await? {
+			if let Ok(v) = <[u8; 8]>::try_from(v.as_slice()) {
+				let ts = u64::from_be_bytes(v);
+				txn.del(crate::key::table::ex::new(ns, db, &rid.tb, ts, &rid.id)).await?;
+			}
+		}
+		// Calculate the new expiry time
+		let ts = match (&tb.expire, self.current.doc.as_ref().is_some()) {
+			(Some(expire), true) => expire.expiry(self.current.doc.as_ref()),
+			_ => None,
+		};
+		// Store the new expiry time
+		match ts {
+			Some(ts) => {
+				txn.set(crate::key::table::ex::new(ns, db, &rid.tb, ts, &rid.id), vec![], None)
+					.await?;
+				txn.set(key, ts.to_be_bytes().to_vec(), None).await?;
+			}
+			None => txn.del(key).await?,
+		}
+		// Carry on
+		Ok(())
+	}
+}
@@ -145,7 +145,12 @@ impl Document {
 					}
 				}
 				// Check for a PERMISSIONS clause
-				if opt.check_perms(Action::Edit)? {
+				let action = if self.is_new() {
+					Action::Create
+				} else {
+					Action::Update
+				};
+				if opt.check_perms(action)? {
 					// Get the permission clause
 					let perms = if self.is_new() {
 						&fd.permissions.create
@@ -1,10 +1,16 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::dbs::Statement;
+use crate::dbs::Workable;
 use crate::doc::Document;
 use crate::err::Error;
+use crate::sql::paths::ID;
+use crate::sql::paths::IN;
+use crate::sql::paths::OUT;
+use crate::sql::thing::Thing;
 use crate::sql::value::Value;
 use reblessive::tree::Stk;
+use std::sync::Arc;
 
 impl Document {
 	pub async fn insert(
@@ -70,6 +76,10 @@ impl Document {
 		self.index(stk, ctx, opt, stm).await?;
 		// Store record data
 		self.store(ctx, opt, stm).await?;
+		// Store expiry data
+		self.expire(ctx, opt, stm).await?;
+		// Store reference data
+		self.refs(ctx, opt, stm).await?;
 		// Run table queries
 		self.table(stk, ctx, opt, stm).await?;
 		// Run lives queries
@@ -106,6 +116,10 @@ impl Document {
 		self.index(stk, ctx, opt, stm).await?;
 		// Store record data
 		self.store(ctx, opt, stm).await?;
+		// Store expiry data
+		self.expire(ctx, opt, stm).await?;
+		// Store reference data
+		self.refs(ctx, opt, stm).await?;
 		// Run table queries
 		self.table(stk, ctx, opt, stm).await?;
 		// Run lives queries
@@ -117,4 +131,67 @@ impl Document {
 		// Yield document
 		self.pluck(stk, ctx, opt, stm).await
 	}
+	/// Imports a version of a record, as exported by a versioned export
+	///
+	/// The record is written as it was exported, so that the stored versions
+	/// are not altered by permissions, field definitions or events. The edges,
+	/// indexes, expiries and references are kept up to date with the record.
+	pub(crate) async fn import_version(
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		stm: &Statement<'_>,
+		rid: Thing,
+		mut value: Value,
+		relation: bool,
+	) -> Result<(), Error> {
+		// Ensure the table exists
+		ctx.tx().ensure_ns_db_tb(opt.ns()?, opt.db()?, &rid.tb, opt.strict).await?;
+		// Check the edges of a relation
+		let extras = match relation {
+			false => Workable::Normal,
+			true => {
+				let l = match value.pick(&*IN) {
+					Value::Thing(v) => v,
+					v => {
+						return Err(Error::InsertStatementIn {
+							value: v.to_string(),
+						})
+					}
+				};
+				let r = match value.pick(&*OUT) {
+					Value::Thing(v) => v,
+					v => {
+						return Err(Error::InsertStatementOut {
+							value: v.to_string(),
+						})
+					}
+				};
+				Workable::Relate(l, r, None)
+			}
+		};
+		// Fetch the latest version, bypassing the record cache
+		let key = crate::key::thing::new(opt.ns()?, opt.db()?, &rid.tb, &rid.id);
+		let initial = match ctx.tx().get(key, None).await? {
+			Some(v) => (&v).into(),
+			None => Value::None,
+		};
+		// Set the record id on the record
+		value.put(&*ID, rid.clone().into());
+		// Create the document for this version
+		let mut doc = Document::new(Some(Arc::new(rid)), None, Arc::new(initial), extras);
+		*doc.current.doc.to_mut() = value;
+		// Store record edges
+		doc.edges(ctx, opt, stm).await?;
+		// Store index data
+		doc.index(stk, ctx, opt, stm).await?;
+		// Store record data
+		doc.store(ctx, opt, stm).await?;
+		// Store expiry data
+		doc.expire(ctx, opt, stm).await?;
+		// Store reference data
+		doc.refs(ctx, opt, stm).await?;
+		// Carry on
+		Ok(())
+	}
 }
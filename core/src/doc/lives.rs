@@ -110,6 +110,17 @@ impl Document {
 					Err(e) => return Err(e),
 					Ok(_) => (),
 				}
+				// Get the id of the record which changed
+				let record = match &self.id {
+					Some(id) => Value::from(id.as_ref().clone()),
+					None => Value::None,
+				};
+				// Get the record content before the change, if
+				// the LIVE query requested it using WITH BEFORE
+				let before = match lv.before && !self.is_new() {
+					true => Some(self.pluck_before(stk, &lqctx, &lqopt, &lq).await?),
+					false => None,
+				};
 				// Finally, let's check what type of statement
 				// caused this LIVE query to run, and send the
 				// relevant notification based on the statement.
@@ -119,6 +130,7 @@ impl Document {
 						chn.send(Notification {
 							id: lv.id,
 							action: Action::Delete,
+							record,
 							result: {
 								// Ensure futures are run
 								let lqopt: &Options = &lqopt.new_with_futures(true);
@@ -130,6 +142,7 @@ impl Document {
 								// Output result
 								value
 							},
+							before,
 						})
 						.await?;
 					} else {
@@ -141,7 +154,9 @@ impl Document {
 						chn.send(Notification {
 							id: lv.id,
 							action: Action::Create,
+							record,
 							result: self.pluck(stk, &lqctx, &lqopt, &lq).await?,
+							before,
 						})
 						.await?;
 					} else {
@@ -153,7 +168,9 @@ impl Document {
 						chn.send(Notification {
 							id: lv.id,
 							action: Action::Update,
+							record,
 							result: self.pluck(stk, &lqctx, &lqopt, &lq).await?,
+							before,
 						})
 						.await?;
 					} else {
@@ -29,12 +29,14 @@ mod edges; // Attempts to store the edge data for this document
 mod empty; // Checks whether the specified document actually exists
 mod erase; // Removes all content and field data for this document
 mod event; // Processes any table events relevant for this document
+mod expire; // Stores the expiry time of this document
 mod field; // Processes any schema-defined fields for this document
 mod index; // Attempts to store the index data for this document
 mod lives; // Processes any live queries relevant for this document
 mod merge; // Merges any field changes for an INSERT statement
 mod pluck; // Pulls the projected expressions from the document
 mod purge; // Deletes this document, and any edges or indexes
+pub(crate) mod reference; // Stores the records referenced by this document
 mod relation; // Checks whether the record is the right kind for the table
 mod reset; // Resets internal fields which were set for this document
 mod store; // Writes the document content to the storage engine
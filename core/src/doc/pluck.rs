@@ -1,6 +1,7 @@
 use crate::ctx::{Context, MutableContext};
 use crate::dbs::Options;
 use crate::dbs::Statement;
+use crate::doc::CursorDoc;
 use crate::doc::Document;
 use crate::err::Error;
 use crate::iam::Action;
@@ -88,10 +89,65 @@ impl Document {
 				_ => Err(Error::Ignore),
 			},
 		}?;
+		// Process the field permissions
+		self.pluck_permissions(stk, ctx, opt, &self.current, &mut out).await?;
+		// Remove any omitted fields from output
+		if let Some(v) = stm.omit() {
+			for v in v.iter() {
+				out.del(stk, ctx, opt, v).await?;
+			}
+		}
+		// Remove metadata fields on output
+		out.cut(&*META);
+		// Output result
+		Ok(out)
+	}
+
+	/// Evaluates the initial doc for the before image of a LIVE query notification.
+	/// The same projection and field permissions as the after image are applied.
+	pub async fn pluck_before(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		stm: &Statement<'_>,
+	) -> Result<Value, Error> {
+		// Ensure futures are run
+		let opt = &opt.new_with_futures(true);
+		// Process the desired output
+		let mut out = match stm {
+			Statement::Live(s) if !s.expr.is_empty() => {
+				s.expr.compute(stk, ctx, opt, Some(&self.initial), false).await?
+			}
+			_ => self.initial.doc.as_ref().compute(stk, ctx, opt, Some(&self.initial)).await?,
+		};
+		// Process the field permissions
+		self.pluck_permissions(stk, ctx, opt, &self.initial, &mut out).await?;
+		// Remove any omitted fields from output
+		if let Some(v) = stm.omit() {
+			for v in v.iter() {
+				out.del(stk, ctx, opt, v).await?;
+			}
+		}
+		// Remove metadata fields on output
+		out.cut(&*META);
+		// Output result
+		Ok(out)
+	}
+
+	/// Removes the fields of the output which the field permissions do not allow to be selected
+	async fn pluck_permissions(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: &CursorDoc,
+		out: &mut Value,
+	) -> Result<(), Error> {
 		// Check if this record exists
 		if self.id.is_some() {
 			// Should we run permissions checks?
-			if opt.check_perms(Action::View)? {
+			if opt.check_perms(Action::Read)? {
 				// Loop through all field statements
 				for fd in self.fd(ctx, opt).await?.iter() {
 					// Loop over each field in document
@@ -103,18 +159,14 @@ impl Document {
 							Permission::Specific(e) => {
 								// Disable permissions
 								let opt = &opt.new_with_perms(false);
-								// Get the current value
-								let val = Arc::new(self.current.doc.as_ref().pick(k));
+								// Get the value of the field
+								let val = Arc::new(doc.doc.as_ref().pick(k));
 								// Configure the context
 								let mut ctx = MutableContext::new(ctx);
 								ctx.add_value("value", val);
 								let ctx = ctx.freeze();
 								// Process the PERMISSION clause
-								if !e
-									.compute(stk, &ctx, opt, Some(&self.current))
-									.await?
-									.is_truthy()
-								{
+								if !e.compute(stk, &ctx, opt, Some(doc)).await?.is_truthy() {
 									out.del(stk, &ctx, opt, k).await?
 								}
 							}
@@ -123,15 +175,7 @@ impl Document {
 				}
 			}
 		}
-		// Remove any omitted fields from output
-		if let Some(v) = stm.omit() {
-			for v in v.iter() {
-				out.del(stk, ctx, opt, v).await?;
-			}
-		}
-		// Remove metadata fields on output
-		out.cut(&*META);
-		// Output result
-		Ok(out)
+		// Carry on
+		Ok(())
 	}
 }
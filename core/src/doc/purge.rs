@@ -1,3 +1,4 @@
+use crate::cnf::NORMAL_FETCH_SIZE;
 use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::dbs::Statement;
@@ -8,9 +9,11 @@ use crate::sql::edges::Edges;
 use crate::sql::paths::EDGE;
 use crate::sql::paths::IN;
 use crate::sql::paths::OUT;
-use crate::sql::statements::DeleteStatement;
+use crate::sql::statements::{DeleteStatement, UpdateStatement};
 use crate::sql::table::Tables;
+use crate::sql::thing::Thing;
 use crate::sql::value::{Value, Values};
+use crate::sql::{Data, Kind, Operator, Output, ReferenceDeleteStrategy};
 use reblessive::tree::Stk;
 
 impl Document {
@@ -55,6 +58,8 @@ impl Document {
 					// Purge the right pointer edge
 					let key = crate::key::graph::new(opt.ns()?, opt.db()?, &r.tb, &r.id, i, rid);
 					txn.del(key).await?;
+					// Release the transaction
+					drop(txn);
 				}
 				_ => {
 					// Release the transaction
@@ -73,6 +78,89 @@ impl Document {
 				}
 			}
 		}
+		// Process the records which reference this record
+		if let Some(rid) = &self.id {
+			self.purge_references(stk, ctx, opt, rid).await?;
+		}
+		// Carry on
+		Ok(())
+	}
+
+	async fn purge_references(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		rid: &Thing,
+	) -> Result<(), Error> {
+		// Get the transaction
+		let txn = ctx.tx();
+		// Get the namespace and database
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		// Referential actions are not subject to permissions
+		let opt = &opt.new_with_perms(false);
+		// Scan the references to this record
+		let beg = crate::key::reference::prefix(ns, db, &rid.tb, &rid.id);
+		let end = crate::key::reference::suffix(ns, db, &rid.tb, &rid.id);
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			let res = txn.batch(rng, *NORMAL_FETCH_SIZE, false).await?;
+			next = res.next;
+			for (k, _) in res.values.iter() {
+				// Decode the reference key
+				let r = crate::key::reference::Ref::decode(k)?;
+				// Get the referencing record
+				let fk = Value::Thing(Thing::from((r.ft, r.fk)));
+				// Get the referencing field definition
+				let fd = match txn.get_tb_field(ns, db, r.ft, r.ff).await {
+					Ok(fd) => fd,
+					Err(Error::FdNotFound {
+						..
+					}) => continue,
+					Err(e) => return Err(e),
+				};
+				// Process the reference
+				match fd.reference.as_ref().map(|v| &v.on_delete) {
+					Some(ReferenceDeleteStrategy::Reject) => {
+						return Err(Error::DeleteRejectedByReference {
+							thing: rid.to_string(),
+							reference: fk.to_string(),
+						});
+					}
+					Some(ReferenceDeleteStrategy::Cascade) => {
+						// Setup the delete statement
+						let stm = DeleteStatement {
+							what: Values(vec![fk]),
+							output: Some(Output::None),
+							..DeleteStatement::default()
+						};
+						// Execute the delete statement
+						stm.compute(stk, ctx, opt, None).await?;
+					}
+					Some(ReferenceDeleteStrategy::Unset) => {
+						// Remove the record from a collection, or unset the field
+						let data = match fd.kind.as_ref().and_then(Kind::inner_kind) {
+							Some(_) => Data::SetExpression(vec![(
+								fd.name.clone(),
+								Operator::Dec,
+								Value::Thing(rid.clone()),
+							)]),
+							None => Data::UnsetExpression(vec![fd.name.clone()]),
+						};
+						// Setup the update statement
+						let stm = UpdateStatement {
+							what: Values(vec![fk]),
+							data: Some(data),
+							output: Some(Output::None),
+							..UpdateStatement::default()
+						};
+						// Execute the update statement
+						stm.compute(stk, ctx, opt, None).await?;
+					}
+					_ => (),
+				}
+			}
+		}
 		// Carry on
 		Ok(())
 	}
@@ -0,0 +1,97 @@
+use crate::cnf::NORMAL_FETCH_SIZE;
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::dbs::Statement;
+use crate::doc::Document;
+use crate::err::Error;
+use crate::kvs::Transaction;
+use crate::sql::idiom::Idiom;
+use crate::sql::thing::Thing;
+use crate::sql::value::Value;
+
+impl Document {
+	pub async fn refs(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_stm: &Statement<'_>,
+	) -> Result<(), Error> {
+		// Check if changed
+		if !self.changed() {
+			return Ok(());
+		}
+		// Get the transaction
+		let txn = ctx.tx();
+		// Get the record id
+		let rid = self.id.as_ref().unwrap();
+		// Get the namespace and database
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		// Loop through all field statements
+		for fd in self.fd(ctx, opt).await?.iter() {
+			// Only process fields with a reference
+			if fd.reference.is_none() {
+				continue;
+			}
+			// Get the name of the field
+			let ff = fd.name.to_string();
+			// Get the old and new referenced records
+			let old = things(self.initial.doc.as_ref().pick(&fd.name));
+			let new = things(self.current.doc.as_ref().pick(&fd.name));
+			// Remove the references which were removed
+			for v in old.iter().filter(|v| !new.contains(v)) {
+				let key = crate::key::reference::new(ns, db, &v.tb, &v.id, &ff, rid);
+				txn.del(key).await?;
+			}
+			// Store the references which were added
+			for v in new.iter().filter(|v| !old.contains(v)) {
+				let key = crate::key::reference::new(ns, db, &v.tb, &v.id, &ff, rid);
+				txn.set(key, vec![], None).await?;
+			}
+		}
+		// Carry on
+		Ok(())
+	}
+}
+
+/// Stores, or removes, the references held in a field by every record of a table
+pub(crate) async fn references(
+	txn: &Transaction,
+	ns: &str,
+	db: &str,
+	tb: &str,
+	fd: &Idiom,
+	store: bool,
+) -> Result<(), Error> {
+	// Get the name of the field
+	let ff = fd.to_string();
+	// Scan the records of the table in batches
+	let beg = crate::key::thing::prefix(ns, db, tb);
+	let end = crate::key::thing::suffix(ns, db, tb);
+	let mut next = Some(beg..end);
+	while let Some(rng) = next {
+		let batch = txn.batch(rng, *NORMAL_FETCH_SIZE, true).await?;
+		next = batch.next;
+		for (k, v) in batch.values.into_iter() {
+			let key: crate::key::thing::Thing = (&k).into();
+			let rid = Thing::from((key.tb, key.id));
+			let val: Value = (&v).into();
+			for v in things(val.pick(fd)) {
+				let key = crate::key::reference::new(ns, db, &v.tb, &v.id, &ff, &rid);
+				match store {
+					true => txn.set(key, vec![], None).await?,
+					false => txn.del(key).await?,
+				}
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Collects the records which are linked in a field value
+fn things(v: Value) -> Vec<Thing> {
+	match v {
+		Value::Thing(v) => vec![v],
+		Value::Array(v) => v.into_iter().flat_map(things).collect(),
+		_ => vec![],
+	}
+}
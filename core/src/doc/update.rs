@@ -32,6 +32,10 @@ impl Document {
 		self.allow(stk, ctx, opt, stm).await?;
 		// Store record data
 		self.store(ctx, opt, stm).await?;
+		// Store expiry data
+		self.expire(ctx, opt, stm).await?;
+		// Store reference data
+		self.refs(ctx, opt, stm).await?;
 		// Store index data
 		self.index(stk, ctx, opt, stm).await?;
 		// Run table queries
@@ -27,6 +27,7 @@ use std::string::FromUtf8Error;
 use storekey::decode::Error as DecodeError;
 use storekey::encode::Error as EncodeError;
 use thiserror::Error;
+use uuid::Uuid;
 
 /// An error originating from an embedded SurrealDB database.
 #[derive(Error, Debug)]
@@ -72,6 +73,38 @@ pub enum Error {
 	#[error("There was a problem with a datastore transaction: {0}")]
 	Tx(String),
 
+	/// There was a problem encrypting or decrypting a value in the datastore
+	#[error("There was a problem with the datastore encryption: {0}")]
+	Encryption(String),
+
+	/// The datastore was opened with a different key than it was encrypted with
+	#[error("The encryption key does not match the key which the datastore was encrypted with")]
+	EncryptionKeyMismatch,
+
+	/// The datastore is encrypted, but no encryption key was specified
+	#[error("The datastore is encrypted, but no encryption key was specified")]
+	EncryptionKeyMissing,
+
+	/// The datastore was opened with a different key encryption setting than it was encrypted with
+	#[error("The datastore was encrypted with a different key encryption setting. Use the `surreal rekey` command to change it")]
+	EncryptionKeysMismatch,
+
+	/// The datastore contains unencrypted data, but an encryption key was specified
+	#[error("The datastore is not encrypted. Use the `surreal rekey` command to encrypt it")]
+	EncryptionNotEnabled,
+
+	/// The datastore is being re-encrypted, but the rekey was interrupted
+	#[error(
+		"The datastore is being re-encrypted. Run the `surreal rekey` command again to complete it"
+	)]
+	EncryptionRekeyInterrupted,
+
+	/// An encryption key was specified for a storage engine which does not support it
+	#[error(
+		"Encryption at rest is only supported by the `rocksdb` and `surrealkv` storage engines"
+	)]
+	EncryptionUnsupported,
+
 	/// There was an error when starting a new datastore transaction
 	#[error("There was an error when starting a new datastore transaction")]
 	TxFailure,
@@ -92,6 +125,10 @@ pub enum Error {
 	#[error("The key being inserted already exists")]
 	TxKeyAlreadyExists,
 
+	/// The transaction could not be committed, as it conflicted with another transaction
+	#[error("Failed to commit transaction due to a read or write conflict. This transaction can be retried")]
+	TxConflict,
+
 	/// The key exceeds a limit set by the KV store
 	#[error("Record id or key is too large")]
 	TxKeyTooLarge,
@@ -104,6 +141,22 @@ pub enum Error {
 	#[error("Transaction is too large")]
 	TxTooLarge,
 
+	/// The interactive transaction does not exist, or was idle for too long
+	#[error("The transaction '{0}' does not exist or has expired")]
+	TxNotFound(Uuid),
+
+	/// A transaction statement was used within an interactive transaction
+	#[error("Transaction statements can't be used within an interactive transaction")]
+	TxInteractive,
+
+	/// The datastore is a read only replica of another datastore
+	#[error("Couldn't write to a read only replica")]
+	ReplicaReadonly,
+
+	/// The change feed no longer contains the changes after a versionstamp
+	#[error("The changes after versionstamp {0} have been removed from the change feed")]
+	ChangefeedGap(u128),
+
 	/// No namespace has been selected
 	#[error("Specify a namespace to use")]
 	NsEmpty,
@@ -201,6 +254,18 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The AFTER clause must evaluate to a cursor returned by a previous query
+	#[error("Found {value} but the AFTER clause must evaluate to a cursor returned by a query")]
+	InvalidCursor {
+		value: String,
+	},
+
+	/// The AFTER clause can not be combined with clauses which reorder the results
+	#[error("The AFTER clause can not be used together with {clause}")]
+	InvalidAfter {
+		clause: String,
+	},
+
 	/// There was an error with the provided JavaScript code
 	#[error("Problem with embedded script function. {message}")]
 	InvalidScript {
@@ -369,6 +434,30 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested sequence does not exist
+	#[error("The sequence '{value}' does not exist")]
+	SqNotFound {
+		value: String,
+	},
+
+	/// The permissions do not allow values to be taken from a sequence
+	#[error("You don't have permission to take values from the '{name}' sequence")]
+	SequencePermissions {
+		name: String,
+	},
+
+	/// All the values of a sequence have been taken
+	#[error("The '{name}' sequence has no values left")]
+	SequenceExhausted {
+		name: String,
+	},
+
+	/// The sequence was defined again while values were being taken from it
+	#[error("The sequence '{name}' was defined again while values were being taken from it")]
+	SequenceRedefined {
+		name: String,
+	},
+
 	/// The requested table does not exist
 	#[error("The table '{value}' does not exist")]
 	TbNotFound {
@@ -429,6 +518,27 @@ pub enum Error {
 		db: String,
 	},
 
+	/// The requested root role does not exist
+	#[error("The root role '{value}' does not exist")]
+	RoleRootNotFound {
+		value: String,
+	},
+
+	/// The requested namespace role does not exist
+	#[error("The role '{value}' does not exist in the namespace '{ns}'")]
+	RoleNsNotFound {
+		value: String,
+		ns: String,
+	},
+
+	/// The requested database role does not exist
+	#[error("The role '{value}' does not exist in the database '{db}'")]
+	RoleDbNotFound {
+		value: String,
+		ns: String,
+		db: String,
+	},
+
 	/// Unable to perform the realtime query
 	#[error("Unable to perform the realtime query")]
 	RealtimeDisabled,
@@ -863,6 +973,19 @@ pub enum Error {
 		value: String,
 	},
 
+	/// A field reference was defined on a field which does not hold records
+	#[error("The field '{name}' can not be a reference, as its type is not a record")]
+	FdReferenceKind {
+		name: String,
+	},
+
+	/// A record could not be deleted, as another record references it
+	#[error("Cannot delete `{thing}`, as it is referenced by `{reference}` with an ON DELETE REJECT clause")]
+	DeleteRejectedByReference {
+		thing: String,
+		reference: String,
+	},
+
 	/// The requested function already exists
 	#[error("The function 'fn::{value}' already exists")]
 	FcAlreadyExists {
@@ -893,6 +1016,12 @@ pub enum Error {
 		value: String,
 	},
 
+	/// The requested sequence already exists
+	#[error("The sequence '{value}' already exists")]
+	SqAlreadyExists {
+		value: String,
+	},
+
 	/// The requested table already exists
 	#[error("The table '{value}' already exists")]
 	TbAlreadyExists {
@@ -932,6 +1061,33 @@ pub enum Error {
 		db: String,
 	},
 
+	/// The role name belongs to a built-in role
+	#[error("The role '{value}' is a built-in role and can not be redefined")]
+	RoleBuiltin {
+		value: String,
+	},
+
+	/// The requested root role already exists
+	#[error("The root role '{value}' already exists")]
+	RoleRootAlreadyExists {
+		value: String,
+	},
+
+	/// The requested namespace role already exists
+	#[error("The role '{value}' already exists in the namespace '{ns}'")]
+	RoleNsAlreadyExists {
+		value: String,
+		ns: String,
+	},
+
+	/// The requested database role already exists
+	#[error("The role '{value}' already exists in the database '{db}'")]
+	RoleDbAlreadyExists {
+		value: String,
+		ns: String,
+		db: String,
+	},
+
 	/// A database index entry for the specified table is already building
 	#[error("Database index `{index}` is currently building")]
 	IndexAlreadyBuilding {
@@ -952,6 +1108,10 @@ pub enum Error {
 	#[error("Serialization error: {0}")]
 	Serialization(String),
 
+	/// The import could not be read
+	#[error("The import is invalid: {0}")]
+	InvalidImport(String),
+
 	/// The requested root access method already exists
 	#[error("The root access method '{ac}' already exists")]
 	AccessRootAlreadyExists {
@@ -1052,6 +1212,15 @@ pub enum Error {
 	#[error("This bearer access grant has an invalid format")]
 	AccessGrantBearerInvalid,
 
+	#[error("This record access method does not allow refresh")]
+	AccessRecordNoRefresh,
+
+	#[error("This record access method requires a refresh token to be provided")]
+	AccessRefreshMissingToken,
+
+	#[error("This refresh token has an invalid format")]
+	AccessGrantRefreshInvalid,
+
 	#[error("This access grant has an invalid subject")]
 	AccessGrantInvalidSubject,
 
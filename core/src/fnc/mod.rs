@@ -24,8 +24,10 @@ pub mod object;
 pub mod operate;
 pub mod parse;
 pub mod rand;
+pub mod record;
 pub mod script;
 pub mod search;
+pub mod sequence;
 pub mod session;
 pub mod shared;
 pub mod sleep;
@@ -45,7 +47,9 @@ pub async fn run(
 	args: Vec<Value>,
 ) -> Result<Value, Error> {
 	if name.eq("sleep")
+		|| name.starts_with("record::refs")
 		|| name.starts_with("search")
+		|| name.starts_with("sequence")
 		|| name.starts_with("http")
 		|| name.starts_with("type::field")
 		|| name.starts_with("type::fields")
@@ -760,10 +764,16 @@ pub async fn asynchronous(
 		"http::patch" => http::patch(ctx).await,
 		"http::delete" => http::delete(ctx).await,
 		//
+		"record::refs" => record::refs((stk, ctx, opt)).await,
+		//
 		"search::analyze" => search::analyze((stk,ctx, Some(opt))).await,
 		"search::score" => search::score((ctx, doc)).await,
 		"search::highlight" => search::highlight((ctx, doc)).await,
 		"search::offsets" => search::offsets((ctx, doc)).await,
+		"search::rrf" => search::rrf((ctx, doc)).await,
+		"search::linear" => search::linear((ctx, doc)).await,
+		//
+		"sequence::nextval" => sequence::nextval((stk, ctx, opt, doc)).await,
 		//
 		"sleep" => sleep::sleep(ctx).await,
 		//
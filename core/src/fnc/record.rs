@@ -0,0 +1,68 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::err::Error;
+use crate::iam::Action;
+use crate::sql::statements::SelectStatement;
+use crate::sql::{Field, Fields, Idiom, Thing, Value, Values};
+use reblessive::tree::Stk;
+
+/// Returns the records which reference a record, optionally filtered by table and field
+pub async fn refs(
+	(stk, ctx, opt): (&mut Stk, &Context, &Options),
+	(rid, ft, ff): (Thing, Option<String>, Option<String>),
+) -> Result<Value, Error> {
+	// Valid options?
+	opt.valid_for_db()?;
+	// Get the NS and DB
+	let (ns, db) = (opt.ns()?, opt.db()?);
+	// Get the range of references to scan
+	let (beg, end) = match (&ft, &ff) {
+		(None, None) => (
+			crate::key::reference::prefix(ns, db, &rid.tb, &rid.id),
+			crate::key::reference::suffix(ns, db, &rid.tb, &rid.id),
+		),
+		(Some(ft), None) => (
+			crate::key::reference::ftprefix(ns, db, &rid.tb, &rid.id, ft),
+			crate::key::reference::ftsuffix(ns, db, &rid.tb, &rid.id, ft),
+		),
+		(Some(ft), Some(ff)) => (
+			crate::key::reference::ffprefix(ns, db, &rid.tb, &rid.id, ft, ff),
+			crate::key::reference::ffsuffix(ns, db, &rid.tb, &rid.id, ft, ff),
+		),
+		(None, Some(_)) => {
+			return Err(Error::InvalidArguments {
+				name: String::from("record::refs"),
+				message: String::from("The table must be specified when filtering by field."),
+			})
+		}
+	};
+	// Fetch the referencing records
+	let keys = ctx.tx().keys(beg..end, u32::MAX).await?;
+	let mut res = keys
+		.iter()
+		.map(|k| {
+			let r = crate::key::reference::Ref::decode(k)?;
+			Ok(Thing::from((r.ft, r.fk)))
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+	// Records may reference this record from several fields
+	res.sort();
+	res.dedup();
+	let res = res.into_iter().map(Value::from).collect::<Vec<_>>();
+	// Only return the records which can be selected
+	if opt.check_perms(Action::View)? {
+		let stm = SelectStatement {
+			expr: Fields(
+				vec![Field::Single {
+					expr: Value::Idiom(Idiom::from("id")),
+					alias: None,
+				}],
+				true,
+			),
+			what: Values(res),
+			..SelectStatement::default()
+		};
+		return stk.run(|stk| stm.compute(stk, ctx, opt, None)).await;
+	}
+	Ok(res.into())
+}
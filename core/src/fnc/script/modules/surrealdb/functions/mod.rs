@@ -20,7 +20,9 @@ mod meta;
 mod object;
 mod parse;
 mod rand;
+mod record;
 mod search;
+mod sequence;
 mod session;
 mod string;
 mod time;
@@ -47,8 +49,10 @@ impl_module_def!(
 	"not" => run,
 	"parse" => (parse::Package),
 	"rand" => (rand::Package),
+	"record" => (record::Package),
 	"array" => (array::Package),
 	"search" => (search::Package),
+	"sequence" => (sequence::Package),
 	"session" => (session::Package),
 	"sleep" => fut Async,
 	"string" => (string::Package),
@@ -5,6 +5,7 @@ use crate::err::Error;
 use crate::fnc::get_execution_context;
 use crate::idx::ft::analyzer::Analyzer;
 use crate::idx::ft::highlighter::HighlightParams;
+use crate::idx::planner::hybrid::RRF_K;
 use crate::sql::Value;
 use reblessive::tree::Stk;
 
@@ -30,6 +31,31 @@ pub async fn score(
 	Ok(Value::None)
 }
 
+pub async fn rrf(
+	(ctx, doc): (&Context, Option<&CursorDoc>),
+	(k,): (Option<f64>,),
+) -> Result<Value, Error> {
+	if let Some((_, doc, _)) = get_execution_context(ctx, doc) {
+		if let Some(fusion) = doc.ir.as_ref().and_then(|ir| ir.fusion()) {
+			return Ok(fusion.rrf(k.unwrap_or(RRF_K)).into());
+		}
+	}
+	Ok(Value::None)
+}
+
+pub async fn linear(
+	(ctx, doc): (&Context, Option<&CursorDoc>),
+	(ft_weight, knn_weight): (Option<f64>, Option<f64>),
+) -> Result<Value, Error> {
+	if let Some((_, doc, _)) = get_execution_context(ctx, doc) {
+		if let Some(fusion) = doc.ir.as_ref().and_then(|ir| ir.fusion()) {
+			let res = fusion.linear(ft_weight.unwrap_or(0.5), knn_weight.unwrap_or(0.5));
+			return Ok(res.into());
+		}
+	}
+	Ok(Value::None)
+}
+
 pub async fn highlight(
 	(ctx, doc): (&Context, Option<&CursorDoc>),
 	args: (Value, Value, Value, Option<Value>),
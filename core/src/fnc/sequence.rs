@@ -0,0 +1,46 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::iam::Action;
+use crate::sql::{Permission, Value};
+use reblessive::tree::Stk;
+
+/// Returns the next value of a sequence
+pub async fn nextval(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(sq,): (String,),
+) -> Result<Value, Error> {
+	// Valid options?
+	opt.valid_for_db()?;
+	// Get the NS and DB
+	let (ns, db) = (opt.ns()?, opt.db()?);
+	// Get the sequence definition
+	let sq = ctx.tx().get_db_sequence(ns, db, &sq).await?;
+	// Check the actor is allowed to take values
+	if opt.check_perms(Action::Edit)? {
+		match &sq.permissions {
+			Permission::Full => (),
+			Permission::None => {
+				return Err(Error::SequencePermissions {
+					name: sq.name.to_raw(),
+				})
+			}
+			Permission::Specific(e) => {
+				// Disable permissions
+				let opt = &opt.new_with_perms(false);
+				// Process the PERMISSION clause
+				if !e.compute(stk, ctx, opt, doc).await?.is_truthy() {
+					return Err(Error::SequencePermissions {
+						name: sq.name.to_raw(),
+					});
+				}
+			}
+		}
+	}
+	// Allocate the next value
+	match ctx.get_sequences() {
+		Some(sequences) => Ok(sequences.next_val(ns, db, &sq).await?.into()),
+		None => Err(Error::Unreachable("sequence::nextval")),
+	}
+}
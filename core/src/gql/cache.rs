@@ -3,11 +3,15 @@ use tokio::sync::RwLock;
 use std::{collections::BTreeMap, fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc};
 
 use async_graphql::dynamic::Schema;
+use async_graphql::{Request, Variables};
 
 use crate::dbs::Session;
 use crate::kvs::Datastore;
+use crate::sql::Object;
 
-use super::{error::GqlError, schema::generate_schema};
+use super::error::{internal_error, GqlError};
+use super::schema::{generate_schema, sql_value_to_gql_value};
+use super::subscription::Subscriptions;
 
 pub trait Invalidator: Debug + Clone + Send + Sync + 'static {
 	type MetaData: Debug + Clone + Send + Sync + Hash;
@@ -17,6 +21,7 @@ pub trait Invalidator: Debug + Clone + Send + Sync + 'static {
 	fn generate(
 		datastore: &Arc<Datastore>,
 		session: &Session,
+		subscriptions: &Subscriptions,
 	) -> impl std::future::Future<Output = Result<(Schema, Self::MetaData), GqlError>> + std::marker::Send;
 }
 
@@ -32,8 +37,9 @@ impl Invalidator for Pessimistic {
 	async fn generate(
 		datastore: &Arc<Datastore>,
 		session: &Session,
+		subscriptions: &Subscriptions,
 	) -> Result<(Schema, Self::MetaData), GqlError> {
-		let schema = generate_schema(datastore, session).await?;
+		let schema = generate_schema(datastore, session, subscriptions).await?;
 		Ok((schema, ()))
 	}
 }
@@ -43,6 +49,7 @@ pub struct SchemaCache<I: Invalidator = Pessimistic> {
 	#[allow(clippy::type_complexity)]
 	inner: Arc<RwLock<BTreeMap<(String, String), (Schema, I::MetaData)>>>,
 	pub datastore: Arc<Datastore>,
+	pub subscriptions: Subscriptions,
 	_invalidator: PhantomData<I>,
 }
 
@@ -56,10 +63,11 @@ impl<I: Invalidator + Debug> Debug for SchemaCache<I> {
 }
 
 impl<I: Invalidator> SchemaCache<I> {
-	pub fn new(datastore: Arc<Datastore>) -> Self {
+	pub fn new(datastore: Arc<Datastore>, subscriptions: Subscriptions) -> Self {
 		SchemaCache {
 			inner: Default::default(),
 			datastore,
+			subscriptions,
 			_invalidator: PhantomData,
 		}
 	}
@@ -75,7 +83,7 @@ impl<I: Invalidator> SchemaCache<I> {
 			}
 		};
 
-		let (schema, meta) = I::generate(&self.datastore, session).await?;
+		let (schema, meta) = I::generate(&self.datastore, session, &self.subscriptions).await?;
 
 		{
 			let mut guard = self.inner.write().await;
@@ -84,4 +92,18 @@ impl<I: Invalidator> SchemaCache<I> {
 
 		Ok(schema)
 	}
+
+	/// Executes a GraphQL query with its variables, and returns the serialized JSON response
+	pub async fn execute(
+		&self,
+		session: &Session,
+		query: String,
+		variables: Object,
+	) -> Result<String, GqlError> {
+		let variables = sql_value_to_gql_value(variables.into())?;
+		let request = Request::new(query).variables(Variables::from_value(variables));
+		let schema = self.get_schema(session).await?;
+		let res = schema.execute(request).await;
+		serde_json::to_string(&res).map_err(|e| internal_error(e.to_string()))
+	}
 }
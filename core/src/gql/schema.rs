@@ -2,13 +2,16 @@ use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::sync::Arc;
 
-use crate::dbs::Session;
+use crate::dbs::{Notification, Session};
 use crate::kvs::Datastore;
 use crate::sql::kind::Literal;
-use crate::sql::statements::{DefineFieldStatement, SelectStatement};
+use crate::sql::statements::{
+	CreateStatement, DefineFieldStatement, DeleteStatement, LiveStatement, RelateStatement,
+	SelectStatement, UpdateStatement, UpsertStatement,
+};
 use crate::sql::Kind;
 use crate::sql::{self, Table};
-use crate::sql::{Cond, Fields};
+use crate::sql::{Cond, Data, Fields, Output, TableType};
 use crate::sql::{Expression, Geometry};
 use crate::sql::{Statement, Thing};
 use async_graphql::dynamic::{Enum, FieldValue, ResolverContext, Type, Union};
@@ -17,9 +20,11 @@ use async_graphql::dynamic::{FieldFuture, InterfaceField};
 use async_graphql::dynamic::{InputObject, Object};
 use async_graphql::dynamic::{InputValue, Schema};
 use async_graphql::dynamic::{Scalar, TypeRef};
+use async_graphql::dynamic::{Subscription, SubscriptionField, SubscriptionFieldFuture};
 use async_graphql::indexmap::IndexMap;
 use async_graphql::Name;
 use async_graphql::Value as GqlValue;
+use futures::StreamExt;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde_json::Number;
@@ -29,6 +34,7 @@ use super::ext::IntoExt;
 use super::ext::ValidatorExt;
 use crate::gql::error::{internal_error, schema_error, type_error};
 use crate::gql::ext::TryAsExt;
+use crate::gql::subscription::{subscription_stream, Subscriptions};
 use crate::gql::utils::{get_record, GqlValueUtils};
 use crate::kvs::LockType;
 use crate::kvs::TransactionType;
@@ -67,13 +73,24 @@ macro_rules! order {
 	}};
 }
 
+macro_rules! data_input {
+	($input_name:expr) => {
+		InputValue::new("data", TypeRef::named(&$input_name))
+	};
+}
+
 fn filter_name_from_table(tb_name: impl Display) -> String {
 	format!("_filter_{tb_name}")
 }
 
+fn input_name_from_table(tb_name: impl Display) -> String {
+	format!("_input_{tb_name}")
+}
+
 pub async fn generate_schema(
 	datastore: &Arc<Datastore>,
 	session: &Session,
+	subscriptions: &Subscriptions,
 ) -> Result<Schema, GqlError> {
 	let kvs = datastore.as_ref();
 	let tx = kvs.transaction(TransactionType::Read, LockType::Optimistic).await?;
@@ -81,6 +98,8 @@ pub async fn generate_schema(
 	let db = session.db.as_ref().expect("missing db should have been caught");
 	let tbs = tx.all_tb(ns, db).await?;
 	let mut query = Object::new("Query");
+	let mut mutation = Object::new("Mutation");
+	let mut subscription = Subscription::new("Subscription");
 	let mut types: Vec<Type> = Vec::new();
 
 	trace!(ns, db, ?tbs, "generating schema");
@@ -287,6 +306,88 @@ pub async fn generate_schema(
 			.argument(id_input!()),
 		);
 
+		let table_input_name = input_name_from_table(&tb.name);
+		let mut table_input = InputObject::new(&table_input_name);
+		let mut table_input_empty = true;
+
+		mutation = mutation
+			.field(
+				Field::new(
+					mutation_name("create", &tb.name),
+					TypeRef::named_nn(tb.name.to_string()),
+					make_mutation_resolver(datastore, session, &tb.name, &fds, MutationOp::Create),
+				)
+				.argument(InputValue::new("id", TypeRef::named(TypeRef::ID)))
+				.argument(data_input!(table_input_name)),
+			)
+			.field(
+				Field::new(
+					mutation_name("update", &tb.name),
+					TypeRef::named(tb.name.to_string()),
+					make_mutation_resolver(datastore, session, &tb.name, &fds, MutationOp::Update),
+				)
+				.argument(id_input!())
+				.argument(data_input!(table_input_name)),
+			)
+			.field(
+				Field::new(
+					mutation_name("upsert", &tb.name),
+					TypeRef::named_nn(tb.name.to_string()),
+					make_mutation_resolver(datastore, session, &tb.name, &fds, MutationOp::Upsert),
+				)
+				.argument(id_input!())
+				.argument(data_input!(table_input_name)),
+			)
+			.field(
+				Field::new(
+					mutation_name("delete", &tb.name),
+					TypeRef::named(tb.name.to_string()),
+					make_mutation_resolver(datastore, session, &tb.name, &fds, MutationOp::Delete),
+				)
+				.argument(id_input!()),
+			);
+
+		if let TableType::Relation(_) = tb.kind {
+			mutation = mutation.field(
+				Field::new(
+					mutation_name("relate", &tb.name),
+					TypeRef::named_nn(tb.name.to_string()),
+					make_mutation_resolver(datastore, session, &tb.name, &fds, MutationOp::Relate),
+				)
+				.argument(InputValue::new("in", TypeRef::named_nn(TypeRef::ID)))
+				.argument(InputValue::new("out", TypeRef::named_nn(TypeRef::ID)))
+				.argument(data_input!(table_input_name)),
+			);
+		}
+
+		let notification_name = format!("_notification_{}", tb.name);
+		let notification_ty_obj = Object::new(&notification_name)
+			.field(Field::new(
+				"id",
+				TypeRef::named_nn(TypeRef::ID),
+				make_notification_field_resolver("id"),
+			))
+			.field(Field::new(
+				"action",
+				TypeRef::named_nn("_action"),
+				make_notification_field_resolver("action"),
+			))
+			.field(Field::new(
+				"record",
+				TypeRef::named(tb.name.to_string()),
+				make_notification_field_resolver("record"),
+			));
+		types.push(Type::Object(notification_ty_obj));
+
+		subscription = subscription.field(
+			SubscriptionField::new(
+				tb.name.to_string(),
+				TypeRef::named_nn(&notification_name),
+				make_subscription_resolver(datastore, session, &tb.name, &fds, subscriptions),
+			)
+			.argument(InputValue::new("filter", TypeRef::named(&table_filter_name))),
+		);
+
 		let mut table_ty_obj = Object::new(tb.name.to_string())
 			.field(Field::new(
 				"id",
@@ -325,9 +426,27 @@ pub async fn generate_schema(
 				fd_type,
 				make_table_field_resolver(datastore, session, fd_name.as_str(), fd.kind.clone()),
 			));
+
+			// The record id and the relation endpoints are set through arguments
+			if matches!(fd_name.as_str(), "id" | "in" | "out") {
+				continue;
+			}
+			// Fields which can not be input are left to their defaults
+			let Some(input_ty) = kind_to_input_type(kind.clone()) else {
+				warn!("The field {} of {} can not be set with GraphQL mutations", fd.name, tb.name);
+				continue;
+			};
+			table_input = table_input.field(InputValue::new(fd.name.to_string(), input_ty));
+			table_input_empty = false;
+		}
+
+		// An input object must declare at least one field to be valid
+		if table_input_empty {
+			table_input = table_input.field(InputValue::new("_", TypeRef::named("any")));
 		}
 
 		types.push(Type::Object(table_ty_obj));
+		types.push(Type::InputObject(table_input));
 		types.push(table_order.into());
 		types.push(Type::Enum(table_orderable));
 		types.push(Type::InputObject(table_filter));
@@ -375,7 +494,12 @@ pub async fn generate_schema(
 
 	trace!("current Query object for schema: {:?}", query);
 
-	let mut schema = Schema::build("Query", None, None).register(query);
+	trace!("current Mutation object for schema: {:?}", mutation);
+
+	let mut schema = Schema::build("Query", Some("Mutation"), Some("Subscription"))
+		.register(query)
+		.register(mutation)
+		.register(subscription);
 	for ty in types {
 		trace!("adding type: {ty:?}");
 		schema = schema.register(ty);
@@ -436,6 +560,9 @@ pub async fn generate_schema(
 	scalar_debug_validated!(schema, "object", Kind::Object);
 	scalar_debug_validated!(schema, "any", Kind::Any);
 
+	let action_enum = Enum::new("_action").items(["CREATE", "UPDATE", "DELETE"]);
+	schema = schema.register(action_enum);
+
 	let id_interface =
 		Interface::new("record").field(InterfaceField::new("id", TypeRef::named_nn(TypeRef::ID)));
 	schema = schema.register(id_interface);
@@ -513,6 +640,271 @@ fn make_table_field_resolver(
 	}
 }
 
+#[derive(Clone, Copy, Debug)]
+enum MutationOp {
+	Create,
+	Update,
+	Upsert,
+	Delete,
+	Relate,
+}
+
+fn mutation_name(prefix: &str, tb_name: impl Display) -> String {
+	let tb_name = tb_name.to_string();
+	let mut chars = tb_name.chars();
+	match chars.next() {
+		Some(c) => format!("{prefix}{}{}", c.to_uppercase(), chars.as_str()),
+		None => prefix.to_string(),
+	}
+}
+
+fn make_mutation_resolver(
+	kvs: &Arc<Datastore>,
+	sess: &Session,
+	tb_name: impl Display,
+	fds: &Arc<[DefineFieldStatement]>,
+	op: MutationOp,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+	let tb_name = tb_name.to_string();
+	let sess_mutation = Arc::new(sess.to_owned());
+	let kvs_mutation = kvs.clone();
+	let fds = fds.clone();
+	move |ctx: ResolverContext| {
+		let tb_name = tb_name.clone();
+		let sess_mutation = sess_mutation.clone();
+		let kvs_mutation = kvs_mutation.clone();
+		let fds = fds.clone();
+		FieldFuture::new(async move {
+			let kvs = kvs_mutation.as_ref();
+
+			let args = ctx.args.as_index_map();
+			trace!("received {op:?} mutation with args: {args:?}");
+
+			let data = match args.get("data") {
+				Some(GqlValue::Object(o)) => Some(data_from_input(o, &fds)?),
+				Some(GqlValue::Null) | None => None,
+				Some(v) => {
+					return Err(internal_error(format!(
+						"Schema validation failed: expected object for data, found: {v}"
+					))
+					.into())
+				}
+			};
+
+			let id = args.get("id").and_then(GqlValueUtils::as_string);
+			let what: SqlValue = match id {
+				// A record id can only refer to a record of the mutated table
+				Some(id) => match Thing::try_from(id.clone()) {
+					Ok(t) if t.tb == tb_name => SqlValue::Thing(t),
+					Ok(t) => {
+						return Err(resolver_error(format!(
+							"The record {t} is not a record of the table {tb_name}"
+						))
+						.into())
+					}
+					Err(_) => SqlValue::Thing(Thing::from((tb_name.clone(), id))),
+				},
+				None => match op {
+					MutationOp::Create | MutationOp::Relate => SqlValue::Table(tb_name.intox()),
+					_ => {
+						return Err(internal_error(format!(
+							"Schema validation failed: No id found in {op:?} mutation"
+						))
+						.into())
+					}
+				},
+			};
+
+			let ast = match op {
+				MutationOp::Create => Statement::Create(CreateStatement {
+					what: vec![what].into(),
+					data: data.map(Data::ContentExpression),
+					output: Some(Output::After),
+					..Default::default()
+				}),
+				MutationOp::Update => Statement::Update(UpdateStatement {
+					what: vec![what].into(),
+					data: data.map(Data::MergeExpression),
+					output: Some(Output::After),
+					..Default::default()
+				}),
+				MutationOp::Upsert => Statement::Upsert(UpsertStatement {
+					what: vec![what].into(),
+					data: data.map(Data::MergeExpression),
+					output: Some(Output::After),
+					..Default::default()
+				}),
+				MutationOp::Delete => Statement::Delete(DeleteStatement {
+					what: vec![what].into(),
+					output: Some(Output::Before),
+					..Default::default()
+				}),
+				MutationOp::Relate => {
+					let endpoint = |name: &str| -> Result<SqlValue, GqlError> {
+						let id =
+							args.get(name).and_then(GqlValueUtils::as_string).ok_or_else(|| {
+								internal_error(format!("Schema validation failed: No {name} found"))
+							})?;
+						match id.clone().try_into() {
+							Ok(t) => Ok(SqlValue::Thing(t)),
+							Err(_) => Err(resolver_error(format!("invalid id: {id}"))),
+						}
+					};
+					Statement::Relate(RelateStatement {
+						kind: what,
+						from: endpoint("in")?,
+						with: endpoint("out")?,
+						data: data.map(Data::ContentExpression),
+						output: Some(Output::After),
+						..Default::default()
+					})
+				}
+			};
+
+			trace!("generated mutation ast: {ast:?}");
+
+			let query = ast.into();
+			trace!("generated mutation: {}", query);
+
+			// The statement runs through the regular document pipeline, so
+			// table and field permissions are applied as for SurrealQL writes
+			let res = kvs.process(query, &sess_mutation, Default::default()).await?;
+			debug_assert_eq!(res.len(), 1);
+			let res = res
+				.into_iter()
+				.next()
+				.expect("response vector should have exactly one value")
+				.result?;
+
+			let res = match res {
+				SqlValue::Array(a) => a.0.into_iter().next(),
+				v => {
+					error!("Found top level value, in result which should be array: {v:?}");
+					return Err("Internal Error".into());
+				}
+			};
+
+			match res {
+				Some(SqlValue::Object(o)) => Ok(Some(FieldValue::owned_any(o))),
+				Some(v) => Err(internal_error(format!("expected object, found: {v:?}")).into()),
+				None => Ok(None),
+			}
+		})
+	}
+}
+
+fn data_from_input(
+	input: &IndexMap<Name, GqlValue>,
+	fds: &[DefineFieldStatement],
+) -> Result<SqlValue, GqlError> {
+	let mut out = BTreeMap::new();
+	for (k, v) in input.iter() {
+		// The placeholder field of an empty input object sets nothing
+		if k.as_str() == "_" {
+			continue;
+		}
+		let Some(fd) = fds.iter().find(|fd| fd.name.to_string() == k.as_str()) else {
+			return Err(resolver_error(format!("Field `{k}` not found")));
+		};
+		out.insert(k.to_string(), gql_to_sql_kind(v, fd.kind.clone().unwrap_or_default())?);
+	}
+	Ok(SqlValue::Object(out.into()))
+}
+
+fn make_subscription_resolver(
+	kvs: &Arc<Datastore>,
+	sess: &Session,
+	tb_name: impl Display,
+	fds: &Arc<[DefineFieldStatement]>,
+	subscriptions: &Subscriptions,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> SubscriptionFieldFuture<'a> + Send + Sync + 'static {
+	let tb_name = tb_name.to_string();
+	let sess_subscription = Arc::new(sess.to_owned());
+	let kvs_subscription = kvs.clone();
+	let fds = fds.clone();
+	let subscriptions = subscriptions.clone();
+	move |ctx: ResolverContext| {
+		let tb_name = tb_name.clone();
+		let sess_subscription = sess_subscription.clone();
+		let kvs_subscription = kvs_subscription.clone();
+		let fds = fds.clone();
+		let subscriptions = subscriptions.clone();
+		SubscriptionFieldFuture::new(async move {
+			let args = ctx.args.as_index_map();
+			trace!("received subscription with args: {args:?}");
+
+			let cond = match args.get("filter") {
+				Some(GqlValue::Object(o)) => Some(cond_from_filter(o, &fds)?),
+				_ => None,
+			};
+
+			// Live queries can only be started from a realtime session
+			let mut sess = sess_subscription.as_ref().to_owned();
+			sess.rt = true;
+
+			let ast = Statement::Live(LiveStatement::from_source_parts(
+				Fields::all(),
+				SqlValue::Table(tb_name.intox()),
+				cond,
+				None,
+				false,
+			));
+
+			trace!("generated subscription ast: {ast:?}");
+
+			// The live query is registered like a SurrealQL LIVE SELECT,
+			// so filters and permissions are applied to each notification
+			let res = kvs_subscription.process(ast.into(), &sess, Default::default()).await?;
+			debug_assert_eq!(res.len(), 1);
+			let res = res
+				.into_iter()
+				.next()
+				.expect("response vector should have exactly one value")
+				.result?;
+
+			let id = match res {
+				SqlValue::Uuid(id) => id,
+				v => {
+					return Err(
+						internal_error(format!("expected live query id, found: {v:?}")).into()
+					)
+				}
+			};
+
+			let rcv = subscriptions.register(id).await;
+			let stream = subscription_stream(id, rcv, kvs_subscription, sess, subscriptions)
+				.map(|n| n.map(FieldValue::owned_any).map_err(async_graphql::Error::from));
+
+			Ok(stream)
+		})
+	}
+}
+
+fn make_notification_field_resolver(
+	fd_name: &'static str,
+) -> impl for<'a> Fn(ResolverContext<'a>) -> FieldFuture<'a> + Send + Sync + 'static {
+	move |ctx: ResolverContext| {
+		FieldFuture::new(async move {
+			let notification: &Notification = ctx
+				.parent_value
+				.downcast_ref::<Notification>()
+				.ok_or_else(|| internal_error("failed to downcast"))?;
+
+			let out = match fd_name {
+				"id" => FieldValue::value(sql_value_to_gql_value(notification.record.clone())?),
+				"action" => {
+					FieldValue::value(GqlValue::Enum(Name::new(notification.action.to_string())))
+				}
+				_ => match &notification.result {
+					SqlValue::Object(o) => FieldValue::owned_any(o.to_owned()),
+					_ => return Ok(None),
+				},
+			};
+			Ok(Some(out))
+		})
+	}
+}
+
 pub fn sql_value_to_gql_value(v: SqlValue) -> Result<GqlValue, GqlError> {
 	let out = match v {
 		SqlValue::None => GqlValue::Null,
@@ -655,6 +1047,41 @@ fn kind_to_type(kind: Kind, types: &mut Vec<Type>) -> Result<TypeRef, GqlError>
 	Ok(out)
 }
 
+/// The input type of a field, or `None` if values of this kind can not be converted from GraphQL
+fn kind_to_input_type(kind: Kind) -> Option<TypeRef> {
+	// Input fields are always nullable, so that defaults and
+	// computed values can be filled in by the field definitions
+	let out = match kind {
+		Kind::Any => TypeRef::named("any"),
+		Kind::Null => TypeRef::named("null"),
+		Kind::Bool => TypeRef::named(TypeRef::BOOLEAN),
+		Kind::Bytes => TypeRef::named("bytes"),
+		Kind::Datetime => TypeRef::named("datetime"),
+		Kind::Decimal => TypeRef::named("decimal"),
+		Kind::Duration => TypeRef::named("duration"),
+		Kind::Float => TypeRef::named(TypeRef::FLOAT),
+		Kind::Int => TypeRef::named(TypeRef::INT),
+		Kind::Number => TypeRef::named("number"),
+		Kind::Object => TypeRef::named("object"),
+		// Points are input as a list of their coordinates
+		Kind::Point => TypeRef::List(Box::new(TypeRef::named(TypeRef::FLOAT))),
+		Kind::String => TypeRef::named(TypeRef::STRING),
+		Kind::Uuid => TypeRef::named("uuid"),
+		// Records are referenced by their id
+		Kind::Record(_) => TypeRef::named(TypeRef::ID),
+		Kind::Option(t) => kind_to_input_type(*t)?,
+		// Unions can not be used as input types
+		Kind::Either(_) => TypeRef::named("any"),
+		Kind::Array(k, _) => TypeRef::List(Box::new(kind_to_input_type(*k)?)),
+		Kind::Geometry(_)
+		| Kind::Set(_, _)
+		| Kind::Function(_, _)
+		| Kind::Range
+		| Kind::Literal(_) => return None,
+	};
+	Some(out)
+}
+
 macro_rules! filter_impl {
 	($filter:ident, $ty:ident, $name:expr) => {
 		$filter = $filter.field(InputValue::new($name, $ty.clone()));
@@ -1155,3 +1582,33 @@ fn gql_to_sql_kind(val: &GqlValue, kind: Kind) -> Result<SqlValue, GqlError> {
 		Kind::Literal(_) => Err(resolver_error("Literals are not yet supported")),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn input(kind: Kind) -> Option<String> {
+		kind_to_input_type(kind).map(|v| v.to_string())
+	}
+
+	#[test]
+	fn input_types() {
+		assert_eq!(input(Kind::Int).as_deref(), Some("Int"));
+		assert_eq!(input(Kind::Option(Box::new(Kind::String))).as_deref(), Some("String"));
+		let records = Kind::Array(Box::new(Kind::Record(vec![Table::from("person")])), None);
+		assert_eq!(input(records).as_deref(), Some("[ID]"));
+		assert_eq!(input(Kind::Point).as_deref(), Some("[Float]"));
+		assert_eq!(input(Kind::Either(vec![Kind::Int, Kind::String])).as_deref(), Some("any"));
+	}
+
+	#[test]
+	fn unsupported_input_types() {
+		assert_eq!(input(Kind::Geometry(vec!["polygon".into()])), None);
+		assert_eq!(input(Kind::Set(Box::new(Kind::Int), None)), None);
+		let sets = Kind::Array(Box::new(Kind::Set(Box::new(Kind::Int), None)), None);
+		assert_eq!(input(sets), None);
+		assert_eq!(input(Kind::Option(Box::new(Kind::Function(None, None)))), None);
+		assert_eq!(input(Kind::Range), None);
+		assert_eq!(input(Kind::Literal(Literal::String("a".into()))), None);
+	}
+}
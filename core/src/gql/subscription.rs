@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use channel::{Receiver, Sender, TrySendError};
+use futures::Stream;
+use tokio::sync::RwLock;
+
+use super::error::{resolver_error, GqlError};
+use crate::dbs::{Notification, Session};
+use crate::kvs::Datastore;
+use crate::sql::statements::KillStatement;
+use crate::sql::{Statement, Uuid};
+
+/// The number of notifications which can be buffered for a subscription
+const SUBSCRIPTION_CHANNEL_SIZE: usize = 100;
+
+/// The channel of a subscription, and whether it was dropped for not keeping up
+#[derive(Clone, Debug)]
+struct Route {
+	sender: Sender<Notification>,
+	lagged: Arc<AtomicBool>,
+}
+
+/// The notifications of a subscription, as they are routed to it
+pub(crate) struct SubscriptionReceiver {
+	receiver: Receiver<Notification>,
+	lagged: Arc<AtomicBool>,
+}
+
+/// Routes live query notifications to the GraphQL subscriptions which started them
+#[derive(Clone, Debug, Default)]
+pub struct Subscriptions {
+	inner: Arc<RwLock<HashMap<Uuid, Route>>>,
+}
+
+impl Subscriptions {
+	/// Starts routing the notifications of a live query to a new channel
+	pub(crate) async fn register(&self, id: Uuid) -> SubscriptionReceiver {
+		let (sender, receiver) = channel::bounded(SUBSCRIPTION_CHANNEL_SIZE);
+		let lagged = Arc::new(AtomicBool::new(false));
+		let route = Route {
+			sender,
+			lagged: lagged.clone(),
+		};
+		self.inner.write().await.insert(id, route);
+		SubscriptionReceiver {
+			receiver,
+			lagged,
+		}
+	}
+
+	/// Stops routing the notifications of a live query
+	pub(crate) async fn unregister(&self, id: &Uuid) {
+		self.inner.write().await.remove(id);
+	}
+
+	/// Delivers a notification to the subscription which owns its live query.
+	/// The notification is handed back if no GraphQL subscription owns it.
+	pub async fn deliver(&self, notification: Notification) -> Option<Notification> {
+		let route = {
+			// We remove the lock asap
+			self.inner.read().await.get(&notification.id).cloned()
+		};
+		match route {
+			Some(route) => {
+				let id = notification.id;
+				// Never wait on a subscriber, as this would hold up every other live query
+				match route.sender.try_send(notification) {
+					Ok(_) => {}
+					// The subscriber is not keeping up, so stop routing and end its stream with an error
+					Err(TrySendError::Full(_)) => {
+						warn!("Dropping GraphQL subscription {id} which is not keeping up with its notifications");
+						route.lagged.store(true, Ordering::Release);
+						route.sender.close();
+						self.unregister(&id).await;
+					}
+					// The subscriber has gone away, so stop routing
+					Err(TrySendError::Closed(_)) => {
+						self.unregister(&id).await;
+					}
+				}
+				None
+			}
+			None => Some(notification),
+		}
+	}
+}
+
+/// Kills the live query behind a subscription when its stream is dropped
+struct SubscriptionGuard {
+	id: Uuid,
+	kvs: Arc<Datastore>,
+	sess: Session,
+	subscriptions: Subscriptions,
+}
+
+impl Drop for SubscriptionGuard {
+	fn drop(&mut self) {
+		let id = self.id;
+		let kvs = self.kvs.clone();
+		let sess = self.sess.clone();
+		let subscriptions = self.subscriptions.clone();
+		tokio::spawn(async move {
+			subscriptions.unregister(&id).await;
+			let ast = Statement::Kill(KillStatement {
+				id: id.into(),
+			});
+			if let Err(e) = kvs.process(ast.into(), &sess, Default::default()).await {
+				warn!("failed to kill live query for subscription: {e}");
+			}
+		});
+	}
+}
+
+/// Turns the notification channel of a live query into a subscription stream
+pub(crate) fn subscription_stream(
+	id: Uuid,
+	rcv: SubscriptionReceiver,
+	kvs: Arc<Datastore>,
+	sess: Session,
+	subscriptions: Subscriptions,
+) -> impl Stream<Item = Result<Notification, GqlError>> + Send {
+	let guard = SubscriptionGuard {
+		id,
+		kvs,
+		sess,
+		subscriptions,
+	};
+	futures::stream::unfold(Some((rcv, guard)), |state| async move {
+		let (rcv, guard) = state?;
+		match rcv.receiver.recv().await {
+			Ok(notification) => Some((Ok(notification), Some((rcv, guard)))),
+			// Tell the subscriber why its stream ends, before it is completed
+			Err(_) if rcv.lagged.load(Ordering::Acquire) => Some((
+				Err(resolver_error(
+					"The subscription was ended as it did not keep up with its notifications",
+				)),
+				None,
+			)),
+			Err(_) => None,
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::dbs::Action;
+	use crate::sql::Value;
+
+	fn notification(id: Uuid) -> Notification {
+		Notification::new(id, Action::Create, Value::None, Value::None)
+	}
+
+	#[tokio::test]
+	async fn deliver_to_subscription() {
+		let subscriptions = Subscriptions::default();
+		let id = Uuid::new_v4();
+		let rcv = subscriptions.register(id).await;
+		// The notification is routed to the subscription
+		assert!(subscriptions.deliver(notification(id)).await.is_none());
+		assert_eq!(rcv.receiver.recv().await.unwrap().id, id);
+		// Notifications for other live queries are handed back
+		let other = Uuid::new_v4();
+		assert_eq!(subscriptions.deliver(notification(other)).await.unwrap().id, other);
+	}
+
+	#[tokio::test]
+	async fn deliver_to_closed_subscription() {
+		let subscriptions = Subscriptions::default();
+		let id = Uuid::new_v4();
+		drop(subscriptions.register(id).await);
+		// The notification is dropped and the subscription is no longer routed
+		assert!(subscriptions.deliver(notification(id)).await.is_none());
+		assert!(subscriptions.deliver(notification(id)).await.is_some());
+	}
+
+	#[tokio::test]
+	async fn deliver_to_full_subscription() {
+		let subscriptions = Subscriptions::default();
+		let id = Uuid::new_v4();
+		let rcv = subscriptions.register(id).await;
+		for _ in 0..SUBSCRIPTION_CHANNEL_SIZE {
+			assert!(subscriptions.deliver(notification(id)).await.is_none());
+		}
+		// Delivering to a full subscription does not wait, and drops the subscriber
+		let res = tokio::time::timeout(
+			std::time::Duration::from_secs(1),
+			subscriptions.deliver(notification(id)),
+		)
+		.await;
+		assert!(matches!(res, Ok(None)));
+		assert!(subscriptions.deliver(notification(id)).await.is_some());
+		// The buffered notifications can still be received before the stream ends
+		assert!(rcv.lagged.load(Ordering::Acquire));
+		for _ in 0..SUBSCRIPTION_CHANNEL_SIZE {
+			assert_eq!(rcv.receiver.recv().await.unwrap().id, id);
+		}
+		assert!(rcv.receiver.recv().await.is_err());
+	}
+}
@@ -2,7 +2,7 @@ use crate::sql::statements::{DefineAccessStatement, DefineUserStatement};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 
-use super::{is_allowed, Action, Actor, Error, Level, Resource, Role};
+use super::{is_allowed, Action, Actor, Error, Grant, Level, Resource, ResourceKind, Role};
 
 /// Specifies the current authentication for the datastore execution context.
 #[revisioned(revision = 1)]
@@ -87,6 +87,21 @@ impl Auth {
 	pub fn has_role(&self, role: &Role) -> bool {
 		self.actor.has_role(role)
 	}
+
+	/// Checks if the current actor has been granted an action on a kind of resource by a custom role
+	pub fn has_grant(&self, action: &Action, kind: &ResourceKind) -> bool {
+		self.actor.has_grant(action, kind)
+	}
+
+	/// Returns the roles of the current actor
+	pub fn roles(&self) -> &[Role] {
+		self.actor.roles()
+	}
+
+	/// Sets the grants of the custom roles held by the current actor
+	pub fn with_grants(self, grants: Vec<Grant>) -> Self {
+		Self::new(self.actor.with_grants(grants))
+	}
 }
 
 impl std::convert::From<(&DefineUserStatement, Level)> for Auth {
@@ -1,15 +1,33 @@
+use std::collections::HashSet;
 use std::str::FromStr;
 
 use cedar_policy::{Entity, EntityId, EntityTypeName, EntityUid};
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
 
 use crate::dbs::Statement;
+use crate::iam::Error;
 
-// TODO(sgirones): For now keep it simple. In the future, we will allow for custom roles and policies using a more exhaustive list of actions and resources.
-#[derive(Clone, Debug, Eq, PartialEq, PartialOrd)]
+/// The actions which can be authorized against a resource.
+///
+/// `View` and `Edit` are action groups. Every other action is a member of one of them,
+/// so policies granting `View` or `Edit` also cover the fine-grained actions in the group.
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub enum Action {
 	View,
 	Edit,
+	Read,
+	Create,
+	Update,
+	Delete,
+	Define,
+	Remove,
+	Export,
+	Import,
+	Kill,
 }
 
 impl std::fmt::Display for Action {
@@ -17,6 +35,35 @@ impl std::fmt::Display for Action {
 		match self {
 			Action::View => write!(f, "View"),
 			Action::Edit => write!(f, "Edit"),
+			Action::Read => write!(f, "Read"),
+			Action::Create => write!(f, "Create"),
+			Action::Update => write!(f, "Update"),
+			Action::Delete => write!(f, "Delete"),
+			Action::Define => write!(f, "Define"),
+			Action::Remove => write!(f, "Remove"),
+			Action::Export => write!(f, "Export"),
+			Action::Import => write!(f, "Import"),
+			Action::Kill => write!(f, "Kill"),
+		}
+	}
+}
+
+impl FromStr for Action {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().as_str() {
+			"view" => Ok(Self::View),
+			"edit" => Ok(Self::Edit),
+			"read" => Ok(Self::Read),
+			"create" => Ok(Self::Create),
+			"update" => Ok(Self::Update),
+			"delete" => Ok(Self::Delete),
+			"define" => Ok(Self::Define),
+			"remove" => Ok(Self::Remove),
+			"export" => Ok(Self::Export),
+			"import" => Ok(Self::Import),
+			"kill" => Ok(Self::Kill),
+			_ => Err(Error::InvalidAction(s.to_string())),
 		}
 	}
 }
@@ -25,6 +72,25 @@ impl Action {
 	pub fn id(&self) -> String {
 		self.to_string()
 	}
+
+	/// Returns the action group which this action belongs to, if any
+	pub fn group(&self) -> Option<Action> {
+		match self {
+			Action::View | Action::Edit => None,
+			Action::Read | Action::Export | Action::Kill => Some(Action::View),
+			_ => Some(Action::Edit),
+		}
+	}
+
+	/// Checks if this action is the given action, or a member of it
+	pub fn is_in(&self, other: &Action) -> bool {
+		self == other || self.group().as_ref() == Some(other)
+	}
+
+	// Cedar policy helpers
+	pub fn cedar_parents(&self) -> HashSet<EntityUid> {
+		self.group().iter().map(EntityUid::from).collect()
+	}
 }
 
 impl std::convert::From<&Action> for EntityUid {
@@ -38,23 +104,38 @@ impl std::convert::From<&Action> for EntityUid {
 
 impl std::convert::From<&Action> for Entity {
 	fn from(action: &Action) -> Self {
-		Entity::new(action.into(), Default::default(), Default::default())
+		Entity::new(action.into(), Default::default(), action.cedar_parents())
 	}
 }
 
 impl From<&Statement<'_>> for Action {
 	fn from(stmt: &Statement) -> Self {
 		match stmt {
-			Statement::Live(_) => Action::View,
-			Statement::Select(_) => Action::View,
-			Statement::Show(_) => Action::View,
-			Statement::Create(_) => Action::Edit,
-			Statement::Upsert(_) => Action::Edit,
-			Statement::Update(_) => Action::Edit,
-			Statement::Relate(_) => Action::Edit,
-			Statement::Delete(_) => Action::Edit,
-			Statement::Insert(_) => Action::Edit,
+			Statement::Live(_) => Action::Read,
+			Statement::Select(_) => Action::Read,
+			Statement::Show(_) => Action::Read,
+			Statement::Create(_) => Action::Create,
+			Statement::Upsert(_) => Action::Update,
+			Statement::Update(_) => Action::Update,
+			Statement::Relate(_) => Action::Create,
+			Statement::Delete(_) => Action::Delete,
+			Statement::Insert(_) => Action::Create,
 			Statement::Access(_) => Action::Edit,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_action_groups() {
+		assert!(Action::Read.is_in(&Action::View));
+		assert!(Action::Kill.is_in(&Action::View));
+		assert!(Action::Define.is_in(&Action::Edit));
+		assert!(!Action::Define.is_in(&Action::View));
+		assert!(Action::Edit.is_in(&Action::Edit));
+		assert!(!Action::View.is_in(&Action::Edit));
+	}
+}
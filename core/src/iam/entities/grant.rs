@@ -0,0 +1,59 @@
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+use super::{Action, ResourceKind};
+
+/// A set of actions which a custom role is allowed to perform on a set of resource kinds.
+/// A grant without any resource kinds applies to every kind of resource.
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Grant {
+	pub actions: Vec<Action>,
+	pub resources: Vec<ResourceKind>,
+}
+
+impl Grant {
+	pub fn new(actions: Vec<Action>, resources: Vec<ResourceKind>) -> Self {
+		Self {
+			actions,
+			resources,
+		}
+	}
+
+	/// Checks if this grant allows the action on the resource kind
+	pub fn allows(&self, action: &Action, kind: &ResourceKind) -> bool {
+		self.actions.iter().any(|a| action.is_in(a))
+			&& (self.resources.is_empty() || self.resources.contains(kind))
+	}
+
+	/// Returns the Cedar policy which permits this grant to an actor
+	pub fn cedar_policy(&self) -> String {
+		let actions = self.actions.iter().map(|a| format!("Action::\"{a}\"")).collect::<Vec<_>>();
+		let mut policy = format!(
+			"permit(principal, action in [{}], resource) when {{ resource.level in principal.level",
+			actions.join(", ")
+		);
+		if !self.resources.is_empty() {
+			let kinds = self.resources.iter().map(|k| format!("\"{k}\"")).collect::<Vec<_>>();
+			policy.push_str(&format!(" && [{}].contains(resource.type)", kinds.join(", ")));
+		}
+		policy.push_str(" };");
+		policy
+	}
+}
+
+impl Display for Grant {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let actions = self.actions.iter().map(|a| a.to_string().to_uppercase()).collect::<Vec<_>>();
+		write!(f, "GRANT {}", actions.join(", "))?;
+		if !self.resources.is_empty() {
+			let kinds =
+				self.resources.iter().map(|k| k.to_string().to_uppercase()).collect::<Vec<_>>();
+			write!(f, " ON {}", kinds.join(", "))?;
+		}
+		Ok(())
+	}
+}
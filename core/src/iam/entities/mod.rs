@@ -1,9 +1,11 @@
 mod action;
+mod grant;
 mod resources;
 mod roles;
 mod schema;
 
 pub use self::action::*;
+pub use self::grant::*;
 pub use self::resources::*;
 pub use self::roles::*;
 pub use self::schema::*;
@@ -7,19 +7,21 @@ use cedar_policy::{Entity, EntityId, EntityTypeName, EntityUid, RestrictedExpres
 use serde::{Deserialize, Serialize};
 
 use super::{Level, Resource, ResourceKind};
-use crate::iam::Role;
+use crate::iam::{Action, Grant, Role};
 use crate::sql::statements::{DefineAccessStatement, DefineUserStatement};
 
 //
 // User
 //
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub struct Actor {
 	res: Resource,
 	roles: Vec<Role>,
+	#[revision(start = 2)]
+	grants: Vec<Grant>,
 }
 
 impl Default for Actor {
@@ -27,6 +29,7 @@ impl Default for Actor {
 		Self {
 			res: ResourceKind::Actor.on_level(Level::No),
 			roles: Vec::new(),
+			grants: Vec::new(),
 		}
 	}
 }
@@ -53,14 +56,36 @@ impl Actor {
 		Self {
 			res: Resource::new(id, super::ResourceKind::Actor, level),
 			roles,
+			grants: Vec::new(),
 		}
 	}
 
+	/// Sets the grants of the custom roles held by the actor.
+	pub fn with_grants(mut self, grants: Vec<Grant>) -> Self {
+		self.grants = grants;
+		self
+	}
+
+	/// Returns the roles held by the actor.
+	pub fn roles(&self) -> &[Role] {
+		&self.roles
+	}
+
+	/// Returns the grants of the custom roles held by the actor.
+	pub fn grants(&self) -> &[Grant] {
+		&self.grants
+	}
+
 	/// Checks if the actor has the given role.
 	pub fn has_role(&self, role: &Role) -> bool {
 		self.roles.contains(role)
 	}
 
+	/// Checks if any of the actor's grants allows the action on the resource kind.
+	pub fn has_grant(&self, action: &Action, kind: &ResourceKind) -> bool {
+		self.grants.iter().any(|g| g.allows(action, kind))
+	}
+
 	// Cedar policy helpers
 	pub fn cedar_attrs(&self) -> HashMap<String, RestrictedExpression> {
 		[
@@ -5,11 +5,12 @@ use std::{
 };
 
 use super::Level;
+use crate::iam::Error;
 
 use cedar_policy::{Entity, EntityId, EntityTypeName, EntityUid, RestrictedExpression};
 use serde::{Deserialize, Serialize};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Default, Debug, Eq, PartialEq, PartialOrd, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -33,6 +34,9 @@ pub enum ResourceKind {
 
 	// IAM
 	Actor,
+
+	#[revision(start = 2)]
+	Sequence,
 }
 
 impl std::fmt::Display for ResourceKind {
@@ -54,6 +58,33 @@ impl std::fmt::Display for ResourceKind {
 			ResourceKind::Index => write!(f, "Index"),
 			ResourceKind::Access => write!(f, "Access"),
 			ResourceKind::Actor => write!(f, "Actor"),
+			ResourceKind::Sequence => write!(f, "Sequence"),
+		}
+	}
+}
+
+impl FromStr for ResourceKind {
+	type Err = Error;
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_ascii_lowercase().as_str() {
+			"any" => Ok(Self::Any),
+			"namespace" => Ok(Self::Namespace),
+			"database" => Ok(Self::Database),
+			"record" => Ok(Self::Record),
+			"table" => Ok(Self::Table),
+			"document" => Ok(Self::Document),
+			"option" => Ok(Self::Option),
+			"function" => Ok(Self::Function),
+			"analyzer" => Ok(Self::Analyzer),
+			"parameter" => Ok(Self::Parameter),
+			"model" => Ok(Self::Model),
+			"event" => Ok(Self::Event),
+			"field" => Ok(Self::Field),
+			"index" => Ok(Self::Index),
+			"access" => Ok(Self::Access),
+			"actor" => Ok(Self::Actor),
+			"sequence" => Ok(Self::Sequence),
+			_ => Err(Error::InvalidResource(s.to_string())),
 		}
 	}
 }
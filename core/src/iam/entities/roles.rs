@@ -5,8 +5,11 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-// In the future, we will allow for custom roles. For now, provide predefined roles.
-#[revisioned(revision = 1)]
+/// The roles which can be assigned to a system user.
+///
+/// Besides the built-in roles, a user can hold custom roles created with `DEFINE ROLE`.
+/// The grants of a custom role are resolved when the user authenticates.
+#[revisioned(revision = 2)]
 #[derive(Hash, Clone, Default, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -15,6 +18,8 @@ pub enum Role {
 	Viewer,
 	Editor,
 	Owner,
+	#[revision(start = 2)]
+	Custom(String),
 }
 
 impl std::fmt::Display for Role {
@@ -23,6 +28,7 @@ impl std::fmt::Display for Role {
 			Self::Viewer => write!(f, "Viewer"),
 			Self::Editor => write!(f, "Editor"),
 			Self::Owner => write!(f, "Owner"),
+			Self::Custom(name) => write!(f, "{name}"),
 		}
 	}
 }
@@ -39,9 +45,16 @@ impl FromStr for Role {
 	}
 }
 
+impl Role {
+	/// Checks if this is one of the built-in roles
+	pub fn is_builtin(&self) -> bool {
+		!matches!(self, Self::Custom(_))
+	}
+}
+
 impl std::convert::From<&Ident> for Role {
 	fn from(id: &Ident) -> Self {
-		Role::from_str(id).unwrap()
+		Role::from_str(id).unwrap_or_else(|_| Role::Custom(id.to_string()))
 	}
 }
 
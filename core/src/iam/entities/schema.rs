@@ -43,6 +43,7 @@ pub static DEFAULT_CEDAR_SCHEMA: Lazy<serde_json::Value> = Lazy::new(|| {
 					"Function": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
 					"Analyzer": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
 					"Parameter": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
+					"Sequence": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
 					"Event": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
 					"Field": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
 					"Index": {"shape": {"type": "Resource"}, "memberOfTypes": ["Level"]},
@@ -66,16 +67,80 @@ pub static DEFAULT_CEDAR_SCHEMA: Lazy<serde_json::Value> = Lazy::new(|| {
 					"View": {
 						"appliesTo": {
 							"principalTypes": [ "Actor" ],
-							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Event", "Field", "Index", "Access", "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
 
 						},
 					},
 					"Edit": {
 						"appliesTo": {
 							"principalTypes": [ "Actor" ],
-							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Event", "Field", "Index", "Access", "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
 						},
 					},
+					// Fine-grained actions, grouped under View and Edit
+					"Read": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "View" } ],
+					},
+					"Export": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "View" } ],
+					},
+					"Kill": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "View" } ],
+					},
+					"Create": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
+					"Update": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
+					"Delete": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
+					"Define": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
+					"Remove": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
+					"Import": {
+						"appliesTo": {
+							"principalTypes": [ "Actor" ],
+							"resourceTypes": [ "Any", "Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Sequence", "Event", "Field", "Index", "Access", "Actor" ],
+						},
+						"memberOf": [ { "id": "Edit" } ],
+					},
 				},
 			}
 		}
@@ -93,6 +158,6 @@ mod tests {
 	#[test]
 	fn test_default_schema() {
 		let schema = default_schema();
-		assert_eq!(schema.action_entities().unwrap().iter().count(), 2);
+		assert_eq!(schema.action_entities().unwrap().iter().count(), 11);
 	}
 }
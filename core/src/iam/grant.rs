@@ -0,0 +1,57 @@
+use super::{Auth, Level, Role};
+use crate::err::Error;
+use crate::kvs::{Datastore, LockType::*, TransactionType::*};
+
+/// Resolves the grants of the custom roles held by an authenticated system user.
+///
+/// Custom roles are looked up on the same level as the user. A role which
+/// no longer exists grants nothing.
+pub async fn grants(kvs: &Datastore, auth: Auth) -> Result<Auth, Error> {
+	// Collect the names of the custom roles
+	let names: Vec<String> = auth
+		.roles()
+		.iter()
+		.filter_map(|r| match r {
+			Role::Custom(name) => Some(name.to_owned()),
+			_ => None,
+		})
+		.collect();
+	// Built-in roles don't need to be resolved
+	if names.is_empty() {
+		return Ok(auth);
+	}
+	// Create a new readonly transaction
+	let tx = kvs.transaction(Read, Optimistic).await?;
+	// Fetch the role definitions
+	let mut grants = Vec::new();
+	for name in names.iter() {
+		let role = match auth.level() {
+			Level::Root => tx.get_root_role(name).await,
+			Level::Namespace(ns) => tx.get_ns_role(ns, name).await,
+			Level::Database(ns, db) => tx.get_db_role(ns, db, name).await,
+			_ => continue,
+		};
+		match role {
+			Ok(role) => grants.extend(role.grants.iter().cloned()),
+			Err(Error::RoleRootNotFound {
+				..
+			})
+			| Err(Error::RoleNsNotFound {
+				..
+			})
+			| Err(Error::RoleDbNotFound {
+				..
+			}) => {
+				trace!("Ignoring undefined role `{name}` for user `{}`", auth.id());
+			}
+			Err(e) => {
+				tx.cancel().await?;
+				return Err(e);
+			}
+		}
+	}
+	// Ensure that the transaction is cancelled
+	tx.cancel().await?;
+	// Attach the grants to the authenticated actor
+	Ok(auth.with_grants(grants))
+}
@@ -7,10 +7,12 @@ pub mod base;
 pub mod check;
 pub mod clear;
 pub mod entities;
+pub mod grant;
 pub mod issue;
 #[cfg(feature = "jwks")]
 pub mod jwks;
 pub mod policies;
+pub mod refresh;
 pub mod signin;
 pub mod signup;
 pub mod token;
@@ -25,6 +27,12 @@ pub enum Error {
 	#[error("Invalid role '{0}'")]
 	InvalidRole(String),
 
+	#[error("Invalid action '{0}'")]
+	InvalidAction(String),
+
+	#[error("Invalid resource '{0}'")]
+	InvalidResource(String),
+
 	#[error("Not enough permissions to perform this action")]
 	NotAllowed {
 		actor: String,
@@ -1,10 +1,17 @@
-use cedar_policy::{Authorizer, Context, Decision, Entities, Entity, EntityUid, Request, Response};
+use cedar_policy::{
+	Authorizer, Context, Decision, Entities, Entity, EntityUid, Policy, PolicySet, Request,
+	Response,
+};
+use once_cell::sync::Lazy;
+use quick_cache::sync::{Cache, GuardResult};
+use std::sync::Arc;
 
 mod policy_set;
 
 use policy_set::*;
 
-use crate::iam::{Action, Actor, Resource};
+use crate::cnf::POLICY_CACHE_SIZE;
+use crate::iam::{Action, Actor, Grant, Resource};
 
 /// Checks if the actor is allowed to do the action on the resource, given the context and based on the default policy set.
 pub fn is_allowed(
@@ -13,15 +20,55 @@ pub fn is_allowed(
 	resource: &Resource,
 	context: Context,
 ) -> (bool, Response) {
+	let mut entities = _get_entities(Some(actor), Some(resource));
+	// The action entity carries the action group it belongs to
+	entities.push(action.into());
 	_is_allowed(
 		Some(actor.into()),
 		Some(action.into()),
 		Some(resource.into()),
-		Entities::from_entities(_get_entities(Some(actor), Some(resource))).unwrap(),
+		Entities::from_entities(entities).unwrap(),
 		context,
+		&_get_policy_set(actor.grants()),
 	)
 }
 
+/// Returns the default policy set, extended with the grants of custom roles.
+///
+/// The policy sets are cached by their grants, so that the policies of the
+/// grants are not parsed again on every permission check.
+fn _get_policy_set(grants: &[Grant]) -> Arc<PolicySet> {
+	static POLICY_SETS: Lazy<Cache<Vec<Grant>, Arc<PolicySet>>> =
+		Lazy::new(|| Cache::new(*POLICY_CACHE_SIZE));
+	match POLICY_SETS.get_value_or_guard(grants, None) {
+		GuardResult::Value(v) => v,
+		GuardResult::Guard(g) => {
+			let policy_set = Arc::new(_new_policy_set(grants));
+			g.insert(policy_set.clone()).ok();
+			policy_set
+		}
+		GuardResult::Timeout => {
+			warn!("Policy cache timeout");
+			Arc::new(_new_policy_set(grants))
+		}
+	}
+}
+
+fn _new_policy_set(grants: &[Grant]) -> PolicySet {
+	let mut policy_set = POLICY_SET.to_owned();
+	for (i, grant) in grants.iter().enumerate() {
+		match Policy::parse(Some(format!("grant{i}")), grant.cedar_policy()) {
+			Ok(policy) => {
+				if let Err(e) = policy_set.add(policy) {
+					warn!("Failed to add the policy for grant `{grant}`: {e}");
+				}
+			}
+			Err(e) => warn!("Failed to parse the policy for grant `{grant}`: {e}"),
+		}
+	}
+	policy_set
+}
+
 fn _get_entities(actor: Option<&Actor>, resource: Option<&Resource>) -> Vec<Entity> {
 	let mut entities = Vec::new();
 	if let Some(actor) = actor {
@@ -39,14 +86,13 @@ fn _is_allowed(
 	resource: Option<EntityUid>,
 	entities: Entities,
 	context: Context,
+	policy_set: &PolicySet,
 ) -> (bool, Response) {
-	let policy_set = POLICY_SET.to_owned();
-
 	let authorizer = Authorizer::new();
 
 	let req = Request::new(actor, action, resource, context);
 
-	let res = authorizer.is_authorized(&req, &policy_set, &entities);
+	let res = authorizer.is_authorized(&req, policy_set, &entities);
 
 	(res.decision() == Decision::Allow, res)
 }
@@ -55,7 +101,7 @@ fn _is_allowed(
 mod tests {
 	use cedar_policy::{ValidationMode, ValidationResult, Validator};
 
-	use crate::iam::{default_schema, entities::Level, ResourceKind, Role};
+	use crate::iam::{default_schema, entities::Level, Grant, ResourceKind, Role};
 
 	use super::*;
 
@@ -92,5 +138,54 @@ mod tests {
 
 		let (allowed, _) = is_allowed(&actor, &Action::View, &res, Context::empty());
 		assert!(!allowed);
+
+		// Returns true if a built-in role is allowed to do a fine-grained action in its action group
+		let actor = Actor::new("test".into(), vec![Role::Editor], Level::Root);
+		let res = ResourceKind::Table.on_db("test", "test");
+
+		let (allowed, _) = is_allowed(&actor, &Action::Define, &res, Context::empty());
+		assert!(allowed);
+	}
+
+	#[test]
+	fn test_is_allowed_with_grants() {
+		let actor = Actor::new(
+			"test".into(),
+			vec![Role::Custom("writer".into())],
+			Level::Namespace("test".into()),
+		)
+		.with_grants(vec![Grant::new(
+			vec![Action::Read, Action::Create],
+			vec![ResourceKind::Record, ResourceKind::Table],
+		)]);
+
+		// Returns true if a grant covers the action on the resource
+		let res = ResourceKind::Record.on_db("test", "test");
+		let (allowed, _) = is_allowed(&actor, &Action::Create, &res, Context::empty());
+		assert!(allowed);
+
+		// Returns false if no grant covers the action
+		let res = ResourceKind::Table.on_db("test", "test");
+		let (allowed, _) = is_allowed(&actor, &Action::Define, &res, Context::empty());
+		assert!(!allowed);
+
+		// Returns false if no grant covers the resource
+		let res = ResourceKind::Function.on_db("test", "test");
+		let (allowed, _) = is_allowed(&actor, &Action::Read, &res, Context::empty());
+		assert!(!allowed);
+
+		// Returns false if the resource is outside the actor's level
+		let res = ResourceKind::Record.on_db("other", "test");
+		let (allowed, _) = is_allowed(&actor, &Action::Read, &res, Context::empty());
+		assert!(!allowed);
+	}
+
+	#[test]
+	fn test_policy_set_cache() {
+		let grants = vec![Grant::new(vec![Action::Read], vec![ResourceKind::Record])];
+		// The policy set of the same grants is only built once
+		let policy_set = _get_policy_set(&grants);
+		assert!(Arc::ptr_eq(&policy_set, &_get_policy_set(&grants)));
+		assert_eq!(policy_set.policies().count(), POLICY_SET.policies().count() + 1);
 	}
 }
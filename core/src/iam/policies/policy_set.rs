@@ -9,7 +9,7 @@ pub static POLICY_SET: Lazy<PolicySet> = Lazy::new(|| {
     // All roles can view all resources on the same level hierarchy or below
     permit(
         principal,
-        action == Action::"View",
+        action in Action::"View",
         resource
     ) when {
         principal.roles.containsAny([Role::"Viewer", Role::"Editor", Role::"Owner"]) &&
@@ -19,18 +19,18 @@ pub static POLICY_SET: Lazy<PolicySet> = Lazy::new(|| {
     // Editor role can edit all non-IAM resources on the same level hierarchy or below
     permit(
         principal,
-        action == Action::"Edit",
+        action in Action::"Edit",
         resource
     ) when {
         principal.roles.contains(Role::"Editor") &&
         resource.level in principal.level &&
-        ["Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Event", "Field", "Index"].contains(resource.type)
+        ["Namespace", "Database", "Record", "Table", "Document", "Option", "Function", "Analyzer", "Parameter", "Event", "Field", "Index", "Sequence"].contains(resource.type)
     };
 
     // Owner role can edit all resources on the same level hierarchy or below
     permit(
         principal,
-        action == Action::"Edit",
+        action in Action::"Edit",
         resource
     ) when {
         principal.roles.contains(Role::"Owner") &&
@@ -0,0 +1,762 @@
+use super::verify::authenticate_record;
+use super::{Actor, Level};
+use crate::cnf::SERVER_NAME;
+use crate::dbs::Session;
+use crate::err::Error;
+use crate::iam::issue::{config, expiration};
+use crate::iam::token::{Claims, Token};
+use crate::iam::Auth;
+use crate::kvs::{Datastore, LockType::*, Transaction, TransactionType::*};
+use crate::sql::statements::access::{self, AccessGrant, Grant, GrantBearer, Subject};
+use crate::sql::statements::DefineAccessStatement;
+use crate::sql::AccessType;
+use crate::sql::Datetime;
+use crate::sql::Object;
+use crate::sql::Thing;
+use crate::sql::Value;
+use chrono::Utc;
+use jsonwebtoken::{encode, Header};
+use std::sync::Arc;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+/// Exchanges a refresh token for new tokens.
+///
+/// Only database `TYPE RECORD` access methods defined `WITH REFRESH` issue refresh
+/// tokens, and `WITH REFRESH` can not be defined on any other access method. A
+/// refresh token is stored as a grant of the database access method, and is bound
+/// to the record which signed in, so that the record can be checked again when the
+/// token is exchanged. Root and namespace access methods have no such record, `JWT`
+/// access methods only verify tokens which were issued elsewhere, and `BEARER` access
+/// methods already issue long-lived keys, so a refresh for any of them is rejected
+/// with an access method mismatch, and the client must sign in again instead.
+pub async fn refresh(kvs: &Datastore, session: &mut Session, vars: Object) -> Result<Token, Error> {
+	// Parse the specified variables
+	let ns = vars.get("NS").or_else(|| vars.get("ns"));
+	let db = vars.get("DB").or_else(|| vars.get("db"));
+	let ac = vars.get("AC").or_else(|| vars.get("ac"));
+	// Check if the parameters exist
+	match (ns, db, ac) {
+		(Some(ns), Some(db), Some(ac)) => {
+			// Process the provided values
+			let ns = ns.to_raw_string();
+			let db = db.to_raw_string();
+			let ac = ac.to_raw_string();
+			// Attempt to refresh using specified access method
+			// Currently, refresh tokens are only issued at the database level
+			super::refresh::db_access(kvs, session, ns, db, ac, vars).await
+		}
+		// Root and namespace access methods do not issue refresh tokens
+		(_, None, Some(_)) => Err(Error::AccessMethodMismatch),
+		_ => Err(Error::NoSigninTarget),
+	}
+}
+
+pub async fn db_access(
+	kvs: &Datastore,
+	session: &mut Session,
+	ns: String,
+	db: String,
+	ac: String,
+	vars: Object,
+) -> Result<Token, Error> {
+	// Extract key identifier and key from the provided variables
+	let (kid, key) = validate_grant_refresh(vars)?;
+	// Create a new readonly transaction
+	let tx = kvs.transaction(Read, Optimistic).await?;
+	// Fetch the specified access method from storage
+	let access = tx.get_db_access(&ns, &db, &ac).await;
+	// Ensure that the transaction is cancelled
+	tx.cancel().await?;
+	// Check the provided access method exists
+	let av = match access {
+		Ok(av) => av,
+		_ => return Err(Error::AccessNotFound),
+	};
+	// Check the access method type
+	// Currently, only the record access method supports refresh
+	let iss = match &av.kind {
+		AccessType::Record(at) => {
+			// Check if the record access method allows refresh
+			if !at.refresh {
+				return Err(Error::AccessRecordNoRefresh);
+			}
+			// Check if the record access method supports issuing tokens
+			match &at.jwt.issue {
+				Some(iss) => iss.clone(),
+				_ => return Err(Error::AccessMethodMismatch),
+			}
+		}
+		_ => return Err(Error::AccessMethodMismatch),
+	};
+	// Create a new readonly transaction
+	let tx = kvs.transaction(Read, Optimistic).await?;
+	// Fetch the specified access grant from storage
+	let gr = match tx.get_db_access_grant(&ns, &db, &ac, &kid).await {
+		Ok(gr) => gr,
+		// Return opaque error to avoid leaking existence of the grant
+		_ => {
+			tx.cancel().await?;
+			return Err(Error::InvalidAuth);
+		}
+	};
+	// Refresh tokens are always issued for a record
+	let rid = match &gr.subject {
+		Some(Subject::Record(rid)) => rid.clone(),
+		// Return opaque error as this code should not be reachable
+		_ => {
+			tx.cancel().await?;
+			return Err(Error::InvalidAuth);
+		}
+	};
+	// Fetch the record which the refresh token was issued for
+	let record = tx.get_record(&ns, &db, &rid.tb, &rid.id).await;
+	// Ensure that the transaction is cancelled
+	tx.cancel().await?;
+	// Check if the refresh token has been revoked
+	if gr.revocation.is_some() {
+		// Return opaque error to avoid leaking revocation status
+		return Err(Error::InvalidAuth);
+	}
+	// Check if the refresh token has expired
+	if let Some(exp) = &gr.expiration {
+		if exp < &Datetime::default() {
+			// The refresh token can no longer be exchanged, so it is removed
+			discard(kvs, &ns, &db, &ac, &kid).await?;
+			// Return opaque error to avoid leaking expiration status
+			return Err(Error::InvalidAuth);
+		}
+	}
+	// Authenticate refresh token against stored grant
+	if verify_grant_refresh(&gr, &key).is_err() {
+		// The key identifier is only known to holders of a refresh token issued for
+		// this grant, so the token has already been exchanged and may have been stolen
+		warn!("Revoking refresh token for record `{rid}` after detecting refresh token reuse");
+		discard(kvs, &ns, &db, &ac, &kid).await?;
+		// Return opaque error to avoid leaking revocation status
+		return Err(Error::InvalidAuth);
+	}
+	// Check that the record still exists
+	if !matches!(record, Ok(v) if v.is_some()) {
+		// The refresh token can no longer be exchanged, so it is removed
+		discard(kvs, &ns, &db, &ac, &kid).await?;
+		// Return opaque error to avoid leaking record existence
+		return Err(Error::InvalidAuth);
+	}
+	// Create the authentication key
+	let enc_key = config(iss.alg, &iss.key)?;
+	// Create the authentication claim
+	let claims = Claims {
+		iss: Some(SERVER_NAME.to_owned()),
+		iat: Some(Utc::now().timestamp()),
+		nbf: Some(Utc::now().timestamp()),
+		exp: expiration(av.duration.token)?,
+		jti: Some(Uuid::new_v4().to_string()),
+		ns: Some(ns.to_owned()),
+		db: Some(db.to_owned()),
+		ac: Some(ac.to_owned()),
+		id: Some(rid.to_raw()),
+		..Claims::default()
+	};
+	// AUTHENTICATE clause
+	let mut auth = rid.clone();
+	if let Some(au) = &av.authenticate {
+		// Setup the system session for executing the clause
+		let mut sess = Session::editor().with_ns(&ns).with_db(&db);
+		sess.rd = Some(rid.clone().into());
+		sess.tk = Some((&claims).into());
+		sess.ip.clone_from(&session.ip);
+		sess.or.clone_from(&session.or);
+		auth = authenticate_record(kvs, &sess, au).await?;
+	}
+	// Create a new writeable transaction
+	let tx = kvs.transaction(Write, Optimistic).await?;
+	// Rotate the refresh token, so that it can only be exchanged once
+	let refresh = match rotate(&tx, &ns, &db, &ac, &kid, &key).await {
+		Ok(refresh) => refresh,
+		Err(e) => {
+			tx.cancel().await?;
+			return Err(e);
+		}
+	};
+	// Concurrent exchanges of the same refresh token will conflict here
+	tx.commit().await?;
+	// Log the authenticated access method info
+	trace!("Refreshing token for database with access method `{}`", ac);
+	// Create the authentication token
+	let enc = encode(&Header::new(iss.alg.into()), &claims, &enc_key);
+	// Set the authentication on the session
+	session.tk = Some((&claims).into());
+	session.ns = Some(ns.to_owned());
+	session.db = Some(db.to_owned());
+	session.ac = Some(ac.to_owned());
+	session.rd = Some(Value::from(auth.to_owned()));
+	session.exp = expiration(av.duration.session)?;
+	session.au = Arc::new(Auth::new(Actor::new(
+		auth.to_string(),
+		Default::default(),
+		Level::Record(ns, db, auth.to_string()),
+	)));
+	// Check the authentication token
+	match enc {
+		// The auth token was created successfully
+		Ok(tk) => Ok(Token::WithRefresh {
+			access: tk,
+			refresh,
+		}),
+		_ => Err(Error::TokenMakingFailed),
+	}
+}
+
+/// Issues and stores a new refresh token for a record authenticated with a record access method
+pub(super) async fn issue(
+	kvs: &Datastore,
+	ns: &str,
+	db: &str,
+	av: &DefineAccessStatement,
+	rid: &Thing,
+) -> Result<String, Error> {
+	// Create a new writeable transaction
+	let tx = kvs.transaction(Write, Optimistic).await?;
+	// Store the new refresh token grant
+	let refresh = match store(&tx, ns, db, av, rid).await {
+		Ok(refresh) => refresh,
+		Err(e) => {
+			tx.cancel().await?;
+			return Err(e);
+		}
+	};
+	tx.commit().await?;
+	Ok(refresh)
+}
+
+/// Replaces the key of a refresh token, so that the previous key can not be exchanged again
+async fn rotate(
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+	ac: &str,
+	kid: &str,
+	key: &str,
+) -> Result<String, Error> {
+	// Fetch the refresh token grant again within this transaction
+	let mut gr = (*tx.get_db_access_grant(ns, db, ac, kid).await?).clone();
+	// The refresh token was revoked or exchanged since it was verified
+	if gr.revocation.is_some() || verify_grant_refresh(&gr, key).is_err() {
+		// Return opaque error to avoid leaking revocation status
+		return Err(Error::InvalidAuth);
+	}
+	// Replace the key, keeping the identifier and the original expiration
+	let grant = match &gr.grant {
+		Grant::Bearer(grant) => grant.rotate_refresh(),
+		// Return opaque error as this code should not be reachable
+		_ => return Err(Error::InvalidAuth),
+	};
+	let refresh = grant.key.0.clone();
+	gr.grant = Grant::Bearer(grant);
+	let key = crate::key::database::access::gr::new(ns, db, ac, kid);
+	tx.set(key, &gr, None).await?;
+	Ok(refresh)
+}
+
+/// Stores a new refresh token grant for a record and returns the refresh token
+async fn store(
+	tx: &Transaction,
+	ns: &str,
+	db: &str,
+	av: &DefineAccessStatement,
+	rid: &Thing,
+) -> Result<String, Error> {
+	// Create a new refresh token
+	let grant = GrantBearer::new_refresh();
+	let refresh = grant.key.0.clone();
+	let gr = AccessGrant {
+		ac: av.name.clone(),
+		// Unique grant identifier.
+		// In the case of refresh tokens, the key identifier, which is kept when rotated.
+		id: grant.id.clone(),
+		// Current time.
+		creation: Datetime::default(),
+		// Current time plus grant duration. Only if set.
+		// This is not extended when the refresh token is rotated.
+		expiration: av.duration.grant.map(|d| d + Datetime::default()),
+		// The grant is initially not revoked.
+		revocation: None,
+		// Subject associated with the grant.
+		subject: Some(Subject::Record(rid.to_owned())),
+		// The contents of the grant.
+		grant: Grant::Bearer(grant),
+	};
+	let ac_str = gr.ac.to_raw();
+	let gr_str = gr.id.to_raw();
+	let key = crate::key::database::access::gr::new(ns, db, &ac_str, &gr_str);
+	tx.set(key, &gr, None).await?;
+	Ok(refresh)
+}
+
+/// Removes the refresh token grants which have expired at the given time
+pub(crate) async fn purge_expired(kvs: &Datastore, now: &Datetime) -> Result<(), Error> {
+	// Fetch all expired refresh token grants
+	let mut expired = Vec::new();
+	let tx = kvs.transaction(Read, Optimistic).await?;
+	for ns in catch!(tx, tx.all_ns()).iter() {
+		for db in catch!(tx, tx.all_db(&ns.name)).iter() {
+			for av in catch!(tx, tx.all_db_accesses(&ns.name, &db.name)).iter() {
+				// Refresh tokens are only issued by record access methods
+				if !matches!(av.kind, AccessType::Record(_)) {
+					continue;
+				}
+				let ac = av.name.to_raw();
+				for gr in catch!(tx, tx.all_db_access_grants(&ns.name, &db.name, &ac)).iter() {
+					let refresh = match &gr.grant {
+						Grant::Bearer(grant) => grant.key.starts_with(access::GRANT_REFRESH_PREFIX),
+						_ => false,
+					};
+					if refresh && gr.expiration.as_ref().is_some_and(|exp| exp < now) {
+						expired.push((
+							ns.name.to_raw(),
+							db.name.to_raw(),
+							ac.clone(),
+							gr.id.to_raw(),
+						));
+					}
+				}
+			}
+		}
+	}
+	tx.cancel().await?;
+	// Remove each grant on its own, so that one failure doesn't prevent the others
+	for (ns, db, ac, kid) in expired.iter() {
+		if let Err(e) = discard(kvs, ns, db, ac, kid).await {
+			warn!("Failed to remove the expired refresh token grant '{kid}' of access method '{ac}' in {ns}/{db}: {e}");
+		}
+	}
+	Ok(())
+}
+
+/// Removes a refresh token grant which can no longer be exchanged
+async fn discard(kvs: &Datastore, ns: &str, db: &str, ac: &str, kid: &str) -> Result<(), Error> {
+	// Create a new writeable transaction
+	let tx = kvs.transaction(Write, Optimistic).await?;
+	let key = crate::key::database::access::gr::new(ns, db, ac, kid);
+	if let Err(e) = tx.del(key).await {
+		tx.cancel().await?;
+		return Err(e);
+	}
+	tx.commit().await
+}
+
+pub fn validate_grant_refresh(vars: Object) -> Result<(String, String), Error> {
+	// Extract the provided refresh token.
+	let key = match vars.get("refresh") {
+		Some(key) => key.to_raw_string(),
+		None => return Err(Error::AccessRefreshMissingToken),
+	};
+	if key.len() != access::GRANT_REFRESH_LENGTH {
+		return Err(Error::AccessGrantRefreshInvalid);
+	}
+	// Retrieve the prefix from the provided refresh token.
+	let prefix: String = key.chars().take(access::GRANT_REFRESH_PREFIX.len()).collect();
+	// Check the prefix of the refresh token.
+	if prefix != access::GRANT_REFRESH_PREFIX {
+		return Err(Error::AccessGrantRefreshInvalid);
+	}
+	// Retrieve the key identifier from the provided refresh token.
+	let kid: String = key
+		.chars()
+		.skip(access::GRANT_REFRESH_PREFIX.len() + 1)
+		.take(access::GRANT_BEARER_ID_LENGTH)
+		.collect();
+	// Check the length of the key identifier.
+	if kid.len() != access::GRANT_BEARER_ID_LENGTH {
+		return Err(Error::AccessGrantRefreshInvalid);
+	};
+
+	Ok((kid, key))
+}
+
+fn verify_grant_refresh(gr: &AccessGrant, key: &str) -> Result<(), Error> {
+	// Check if the provided refresh token matches the key in the grant.
+	// We use time-constant comparison to prevent timing attacks.
+	match &gr.grant {
+		Grant::Bearer(grant) => {
+			let grant_key_bytes: &[u8] = grant.key.as_bytes();
+			let refresh_key_bytes: &[u8] = key.as_bytes();
+			let ok: bool = grant_key_bytes.ct_eq(refresh_key_bytes).into();
+			if !ok {
+				return Err(Error::InvalidAuth);
+			}
+		}
+		// Return opaque error as this code should not be reachable
+		_ => return Err(Error::InvalidAuth),
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::HashMap;
+
+	async fn signin_record(ds: &Datastore) -> Result<Token, Error> {
+		let mut sess = Session {
+			ns: Some("test".to_string()),
+			db: Some("test".to_string()),
+			..Default::default()
+		};
+		let mut vars: HashMap<&str, Value> = HashMap::new();
+		vars.insert("user", "user".into());
+		vars.insert("pass", "pass".into());
+		super::super::signin::db_access(
+			ds,
+			&mut sess,
+			"test".to_string(),
+			"test".to_string(),
+			"user".to_string(),
+			vars.into(),
+		)
+		.await
+	}
+
+	async fn refresh_record(ds: &Datastore, refresh: &str) -> (Session, Result<Token, Error>) {
+		let mut sess = Session {
+			ns: Some("test".to_string()),
+			db: Some("test".to_string()),
+			..Default::default()
+		};
+		let mut vars: HashMap<&str, Value> = HashMap::new();
+		vars.insert("refresh", refresh.into());
+		let res = db_access(
+			ds,
+			&mut sess,
+			"test".to_string(),
+			"test".to_string(),
+			"user".to_string(),
+			vars.into(),
+		)
+		.await;
+		(sess, res)
+	}
+
+	async fn setup(refresh: bool) -> Datastore {
+		let ds = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let sql = format!(
+			r#"
+			DEFINE ACCESS user ON DATABASE TYPE RECORD
+				SIGNIN (
+					SELECT * FROM user WHERE name = $user AND crypto::argon2::compare(pass, $pass)
+				)
+				{}
+				DURATION FOR GRANT 30d, FOR SESSION 2h
+			;
+
+			CREATE user:test CONTENT {{
+				name: 'user',
+				pass: crypto::argon2::generate('pass')
+			}}
+			"#,
+			if refresh {
+				"WITH REFRESH"
+			} else {
+				""
+			}
+		);
+		ds.execute(&sql, &sess, None).await.unwrap();
+		ds
+	}
+
+	#[tokio::test]
+	async fn test_refresh_record() {
+		// Test exchanging a refresh token for new tokens
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let refresh = tk.refresh().expect("Signin is expected to issue a refresh token");
+			assert!(refresh.starts_with(access::GRANT_REFRESH_PREFIX));
+
+			let (sess, res) = refresh_record(&ds, refresh).await;
+			assert!(res.is_ok(), "Failed to refresh with refresh token: {:?}", res);
+			let tk = res.unwrap();
+			assert_ne!(tk.refresh(), Some(refresh), "Refresh token is expected to be rotated");
+			assert_eq!(sess.ns, Some("test".to_string()));
+			assert_eq!(sess.db, Some("test".to_string()));
+			assert_eq!(sess.ac, Some("user".to_string()));
+			assert_eq!(sess.au.id(), "user:test");
+			assert!(sess.au.is_record());
+			assert_eq!(sess.au.level().id(), Some("user:test"));
+			// Session expiration should match the defined duration
+			let exp = sess.exp.unwrap();
+			let max_sess_exp = (Utc::now() + chrono::Duration::hours(2)).timestamp() + 10;
+			assert!(
+				exp < max_sess_exp,
+				"Session expiration is expected to follow the defined duration"
+			);
+
+			// The rotated refresh token can be exchanged again
+			let (_, res) = refresh_record(&ds, tk.refresh().unwrap()).await;
+			assert!(res.is_ok(), "Failed to refresh with rotated refresh token: {:?}", res);
+		}
+
+		// Test reusing a refresh token which has already been exchanged
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let first = tk.refresh().unwrap().to_owned();
+			let (_, res) = refresh_record(&ds, &first).await;
+			let second = res.unwrap().refresh().unwrap().to_owned();
+
+			// The exchanged refresh token can not be used again
+			let (_, res) = refresh_record(&ds, &first).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => {
+					panic!("Expected reused refresh token to fail, but instead received: {:?}", res)
+				}
+			}
+			// The reuse revokes the refresh token which replaced it
+			let (_, res) = refresh_record(&ds, &second).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => panic!(
+					"Expected revoked refresh token to fail, but instead received: {:?}",
+					res
+				),
+			}
+			// The refresh token grant has been removed
+			let sess = Session::owner().with_ns("test").with_db("test");
+			let res = ds
+				.execute("ACCESS user LIST", &sess, None)
+				.await
+				.unwrap()
+				.remove(0)
+				.result
+				.unwrap();
+			let Value::Array(grants) = res else {
+				panic!("Expected a list of grants, but instead received: {:?}", res);
+			};
+			assert!(grants.is_empty(), "Expected no grants, but instead received: {:?}", grants);
+		}
+
+		// Test rotating a refresh token does not extend its expiration
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let refresh = tk.refresh().unwrap().to_owned();
+			let (kid, _) = validate_grant_refresh(
+				map! {
+					"refresh".to_string() => Value::from(refresh.as_str()),
+				}
+				.into(),
+			)
+			.unwrap();
+			let tx = ds.transaction(Read, Optimistic).await.unwrap();
+			let before = tx.get_db_access_grant("test", "test", "user", &kid).await.unwrap();
+			tx.cancel().await.unwrap();
+			let (_, res) = refresh_record(&ds, &refresh).await;
+			assert!(res.is_ok(), "Failed to refresh with refresh token: {:?}", res);
+			let tx = ds.transaction(Read, Optimistic).await.unwrap();
+			let after = tx.get_db_access_grant("test", "test", "user", &kid).await.unwrap();
+			tx.cancel().await.unwrap();
+			assert_eq!(before.id, after.id);
+			assert_eq!(before.creation, after.creation);
+			assert_eq!(before.expiration, after.expiration);
+			assert_ne!(before.grant, after.grant);
+		}
+
+		// Test refresh tokens revoked with the access statement
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let refresh = tk.refresh().unwrap();
+			let (kid, _) = validate_grant_refresh(
+				map! {
+					"refresh".to_string() => Value::from(refresh),
+				}
+				.into(),
+			)
+			.unwrap();
+			let sess = Session::owner().with_ns("test").with_db("test");
+			ds.execute(&format!("ACCESS user REVOKE `{kid}`"), &sess, None)
+				.await
+				.unwrap()
+				.remove(0)
+				.result
+				.unwrap();
+			let (_, res) = refresh_record(&ds, refresh).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => panic!(
+					"Expected revoked refresh token to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+	}
+
+	async fn list_grants(ds: &Datastore) -> usize {
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let res = ds.execute("ACCESS user LIST", &sess, None).await.unwrap();
+		match res.into_iter().next().unwrap().result.unwrap() {
+			Value::Array(grants) => grants.len(),
+			res => panic!("Expected a list of grants, but instead received: {:?}", res),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_refresh_record_purge_expired() {
+		let ds = setup(true).await;
+		let tk = signin_record(&ds).await.unwrap();
+		let refresh = tk.refresh().unwrap().to_owned();
+		// The refresh token grant is kept until it expires
+		purge_expired(&ds, &Datetime::default()).await.unwrap();
+		assert_eq!(list_grants(&ds).await, 1);
+		let (_, res) = refresh_record(&ds, &refresh).await;
+		let refresh = res.unwrap().refresh().unwrap().to_owned();
+		// The refresh token grant is removed once it has expired
+		let later = Utc::now() + chrono::Duration::days(31);
+		purge_expired(&ds, &later.into()).await.unwrap();
+		assert_eq!(list_grants(&ds).await, 0);
+		let (_, res) = refresh_record(&ds, &refresh).await;
+		match res {
+			Err(Error::InvalidAuth) => {} // ok
+			res => panic!("Expected purged refresh token to fail, but instead received: {:?}", res),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_refresh_record_invalid() {
+		// Test with an access method which does not allow refresh
+		{
+			let ds = setup(false).await;
+			let tk = signin_record(&ds).await.unwrap();
+			assert!(tk.refresh().is_none(), "Signin is not expected to issue a refresh token");
+
+			let (_, res) =
+				refresh_record(&ds, "surreal-refresh-aaaaaaaaaaaa-aaaaaaaaaaaaaaaaaaaaaaaa").await;
+			match res {
+				Err(Error::AccessRecordNoRefresh) => {} // ok
+				res => panic!("Expected refresh to be disallowed, but instead received: {:?}", res),
+			}
+		}
+
+		// Test with a refresh token with an invalid format
+		{
+			let ds = setup(true).await;
+			let (_, res) =
+				refresh_record(&ds, "surreal-bearer-aaaaaaaaaaaa-aaaaaaaaaaaaaaaaaaaaaaaa").await;
+			match res {
+				Err(Error::AccessGrantRefreshInvalid) => {} // ok
+				res => panic!("Expected invalid refresh token, but instead received: {:?}", res),
+			}
+		}
+
+		// Test with a refresh token which does not exist
+		{
+			let ds = setup(true).await;
+			let (_, res) =
+				refresh_record(&ds, "surreal-refresh-aaaaaaaaaaaa-aaaaaaaaaaaaaaaaaaaaaaaa").await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => panic!(
+					"Expected unknown refresh token to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+
+		// Test with a refresh token with an incorrect key
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let refresh = tk.refresh().unwrap();
+			let forged = format!("{}{}", &refresh[..refresh.len() - 1], "!");
+			let (_, res) = refresh_record(&ds, &forged).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => {
+					panic!("Expected forged refresh token to fail, but instead received: {:?}", res)
+				}
+			}
+			// The key identifier can only be known from a refresh token which was issued,
+			// so the refresh token which shares it is revoked as well
+			let (_, res) = refresh_record(&ds, refresh).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => panic!(
+					"Expected revoked refresh token to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+
+		// Test with a refresh token for a record which no longer exists
+		{
+			let ds = setup(true).await;
+			let tk = signin_record(&ds).await.unwrap();
+			let refresh = tk.refresh().unwrap();
+			let sess = Session::owner().with_ns("test").with_db("test");
+			ds.execute("DELETE user:test", &sess, None).await.unwrap().remove(0).result.unwrap();
+			let (_, res) = refresh_record(&ds, refresh).await;
+			match res {
+				Err(Error::InvalidAuth) => {} // ok
+				res => panic!(
+					"Expected refresh token for a deleted record to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn test_refresh_system_access() {
+		let ds = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let sql = "
+			DEFINE ACCESS user ON ROOT TYPE JWT ALGORITHM HS512 KEY 'secret';
+			DEFINE ACCESS user ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY 'secret';
+			DEFINE ACCESS token ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'secret';
+		";
+		for res in ds.execute(sql, &sess, None).await.unwrap() {
+			res.result.unwrap();
+		}
+		let refresh = "surreal-refresh-aaaaaaaaaaaa-aaaaaaaaaaaaaaaaaaaaaaaa";
+		// Test with root and namespace access methods
+		for ns in [None, Some("test")] {
+			let mut vars: HashMap<&str, Value> = HashMap::new();
+			if let Some(ns) = ns {
+				vars.insert("ns", ns.into());
+			}
+			vars.insert("ac", "user".into());
+			vars.insert("refresh", refresh.into());
+			let res = super::refresh(&ds, &mut Session::default(), vars.into()).await;
+			match res {
+				Err(Error::AccessMethodMismatch) => {} // ok
+				res => panic!(
+					"Expected refresh with a system access method to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+		// Test with a database access method which is not a record access method
+		{
+			let mut vars: HashMap<&str, Value> = HashMap::new();
+			vars.insert("ns", "test".into());
+			vars.insert("db", "test".into());
+			vars.insert("ac", "token".into());
+			vars.insert("refresh", refresh.into());
+			let res = super::refresh(&ds, &mut Session::default(), vars.into()).await;
+			match res {
+				Err(Error::AccessMethodMismatch) => {} // ok
+				res => panic!(
+					"Expected refresh with a JWT access method to fail, but instead received: {:?}",
+					res
+				),
+			}
+		}
+		// Test that refresh can not be defined on other access methods
+		let sql = "DEFINE ACCESS other ON ROOT TYPE JWT ALGORITHM HS512 KEY 'secret' WITH REFRESH";
+		assert!(ds.execute(sql, &sess, None).await.is_err());
+	}
+}
@@ -1,3 +1,4 @@
+use super::grant::grants;
 use super::verify::{
 	authenticate_generic, authenticate_record, verify_db_creds, verify_ns_creds, verify_root_creds,
 };
@@ -6,7 +7,7 @@ use crate::cnf::{EXPERIMENTAL_BEARER_ACCESS, INSECURE_FORWARD_ACCESS_ERRORS, SER
 use crate::dbs::Session;
 use crate::err::Error;
 use crate::iam::issue::{config, expiration};
-use crate::iam::token::{Claims, HEADER};
+use crate::iam::token::{Claims, Token, HEADER};
 use crate::iam::Auth;
 use crate::kvs::{Datastore, LockType::*, TransactionType::*};
 use crate::sql::statements::{access, AccessGrant};
@@ -20,7 +21,7 @@ use std::sync::Arc;
 use subtle::ConstantTimeEq;
 use uuid::Uuid;
 
-pub async fn signin(kvs: &Datastore, session: &mut Session, vars: Object) -> Result<String, Error> {
+pub async fn signin(kvs: &Datastore, session: &mut Session, vars: Object) -> Result<Token, Error> {
 	// Parse the specified variables
 	let ns = vars.get("NS").or_else(|| vars.get("ns"));
 	let db = vars.get("DB").or_else(|| vars.get("db"));
@@ -112,7 +113,7 @@ pub async fn db_access(
 	db: String,
 	ac: String,
 	vars: Object,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	// Create a new readonly transaction
 	let tx = kvs.transaction(Read, Optimistic).await?;
 	// Fetch the specified access method from storage
@@ -164,6 +165,8 @@ pub async fn db_access(
 												id: Some(rid.to_raw()),
 												..Claims::default()
 											};
+											// The refresh token is issued for the record used in the token
+											let subject = rid.clone();
 											// AUTHENTICATE clause
 											if let Some(au) = &av.authenticate {
 												// Setup the system session for finding the signin record
@@ -180,6 +183,17 @@ pub async fn db_access(
 												"Signing in to database with access method `{}`",
 												ac
 											);
+											// Issue a refresh token if the access method allows it
+											let refresh = if at.refresh {
+												Some(
+													super::refresh::issue(
+														kvs, &ns, &db, &av, &subject,
+													)
+													.await?,
+												)
+											} else {
+												None
+											};
 											// Create the authentication token
 											let enc =
 												encode(&Header::new(iss.alg.into()), &claims, &key);
@@ -198,7 +212,13 @@ pub async fn db_access(
 											// Check the authentication token
 											match enc {
 												// The auth token was created successfully
-												Ok(tk) => Ok(tk),
+												Ok(tk) => Ok(match refresh {
+													Some(refresh) => Token::WithRefresh {
+														access: tk,
+														refresh,
+													},
+													None => Token::Access(tk),
+												}),
 												_ => Err(Error::TokenMakingFailed),
 											}
 										}
@@ -305,11 +325,17 @@ pub async fn db_access(
 					session.exp = expiration(av.duration.session)?;
 					match &gr.subject {
 						Some(access::Subject::User(user)) => {
-							session.au = Arc::new(Auth::new(Actor::new(
-								user.to_string(),
-								roles.iter().map(Role::from).collect(),
-								Level::Database(ns, db),
-							)));
+							session.au = Arc::new(
+								grants(
+									kvs,
+									Auth::new(Actor::new(
+										user.to_string(),
+										roles.iter().map(Role::from).collect(),
+										Level::Database(ns, db),
+									)),
+								)
+								.await?,
+							);
 						}
 						Some(access::Subject::Record(rid)) => {
 							session.au = Arc::new(Auth::new(Actor::new(
@@ -325,7 +351,7 @@ pub async fn db_access(
 					// Check the authentication token.
 					match enc {
 						// The authentication token was created successfully.
-						Ok(tk) => Ok(tk),
+						Ok(tk) => Ok(Token::Access(tk)),
 						_ => Err(Error::TokenMakingFailed),
 					}
 				}
@@ -343,7 +369,7 @@ pub async fn db_user(
 	db: String,
 	user: String,
 	pass: String,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	match verify_db_creds(kvs, &ns, &db, &user, &pass).await {
 		Ok(u) => {
 			// Create the authentication key
@@ -369,11 +395,13 @@ pub async fn db_user(
 			session.ns = Some(ns.to_owned());
 			session.db = Some(db.to_owned());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Database(ns.to_owned(), db.to_owned())).into());
+			session.au = Arc::new(
+				grants(kvs, (&u, Level::Database(ns.to_owned(), db.to_owned())).into()).await?,
+			);
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
-				Ok(tk) => Ok(tk),
+				Ok(tk) => Ok(Token::Access(tk)),
 				_ => Err(Error::TokenMakingFailed),
 			}
 		}
@@ -387,7 +415,7 @@ pub async fn ns_access(
 	ns: String,
 	ac: String,
 	vars: Object,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	// Create a new readonly transaction
 	let tx = kvs.transaction(Read, Optimistic).await?;
 	// Fetch the specified access method from storage
@@ -485,11 +513,17 @@ pub async fn ns_access(
 					session.exp = expiration(av.duration.session)?;
 					match &gr.subject {
 						Some(access::Subject::User(user)) => {
-							session.au = Arc::new(Auth::new(Actor::new(
-								user.to_string(),
-								roles.iter().map(Role::from).collect(),
-								Level::Namespace(ns),
-							)));
+							session.au = Arc::new(
+								grants(
+									kvs,
+									Auth::new(Actor::new(
+										user.to_string(),
+										roles.iter().map(Role::from).collect(),
+										Level::Namespace(ns),
+									)),
+								)
+								.await?,
+							);
 						}
 						// Return opaque error as this code should not be reachable.
 						_ => return Err(Error::InvalidAuth),
@@ -497,7 +531,7 @@ pub async fn ns_access(
 					// Check the authentication token.
 					match enc {
 						// The authentication token was created successfully.
-						Ok(tk) => Ok(tk),
+						Ok(tk) => Ok(Token::Access(tk)),
 						_ => Err(Error::TokenMakingFailed),
 					}
 				}
@@ -514,7 +548,7 @@ pub async fn ns_user(
 	ns: String,
 	user: String,
 	pass: String,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	match verify_ns_creds(kvs, &ns, &user, &pass).await {
 		Ok(u) => {
 			// Create the authentication key
@@ -538,11 +572,11 @@ pub async fn ns_user(
 			session.tk = Some((&val).into());
 			session.ns = Some(ns.to_owned());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Namespace(ns.to_owned())).into());
+			session.au = Arc::new(grants(kvs, (&u, Level::Namespace(ns.to_owned())).into()).await?);
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
-				Ok(tk) => Ok(tk),
+				Ok(tk) => Ok(Token::Access(tk)),
 				_ => Err(Error::TokenMakingFailed),
 			}
 		}
@@ -556,7 +590,7 @@ pub async fn root_user(
 	session: &mut Session,
 	user: String,
 	pass: String,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	match verify_root_creds(kvs, &user, &pass).await {
 		Ok(u) => {
 			// Create the authentication key
@@ -578,11 +612,11 @@ pub async fn root_user(
 			// Set the authentication on the session
 			session.tk = Some(val.into());
 			session.exp = expiration(u.duration.session)?;
-			session.au = Arc::new((&u, Level::Root).into());
+			session.au = Arc::new(grants(kvs, (&u, Level::Root).into()).await?);
 			// Check the authentication token
 			match enc {
 				// The auth token was created successfully
-				Ok(tk) => Ok(tk),
+				Ok(tk) => Ok(Token::Access(tk)),
 				_ => Err(Error::TokenMakingFailed),
 			}
 		}
@@ -596,7 +630,7 @@ pub async fn root_access(
 	session: &mut Session,
 	ac: String,
 	vars: Object,
-) -> Result<String, Error> {
+) -> Result<Token, Error> {
 	// Create a new readonly transaction
 	let tx = kvs.transaction(Read, Optimistic).await?;
 	// Fetch the specified access method from storage
@@ -692,11 +726,17 @@ pub async fn root_access(
 					session.exp = expiration(av.duration.session)?;
 					match &gr.subject {
 						Some(access::Subject::User(user)) => {
-							session.au = Arc::new(Auth::new(Actor::new(
-								user.to_string(),
-								roles.iter().map(Role::from).collect(),
-								Level::Root,
-							)));
+							session.au = Arc::new(
+								grants(
+									kvs,
+									Auth::new(Actor::new(
+										user.to_string(),
+										roles.iter().map(Role::from).collect(),
+										Level::Root,
+									)),
+								)
+								.await?,
+							);
 						}
 						// Return opaque error as this code should not be reachable.
 						_ => return Err(Error::InvalidAuth),
@@ -704,7 +744,7 @@ pub async fn root_access(
 					// Check the authentication token.
 					match enc {
 						// The authentication token was created successfully.
-						Ok(tk) => Ok(tk),
+						Ok(tk) => Ok(Token::Access(tk)),
 						_ => Err(Error::TokenMakingFailed),
 					}
 				}
@@ -1026,7 +1066,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 				let val = Validation::new(Algorithm::RS256);
 				// Check that token can be verified with the defined public key
 				let token_data = decode::<Claims>(
-					&tk,
+					tk.access(),
 					&DecodingKey::from_rsa_pem(public_key.as_ref()).unwrap(),
 					&val,
 				)
@@ -1141,7 +1181,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -1220,7 +1260,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -1339,7 +1379,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -1409,7 +1449,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -1511,7 +1551,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -1576,7 +1616,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 			// Decode token and check that it has been issued as intended
 			if let Ok(tk) = res {
 				// Decode token without validation
-				let token_data = decode::<Claims>(&tk, &DecodingKey::from_secret(&[]), &{
+				let token_data = decode::<Claims>(tk.access(), &DecodingKey::from_secret(&[]), &{
 					let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
 					validation.insecure_disable_signature_validation();
 					validation.validate_nbf = false;
@@ -2,7 +2,7 @@ use crate::cnf::{INSECURE_FORWARD_ACCESS_ERRORS, SERVER_NAME};
 use crate::dbs::Session;
 use crate::err::Error;
 use crate::iam::issue::{config, expiration};
-use crate::iam::token::Claims;
+use crate::iam::token::{Claims, Token};
 use crate::iam::Auth;
 use crate::iam::{Actor, Level};
 use crate::kvs::{Datastore, LockType::*, TransactionType::*};
@@ -18,7 +18,7 @@ pub async fn signup(
 	kvs: &Datastore,
 	session: &mut Session,
 	vars: Object,
-) -> Result<Option<String>, Error> {
+) -> Result<Option<Token>, Error> {
 	// Parse the specified variables
 	let ns = vars.get("NS").or_else(|| vars.get("ns"));
 	let db = vars.get("DB").or_else(|| vars.get("db"));
@@ -45,7 +45,7 @@ pub async fn db_access(
 	db: String,
 	ac: String,
 	vars: Object,
-) -> Result<Option<String>, Error> {
+) -> Result<Option<Token>, Error> {
 	// Create a new readonly transaction
 	let tx = kvs.transaction(Read, Optimistic).await?;
 	// Fetch the specified access method from storage
@@ -95,6 +95,8 @@ pub async fn db_access(
 												id: Some(rid.to_raw()),
 												..Claims::default()
 											};
+											// The refresh token is issued for the record used in the token
+											let subject = rid.clone();
 											// AUTHENTICATE clause
 											if let Some(au) = &av.authenticate {
 												// Setup the system session for finding the signin record
@@ -124,6 +126,17 @@ pub async fn db_access(
 											}
 											// Log the authenticated access method info
 											trace!("Signing up with access method `{}`", ac);
+											// Issue a refresh token if the access method allows it
+											let refresh = if at.refresh {
+												Some(
+													super::refresh::issue(
+														kvs, &ns, &db, &av, &subject,
+													)
+													.await?,
+												)
+											} else {
+												None
+											};
 											// Create the authentication token
 											let enc =
 												encode(&Header::new(iss.alg.into()), &claims, &key);
@@ -142,7 +155,13 @@ pub async fn db_access(
 											// Check the authentication token
 											match enc {
 												// The auth token was created successfully
-												Ok(tk) => Ok(Some(tk)),
+												Ok(tk) => Ok(Some(match refresh {
+													Some(refresh) => Token::WithRefresh {
+														access: tk,
+														refresh,
+													},
+													None => Token::Access(tk),
+												})),
 												_ => Err(Error::TokenMakingFailed),
 											}
 										}
@@ -411,7 +430,7 @@ dn/RsYEONbwQSjIfMPkvxF+8HQ==
 				let val = Validation::new(Algorithm::RS256);
 				// Check that token can be verified with the defined public key
 				let token_data = decode::<Claims>(
-					&tk,
+					tk.access(),
 					&DecodingKey::from_rsa_pem(public_key.as_ref()).unwrap(),
 					&val,
 				)
@@ -236,3 +236,57 @@ impl From<&Claims> for Value {
 		out.into()
 	}
 }
+
+/// The tokens issued after successfully authenticating with an access method
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Token {
+	/// A single access token
+	Access(String),
+	/// An access token together with a refresh token, which can
+	/// be exchanged once for a new pair of tokens
+	WithRefresh {
+		access: String,
+		refresh: String,
+	},
+}
+
+impl Token {
+	/// Returns the access token
+	pub fn access(&self) -> &str {
+		match self {
+			Token::Access(access) => access,
+			Token::WithRefresh {
+				access,
+				..
+			} => access,
+		}
+	}
+	/// Returns the refresh token, if one was issued
+	pub fn refresh(&self) -> Option<&str> {
+		match self {
+			Token::Access(_) => None,
+			Token::WithRefresh {
+				refresh,
+				..
+			} => Some(refresh),
+		}
+	}
+}
+
+impl From<Token> for Value {
+	fn from(v: Token) -> Value {
+		match v {
+			// A single access token is returned as a string
+			Token::Access(access) => access.into(),
+			// Otherwise both tokens are returned in an object
+			Token::WithRefresh {
+				access,
+				refresh,
+			} => Value::from(map! {
+				"token".to_string() => access.into(),
+				"refresh".to_string() => refresh.into(),
+			}),
+		}
+	}
+}
@@ -1,6 +1,7 @@
 use crate::cnf::INSECURE_FORWARD_ACCESS_ERRORS;
 use crate::dbs::Session;
 use crate::err::Error;
+use crate::iam::grant::grants;
 #[cfg(feature = "jwks")]
 use crate::iam::jwks;
 use crate::iam::{issue::expiration, token::Claims, Actor, Auth, Level, Role};
@@ -95,7 +96,9 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as database user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au = Arc::new((&u, Level::Database(ns.to_owned(), db.to_owned())).into());
+				session.au = Arc::new(
+					grants(kvs, (&u, Level::Database(ns.to_owned(), db.to_owned())).into()).await?,
+				);
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -105,7 +108,8 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as namespace user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au = Arc::new((&u, Level::Namespace(ns.to_owned())).into());
+				session.au =
+					Arc::new(grants(kvs, (&u, Level::Namespace(ns.to_owned())).into()).await?);
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -115,7 +119,7 @@ pub async fn basic(
 			Ok(u) => {
 				debug!("Authenticated as root user '{}'", user);
 				session.exp = expiration(u.duration.session)?;
-				session.au = Arc::new((&u, Level::Root).into());
+				session.au = Arc::new(grants(kvs, (&u, Level::Root).into()).await?);
 				Ok(())
 			}
 			Err(err) => Err(err),
@@ -358,11 +362,17 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			session.ns = Some(ns.to_owned());
 			session.db = Some(db.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(|r| r.into()).collect(),
-				Level::Database(ns.to_string(), db.to_string()),
-			)));
+			session.au = Arc::new(
+				grants(
+					kvs,
+					Auth::new(Actor::new(
+						id.to_string(),
+						de.roles.iter().map(|r| r.into()).collect(),
+						Level::Database(ns.to_string(), db.to_string()),
+					)),
+				)
+				.await?,
+			);
 			Ok(())
 		}
 		// Check if this is namespace access
@@ -460,11 +470,17 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			session.tk = Some(value);
 			session.ns = Some(ns.to_owned());
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(|r| r.into()).collect(),
-				Level::Namespace(ns.to_string()),
-			)));
+			session.au = Arc::new(
+				grants(
+					kvs,
+					Auth::new(Actor::new(
+						id.to_string(),
+						de.roles.iter().map(|r| r.into()).collect(),
+						Level::Namespace(ns.to_string()),
+					)),
+				)
+				.await?,
+			);
 			Ok(())
 		}
 		// Check if this is root access
@@ -554,11 +570,17 @@ pub async fn token(kvs: &Datastore, session: &mut Session, token: &str) -> Resul
 			// Set the session
 			session.tk = Some(value);
 			session.exp = expiration(de.duration.session)?;
-			session.au = Arc::new(Auth::new(Actor::new(
-				id.to_string(),
-				de.roles.iter().map(|r| r.into()).collect(),
-				Level::Root,
-			)));
+			session.au = Arc::new(
+				grants(
+					kvs,
+					Auth::new(Actor::new(
+						id.to_string(),
+						de.roles.iter().map(|r| r.into()).collect(),
+						Level::Root,
+					)),
+				)
+				.await?,
+			);
 			Ok(())
 		}
 		// There was an auth error
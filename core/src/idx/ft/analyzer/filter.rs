@@ -1,10 +1,13 @@
 use crate::err::Error;
+use crate::idx::ft::analyzer::stopwords::stopwords;
 use crate::idx::ft::analyzer::tokenizer::Tokens;
 use crate::idx::ft::offsets::Position;
 use crate::sql::filter::Filter as SqlFilter;
 use crate::sql::language::Language;
 use deunicode::deunicode;
 use rust_stemmers::{Algorithm, Stemmer};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Copy)]
 pub(super) enum FilteringStage {
@@ -18,6 +21,8 @@ pub(super) enum Filter {
 	EdgeNgram(u16, u16),
 	Lowercase,
 	Uppercase,
+	Stopwords(HashSet<String>),
+	Synonyms(HashMap<String, String>),
 }
 
 impl From<&SqlFilter> for Filter {
@@ -50,6 +55,24 @@ impl From<&SqlFilter> for Filter {
 				Filter::Stemmer(a)
 			}
 			SqlFilter::Uppercase => Filter::Uppercase,
+			SqlFilter::Stopwords(l) => {
+				Filter::Stopwords(stopwords(l).iter().map(|w| w.to_string()).collect())
+			}
+			SqlFilter::StopwordList(words) => {
+				Filter::Stopwords(words.iter().map(|w| w.to_lowercase()).collect())
+			}
+			SqlFilter::Synonyms(groups) => {
+				// Every term of a group is replaced by the first term of the group
+				let mut synonyms = HashMap::new();
+				for group in groups {
+					if let Some(first) = group.first() {
+						for w in group {
+							synonyms.insert(w.to_lowercase(), first.clone());
+						}
+					}
+				}
+				Filter::Synonyms(synonyms)
+			}
 		}
 	}
 }
@@ -95,6 +118,35 @@ impl Filter {
 			Filter::Ngram(min, max) => Self::ngram(c, *min, *max),
 			Filter::Stemmer(s) => Self::stem(s, c),
 			Filter::Uppercase => Self::uppercase(c),
+			Filter::Stopwords(words) => Self::stopwords(words, c),
+			Filter::Synonyms(synonyms) => Self::synonyms(synonyms, c),
+		}
+	}
+
+	/// Stop words and synonyms are matched case insensitively
+	#[inline]
+	fn case_insensitive(c: &str) -> Cow<'_, str> {
+		if c.chars().any(char::is_uppercase) {
+			Cow::Owned(c.to_lowercase())
+		} else {
+			Cow::Borrowed(c)
+		}
+	}
+
+	#[inline]
+	fn stopwords(words: &HashSet<String>, c: &str) -> FilterResult {
+		if words.contains(Self::case_insensitive(c).as_ref()) {
+			FilterResult::Ignore
+		} else {
+			FilterResult::Term(Term::Unchanged)
+		}
+	}
+
+	#[inline]
+	fn synonyms(synonyms: &HashMap<String, String>, c: &str) -> FilterResult {
+		match synonyms.get(Self::case_insensitive(c).as_ref()) {
+			Some(s) => Self::check_term(c, s.clone()),
+			None => FilterResult::Term(Term::Unchanged),
 		}
 	}
 
@@ -842,4 +894,34 @@ mod tests {
 		)
 		.await;
 	}
+
+	#[tokio::test]
+	async fn test_stopwords() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank,class FILTERS lowercase,stopwords(english);",
+			"The quick brown fox jumps over the lazy dog",
+			&["quick", "brown", "fox", "jumps", "lazy", "dog"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_stopword_list() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank FILTERS stopwords('Foo', 'bar');",
+			"foo Bar baz",
+			&["baz"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_synonyms() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank FILTERS synonyms(['tv', 'television'], ['car', 'automobile', 'auto']);",
+			"Television AND auto",
+			&["tv", "AND", "car"],
+		)
+		.await;
+	}
 }
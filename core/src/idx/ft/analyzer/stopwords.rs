@@ -0,0 +1,997 @@
+use crate::sql::language::Language;
+
+/// Returns the built-in list of stop words of a language.
+/// The lists are lowercase, and are mostly derived from the Snowball project.
+pub(super) fn stopwords(l: &Language) -> &'static [&'static str] {
+	match l {
+		Language::Arabic => ARABIC,
+		Language::Danish => DANISH,
+		Language::Dutch => DUTCH,
+		Language::English => ENGLISH,
+		Language::French => FRENCH,
+		Language::German => GERMAN,
+		Language::Greek => GREEK,
+		Language::Hungarian => HUNGARIAN,
+		Language::Italian => ITALIAN,
+		Language::Norwegian => NORWEGIAN,
+		Language::Portuguese => PORTUGUESE,
+		Language::Romanian => ROMANIAN,
+		Language::Russian => RUSSIAN,
+		Language::Spanish => SPANISH,
+		Language::Swedish => SWEDISH,
+		Language::Tamil => TAMIL,
+		Language::Turkish => TURKISH,
+	}
+}
+
+const ARABIC: &[&str] = &[
+	"في",
+	"من",
+	"على",
+	"إلى",
+	"عن",
+	"مع",
+	"هذا",
+	"هذه",
+	"ذلك",
+	"تلك",
+	"التي",
+	"الذي",
+	"الذين",
+	"هو",
+	"هي",
+	"هم",
+	"هن",
+	"أنا",
+	"نحن",
+	"أنت",
+	"أنتم",
+	"كان",
+	"كانت",
+	"يكون",
+	"لم",
+	"لن",
+	"لا",
+	"ما",
+	"ماذا",
+	"متى",
+	"أين",
+	"كيف",
+	"إن",
+	"أن",
+	"أو",
+	"ثم",
+	"بل",
+	"لكن",
+	"قد",
+	"كل",
+	"بعض",
+	"غير",
+	"بين",
+	"عند",
+	"حتى",
+	"إذا",
+	"منذ",
+	"و",
+	"ف",
+	"ب",
+	"ل",
+	"ك",
+];
+
+const DANISH: &[&str] = &[
+	"og", "i", "jeg", "det", "at", "en", "den", "til", "er", "som", "på", "de", "med", "han", "af",
+	"for", "ikke", "der", "var", "mig", "sig", "men", "et", "har", "om", "vi", "min", "havde",
+	"ham", "hun", "nu", "over", "da", "fra", "du", "ud", "sin", "dem", "os", "op", "man", "hans",
+	"hvor", "eller", "hvad", "skal", "selv", "her", "alle", "vil", "blev", "kunne", "ind", "når",
+	"være", "dog", "noget", "ville", "jo", "deres", "efter", "ned", "skulle", "denne", "end",
+	"dette", "mit", "også", "under", "have", "dig", "anden", "hende", "mine", "alt", "meget",
+	"sit", "sine", "vor", "mod", "disse", "hvis", "din", "nogle", "hos", "blive", "mange", "ad",
+	"bliver", "hendes", "været", "thi", "jer", "sådan",
+];
+
+const DUTCH: &[&str] = &[
+	"de", "en", "van", "ik", "te", "dat", "die", "in", "een", "hij", "het", "niet", "zijn", "is",
+	"was", "op", "aan", "met", "als", "voor", "had", "er", "maar", "om", "hem", "dan", "zou", "of",
+	"wat", "mijn", "men", "dit", "zo", "door", "over", "ze", "zich", "bij", "ook", "tot", "je",
+	"mij", "uit", "der", "daar", "haar", "naar", "heb", "hoe", "heeft", "hebben", "deze", "u",
+	"want", "nog", "zal", "me", "zij", "nu", "ge", "geen", "omdat", "iets", "worden", "toch", "al",
+	"waren", "veel", "meer", "doen", "toen", "moet", "ben", "zonder", "kan", "hun", "dus", "alles",
+	"onder", "ja", "eens", "hier", "wie", "werd", "altijd", "doch", "wordt", "wezen", "kunnen",
+	"ons", "zelf", "tegen", "na", "reeds", "wil", "kon", "niets", "uw", "iemand", "geweest",
+	"andere",
+];
+
+const ENGLISH: &[&str] = &[
+	"i",
+	"me",
+	"my",
+	"myself",
+	"we",
+	"our",
+	"ours",
+	"ourselves",
+	"you",
+	"your",
+	"yours",
+	"yourself",
+	"yourselves",
+	"he",
+	"him",
+	"his",
+	"himself",
+	"she",
+	"her",
+	"hers",
+	"herself",
+	"it",
+	"its",
+	"itself",
+	"they",
+	"them",
+	"their",
+	"theirs",
+	"themselves",
+	"what",
+	"which",
+	"who",
+	"whom",
+	"this",
+	"that",
+	"these",
+	"those",
+	"am",
+	"is",
+	"are",
+	"was",
+	"were",
+	"be",
+	"been",
+	"being",
+	"have",
+	"has",
+	"had",
+	"having",
+	"do",
+	"does",
+	"did",
+	"doing",
+	"would",
+	"should",
+	"could",
+	"ought",
+	"a",
+	"an",
+	"the",
+	"and",
+	"but",
+	"if",
+	"or",
+	"because",
+	"as",
+	"until",
+	"while",
+	"of",
+	"at",
+	"by",
+	"for",
+	"with",
+	"about",
+	"against",
+	"between",
+	"into",
+	"through",
+	"during",
+	"before",
+	"after",
+	"above",
+	"below",
+	"to",
+	"from",
+	"up",
+	"down",
+	"in",
+	"out",
+	"on",
+	"off",
+	"over",
+	"under",
+	"again",
+	"further",
+	"then",
+	"once",
+	"here",
+	"there",
+	"when",
+	"where",
+	"why",
+	"how",
+	"all",
+	"any",
+	"both",
+	"each",
+	"few",
+	"more",
+	"most",
+	"other",
+	"some",
+	"such",
+	"no",
+	"nor",
+	"not",
+	"only",
+	"own",
+	"same",
+	"so",
+	"than",
+	"too",
+	"very",
+];
+
+const FRENCH: &[&str] = &[
+	"au", "aux", "avec", "ce", "ces", "dans", "de", "des", "du", "elle", "en", "et", "eux", "il",
+	"je", "la", "le", "les", "leur", "lui", "ma", "mais", "me", "même", "mes", "moi", "mon", "ne",
+	"nos", "notre", "nous", "on", "ou", "par", "pas", "pour", "qu", "que", "qui", "sa", "se",
+	"ses", "son", "sur", "ta", "te", "tes", "toi", "ton", "tu", "un", "une", "vos", "votre",
+	"vous", "c", "d", "j", "l", "à", "m", "n", "s", "t", "y", "été", "étée", "étées", "étés",
+	"étant", "suis", "es", "est", "sommes", "êtes", "sont", "serai", "sera", "serons", "seront",
+	"étais", "était", "étions", "étiez", "étaient", "fus", "fut", "ai", "as", "avons", "avez",
+	"ont", "aurai", "aura", "avais", "avait", "avions", "aviez", "avaient", "eu", "eut", "ayant",
+];
+
+const GERMAN: &[&str] = &[
+	"aber", "alle", "allem", "allen", "aller", "alles", "als", "also", "am", "an", "ander",
+	"andere", "anderem", "anderen", "anderer", "anderes", "auch", "auf", "aus", "bei", "bin",
+	"bis", "bist", "da", "damit", "dann", "der", "den", "des", "dem", "die", "das", "dass",
+	"derselbe", "dich", "dir", "du", "dies", "diese", "diesem", "diesen", "dieser", "dieses",
+	"doch", "dort", "durch", "ein", "eine", "einem", "einen", "einer", "eines", "er", "es", "euer",
+	"eure", "für", "hatte", "hatten", "hattest", "hattet", "hier", "hin", "hinter", "ich", "ihr",
+	"ihre", "im", "in", "ist", "jede", "jedem", "jeden", "jeder", "jedes", "jene", "jetzt", "kann",
+	"kein", "keine", "machen", "man", "manche", "mein", "meine", "mich", "mir", "mit", "muss",
+	"nach", "nicht", "nichts", "noch", "nun", "nur", "ob", "oder", "ohne", "sehr", "sein", "seine",
+	"sich", "sie", "sind", "so", "solche", "soll", "sollte", "sondern", "sonst", "über", "um",
+	"und", "uns", "unter", "viel", "vom", "von", "vor", "während", "war", "waren", "warst", "was",
+	"weg", "weil", "weiter", "welche", "wenn", "werde", "werden", "wie", "wieder", "will", "wir",
+	"wird", "wirst", "wo", "wollen", "wollte", "würde", "würden", "zu", "zum", "zur", "zwar",
+	"zwischen",
+];
+
+const GREEK: &[&str] = &[
+	"ο",
+	"η",
+	"το",
+	"οι",
+	"τα",
+	"του",
+	"της",
+	"των",
+	"τον",
+	"την",
+	"και",
+	"κι",
+	"κ",
+	"ειμαι",
+	"εισαι",
+	"ειναι",
+	"ειμαστε",
+	"ειστε",
+	"στο",
+	"στον",
+	"στη",
+	"στην",
+	"μα",
+	"αλλα",
+	"απο",
+	"για",
+	"προς",
+	"με",
+	"σε",
+	"ως",
+	"παρα",
+	"αντι",
+	"κατα",
+	"μετα",
+	"θα",
+	"να",
+	"δε",
+	"δεν",
+	"μη",
+	"μην",
+	"επι",
+	"ενω",
+	"εαν",
+	"αν",
+	"τοτε",
+	"που",
+	"πως",
+	"ποιος",
+	"ποια",
+	"ποιο",
+	"οπως",
+	"ομως",
+	"ισως",
+	"οσο",
+	"οτι",
+	"ένα",
+	"μια",
+];
+
+const HUNGARIAN: &[&str] = &[
+	"a",
+	"ahogy",
+	"ahol",
+	"aki",
+	"akik",
+	"akkor",
+	"alatt",
+	"által",
+	"általában",
+	"amely",
+	"amelyek",
+	"amelyekben",
+	"amelyeket",
+	"amelyet",
+	"amelynek",
+	"ami",
+	"amit",
+	"amolyan",
+	"amíg",
+	"amikor",
+	"át",
+	"abban",
+	"ahhoz",
+	"annak",
+	"arra",
+	"arról",
+	"az",
+	"azok",
+	"azon",
+	"azt",
+	"azzal",
+	"azért",
+	"aztán",
+	"azután",
+	"azonban",
+	"bár",
+	"be",
+	"belül",
+	"benne",
+	"cikk",
+	"cikkek",
+	"cikkeket",
+	"csak",
+	"de",
+	"e",
+	"eddig",
+	"egész",
+	"egy",
+	"egyes",
+	"egyetlen",
+	"egyéb",
+	"egyik",
+	"egyre",
+	"ehhez",
+	"ekkor",
+	"el",
+	"elég",
+	"ellen",
+	"elő",
+	"először",
+	"előtt",
+	"első",
+	"én",
+	"éppen",
+	"ebben",
+	"erre",
+	"es",
+	"és",
+	"ez",
+	"ezek",
+	"ezen",
+	"ezt",
+	"ezzel",
+	"ezért",
+	"fel",
+	"felé",
+	"hanem",
+	"hiszen",
+	"hogy",
+	"hogyan",
+	"igen",
+	"így",
+	"ill",
+	"is",
+	"ismét",
+	"itt",
+	"jó",
+	"jól",
+	"kell",
+	"kellett",
+	"keresztül",
+	"ki",
+	"kívül",
+	"között",
+	"közül",
+	"le",
+	"lehet",
+	"lesz",
+	"lett",
+	"meg",
+	"mellett",
+	"mert",
+	"mi",
+	"mikor",
+	"milyen",
+	"minden",
+	"mint",
+	"mintha",
+	"mit",
+	"mivel",
+	"miért",
+	"most",
+	"nagy",
+	"nagyobb",
+	"nem",
+	"ne",
+	"néha",
+	"nincs",
+	"nélkül",
+	"olyan",
+	"ott",
+	"össze",
+	"ő",
+	"ők",
+	"őket",
+	"pedig",
+	"persze",
+	"rá",
+	"s",
+	"saját",
+	"sem",
+	"semmi",
+	"sok",
+	"sokat",
+	"sokkal",
+	"számára",
+	"szemben",
+	"szerint",
+	"szinte",
+	"talán",
+	"tehát",
+	"teljes",
+	"tovább",
+	"továbbá",
+	"több",
+	"úgy",
+	"ugyanis",
+	"új",
+	"újabb",
+	"újra",
+	"után",
+	"utána",
+	"utolsó",
+	"vagy",
+	"vagyis",
+	"valaki",
+	"valami",
+	"valamint",
+	"való",
+	"vagyok",
+	"van",
+	"vannak",
+	"volt",
+	"voltam",
+	"voltak",
+	"voltunk",
+	"vissza",
+	"vele",
+	"viszont",
+	"volna",
+];
+
+const ITALIAN: &[&str] = &[
+	"ad", "al", "allo", "ai", "agli", "all", "agl", "alla", "alle", "con", "col", "coi", "da",
+	"dal", "dallo", "dai", "dagli", "dall", "dagl", "dalla", "dalle", "di", "del", "dello", "dei",
+	"degli", "dell", "degl", "della", "delle", "in", "nel", "nello", "nei", "negli", "nell",
+	"negl", "nella", "nelle", "su", "sul", "sullo", "sui", "sugli", "sull", "sugl", "sulla",
+	"sulle", "per", "tra", "contro", "io", "tu", "lui", "lei", "noi", "voi", "loro", "mio", "mia",
+	"miei", "mie", "tuo", "tua", "tuoi", "tue", "suo", "sua", "suoi", "sue", "nostro", "nostra",
+	"nostri", "nostre", "vostro", "vostra", "vostri", "vostre", "mi", "ti", "ci", "vi", "lo", "la",
+	"li", "le", "gli", "ne", "il", "un", "uno", "una", "ma", "ed", "se", "perché", "anche", "come",
+	"dov", "dove", "che", "chi", "cui", "non", "più", "quale", "quanto", "quanti", "quanta",
+	"quante", "quello", "quelli", "quella", "quelle", "questo", "questi", "questa", "queste", "si",
+	"tutto", "tutti", "a", "c", "e", "i", "l", "o", "ho", "hai", "ha", "abbiamo", "avete", "hanno",
+	"sono", "sei", "è", "siamo", "siete", "era", "erano", "fu", "stato",
+];
+
+const NORWEGIAN: &[&str] = &[
+	"og", "i", "jeg", "det", "at", "en", "et", "den", "til", "er", "som", "på", "de", "med", "han",
+	"av", "ikke", "der", "så", "var", "meg", "seg", "men", "ett", "har", "om", "vi", "min", "mitt",
+	"ha", "hadde", "hun", "nå", "over", "da", "ved", "fra", "du", "ut", "sin", "dem", "oss", "opp",
+	"man", "kan", "hans", "hvor", "eller", "hva", "skal", "selv", "sjøl", "her", "alle", "vil",
+	"bli", "ble", "blitt", "kunne", "inn", "når", "være", "kom", "noen", "noe", "ville", "dere",
+	"deres", "kun", "ja", "etter", "ned", "skulle", "denne", "for", "deg", "si", "sine", "sitt",
+	"mot", "å", "meget", "hvorfor", "dette", "disse", "uten", "hvordan", "ingen", "din", "ditt",
+	"blir", "samme", "hvilken", "hvilke", "sånn", "inni", "mellom", "vår", "hver", "hvem", "vors",
+	"hvis", "både", "bare", "enn", "fordi", "før", "mange", "også", "slik", "vært", "båe",
+];
+
+const PORTUGUESE: &[&str] = &[
+	"de", "a", "o", "que", "e", "do", "da", "em", "um", "para", "com", "não", "uma", "os", "no",
+	"se", "na", "por", "mais", "as", "dos", "como", "mas", "ao", "ele", "das", "à", "seu", "sua",
+	"ou", "quando", "muito", "nos", "já", "eu", "também", "só", "pelo", "pela", "até", "isso",
+	"ela", "entre", "depois", "sem", "mesmo", "aos", "seus", "quem", "nas", "me", "esse", "eles",
+	"você", "essa", "num", "nem", "suas", "meu", "às", "minha", "numa", "pelos", "elas", "qual",
+	"nós", "lhe", "deles", "essas", "esses", "pelas", "este", "dele", "tu", "te", "vocês", "vos",
+	"lhes", "meus", "minhas", "teu", "tua", "teus", "tuas", "nosso", "nossa", "nossos", "nossas",
+	"dela", "delas", "esta", "estes", "estas", "aquele", "aquela", "aqueles", "aquelas", "isto",
+	"aquilo", "estou", "está", "estamos", "estão", "sou", "é", "somos", "são", "era", "foi", "tem",
+	"temos", "têm", "tinha", "há",
+];
+
+const ROMANIAN: &[&str] = &[
+	"a",
+	"acea",
+	"aceasta",
+	"această",
+	"aceea",
+	"acei",
+	"aceia",
+	"acel",
+	"acela",
+	"acele",
+	"acelea",
+	"acest",
+	"acesta",
+	"aceste",
+	"acestea",
+	"acestei",
+	"acestia",
+	"acestui",
+	"aceşti",
+	"aceştia",
+	"acolo",
+	"acum",
+	"ai",
+	"aia",
+	"aibă",
+	"aici",
+	"al",
+	"ale",
+	"alea",
+	"altceva",
+	"altcineva",
+	"am",
+	"ar",
+	"are",
+	"aş",
+	"aşadar",
+	"asta",
+	"ăla",
+	"ălea",
+	"ăsta",
+	"ăstea",
+	"ăştia",
+	"atât",
+	"atâta",
+	"atunci",
+	"au",
+	"avea",
+	"ca",
+	"care",
+	"către",
+	"ce",
+	"cea",
+	"cei",
+	"cel",
+	"cele",
+	"cine",
+	"cu",
+	"cum",
+	"da",
+	"dacă",
+	"dar",
+	"de",
+	"deci",
+	"deja",
+	"din",
+	"după",
+	"el",
+	"ea",
+	"ei",
+	"ele",
+	"eram",
+	"este",
+	"eu",
+	"fi",
+	"fie",
+	"fost",
+	"i",
+	"ia",
+	"iar",
+	"ii",
+	"în",
+	"îl",
+	"îmi",
+	"împotriva",
+	"încât",
+	"încă",
+	"înainte",
+	"între",
+	"întrucât",
+	"îţi",
+	"la",
+	"le",
+	"li",
+	"lor",
+	"lui",
+	"mai",
+	"mea",
+	"mei",
+	"mele",
+	"mereu",
+	"meu",
+	"mi",
+	"mie",
+	"mine",
+	"mult",
+	"multă",
+	"mulţi",
+	"ne",
+	"nici",
+	"nimic",
+	"noi",
+	"nostru",
+	"nouă",
+	"nu",
+	"o",
+	"oricare",
+	"orice",
+	"pe",
+	"pentru",
+	"prin",
+	"sa",
+	"să",
+	"sau",
+	"se",
+	"şi",
+	"sunt",
+	"suntem",
+	"sub",
+	"ta",
+	"tale",
+	"te",
+	"ţi",
+	"toată",
+	"toate",
+	"tot",
+	"toţi",
+	"tu",
+	"un",
+	"una",
+	"unde",
+	"unei",
+	"unele",
+	"uneori",
+	"unor",
+	"unui",
+	"vă",
+	"voi",
+	"vor",
+];
+
+const RUSSIAN: &[&str] = &[
+	"и",
+	"в",
+	"во",
+	"не",
+	"что",
+	"он",
+	"на",
+	"я",
+	"с",
+	"со",
+	"как",
+	"а",
+	"то",
+	"все",
+	"она",
+	"так",
+	"его",
+	"но",
+	"да",
+	"ты",
+	"к",
+	"у",
+	"же",
+	"вы",
+	"за",
+	"бы",
+	"по",
+	"только",
+	"ее",
+	"мне",
+	"было",
+	"вот",
+	"от",
+	"меня",
+	"еще",
+	"нет",
+	"о",
+	"из",
+	"ему",
+	"теперь",
+	"когда",
+	"даже",
+	"ну",
+	"вдруг",
+	"ли",
+	"если",
+	"уже",
+	"или",
+	"ни",
+	"быть",
+	"был",
+	"него",
+	"до",
+	"вас",
+	"нибудь",
+	"опять",
+	"уж",
+	"вам",
+	"ведь",
+	"там",
+	"потом",
+	"себя",
+	"ничего",
+	"ей",
+	"может",
+	"они",
+	"тут",
+	"где",
+	"есть",
+	"надо",
+	"ней",
+	"для",
+	"мы",
+	"тебя",
+	"их",
+	"чем",
+	"была",
+	"сам",
+	"чтоб",
+	"без",
+	"будто",
+	"чего",
+	"раз",
+	"тоже",
+	"себе",
+	"под",
+	"будет",
+	"ж",
+	"тогда",
+	"кто",
+	"этот",
+	"того",
+	"потому",
+	"этого",
+	"какой",
+	"совсем",
+	"ним",
+	"здесь",
+	"этом",
+	"один",
+	"почти",
+	"мой",
+	"тем",
+	"чтобы",
+	"нее",
+	"были",
+	"куда",
+	"зачем",
+	"всех",
+	"никогда",
+	"можно",
+	"при",
+	"наконец",
+	"два",
+	"об",
+	"другой",
+	"хоть",
+	"после",
+	"над",
+	"больше",
+	"тот",
+	"через",
+	"эти",
+	"нас",
+	"про",
+	"всего",
+	"них",
+	"какая",
+	"много",
+	"разве",
+	"три",
+	"эту",
+	"моя",
+	"впрочем",
+	"хорошо",
+	"свою",
+	"этой",
+	"перед",
+	"иногда",
+	"лучше",
+	"чуть",
+	"том",
+	"нельзя",
+	"такой",
+	"им",
+	"более",
+	"всегда",
+	"конечно",
+	"всю",
+	"между",
+];
+
+const SPANISH: &[&str] = &[
+	"de", "la", "que", "el", "en", "y", "a", "los", "del", "se", "las", "por", "un", "para", "con",
+	"no", "una", "su", "al", "lo", "como", "más", "pero", "sus", "le", "ya", "o", "este", "sí",
+	"porque", "esta", "entre", "cuando", "muy", "sin", "sobre", "también", "me", "hasta", "hay",
+	"donde", "quien", "desde", "todo", "nos", "durante", "todos", "uno", "les", "ni", "contra",
+	"otros", "ese", "eso", "ante", "ellos", "e", "esto", "mí", "antes", "algunos", "qué", "unos",
+	"yo", "otro", "otras", "otra", "él", "tanto", "esa", "estos", "mucho", "quienes", "nada",
+	"muchos", "cual", "poco", "ella", "estar", "estas", "algunas", "algo", "nosotros", "mi", "mis",
+	"tú", "te", "ti", "tu", "tus", "ellas", "nosotras", "vosotros", "vosotras", "os", "mío", "mía",
+	"míos", "mías", "tuyo", "tuya", "suyo", "suya", "nuestro", "nuestra", "vuestro", "vuestra",
+	"esos", "esas", "estoy", "está", "estamos", "están", "es", "son", "fue", "era", "ha", "han",
+	"he", "tiene", "tienen",
+];
+
+const SWEDISH: &[&str] = &[
+	"och", "det", "att", "i", "en", "jag", "hon", "som", "han", "på", "den", "med", "var", "sig",
+	"för", "så", "till", "är", "men", "ett", "om", "hade", "de", "av", "icke", "mig", "du",
+	"henne", "då", "sin", "nu", "har", "inte", "hans", "honom", "skulle", "hennes", "där", "min",
+	"man", "ej", "vid", "kunde", "något", "från", "ut", "när", "efter", "upp", "vi", "dem", "vara",
+	"vad", "över", "än", "dig", "kan", "sina", "här", "ha", "mot", "alla", "under", "någon",
+	"eller", "allt", "mycket", "sedan", "ju", "denna", "själv", "detta", "åt", "utan", "varit",
+	"hur", "ingen", "mitt", "ni", "bli", "blev", "oss", "din", "dessa", "några", "deras", "blir",
+	"mina", "samma", "vilken", "er", "sådan", "vår", "blivit", "dess", "inom", "mellan", "sådant",
+	"varför", "varje", "vilka", "ditt", "vem", "vilket", "sitta", "sådana", "vart", "dina", "vars",
+	"vårt", "våra", "ert", "era", "vilkas",
+];
+
+const TAMIL: &[&str] = &[
+	"ஒரு",
+	"என்று",
+	"மற்றும்",
+	"இந்த",
+	"இது",
+	"என்ற",
+	"கொண்டு",
+	"என்பது",
+	"பல",
+	"ஆகும்",
+	"அல்லது",
+	"அவர்",
+	"நான்",
+	"உள்ள",
+	"அந்த",
+	"இவர்",
+	"என",
+	"முதல்",
+	"என்ன",
+	"இருந்து",
+	"சில",
+	"என்",
+	"போன்ற",
+	"வேண்டும்",
+	"வந்து",
+	"இதன்",
+	"அது",
+	"அவன்",
+	"தான்",
+	"பலரும்",
+	"என்னும்",
+	"மேலும்",
+	"பின்னர்",
+	"கொண்ட",
+	"இருக்கும்",
+	"தனது",
+	"உள்ளது",
+	"போது",
+	"என்றும்",
+	"அதன்",
+	"தன்",
+	"பிறகு",
+	"அவர்கள்",
+	"வரை",
+	"அவள்",
+	"நீ",
+	"ஆகிய",
+	"இருந்தது",
+	"உள்ளன",
+	"வந்த",
+	"இருந்த",
+	"மிகவும்",
+	"இங்கு",
+	"மீது",
+	"ஓர்",
+	"இவை",
+	"இந்தக்",
+	"பற்றி",
+	"வரும்",
+	"வேறு",
+	"இரு",
+	"இதில்",
+	"போல்",
+	"இப்போது",
+	"அவரது",
+	"மட்டும்",
+	"இந்தப்",
+	"எனும்",
+	"மேல்",
+	"பின்",
+	"சேர்ந்த",
+	"ஆகியோர்",
+	"எனக்கு",
+	"இன்னும்",
+	"அந்தப்",
+	"அன்று",
+	"ஒரே",
+	"மிக",
+	"அங்கு",
+	"பல்வேறு",
+	"விட்டு",
+	"பெரும்",
+	"அதை",
+	"பற்றிய",
+	"உன்",
+	"அதிக",
+	"அந்தக்",
+	"பேர்",
+	"இதனால்",
+	"அவை",
+	"அதே",
+	"ஏன்",
+	"முறை",
+	"யார்",
+	"என்பதை",
+	"எல்லாம்",
+	"மட்டுமே",
+	"இங்கே",
+	"அங்கே",
+	"இடம்",
+	"இடத்தில்",
+	"அதில்",
+	"நாம்",
+	"அதற்கு",
+	"எனவே",
+	"பிற",
+	"சிறு",
+	"மற்ற",
+	"விட",
+	"எந்த",
+	"எனவும்",
+	"எனப்படும்",
+	"எனினும்",
+	"அடுத்த",
+	"இதனை",
+	"இதை",
+	"கொள்ள",
+	"இந்தத்",
+	"இதற்கு",
+	"அதனால்",
+	"தவிர",
+	"போல",
+	"வரையில்",
+	"சற்று",
+	"எனக்",
+];
+
+const TURKISH: &[&str] = &[
+	"acaba", "ama", "aslında", "az", "bazı", "belki", "biri", "birkaç", "birşey", "biz", "bu",
+	"çok", "çünkü", "da", "daha", "de", "defa", "diye", "eğer", "en", "gibi", "hem", "hep",
+	"hepsi", "her", "hiç", "için", "ile", "ise", "kez", "ki", "kim", "mı", "mu", "mü", "nasıl",
+	"ne", "neden", "nerde", "nerede", "nereye", "niçin", "niye", "o", "sanki", "şey", "siz", "şu",
+	"tüm", "ve", "veya", "ya", "yani",
+];
@@ -4,6 +4,7 @@ use crate::idx::ft::analyzer::filter::{Filter, FilterResult, Term};
 use crate::idx::ft::offsets::{Offset, Position};
 use crate::sql::tokenizer::Tokenizer as SqlTokenizer;
 use crate::sql::Value;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub(in crate::idx) struct Tokens {
 	/// The input string
@@ -183,12 +184,16 @@ impl Token {
 
 pub(super) struct Tokenizer {
 	splitters: Vec<Splitter>,
+	unicode: bool,
+	cjk: bool,
 }
 
 impl Tokenizer {
 	pub(in crate::idx::ft) fn new(t: &[SqlTokenizer]) -> Self {
 		Self {
 			splitters: t.iter().map(|t| t.into()).collect(),
+			unicode: t.contains(&SqlTokenizer::Unicode),
+			cjk: t.contains(&SqlTokenizer::Cjk),
 		}
 	}
 
@@ -208,15 +213,60 @@ impl Tokenizer {
 
 	pub(super) fn tokenize(t: &[SqlTokenizer], i: String) -> Tokens {
 		let mut w = Tokenizer::new(t);
-		let mut last_char_pos = 0;
-		let mut last_byte_pos = 0;
-		let mut current_char_pos = 0;
-		let mut current_byte_pos = 0;
 		let mut t = Vec::new();
+		if w.unicode {
+			// The words are first extracted following the Unicode word boundaries (UAX#29),
+			// then the other tokenizers are applied on each of them.
+			let mut words: Vec<(usize, usize)> = Vec::new();
+			for (b, s) in i.unicode_word_indices() {
+				let e = b + s.len();
+				// Adjacent ideographs are kept together, so the CJK bigrams can be generated
+				if let Some(last) = words.last_mut() {
+					if w.cjk
+						&& last.1 == b && i[..b].chars().next_back().is_some_and(is_cjk)
+						&& s.chars().next().is_some_and(is_cjk)
+					{
+						last.1 = e;
+						continue;
+					}
+				}
+				words.push((b, e));
+			}
+			let mut char_pos = 0;
+			let mut byte_pos = 0;
+			for (b, e) in words {
+				char_pos += i[byte_pos..b].chars().count() as Position;
+				byte_pos = b;
+				w.reset();
+				w.split(&i[b..e], char_pos, byte_pos as Position, &mut t);
+			}
+		} else {
+			w.split(&i, 0, 0, &mut t);
+		}
+		if w.cjk {
+			t = Self::cjk_bigrams(&i, t);
+		}
+		Tokens {
+			i,
+			t,
+		}
+	}
+
+	fn reset(&mut self) {
+		for s in &mut self.splitters {
+			s.state = 0;
+		}
+	}
+
+	fn split(&mut self, i: &str, char_pos: Position, byte_pos: Position, t: &mut Vec<Token>) {
+		let mut last_char_pos = char_pos;
+		let mut last_byte_pos = byte_pos;
+		let mut current_char_pos = char_pos;
+		let mut current_byte_pos = byte_pos;
 		for c in i.chars() {
 			let char_len = c.len_utf8() as Position;
 			let is_valid = Self::is_valid(c);
-			let should_split = w.should_split(c);
+			let should_split = self.should_split(c);
 			if should_split || !is_valid {
 				// The last pos may be more advanced due to the is_valid process
 				if last_char_pos < current_char_pos {
@@ -245,13 +295,79 @@ impl Tokenizer {
 				len: current_char_pos - last_char_pos,
 			});
 		}
-		Tokens {
-			i,
-			t,
+	}
+
+	/// Replaces every run of CJK characters by its overlapping bigrams.
+	/// A run made of a single character is kept as a unigram.
+	fn cjk_bigrams(i: &str, tokens: Vec<Token>) -> Vec<Token> {
+		let mut res = Vec::with_capacity(tokens.len());
+		for tk in tokens {
+			let Token::Ref {
+				chars,
+				bytes,
+				..
+			} = tk
+			else {
+				res.push(tk);
+				continue;
+			};
+			// Collect the characters with their char and byte positions
+			let s = &i[bytes.0 as usize..bytes.1 as usize];
+			let mut cs = Vec::with_capacity(s.len());
+			let mut b = bytes.0;
+			for (p, c) in (chars.0..).zip(s.chars()) {
+				cs.push((c, p, b));
+				b += c.len_utf8() as Position;
+			}
+			let mut start = 0;
+			while start < cs.len() {
+				let cjk = is_cjk(cs[start].0);
+				let mut end = start + 1;
+				while end < cs.len() && is_cjk(cs[end].0) == cjk {
+					end += 1;
+				}
+				let end_char = cs.get(end).map(|c| c.1).unwrap_or(chars.2);
+				let end_byte = cs.get(end).map(|c| c.2).unwrap_or(bytes.1);
+				if cjk && end - start > 1 {
+					for k in start..end - 1 {
+						let next_char = cs.get(k + 2).map(|c| c.1).unwrap_or(chars.2);
+						let next_byte = cs.get(k + 2).map(|c| c.2).unwrap_or(bytes.1);
+						res.push(Token::Ref {
+							chars: (cs[k].1, cs[k].1, next_char),
+							bytes: (cs[k].2, next_byte),
+							len: 2,
+						});
+					}
+				} else {
+					res.push(Token::Ref {
+						chars: (cs[start].1, cs[start].1, end_char),
+						bytes: (cs[start].2, end_byte),
+						len: end_char - cs[start].1,
+					});
+				}
+				start = end;
+			}
 		}
+		res
 	}
 }
 
+/// Returns true for the ideographic and syllabic characters of the Chinese, Japanese and Korean scripts
+fn is_cjk(c: char) -> bool {
+	matches!(c,
+		'\u{1100}'..='\u{11FF}' // Hangul Jamo
+		| '\u{3040}'..='\u{309F}' // Hiragana
+		| '\u{30A0}'..='\u{30FF}' // Katakana
+		| '\u{3130}'..='\u{318F}' // Hangul Compatibility Jamo
+		| '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+		| '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+		| '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+		| '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+		| '\u{FF66}'..='\u{FF9F}' // Halfwidth Katakana
+		| '\u{20000}'..='\u{2FA1F}' // CJK Unified Ideographs Extension B to F
+	)
+}
+
 struct Splitter {
 	t: SqlTokenizer,
 	state: u8,
@@ -273,6 +389,8 @@ impl Splitter {
 			SqlTokenizer::Camel => self.camel_state(c),
 			SqlTokenizer::Class => self.class_state(c),
 			SqlTokenizer::Punct => self.punct_state(c),
+			// Applied before and after the splitters
+			SqlTokenizer::Unicode | SqlTokenizer::Cjk => false,
 		}
 	}
 
@@ -400,4 +518,34 @@ static LANGUAGE: &str = "Rust";"#,
 		)
 		.await;
 	}
+
+	#[tokio::test]
+	async fn test_tokenize_unicode() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS unicode FILTERS lowercase",
+			"Can't stop, won't stop! 3.14 e-mail",
+			&["can't", "stop", "won't", "stop", "3.14", "e", "mail"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_tokenize_cjk() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS blank,cjk",
+			"東京都 iPhone手机壳 猫",
+			&["東京", "京都", "iPhone", "手机", "机壳", "猫"],
+		)
+		.await;
+	}
+
+	#[tokio::test]
+	async fn test_tokenize_unicode_cjk() {
+		test_analyzer(
+			"ANALYZER test TOKENIZERS unicode,cjk FILTERS lowercase",
+			"我爱北京, Hello!",
+			&["我爱", "爱北", "北京", "hello"],
+		)
+		.await;
+	}
 }
@@ -0,0 +1,232 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::err::Error;
+use crate::iam::Action;
+use crate::key::index::Index as IndexKey;
+use crate::kvs::Key;
+use crate::sql::index::Index;
+use crate::sql::permission::Permission;
+use crate::sql::statements::SelectStatement;
+use crate::sql::with::With;
+use crate::sql::{Array, Field, Function, Idiom, Kind, Object, Table, Value};
+use reblessive::tree::Stk;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// The numeric values which are stored in a field
+#[derive(Clone, Copy, Debug)]
+enum Numeric {
+	Int,
+	Float,
+}
+
+impl Numeric {
+	/// Returns the numeric type of a field, if every value of the field is of this type
+	fn from_kind(kind: Option<&Kind>) -> Option<Self> {
+		match kind {
+			Some(Kind::Int) => Some(Self::Int),
+			Some(Kind::Float) => Some(Self::Float),
+			Some(Kind::Option(k)) => Self::from_kind(Some(k)),
+			_ => None,
+		}
+	}
+
+	/// Returns the range of the index keys which contain a value of this type
+	fn range(&self, ns: &str, db: &str, tb: &str, ix: &str) -> Range<Key> {
+		let (beg, end) = match self {
+			Self::Int => (Value::from(i64::MIN), Value::from(i64::MAX)),
+			Self::Float => (Value::from(f64::NEG_INFINITY), Value::from(f64::INFINITY)),
+		};
+		let beg = Array::from(vec![beg]);
+		let end = Array::from(vec![end]);
+		IndexKey::prefix_ids_beg(ns, db, tb, ix, &beg)
+			..IndexKey::prefix_ids_end(ns, db, tb, ix, &end)
+	}
+}
+
+/// An aggregate which is answered without iterating the records
+#[derive(Clone, Debug)]
+enum Aggregate {
+	/// The number of record keys of the table
+	Count,
+	/// The first numeric entry of a single column index
+	Min(String, Numeric),
+	/// The last numeric entry of a single column index
+	Max(String, Numeric),
+}
+
+impl Aggregate {
+	fn explain(&self) -> Value {
+		match self {
+			Self::Count => Value::from("Count Keys"),
+			Self::Min(ix, _) => Value::from(Object::from(BTreeMap::from([
+				("operation".to_string(), Value::from("First Entry")),
+				("index".to_string(), Value::from(ix.as_str())),
+			]))),
+			Self::Max(ix, _) => Value::from(Object::from(BTreeMap::from([
+				("operation".to_string(), Value::from("Last Entry")),
+				("index".to_string(), Value::from(ix.as_str())),
+			]))),
+		}
+	}
+}
+
+/// The aggregates of a `GROUP ALL` selection, which are answered from
+/// the record keys of the table, or from the entries of its indexes.
+#[derive(Clone, Debug)]
+pub(crate) struct AggregatePlan {
+	tb: Table,
+	fields: Vec<(Idiom, Aggregate)>,
+}
+
+impl AggregatePlan {
+	/// Checks if the selection only contains aggregates which can be answered from the keys
+	pub(super) async fn build(
+		ctx: &Context,
+		opt: &Options,
+		with: Option<&With>,
+		stm: &SelectStatement,
+		tb: &Table,
+	) -> Result<Option<Self>, Error> {
+		// The selection must aggregate every record of the table
+		if !matches!(&stm.group, Some(g) if g.is_empty())
+			|| stm.cond.is_some()
+			|| stm.split.is_some()
+			|| stm.version.is_some()
+			|| stm.omit.is_some()
+			|| stm.expr.1
+		{
+			return Ok(None);
+		}
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		let txn = ctx.tx();
+		let tbs = match txn.get_tb(ns, db, tb).await {
+			Ok(v) => v,
+			Err(Error::TbNotFound {
+				..
+			}) => return Ok(None),
+			Err(e) => return Err(e),
+		};
+		// The records must be visible without checking each of them
+		let perms = opt.check_perms(Action::View)?;
+		if perms && (opt.auth.is_record() || !matches!(tbs.permissions.select, Permission::Full)) {
+			return Ok(None);
+		}
+		let fds = txn.all_tb_fields(ns, db, tb).await?;
+		let ixs = txn.all_tb_indexes(ns, db, tb).await?;
+		let mut fields = Vec::with_capacity(stm.expr.0.len());
+		for field in stm.expr.0.iter() {
+			let Field::Single {
+				expr: Value::Function(f),
+				alias,
+			} = field
+			else {
+				return Ok(None);
+			};
+			let aggregate = match f.as_ref() {
+				Function::Normal(name, args) if name == "count" && args.is_empty() => {
+					Aggregate::Count
+				}
+				Function::Normal(name, args) if name == "math::min" || name == "math::max" => {
+					let [Value::Idiom(idiom)] = args.as_slice() else {
+						return Ok(None);
+					};
+					// The field must only contain values of a single numeric type, which
+					// can't be hidden by its permissions, to read them in the key order
+					let Some(fd) = fds.iter().find(|fd| fd.name == *idiom) else {
+						return Ok(None);
+					};
+					if perms && !matches!(fd.permissions.select, Permission::Full) {
+						return Ok(None);
+					}
+					let Some(numeric) = Numeric::from_kind(fd.kind.as_ref()) else {
+						return Ok(None);
+					};
+					// The field must be the only column of a standard or unique index
+					let Some(ix) = ixs.iter().find(|ix| {
+						matches!(ix.index, Index::Idx | Index::Uniq)
+							&& ix.cols.len() == 1 && ix.cols[0] == *idiom
+							&& match with {
+								None => true,
+								Some(With::NoIndex) => false,
+								Some(With::Index(names)) => names.contains(&ix.name.0),
+							}
+					}) else {
+						return Ok(None);
+					};
+					match name.as_str() {
+						"math::min" => Aggregate::Min(ix.name.0.clone(), numeric),
+						_ => Aggregate::Max(ix.name.0.clone(), numeric),
+					}
+				}
+				_ => return Ok(None),
+			};
+			let idiom = alias.clone().unwrap_or_else(|| f.to_idiom());
+			fields.push((idiom, aggregate));
+		}
+		if fields.is_empty() {
+			return Ok(None);
+		}
+		Ok(Some(Self {
+			tb: tb.clone(),
+			fields,
+		}))
+	}
+
+	/// Computes the grouped record, or nothing if the table has no records
+	pub(crate) async fn compute(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+	) -> Result<Option<Value>, Error> {
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		let txn = ctx.tx();
+		// Like any group, the group of all the records only exists if there are records
+		let beg = crate::key::thing::prefix(ns, db, &self.tb);
+		let end = crate::key::thing::suffix(ns, db, &self.tb);
+		let count = if self.fields.iter().any(|(_, a)| matches!(a, Aggregate::Count)) {
+			txn.count(beg..end).await?
+		} else {
+			txn.keys(beg..end, 1).await?.len()
+		};
+		if count == 0 {
+			return Ok(None);
+		}
+		let mut obj = Value::base();
+		for (idiom, aggregate) in self.fields.iter() {
+			let keys = match aggregate {
+				Aggregate::Count => {
+					obj.set(stk, ctx, opt, idiom, count.into()).await?;
+					continue;
+				}
+				Aggregate::Min(ix, numeric) => {
+					txn.keys(numeric.range(ns, db, &self.tb, ix), 1).await?
+				}
+				Aggregate::Max(ix, numeric) => {
+					txn.keysr(numeric.range(ns, db, &self.tb, ix), 1).await?
+				}
+			};
+			let val = match keys.first() {
+				Some(k) => {
+					IndexKey::decode(k)?.fd.into_owned().0.into_iter().next().unwrap_or_default()
+				}
+				None => Value::None,
+			};
+			obj.set(stk, ctx, opt, idiom, val).await?;
+		}
+		Ok(Some(obj))
+	}
+
+	pub(crate) fn table(&self) -> &Table {
+		&self.tb
+	}
+
+	pub(crate) fn explain(&self) -> Value {
+		let mut explain = BTreeMap::new();
+		for (idiom, aggregate) in self.fields.iter() {
+			explain.insert(Value::from(idiom.clone()).to_string(), aggregate.explain());
+		}
+		explain.into()
+	}
+}
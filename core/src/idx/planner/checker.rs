@@ -14,6 +14,11 @@ use std::collections::hash_map::Entry;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+/// The number of documents which are evaluated before the selectivity of a condition is estimated
+const SELECTIVITY_MIN_EVALUATIONS: usize = 200;
+/// The ratio of matching documents below which a condition is considered highly selective
+const SELECTIVITY_THRESHOLD: f64 = 0.05;
+
 pub enum HnswConditionChecker<'a> {
 	Hnsw(HnswChecker),
 	HnswCondition(HnswCondChecker<'a>),
@@ -35,9 +40,21 @@ impl<'a> HnswConditionChecker<'a> {
 			opt,
 			cond,
 			cache: Default::default(),
+			evaluated: 0,
+			truthy: 0,
 		})
 	}
 
+	/// Returns true if few of the documents evaluated so far match the condition.
+	/// A graph traversal would then visit most of the graph to collect enough
+	/// results, and a brute-force scan of the vectors is cheaper.
+	pub(in crate::idx) fn is_highly_selective(&self) -> bool {
+		match self {
+			Self::HnswCondition(c) => c.is_highly_selective(),
+			Self::Hnsw(_) => false,
+		}
+	}
+
 	pub(in crate::idx) async fn check_truthy(
 		&mut self,
 		tx: &Transaction,
@@ -51,12 +68,6 @@ impl<'a> HnswConditionChecker<'a> {
 		}
 	}
 
-	pub(in crate::idx) fn expire(&mut self, doc_id: u64) {
-		if let Self::HnswCondition(c) = self {
-			c.expire(doc_id)
-		}
-	}
-
 	pub(in crate::idx) fn expires(&mut self, doc_ids: Ids64) {
 		if let Self::HnswCondition(c) = self {
 			c.expires(doc_ids)
@@ -195,8 +206,9 @@ impl CheckerCacheEntry {
 					let truthy = cond.compute(stk, ctx, opt, Some(&cursor_doc)).await?.is_truthy();
 					(cursor_doc.doc.as_arc(), truthy)
 				};
+				// Only the records which match the condition are returned as results
 				return Ok(CheckerCacheEntry {
-					record: Some((rid, value)),
+					record: truthy.then_some((rid, value)),
 					truthy,
 				});
 			}
@@ -279,6 +291,8 @@ pub struct HnswCondChecker<'a> {
 	opt: &'a Options,
 	cond: Arc<Cond>,
 	cache: HashMap<DocId, CheckerCacheEntry>,
+	evaluated: usize,
+	truthy: usize,
 }
 
 impl<'a> HnswCondChecker<'a> {
@@ -304,6 +318,10 @@ impl<'a> HnswCondChecker<'a> {
 							.await?;
 					let truthy = ent.truthy;
 					e.insert(ent);
+					self.evaluated += 1;
+					if truthy {
+						self.truthy += 1;
+					}
 					truthy
 				}
 			} {
@@ -313,6 +331,11 @@ impl<'a> HnswCondChecker<'a> {
 		Ok(res)
 	}
 
+	fn is_highly_selective(&self) -> bool {
+		self.evaluated >= SELECTIVITY_MIN_EVALUATIONS
+			&& (self.truthy as f64) < (self.evaluated as f64) * SELECTIVITY_THRESHOLD
+	}
+
 	fn expire(&mut self, doc_id: DocId) {
 		self.cache.remove(&doc_id);
 	}
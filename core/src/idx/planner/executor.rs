@@ -2,7 +2,7 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
-use crate::idx::docids::DocIds;
+use crate::idx::docids::{DocId, DocIds};
 use crate::idx::ft::analyzer::{Analyzer, TermsList, TermsSet};
 use crate::idx::ft::highlighter::HighlightParams;
 use crate::idx::ft::scorer::BM25Scorer;
@@ -10,13 +10,14 @@ use crate::idx::ft::termdocs::TermsDocs;
 use crate::idx::ft::terms::Terms;
 use crate::idx::ft::{FtIndex, MatchRef};
 use crate::idx::planner::checker::{HnswConditionChecker, MTreeConditionChecker};
+use crate::idx::planner::hybrid::FT_HITS_FACTOR;
 use crate::idx::planner::iterators::{
-	IndexEqualThingIterator, IndexJoinThingIterator, IndexRangeThingIterator,
+	HybridIterator, IndexEqualThingIterator, IndexJoinThingIterator, IndexRangeThingIterator,
 	IndexUnionThingIterator, IteratorRecord, IteratorRef, KnnIterator, KnnIteratorResult,
 	MatchesThingIterator, ThingIterator, UniqueEqualThingIterator, UniqueJoinThingIterator,
 	UniqueRangeThingIterator, UniqueUnionThingIterator,
 };
-use crate::idx::planner::knn::{KnnBruteForceResult, KnnPriorityList};
+use crate::idx::planner::knn::{KnnBruteForceResult, KnnPriorityList, KnnRerank};
 use crate::idx::planner::plan::IndexOperator::Matches;
 use crate::idx::planner::plan::{IndexOperator, IndexOption, RangeValue};
 use crate::idx::planner::tree::{IdiomPosition, IndexRef, IndexesMap};
@@ -24,7 +25,7 @@ use crate::idx::planner::IterationStage;
 use crate::idx::trees::mtree::MTreeIndex;
 use crate::idx::trees::store::hnsw::SharedHnswIndex;
 use crate::idx::IndexKeyBase;
-use crate::kvs::{Key, TransactionType};
+use crate::kvs::{Key, Transaction, TransactionType};
 use crate::sql::index::{Distance, Index};
 use crate::sql::statements::DefineIndexStatement;
 use crate::sql::{Cond, Expression, Idiom, Number, Object, Table, Thing, Value};
@@ -71,6 +72,8 @@ pub(super) struct InnerQueryExecutor {
 	mt_entries: HashMap<Arc<Expression>, MtEntry>,
 	hnsw_entries: HashMap<Arc<Expression>, HnswEntry>,
 	knn_bruteforce_entries: HashMap<Arc<Expression>, KnnBruteForceEntry>,
+	/// The limit of the query, if any
+	limit: Option<u32>,
 }
 
 impl From<InnerQueryExecutor> for QueryExecutor {
@@ -82,6 +85,8 @@ impl From<InnerQueryExecutor> for QueryExecutor {
 pub(super) enum IteratorEntry {
 	Single(Option<Arc<Expression>>, IndexOption),
 	Range(HashSet<Arc<Expression>>, IndexRef, RangeValue, RangeValue),
+	/// Fusion of a full-text search and a KNN search
+	Hybrid((Arc<Expression>, IndexOption), (Arc<Expression>, IndexOption)),
 }
 
 impl IteratorEntry {
@@ -97,6 +102,11 @@ impl IteratorEntry {
 				e.insert("to", Value::from(to));
 				Value::from(Object::from(e))
 			}
+			Self::Hybrid((_, ft), (_, knn)) => {
+				let mut e = HashMap::default();
+				e.insert("hybrid", Value::from(vec![ft.explain(ix_def), knn.explain(ix_def)]));
+				Value::from(Object::from(e))
+			}
 		}
 	}
 }
@@ -112,6 +122,7 @@ impl InnerQueryExecutor {
 		knns: KnnExpressions,
 		kbtes: KnnBruteForceExpressions,
 		knn_condition: Option<Cond>,
+		limit: Option<u32>,
 	) -> Result<Self, Error> {
 		let mut mr_entries = HashMap::default();
 		let mut exp_entries = HashMap::default();
@@ -161,6 +172,8 @@ impl InnerQueryExecutor {
 					}
 					Index::MTree(p) => {
 						if let IndexOperator::Knn(a, k) = io.op() {
+							let rerank =
+								KnnRerank::new(idx_def, &p.distance, p.quantization, p.rerank);
 							let entry = match mt_map.entry(ix_ref) {
 								Entry::Occupied(e) => {
 									MtEntry::new(
@@ -171,6 +184,7 @@ impl InnerQueryExecutor {
 										a,
 										*k,
 										knn_condition.clone(),
+										rerank,
 									)
 									.await?
 								}
@@ -194,6 +208,7 @@ impl InnerQueryExecutor {
 										a,
 										*k,
 										knn_condition.clone(),
+										rerank,
 									)
 									.await?;
 									e.insert(mt);
@@ -205,6 +220,8 @@ impl InnerQueryExecutor {
 					}
 					Index::Hnsw(p) => {
 						if let IndexOperator::Ann(a, k, ef) = io.op() {
+							let rerank =
+								KnnRerank::new(idx_def, &p.distance, p.quantization, p.rerank);
 							let entry = match hnsw_map.entry(ix_ref) {
 								Entry::Occupied(e) => {
 									HnswEntry::new(
@@ -216,6 +233,7 @@ impl InnerQueryExecutor {
 										*k,
 										*ef,
 										knn_condition.clone(),
+										rerank,
 									)
 									.await?
 								}
@@ -236,6 +254,7 @@ impl InnerQueryExecutor {
 										*k,
 										*ef,
 										knn_condition.clone(),
+										rerank,
 									)
 									.await?;
 									e.insert(hnsw);
@@ -265,6 +284,7 @@ impl InnerQueryExecutor {
 			mt_entries,
 			hnsw_entries,
 			knn_bruteforce_entries,
+			limit,
 		})
 	}
 
@@ -321,6 +341,9 @@ impl QueryExecutor {
 		match self.0.it_entries.get(irf as usize) {
 			Some(IteratorEntry::Single(Some(e), ..)) => exp.eq(e.as_ref()),
 			Some(IteratorEntry::Range(es, ..)) => es.contains(exp),
+			Some(IteratorEntry::Hybrid((ft, _), (knn, _))) => {
+				exp.eq(ft.as_ref()) || exp.eq(knn.as_ref())
+			}
 			_ => false,
 		}
 	}
@@ -352,6 +375,9 @@ impl QueryExecutor {
 				IteratorEntry::Range(_, ixr, from, to) => {
 					Ok(self.new_range_iterator(opt, *ixr, from, to)?)
 				}
+				IteratorEntry::Hybrid((ft, io), (knn, knn_io)) => {
+					self.new_hybrid_iterator(irf, ft, io, knn, knn_io)
+				}
 			}
 		} else {
 			Ok(None)
@@ -418,6 +444,33 @@ impl QueryExecutor {
 					None
 				}
 			}
+			IndexOperator::Compound {
+				prefix,
+				from,
+				to,
+				..
+			} => Some(ThingIterator::IndexRange(IndexRangeThingIterator::compound(
+				irf,
+				opt.ns()?,
+				opt.db()?,
+				&ix.what,
+				&ix.name,
+				prefix,
+				from,
+				to,
+			))),
+			IndexOperator::StartsWith {
+				prefix,
+				value,
+			} => Some(ThingIterator::IndexRange(IndexRangeThingIterator::starts_with(
+				irf,
+				opt.ns()?,
+				opt.db()?,
+				&ix.what,
+				&ix.name,
+				prefix,
+				value,
+			))),
 			_ => None,
 		})
 	}
@@ -498,6 +551,33 @@ impl QueryExecutor {
 					None
 				}
 			}
+			IndexOperator::Compound {
+				prefix,
+				from,
+				to,
+				..
+			} => Some(ThingIterator::UniqueRange(UniqueRangeThingIterator::compound(
+				irf,
+				opt.ns()?,
+				opt.db()?,
+				&ix.what,
+				&ix.name,
+				prefix,
+				from,
+				to,
+			))),
+			IndexOperator::StartsWith {
+				prefix,
+				value,
+			} => Some(ThingIterator::UniqueRange(UniqueRangeThingIterator::starts_with(
+				irf,
+				opt.ns()?,
+				opt.db()?,
+				&ix.what,
+				&ix.name,
+				prefix,
+				value,
+			))),
 			_ => None,
 		})
 	}
@@ -541,6 +621,37 @@ impl QueryExecutor {
 		None
 	}
 
+	fn new_hybrid_iterator(
+		&self,
+		irf: IteratorRef,
+		ft: &Arc<Expression>,
+		io: &IndexOption,
+		knn: &Arc<Expression>,
+		knn_io: &IndexOption,
+	) -> Result<Option<ThingIterator>, Error> {
+		if let Some(fti) = self.0.ft_map.get(&io.ix_ref()) {
+			if let Some(fte) = self.0.exp_entries.get(ft) {
+				let res = if let Some(he) = self.0.hnsw_entries.get(knn) {
+					he.res.clone()
+				} else if let Some(mte) = self.0.mt_entries.get(knn) {
+					mte.res.clone()
+				} else {
+					return Ok(None);
+				};
+				// Only the best full-text hits can be ranked within the results
+				let k = match knn_io.op() {
+					IndexOperator::Knn(_, k) | IndexOperator::Ann(_, k, _) => *k,
+					_ => 0,
+				};
+				let size = (k.max(self.0.limit.unwrap_or(0)) as usize).max(1) * FT_HITS_FACTOR;
+				let hits = fti.new_hits_iterator(fte.0.terms_docs.clone())?;
+				let it = HybridIterator::new(irf, hits, fte.clone(), res, size);
+				return Ok(Some(ThingIterator::Hybrid(it)));
+			}
+		}
+		Ok(None)
+	}
+
 	async fn build_iterators(
 		&self,
 		opt: &Options,
@@ -728,7 +839,7 @@ impl QueryExecutor {
 }
 
 #[derive(Clone)]
-struct FtEntry(Arc<Inner>);
+pub(super) struct FtEntry(Arc<Inner>);
 
 struct Inner {
 	index_option: IndexOption,
@@ -769,6 +880,18 @@ impl FtEntry {
 			Ok(None)
 		}
 	}
+
+	/// The BM25 score of a matching document, if the index has a scorer
+	pub(super) async fn score(
+		&self,
+		tx: &Transaction,
+		doc_id: DocId,
+	) -> Result<Option<f64>, Error> {
+		if let Some(scorer) = &self.0.scorer {
+			return Ok(scorer.score(tx, doc_id).await?.map(f64::from));
+		}
+		Ok(None)
+	}
 }
 
 #[derive(Clone)]
@@ -777,6 +900,7 @@ pub(super) struct MtEntry {
 }
 
 impl MtEntry {
+	#[allow(clippy::too_many_arguments)]
 	async fn new(
 		stk: &mut Stk,
 		ctx: &Context,
@@ -785,13 +909,20 @@ impl MtEntry {
 		o: &[Number],
 		k: u32,
 		cond: Option<Arc<Cond>>,
+		rerank: Option<KnnRerank<'_>>,
 	) -> Result<Self, Error> {
 		let cond_checker = if let Some(cond) = cond {
 			MTreeConditionChecker::new_cond(ctx, opt, cond)
 		} else {
 			MTreeConditionChecker::new(ctx)
 		};
-		let res = mt.knn_search(stk, ctx, o, k as usize, cond_checker).await?;
+		let k = k as usize;
+		let res = if let Some(r) = rerank {
+			let res = mt.knn_search(stk, ctx, o, r.candidates(k), cond_checker).await?;
+			r.rerank(stk, ctx, opt, o, k, res).await?
+		} else {
+			mt.knn_search(stk, ctx, o, k, cond_checker).await?
+		};
 		Ok(Self {
 			res,
 		})
@@ -814,17 +945,24 @@ impl HnswEntry {
 		n: u32,
 		ef: u32,
 		cond: Option<Arc<Cond>>,
+		rerank: Option<KnnRerank<'_>>,
 	) -> Result<Self, Error> {
 		let cond_checker = if let Some(cond) = cond {
 			HnswConditionChecker::new_cond(ctx, opt, cond)
 		} else {
 			HnswConditionChecker::new()
 		};
-		let res = h
-			.read()
-			.await
-			.knn_search(&ctx.tx(), stk, v, n as usize, ef as usize, cond_checker)
-			.await?;
+		let (n, ef) = (n as usize, ef as usize);
+		let h = h.read().await;
+		let res = if let Some(r) = rerank {
+			// The search has to keep enough candidates to re-rank
+			let c = r.candidates(n);
+			let res = h.knn_search(&ctx.tx(), stk, v, c, ef.max(c), cond_checker).await?;
+			r.rerank(stk, ctx, opt, v, n, res).await?
+		} else {
+			h.knn_search(&ctx.tx(), stk, v, n, ef, cond_checker).await?
+		};
+		drop(h);
 		Ok(Self {
 			res,
 		})
@@ -0,0 +1,178 @@
+use crate::idx::docids::DocId;
+use crate::idx::planner::iterators::KnnIteratorResult;
+use crate::sql::{Thing, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+/// The default constant of the reciprocal rank fusion
+pub(crate) const RRF_K: f64 = 60.0;
+
+/// How many full-text hits are fused, as a multiple of the
+/// largest of the KNN neighbour count and the query limit
+pub(super) const FT_HITS_FACTOR: usize = 4;
+
+/// The position of a record in the full-text and the vector
+/// result sets of a hybrid search. Each side holds the rank of
+/// the record, starting at 1, and its score normalised between
+/// 0 and 1, where 1 is the best match of the result set.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Fusion {
+	ft: Option<(usize, f64)>,
+	knn: Option<(usize, f64)>,
+}
+
+impl Fusion {
+	/// Reciprocal rank fusion of both result sets
+	pub(crate) fn rrf(&self, k: f64) -> f64 {
+		[self.ft, self.knn].iter().flatten().map(|(rank, _)| 1.0 / (k + *rank as f64)).sum()
+	}
+
+	/// Weighted linear fusion of the normalised scores of both result sets
+	pub(crate) fn linear(&self, ft_weight: f64, knn_weight: f64) -> f64 {
+		let ft = self.ft.map(|(_, s)| s * ft_weight).unwrap_or(0.0);
+		let knn = self.knn.map(|(_, s)| s * knn_weight).unwrap_or(0.0);
+		ft + knn
+	}
+}
+
+/// A full-text hit, with its document id and its BM25 score
+pub(super) type FtHit = (Arc<Thing>, DocId, f64);
+
+/// Keeps the best scored full-text hits, without buffering every hit of the search
+pub(super) struct FtTopHits {
+	size: usize,
+	hits: Vec<FtHit>,
+}
+
+impl FtTopHits {
+	pub(super) fn new(size: usize) -> Self {
+		Self {
+			size,
+			hits: Vec::with_capacity(size.saturating_mul(2).min(1024)),
+		}
+	}
+
+	pub(super) fn push(&mut self, hit: FtHit) {
+		self.hits.push(hit);
+		// Only drop the worst hits once the buffer is full, so pushes stay amortised
+		if self.hits.len() >= self.size.saturating_mul(2) {
+			self.truncate();
+		}
+	}
+
+	pub(super) fn into_hits(mut self) -> Vec<FtHit> {
+		self.truncate();
+		self.hits
+	}
+
+	fn truncate(&mut self) {
+		if self.hits.len() > self.size {
+			self.hits.select_nth_unstable_by(self.size, |a, b| b.2.total_cmp(&a.2));
+			self.hits.truncate(self.size);
+		}
+	}
+}
+
+/// A record of a hybrid search, with its document id, its distance, and its fusion
+pub(super) type HybridResult = (Arc<Thing>, Option<DocId>, Option<f64>, Option<Arc<Value>>, Fusion);
+
+/// Fuses the full-text hits with the KNN results. Every record
+/// is returned once, ordered by their reciprocal rank fusion.
+pub(super) fn fuse(mut ft: Vec<FtHit>, knn: VecDeque<KnnIteratorResult>) -> VecDeque<HybridResult> {
+	let mut res: Vec<HybridResult> = Vec::with_capacity(ft.len() + knn.len());
+	let mut pos: HashMap<Arc<Thing>, usize> = HashMap::with_capacity(res.capacity());
+	// The best full-text matches have the highest score
+	ft.sort_by(|a, b| b.2.total_cmp(&a.2));
+	let (min, max) = min_max(ft.iter().map(|h| h.2));
+	for (i, (thg, doc_id, score)) in ft.into_iter().enumerate() {
+		let fusion = Fusion {
+			ft: Some((i + 1, normalise(score, min, max))),
+			knn: None,
+		};
+		pos.insert(thg.clone(), res.len());
+		res.push((thg, Some(doc_id), None, None, fusion));
+	}
+	// The best vector matches have the lowest distance
+	let mut knn: Vec<KnnIteratorResult> = knn.into();
+	knn.sort_by(|a, b| a.1.total_cmp(&b.1));
+	let (min, max) = min_max(knn.iter().map(|r| r.1));
+	for (i, (thg, dist, val)) in knn.into_iter().enumerate() {
+		let side = Some((i + 1, 1.0 - normalise(dist, min, max)));
+		match pos.get(&thg) {
+			Some(p) => {
+				let r = &mut res[*p];
+				r.2 = Some(dist);
+				r.3 = val;
+				r.4.knn = side;
+			}
+			None => {
+				let fusion = Fusion {
+					ft: None,
+					knn: side,
+				};
+				res.push((thg, None, Some(dist), val, fusion));
+			}
+		}
+	}
+	res.sort_by(|a, b| b.4.rrf(RRF_K).total_cmp(&a.4.rrf(RRF_K)));
+	res.into()
+}
+
+fn min_max(values: impl Iterator<Item = f64>) -> (f64, f64) {
+	values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)))
+}
+
+/// Min-max normalisation, where a single value is normalised to 1
+fn normalise(v: f64, min: f64, max: f64) -> f64 {
+	if max > min {
+		(v - min) / (max - min)
+	} else {
+		1.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn thing(id: &str) -> Arc<Thing> {
+		Arc::new(Thing::from(("doc", id)))
+	}
+
+	#[test]
+	fn fuse_ranks() {
+		let ft = vec![(thing("a"), 1, 1.0), (thing("b"), 2, 3.0)];
+		let knn = VecDeque::from([(thing("c"), 0.5, None), (thing("a"), 0.1, None)]);
+		let res = fuse(ft, knn);
+		assert_eq!(res.len(), 3);
+		// The record found by both searches is ranked first
+		let (thg, doc_id, dist, _, fusion) = &res[0];
+		assert_eq!(thg, &thing("a"));
+		assert_eq!(*doc_id, Some(1));
+		assert_eq!(*dist, Some(0.1));
+		assert_eq!(fusion.rrf(RRF_K), 1.0 / 62.0 + 1.0 / 61.0);
+		assert_eq!(fusion.linear(0.5, 0.5), 0.5);
+		// A record found by a single search has a single rank
+		let (thg, _, dist, _, fusion) = &res[1];
+		assert_eq!(thg, &thing("b"));
+		assert_eq!(*dist, None);
+		assert_eq!(fusion.rrf(RRF_K), 1.0 / 61.0);
+		assert_eq!(fusion.linear(0.5, 0.5), 0.5);
+		let (thg, doc_id, _, _, fusion) = &res[2];
+		assert_eq!(thg, &thing("c"));
+		assert_eq!(*doc_id, None);
+		assert_eq!(fusion.linear(0.5, 0.5), 0.0);
+	}
+
+	#[test]
+	fn ft_top_hits() {
+		let mut top = FtTopHits::new(3);
+		for i in 0..100 {
+			top.push((thing(&i.to_string()), i, (i % 10) as f64 + i as f64 / 1000.0));
+		}
+		let mut hits = top.into_hits();
+		hits.sort_by(|a, b| b.2.total_cmp(&a.2));
+		let ids: Vec<DocId> = hits.iter().map(|h| h.1).collect();
+		assert_eq!(ids, vec![99, 89, 79]);
+	}
+}
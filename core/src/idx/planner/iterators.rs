@@ -4,6 +4,8 @@ use crate::err::Error;
 use crate::idx::docids::DocId;
 use crate::idx::ft::termdocs::TermsDocs;
 use crate::idx::ft::{FtIndex, HitsIterator};
+use crate::idx::planner::executor::FtEntry;
+use crate::idx::planner::hybrid::{self, FtTopHits, Fusion, HybridResult};
 use crate::idx::planner::plan::RangeValue;
 use crate::key::index::Index;
 use crate::kvs::Key;
@@ -21,6 +23,8 @@ pub(crate) struct IteratorRecord {
 	irf: IteratorRef,
 	doc_id: Option<DocId>,
 	dist: Option<f64>,
+	key: Option<Key>,
+	fusion: Option<Fusion>,
 }
 
 impl IteratorRecord {
@@ -34,6 +38,26 @@ impl IteratorRecord {
 	pub(crate) fn dist(&self) -> Option<f64> {
 		self.dist
 	}
+
+	/// The ranks of the record, for hybrid searches
+	pub(crate) fn fusion(&self) -> Option<&Fusion> {
+		self.fusion.as_ref()
+	}
+
+	/// The index key of the record, for iterators which can be resumed
+	pub(crate) fn key(&self) -> Option<&Key> {
+		self.key.as_ref()
+	}
+
+	fn with_key(irf: IteratorRef, key: Key) -> Self {
+		IteratorRecord {
+			irf,
+			doc_id: None,
+			dist: None,
+			key: Some(key),
+			fusion: None,
+		}
+	}
 }
 impl From<IteratorRef> for IteratorRecord {
 	fn from(irf: IteratorRef) -> Self {
@@ -41,6 +65,8 @@ impl From<IteratorRef> for IteratorRecord {
 			irf,
 			doc_id: None,
 			dist: None,
+			key: None,
+			fusion: None,
 		}
 	}
 }
@@ -113,6 +139,7 @@ pub(crate) enum ThingIterator {
 	UniqueJoin(Box<UniqueJoinThingIterator>),
 	Matches(MatchesThingIterator),
 	Knn(KnnIterator),
+	Hybrid(HybridIterator),
 }
 
 impl ThingIterator {
@@ -131,10 +158,34 @@ impl ThingIterator {
 			Self::UniqueUnion(i) => i.next_batch(ctx, txn, size).await,
 			Self::Matches(i) => i.next_batch(ctx, txn, size).await,
 			Self::Knn(i) => i.next_batch(ctx, size).await,
+			Self::Hybrid(i) => i.next_batch(ctx, txn, size).await,
 			Self::IndexJoin(i) => Box::pin(i.next_batch(ctx, txn, size)).await,
 			Self::UniqueJoin(i) => Box::pin(i.next_batch(ctx, txn, size)).await,
 		}
 	}
+
+	/// Checks if the iteration can be resumed from the key of a record
+	pub(crate) fn can_resume(&self) -> bool {
+		matches!(self, Self::IndexEqual(_) | Self::IndexRange(_) | Self::UniqueRange(_))
+	}
+
+	/// Resumes the iteration after the given key
+	pub(crate) fn resume(&mut self, mut key: Key) {
+		key.push(0x00);
+		match self {
+			Self::IndexEqual(i) if key > i.beg => i.beg = key.min(i.end.clone()),
+			Self::IndexRange(i) => i.r.resume(key),
+			Self::UniqueRange(i) => {
+				// The end key of a unique range is fetched on its own
+				if key > i.r.end {
+					i.done = true;
+				} else {
+					i.r.resume(key);
+				}
+			}
+			_ => {}
+		}
+	}
 }
 
 pub(crate) type CollectorRecord = (Arc<Thing>, IteratorRecord, Option<Arc<Value>>);
@@ -180,7 +231,9 @@ impl IndexEqualThingIterator {
 			*beg = key;
 		}
 		let mut records = B::with_capacity(res.len());
-		res.into_iter().for_each(|(_, val)| records.add((Arc::new(val.into()), irf.into(), None)));
+		res.into_iter().for_each(|(key, val)| {
+			records.add((Arc::new(val.into()), IteratorRecord::with_key(irf, key), None))
+		});
 		Ok(records)
 	}
 
@@ -220,6 +273,70 @@ impl RangeScan {
 		}
 	}
 
+	/// Computes the range of the entries matching the leading columns, within the range of the next column.
+	/// The keys are computed so that the bounds never need to be filtered.
+	#[allow(clippy::too_many_arguments)]
+	fn compound(
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		from: &RangeValue,
+		to: &RangeValue,
+	) -> Self {
+		let key = |v: &Value| {
+			let mut fd = prefix.clone();
+			fd.push(v.clone());
+			Index::prefix_composite(ns, db, ix_what, ix_name, &fd)
+		};
+		let beg = if from.value.is_none() {
+			Index::prefix_composite_beg(ns, db, ix_what, ix_name, prefix)
+		} else if from.inclusive {
+			key(&from.value)
+		} else {
+			let mut k = key(&from.value);
+			k.push(0xff);
+			k
+		};
+		let end = if to.value.is_none() {
+			Index::prefix_composite_end(ns, db, ix_what, ix_name, prefix)
+		} else if to.inclusive {
+			let mut k = key(&to.value);
+			k.push(0xff);
+			k
+		} else {
+			key(&to.value)
+		};
+		Self::new(beg, true, end, true)
+	}
+
+	/// Computes the range of the entries matching the leading columns, whose next column starts with a string
+	fn starts_with(
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		value: &str,
+	) -> Self {
+		let mut fd = prefix.clone();
+		fd.push(Value::from(value));
+		let mut beg = Index::prefix_composite(ns, db, ix_what, ix_name, &fd);
+		// Remove the terminator of the string, so that any longer string matches
+		beg.pop();
+		let mut end = beg.clone();
+		end.push(0xff);
+		Self::new(beg, true, end, true)
+	}
+
+	fn resume(&mut self, key: Key) {
+		if key > self.beg {
+			self.beg = key.min(self.end.clone());
+			self.beg_excl = None;
+		}
+	}
+
 	fn matches(&mut self, k: &Key) -> bool {
 		if let Some(b) = &self.beg_excl {
 			if b.eq(k) {
@@ -274,6 +391,41 @@ impl IndexRangeThingIterator {
 		Self::new(irf, ns, db, ix_what, ix_name, &full_range, &full_range)
 	}
 
+	/// Scans the entries matching the leading columns, within the range of the next column.
+	/// The keys are computed so that the bounds never need to be filtered.
+	#[allow(clippy::too_many_arguments)]
+	pub(super) fn compound(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		from: &RangeValue,
+		to: &RangeValue,
+	) -> Self {
+		Self {
+			irf,
+			r: RangeScan::compound(ns, db, ix_what, ix_name, prefix, from, to),
+		}
+	}
+
+	/// Scans the entries matching the leading columns, whose next column starts with a string
+	pub(super) fn starts_with(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		value: &str,
+	) -> Self {
+		Self {
+			irf,
+			r: RangeScan::starts_with(ns, db, ix_what, ix_name, prefix, value),
+		}
+	}
+
 	fn compute_beg(
 		ns: &str,
 		db: &str,
@@ -323,9 +475,9 @@ impl IndexRangeThingIterator {
 			self.r.beg.push(0x00);
 		}
 		let mut records = B::with_capacity(res.len());
-		res.into_iter()
-			.filter(|(k, _)| self.r.matches(k))
-			.for_each(|(_, v)| records.add((Arc::new(v.into()), self.irf.into(), None)));
+		res.into_iter().filter(|(k, _)| self.r.matches(k)).for_each(|(k, v)| {
+			records.add((Arc::new(v.into()), IteratorRecord::with_key(self.irf, k), None))
+		});
 		Ok(records)
 	}
 }
@@ -592,6 +744,43 @@ impl UniqueRangeThingIterator {
 		Self::new(irf, ns, db, ix_what, ix_name, &full_range, &full_range)
 	}
 
+	/// Scans the entries matching the leading columns, within the range of the next column.
+	/// The keys are computed so that the bounds never need to be filtered.
+	#[allow(clippy::too_many_arguments)]
+	pub(super) fn compound(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		from: &RangeValue,
+		to: &RangeValue,
+	) -> Self {
+		Self {
+			irf,
+			r: RangeScan::compound(ns, db, ix_what, ix_name, prefix, from, to),
+			done: false,
+		}
+	}
+
+	/// Scans the entries matching the leading columns, whose next column starts with a string
+	pub(super) fn starts_with(
+		irf: IteratorRef,
+		ns: &str,
+		db: &str,
+		ix_what: &Ident,
+		ix_name: &Ident,
+		prefix: &Array,
+		value: &str,
+	) -> Self {
+		Self {
+			irf,
+			r: RangeScan::starts_with(ns, db, ix_what, ix_name, prefix, value),
+			done: false,
+		}
+	}
+
 	fn compute_beg(
 		ns: &str,
 		db: &str,
@@ -643,14 +832,14 @@ impl UniqueRangeThingIterator {
 			}
 			if self.r.matches(&k) {
 				let rid: Thing = v.into();
-				records.add((rid.into(), self.irf.into(), None));
+				records.add((rid.into(), IteratorRecord::with_key(self.irf, k), None));
 			}
 		}
 		let end = self.r.end.clone();
 		if self.r.matches(&end) {
-			if let Some(v) = tx.get(end, None).await? {
+			if let Some(v) = tx.get(end.clone(), None).await? {
 				let rid: Thing = v.into();
-				records.add((rid.into(), self.irf.into(), None));
+				records.add((rid.into(), IteratorRecord::with_key(self.irf, end), None));
 			}
 		}
 		self.done = true;
@@ -778,6 +967,8 @@ impl MatchesThingIterator {
 						irf: self.irf,
 						doc_id: Some(doc_id),
 						dist: None,
+						key: None,
+						fusion: None,
 					};
 					records.add((thg.into(), ir, None));
 					self.hits_left -= 1;
@@ -819,6 +1010,8 @@ impl KnnIterator {
 					irf: self.irf,
 					doc_id: None,
 					dist: Some(dist),
+					key: None,
+					fusion: None,
 				};
 				records.add((thing, ir, val));
 			} else {
@@ -828,3 +1021,85 @@ impl KnnIterator {
 		Ok(records)
 	}
 }
+
+pub(crate) struct HybridIterator {
+	irf: IteratorRef,
+	hits: Option<HitsIterator>,
+	ft: FtEntry,
+	knn: VecDeque<KnnIteratorResult>,
+	/// The number of full-text hits which are fused
+	size: usize,
+	res: Option<VecDeque<HybridResult>>,
+}
+
+impl HybridIterator {
+	pub(super) fn new(
+		irf: IteratorRef,
+		hits: Option<HitsIterator>,
+		ft: FtEntry,
+		knn: VecDeque<KnnIteratorResult>,
+		size: usize,
+	) -> Self {
+		Self {
+			irf,
+			hits,
+			ft,
+			knn,
+			size,
+			res: None,
+		}
+	}
+
+	/// Scores every full-text hit, and fuses the best of them with the KNN results
+	async fn fuse(
+		&mut self,
+		ctx: &Context,
+		tx: &Transaction,
+	) -> Result<VecDeque<HybridResult>, Error> {
+		let mut ft = FtTopHits::new(self.size);
+		if let Some(hits) = &mut self.hits {
+			while !ctx.is_done() {
+				if let Some((thg, doc_id)) = hits.next(tx).await? {
+					let score = self.ft.score(tx, doc_id).await?.unwrap_or(0.0);
+					ft.push((Arc::new(thg), doc_id, score));
+				} else {
+					break;
+				}
+			}
+		}
+		Ok(hybrid::fuse(ft.into_hits(), std::mem::take(&mut self.knn)))
+	}
+
+	async fn next_batch<B: IteratorBatch>(
+		&mut self,
+		ctx: &Context,
+		tx: &Transaction,
+		limit: u32,
+	) -> Result<B, Error> {
+		// Both result sets are ranked before the first batch
+		if self.res.is_none() {
+			self.res = Some(self.fuse(ctx, tx).await?);
+		}
+		if let Some(res) = &mut self.res {
+			let limit = limit as usize;
+			let mut records = B::with_capacity(limit.min(res.len()));
+			while limit > records.len() && !ctx.is_done() {
+				if let Some((thing, doc_id, dist, val, fusion)) = res.pop_front() {
+					let ir = IteratorRecord {
+						irf: self.irf,
+						doc_id,
+						dist,
+						key: None,
+						fusion: Some(fusion),
+					};
+					records.add((thing, ir, val));
+				} else {
+					break;
+				}
+			}
+			Ok(records)
+		} else {
+			Ok(B::empty())
+		}
+	}
+}
@@ -1,7 +1,15 @@
-use crate::sql::{Expression, Number, Thing};
+use crate::ctx::Context;
+use crate::dbs::{Iterable, Options};
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::idx::planner::iterators::KnnIteratorResult;
+use crate::sql::index::{Distance, Quantization};
+use crate::sql::statements::DefineIndexStatement;
+use crate::sql::{Expression, Idiom, Number, Thing};
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use reblessive::tree::Stk;
 use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -152,3 +160,68 @@ impl KnnBruteForceResults {
 		None
 	}
 }
+
+/// Re-ranks the candidates of a quantized vector index. The distances between
+/// quantized vectors are approximations, so more candidates than requested are
+/// collected, and their distances to the full-precision vectors of the records
+/// are computed to select the nearest neighbors.
+#[derive(Clone, Copy)]
+pub(super) struct KnnRerank<'a> {
+	id: &'a Idiom,
+	dist: &'a Distance,
+	factor: usize,
+}
+
+impl<'a> KnnRerank<'a> {
+	pub(super) fn new(
+		ix: &'a DefineIndexStatement,
+		dist: &'a Distance,
+		quantization: Option<Quantization>,
+		rerank: Option<u16>,
+	) -> Option<Self> {
+		match (quantization, rerank, ix.cols.first()) {
+			(Some(_), Some(factor), Some(id)) if factor > 0 => Some(Self {
+				id,
+				dist,
+				factor: factor as usize,
+			}),
+			_ => None,
+		}
+	}
+
+	/// The number of candidates to collect for `k` results
+	pub(super) fn candidates(&self, k: usize) -> usize {
+		k * self.factor
+	}
+
+	pub(super) async fn rerank(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		pt: &[Number],
+		k: usize,
+		res: VecDeque<KnnIteratorResult>,
+	) -> Result<VecDeque<KnnIteratorResult>, Error> {
+		let txn = ctx.tx();
+		let pt = pt.to_vec();
+		let mut reranked = Vec::with_capacity(res.len());
+		for (rid, _, val) in res {
+			// The record may already have been fetched by the condition checker
+			let val = match val {
+				Some(val) => val,
+				None => Arc::new(Iterable::fetch_thing(&txn, opt, &rid).await?),
+			};
+			if val.is_none_or_null() {
+				continue;
+			}
+			let doc = CursorDoc::new(Some(rid.clone()), None, val.clone());
+			let v: Vec<Number> = self.id.compute(stk, ctx, opt, Some(&doc)).await?.try_into()?;
+			let dist = self.dist.compute(&v, &pt)?.to_float();
+			reranked.push((rid, dist, Some(val)));
+		}
+		reranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+		reranked.truncate(k);
+		Ok(reranked.into())
+	}
+}
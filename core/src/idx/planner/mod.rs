@@ -1,5 +1,7 @@
+pub(crate) mod aggregate;
 pub mod checker;
 pub(crate) mod executor;
+pub(crate) mod hybrid;
 pub(crate) mod iterators;
 pub(in crate::idx) mod knn;
 pub(crate) mod plan;
@@ -9,11 +11,13 @@ pub(in crate::idx) mod tree;
 use crate::ctx::Context;
 use crate::dbs::{Iterable, Iterator, Options};
 use crate::err::Error;
+use crate::idx::planner::aggregate::AggregatePlan;
 use crate::idx::planner::executor::{InnerQueryExecutor, IteratorEntry, QueryExecutor};
 use crate::idx::planner::iterators::IteratorRef;
 use crate::idx::planner::knn::KnnBruteForceResults;
 use crate::idx::planner::plan::{Plan, PlanBuilder};
 use crate::idx::planner::tree::Tree;
+use crate::sql::statements::SelectStatement;
 use crate::sql::with::With;
 use crate::sql::{Cond, Orders, Table};
 use reblessive::tree::Stk;
@@ -56,6 +60,25 @@ impl QueryPlanner {
 		}
 	}
 
+	/// Answers the aggregates of a `GROUP ALL` selection from the keys of the table, or
+	/// of its indexes, when possible. Returns `false` if the table has to be iterated.
+	pub(crate) async fn add_aggregate(
+		&self,
+		ctx: &Context,
+		stm: &SelectStatement,
+		t: &Table,
+		it: &mut Iterator,
+	) -> Result<bool, Error> {
+		let with = self.with.as_ref().map(|w| w.as_ref());
+		match AggregatePlan::build(ctx, &self.opt, with, stm, t).await? {
+			Some(agg) => {
+				it.ingest(Iterable::Aggregate(agg));
+				Ok(true)
+			}
+			None => Ok(false),
+		}
+	}
+
 	pub(crate) async fn add_iterables(
 		&mut self,
 		stk: &mut Stk,
@@ -77,6 +100,7 @@ impl QueryPlanner {
 
 		let is_knn = !tree.knn_expressions.is_empty();
 		let order = tree.index_map.order_limit.take();
+		let compound = tree.index_map.compound.take();
 		let mut exe = InnerQueryExecutor::new(
 			stk,
 			ctx,
@@ -86,6 +110,7 @@ impl QueryPlanner {
 			tree.knn_expressions,
 			tree.knn_brute_force_expressions,
 			tree.knn_condition,
+			it.start_limit(),
 		)
 		.await?;
 		match PlanBuilder::build(
@@ -93,12 +118,13 @@ impl QueryPlanner {
 			self.with.as_ref().map(|w| w.as_ref()),
 			tree.with_indexes,
 			order,
+			compound,
 		)? {
 			Plan::SingleIndex(exp, io) => {
 				if io.require_distinct() {
 					self.requires_distinct = true;
 				}
-				let is_order = exp.is_none();
+				let is_order = io.is_order();
 				let ir = exe.add_iterator(IteratorEntry::Single(exp, io));
 				self.add(t.clone(), Some(ir), exe, it);
 				if is_order {
@@ -123,6 +149,10 @@ impl QueryPlanner {
 				let ir = exe.add_iterator(IteratorEntry::Range(rq.exps, ixn, rq.from, rq.to));
 				self.add(t.clone(), Some(ir), exe, it);
 			}
+			Plan::Hybrid(ft, knn) => {
+				let ir = exe.add_iterator(IteratorEntry::Hybrid(ft, knn));
+				self.add(t.clone(), Some(ir), exe, it);
+			}
 			Plan::TableIterator(fallback) => {
 				if let Some(fallback) = fallback {
 					self.fallbacks.push(fallback);
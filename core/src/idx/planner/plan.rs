@@ -10,6 +10,9 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 use std::hash::Hash;
 use std::sync::Arc;
 
+/// An expression backed by an index
+type IndexedExpression = (Arc<Expression>, IndexOption);
+
 /// The `PlanBuilder` struct represents a builder for constructing query plans.
 pub(super) struct PlanBuilder {
 	/// Do we have at least one index?
@@ -34,6 +37,7 @@ impl PlanBuilder {
 		with: Option<&With>,
 		with_indexes: Vec<IndexRef>,
 		order: Option<IndexOption>,
+		compound: Option<IndexOption>,
 	) -> Result<Plan, Error> {
 		if let Some(With::NoIndex) = with {
 			return Ok(Plan::TableIterator(Some("WITH NOINDEX".to_string())));
@@ -53,6 +57,13 @@ impl PlanBuilder {
 				return Ok(Plan::TableIterator(Some(e.to_string())));
 			}
 		}
+		let mut compound = b.filter_index_option(compound.as_ref());
+		// An index restricted on several columns is the most selective option
+		if let Some(io) = &compound {
+			if io.compound_columns() > 1 && !b.has_exclusive_options() {
+				return Ok(Plan::SingleIndex(None, io.clone()));
+			}
+		}
 
 		// If every boolean operator are AND then we can use the single index plan
 		if b.all_and {
@@ -67,11 +78,19 @@ impl PlanBuilder {
 			if let Some((e, i)) = b.non_range_indexes.pop() {
 				return Ok(Plan::SingleIndex(Some(e), i));
 			}
+			// Then the compound index option
+			if let Some(io) = compound.take() {
+				return Ok(Plan::SingleIndex(None, io));
+			}
 			// If there is an order option
 			if let Some(o) = order {
 				return Ok(Plan::SingleIndex(None, o.clone()));
 			}
 		}
+		// A full-text match OR a KNN search are fused in a single iterator
+		else if let Some((ft, knn)) = b.take_hybrid() {
+			return Ok(Plan::Hybrid(ft, knn));
+		}
 		// If every expression is backed by an index with can use the MultiIndex plan
 		else if b.all_exp_with_index {
 			let mut ranges = Vec::with_capacity(b.groups.len());
@@ -84,6 +103,10 @@ impl PlanBuilder {
 			}
 			return Ok(Plan::MultiIndex(b.non_range_indexes, ranges));
 		}
+		// The compound index option only relies on the AND conditions at the root
+		if let Some(io) = compound {
+			return Ok(Plan::SingleIndex(None, io));
+		}
 		Ok(Plan::TableIterator(None))
 	}
 
@@ -116,6 +139,10 @@ impl PlanBuilder {
 				self.eval_node(right)?;
 				Ok(())
 			}
+			Node::StartsWith(..) => {
+				self.all_exp_with_index = false;
+				Ok(())
+			}
 			Node::Unsupported(reason) => Err(reason.to_owned()),
 			_ => Ok(()),
 		}
@@ -141,6 +168,33 @@ impl PlanBuilder {
 		}
 	}
 
+	/// Checks if the condition is the union of a full-text match and a KNN search
+	fn take_hybrid(&mut self) -> Option<(IndexedExpression, IndexedExpression)> {
+		if !self.all_exp_with_index || !self.groups.is_empty() || self.non_range_indexes.len() != 2
+		{
+			return None;
+		}
+		let ft = self
+			.non_range_indexes
+			.iter()
+			.position(|(_, io)| matches!(io.op(), IndexOperator::Matches(..)))?;
+		if !self
+			.non_range_indexes
+			.iter()
+			.any(|(_, io)| matches!(io.op(), IndexOperator::Knn(..) | IndexOperator::Ann(..)))
+		{
+			return None;
+		}
+		let ft = self.non_range_indexes.remove(ft);
+		let knn = self.non_range_indexes.remove(0);
+		Some((ft, knn))
+	}
+
+	/// Full-text matches must be served by their own index iterator
+	fn has_exclusive_options(&self) -> bool {
+		self.non_range_indexes.iter().any(|(_, io)| matches!(io.op(), IndexOperator::Matches(..)))
+	}
+
 	fn add_index_option(&mut self, group_ref: GroupRef, exp: Arc<Expression>, io: IndexOption) {
 		if let IndexOperator::RangePart(_, _) = io.op() {
 			let level = self.groups.entry(group_ref).or_default();
@@ -168,6 +222,8 @@ pub(super) enum Plan {
 	MultiIndex(Vec<(Arc<Expression>, IndexOption)>, Vec<(IndexRef, UnionRangeQueryBuilder)>),
 	/// Index scan for record matching a given range
 	SingleIndexRange(IndexRef, UnionRangeQueryBuilder),
+	/// Fusion of a full-text search and a KNN search
+	Hybrid((Arc<Expression>, IndexOption), (Arc<Expression>, IndexOption)),
 }
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
@@ -190,6 +246,19 @@ pub(super) enum IndexOperator {
 	Knn(Arc<Vec<Number>>, u32),
 	Ann(Arc<Vec<Number>>, u32, u32),
 	Order(bool),
+	/// Equality on the leading columns of an index, and a range on the following column
+	Compound {
+		prefix: Arc<Array>,
+		from: RangeValue,
+		to: RangeValue,
+		/// Are the records returned in the order of the first ORDER clause?
+		order: bool,
+	},
+	/// Equality on the leading columns of an index, and a string prefix on the following column
+	StartsWith {
+		prefix: Arc<Array>,
+		value: String,
+	},
 }
 
 impl IndexOption {
@@ -211,6 +280,38 @@ impl IndexOption {
 		matches!(self.op.as_ref(), IndexOperator::Union(_))
 	}
 
+	/// Are the records returned in the order of the first ORDER clause?
+	pub(super) fn is_order(&self) -> bool {
+		matches!(
+			self.op.as_ref(),
+			IndexOperator::Order(_)
+				| IndexOperator::Compound {
+					order: true,
+					..
+				}
+		)
+	}
+
+	/// The number of index columns which restrict a compound scan
+	pub(super) fn compound_columns(&self) -> usize {
+		match self.op.as_ref() {
+			IndexOperator::Compound {
+				prefix,
+				from,
+				to,
+				..
+			} => {
+				let bounded = !from.value.is_none() || !to.value.is_none();
+				prefix.len() + bounded as usize
+			}
+			IndexOperator::StartsWith {
+				prefix,
+				..
+			} => prefix.len() + 1,
+			_ => 0,
+		}
+	}
+
 	pub(super) fn ix_ref(&self) -> IndexRef {
 		self.ix_ref
 	}
@@ -287,6 +388,25 @@ impl IndexOption {
 				e.insert("operator", Value::from("Order"));
 				e.insert("ascending", Value::from(*asc));
 			}
+			IndexOperator::Compound {
+				prefix,
+				from,
+				to,
+				..
+			} => {
+				e.insert("operator", Value::from("compound"));
+				e.insert("prefix", Value::Array(prefix.as_ref().clone()));
+				e.insert("from", Value::from(from));
+				e.insert("to", Value::from(to));
+			}
+			IndexOperator::StartsWith {
+				prefix,
+				value,
+			} => {
+				e.insert("operator", Value::from("string::startsWith"));
+				e.insert("prefix", Value::Array(prefix.as_ref().clone()));
+				e.insert("value", Value::from(value.as_str()));
+			}
 		};
 		Value::from(e)
 	}
@@ -299,7 +419,7 @@ pub(super) struct RangeValue {
 }
 
 impl RangeValue {
-	fn set_to(&mut self, v: &Value) {
+	pub(super) fn set_to(&mut self, v: &Value) {
 		if self.value.is_none() {
 			self.value = v.clone();
 			return;
@@ -310,7 +430,7 @@ impl RangeValue {
 		}
 	}
 
-	fn set_to_inclusive(&mut self, v: &Value) {
+	pub(super) fn set_to_inclusive(&mut self, v: &Value) {
 		if self.value.is_none() {
 			self.value = v.clone();
 			self.inclusive = true;
@@ -326,7 +446,7 @@ impl RangeValue {
 		}
 	}
 
-	fn set_from(&mut self, v: &Value) {
+	pub(super) fn set_from(&mut self, v: &Value) {
 		if self.value.is_none() {
 			self.value = v.clone();
 			return;
@@ -337,7 +457,7 @@ impl RangeValue {
 		}
 	}
 
-	fn set_from_inclusive(&mut self, v: &Value) {
+	pub(super) fn set_from_inclusive(&mut self, v: &Value) {
 		if self.value.is_none() {
 			self.value = v.clone();
 			self.inclusive = true;
@@ -4,14 +4,14 @@ use crate::err::Error;
 use crate::idx::planner::executor::{
 	KnnBruteForceExpression, KnnBruteForceExpressions, KnnExpressions,
 };
-use crate::idx::planner::plan::{IndexOperator, IndexOption};
+use crate::idx::planner::plan::{IndexOperator, IndexOption, RangeValue};
 use crate::idx::planner::rewriter::KnnConditionRewriter;
 use crate::kvs::Transaction;
 use crate::sql::index::Index;
 use crate::sql::statements::{DefineFieldStatement, DefineIndexStatement};
 use crate::sql::{
-	Array, Cond, Expression, Idiom, Kind, Number, Operator, Order, Orders, Part, Subquery, Table,
-	Value, With,
+	Array, Cond, Expression, Function, Idiom, Kind, Number, Operator, Order, Orders, Part,
+	Subquery, Table, Value, With,
 };
 use reblessive::tree::Stk;
 use std::collections::HashMap;
@@ -43,6 +43,7 @@ impl Tree {
 			b.eval_cond(stk, cond).await?;
 		}
 		b.eval_order().await?;
+		b.eval_compound_indexes();
 		Ok(Self {
 			root: b.root,
 			index_map: b.index_map,
@@ -152,6 +153,96 @@ impl<'a> TreeBuilder<'a> {
 		Ok(())
 	}
 
+	/// Looks for a standard or unique index whose leading columns are matched by equalities,
+	/// followed by a range, a string prefix or the first ORDER on the next column.
+	fn eval_compound_indexes(&mut self) {
+		// KNN expressions require their own iterators
+		if !self.knn_expressions.is_empty() {
+			return;
+		}
+		let (Some(root), Some(schema)) = (&self.root, self.schemas.get(self.table)) else {
+			return;
+		};
+		let mut conditions = Vec::new();
+		Condition::collect(root, &mut conditions);
+		let mut best: Option<(usize, &DefineIndexStatement, IndexOperator)> = None;
+		for ix in schema.indexes.iter() {
+			if !matches!(ix.index, Index::Idx | Index::Uniq) {
+				continue;
+			}
+			if let Some(With::Index(ixs)) = self.with {
+				if !ixs.contains(&ix.name.0) {
+					continue;
+				}
+			}
+			// Collect the equalities on the leading columns
+			let mut prefix = Vec::new();
+			while let Some(col) = ix.cols.get(prefix.len()) {
+				match conditions.iter().find_map(|c| c.equality(col)) {
+					Some(v) => prefix.push(v),
+					None => break,
+				}
+			}
+			// Collect the range or the string prefix on the next column
+			let mut from = RangeValue::default();
+			let mut to = RangeValue::default();
+			let mut starts_with = None;
+			let mut order = false;
+			if let Some(col) = ix.cols.get(prefix.len()) {
+				for c in conditions.iter() {
+					match c {
+						Condition::Compare(id, op, v) if *id == col && Condition::is_key(v) => {
+							match op {
+								Operator::LessThan => to.set_to(v),
+								Operator::LessThanOrEqual => to.set_to_inclusive(v),
+								Operator::MoreThan => from.set_from(v),
+								Operator::MoreThanOrEqual => from.set_from_inclusive(v),
+								_ => {}
+							}
+						}
+						Condition::StartsWith(id, s) if *id == col => starts_with = Some(*s),
+						_ => {}
+					}
+				}
+				if let Some(o) = self.first_order {
+					order = o.order == *col && o.direction && !o.random && !o.collate && !o.numeric;
+				}
+			}
+			// Single column ranges and equalities are already handled by the index options
+			if ix.cols.len() < 2 && starts_with.is_none() {
+				continue;
+			}
+			let bounded = starts_with.is_some() || !from.value.is_none() || !to.value.is_none();
+			let score = prefix.len() * 2 + bounded as usize;
+			if score == 0 || matches!(&best, Some((s, ..)) if *s >= score) {
+				continue;
+			}
+			let prefix = Arc::new(Array::from(prefix));
+			let op = match starts_with {
+				Some(s) => IndexOperator::StartsWith {
+					prefix,
+					value: s.to_owned(),
+				},
+				None => IndexOperator::Compound {
+					prefix,
+					from,
+					to,
+					order,
+				},
+			};
+			best = Some((score, ix, op));
+		}
+		if let Some((_, ix, op)) = best {
+			let ixr = self.index_map.definitions.len() as IndexRef;
+			if let Some(With::Index(_)) = self.with {
+				self.with_indexes.push(ixr);
+			}
+			let id = ix.cols[0].clone();
+			self.index_map.definitions.push(ix.clone());
+			self.index_map.compound = Some(IndexOption::new(ixr, id, IdiomPosition::None, op));
+		}
+	}
+
 	async fn eval_cond(&mut self, stk: &mut Stk, cond: &Cond) -> Result<(), Error> {
 		self.root = Some(self.eval_value(stk, 0, &cond.0).await?);
 		self.knn_condition = if self.knn_expressions.is_empty() {
@@ -182,14 +273,38 @@ impl<'a> TreeBuilder<'a> {
 			| Value::Datetime(_)
 			| Value::Param(_)
 			| Value::Null
-			| Value::None
-			| Value::Function(_) => Ok(Node::Computable),
+			| Value::None => Ok(Node::Computable),
+			Value::Function(f) => self.eval_function(stk, group, f).await,
 			Value::Array(a) => self.eval_array(stk, a).await,
 			Value::Subquery(s) => self.eval_subquery(stk, s).await,
 			_ => Ok(Node::Unsupported(format!("Unsupported value: {}", v))),
 		}
 	}
 
+	async fn eval_function(
+		&mut self,
+		stk: &mut Stk,
+		group: GroupRef,
+		f: &Function,
+	) -> Result<Node, Error> {
+		// Detect a string prefix on a field, which can be served by a prefix scan
+		if let Function::Normal(name, args) = f {
+			if name == "string::startsWith" {
+				if let [Value::Idiom(i), v] = args.as_slice() {
+					let n = stk.run(|stk| self.eval_idiom(stk, group, i)).await?;
+					if let Some(id) = n.is_field() {
+						if let Ok(Value::Strand(s)) =
+							stk.run(|stk| v.compute(stk, self.ctx, self.opt, None)).await
+						{
+							return Ok(Node::StartsWith(id.clone(), s.0));
+						}
+					}
+				}
+			}
+		}
+		Ok(Node::Computable)
+	}
+
 	async fn compute(&self, stk: &mut Stk, v: &Value, n: Node) -> Result<Node, Error> {
 		Ok(if n == Node::Computable {
 			match v.compute(stk, self.ctx, self.opt, None).await {
@@ -583,6 +698,7 @@ pub(super) struct IndexesMap {
 	pub(super) options: Vec<(Arc<Expression>, IndexOption)>,
 	pub(super) definitions: Vec<DefineIndexStatement>,
 	pub(super) order_limit: Option<IndexOption>,
+	pub(super) compound: Option<IndexOption>,
 }
 
 #[derive(Clone)]
@@ -616,6 +732,7 @@ pub(super) enum Node {
 	IndexedField(Idiom, Vec<IndexRef>),
 	RecordField(Idiom, RecordOptions),
 	NonIndexedField(Idiom),
+	StartsWith(Idiom, String),
 	Computable,
 	Computed(Arc<Value>),
 	Unsupported(String),
@@ -650,6 +767,54 @@ impl Node {
 	}
 }
 
+/// A condition of the AND chain at the root of the WHERE clause
+enum Condition<'a> {
+	Compare(&'a Idiom, Operator, Arc<Value>),
+	StartsWith(&'a Idiom, &'a str),
+}
+
+impl<'a> Condition<'a> {
+	/// Collects the conditions which every matching record satisfies
+	fn collect(n: &'a Node, conditions: &mut Vec<Self>) {
+		match n {
+			Node::Expression {
+				left,
+				right,
+				exp,
+				..
+			} => {
+				let op = exp.operator();
+				if let Operator::And = op {
+					Self::collect(left, conditions);
+					Self::collect(right, conditions);
+				} else if let (Some(id), Some(v)) = (left.is_field(), right.is_computed()) {
+					conditions.push(Self::Compare(id, IdiomPosition::Left.transform(op), v));
+				} else if let (Some(id), Some(v)) = (right.is_field(), left.is_computed()) {
+					conditions.push(Self::Compare(id, IdiomPosition::Right.transform(op), v));
+				}
+			}
+			Node::StartsWith(id, s) => conditions.push(Self::StartsWith(id, s)),
+			_ => {}
+		}
+	}
+
+	fn equality(&self, col: &Idiom) -> Option<Value> {
+		match self {
+			Self::Compare(id, Operator::Equal | Operator::Exact, v)
+				if *id == col && Self::is_key(v) =>
+			{
+				Some(v.as_ref().clone())
+			}
+			_ => None,
+		}
+	}
+
+	/// Arrays are indexed per element, so only scalar values match a single column of the key
+	fn is_key(v: &Value) -> bool {
+		!matches!(v, Value::None | Value::Array(_) | Value::Object(_))
+	}
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub(super) enum IdiomPosition {
 	/// The idiom is on the left of the condition clause
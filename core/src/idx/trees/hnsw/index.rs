@@ -9,7 +9,7 @@ use crate::idx::trees::knn::{KnnResult, KnnResultBuilder};
 use crate::idx::trees::vector::{SharedVector, Vector};
 use crate::idx::IndexKeyBase;
 use crate::kvs::Transaction;
-use crate::sql::index::{HnswParams, VectorType};
+use crate::sql::index::{HnswParams, Quantization, VectorType};
 use crate::sql::{Id, Number, Value};
 #[cfg(debug_assertions)]
 use ahash::HashMap;
@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 pub struct HnswIndex {
 	dim: usize,
 	vector_type: VectorType,
+	quantization: Option<Quantization>,
 	hnsw: HnswFlavor,
 	docs: HnswDocs,
 	vec_docs: VecDocs,
@@ -80,6 +81,7 @@ impl HnswIndex {
 		Ok(Self {
 			dim: p.dimension as usize,
 			vector_type: p.vector_type,
+			quantization: p.quantization,
 			hnsw: HnswFlavor::new(ikb.clone(), p),
 			docs: HnswDocs::new(tx, tb, ikb.clone()).await?,
 			vec_docs: VecDocs::new(ikb),
@@ -101,6 +103,7 @@ impl HnswIndex {
 			// Extract the vector
 			let vector = Vector::try_from_value(self.vector_type, self.dim, value)?;
 			vector.check_dimension(self.dim)?;
+			let vector = vector.quantize(self.quantization);
 			// Insert the vector
 			self.vec_docs.insert(tx, vector, doc_id, &mut self.hnsw).await?;
 		}
@@ -121,6 +124,7 @@ impl HnswIndex {
 				// Extract the vector
 				let vector = Vector::try_from_value(self.vector_type, self.dim, v)?;
 				vector.check_dimension(self.dim)?;
+				let vector = vector.quantize(self.quantization);
 				// Remove the vector
 				self.vec_docs.remove(tx, &vector, doc_id, &mut self.hnsw).await?;
 			}
@@ -144,8 +148,9 @@ impl HnswIndex {
 		mut chk: HnswConditionChecker<'_>,
 	) -> Result<VecDeque<KnnIteratorResult>, Error> {
 		// Extract the vector
-		let vector: SharedVector = Vector::try_from_vector(self.vector_type, pt)?.into();
+		let vector = Vector::try_from_vector(self.vector_type, pt)?;
 		vector.check_dimension(self.dim)?;
+		let vector: SharedVector = vector.quantize(self.quantization).into();
 		let search = HnswSearch::new(vector, k, ef);
 		// Do the search
 		let result = self.search(tx, stk, &search, &mut chk).await?;
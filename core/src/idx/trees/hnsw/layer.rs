@@ -109,7 +109,7 @@ where
 		ep_dist: f64,
 		ep_id: ElementId,
 		chk: &mut HnswConditionChecker<'_>,
-	) -> Result<DoublePriorityQueue, Error> {
+	) -> Result<Option<DoublePriorityQueue>, Error> {
 		let visited = HashSet::from_iter([ep_id]);
 		let candidates = DoublePriorityQueue::from(ep_dist, ep_id);
 		let mut w = DoublePriorityQueue::default();
@@ -198,13 +198,12 @@ where
 		mut visited: HashSet<ElementId>,
 		mut w: DoublePriorityQueue,
 		chk: &mut HnswConditionChecker<'_>,
-	) -> Result<DoublePriorityQueue, Error> {
-		let mut f_dist = w.peek_last_dist().unwrap_or(f64::MAX);
-
+	) -> Result<Option<DoublePriorityQueue>, Error> {
 		let ef = search.ef();
 		let pt = search.pt();
 		let elements = search.elements();
 
+		let mut f_dist = Self::furthest_dist(&w, ef);
 		while let Some((dist, doc)) = candidates.pop_first() {
 			if dist > f_dist {
 				break;
@@ -217,21 +216,38 @@ where
 					}
 					if let Some(e_pt) = elements.get_vector(tx, &e_id).await? {
 						let e_dist = elements.distance(&e_pt, pt);
-						if e_dist < f_dist || w.len() < ef {
+						if e_dist < f_dist {
+							// Elements which don't match the condition are still expanded,
+							// so the traversal can reach the matching elements behind them
 							candidates.push(e_dist, e_id);
 							if Self::add_if_truthy(
 								tx, stk, search, &mut w, &e_pt, e_dist, e_id, chk,
 							)
 							.await?
 							{
-								f_dist = w.peek_last_dist().unwrap(); // w can't be empty
+								f_dist = Self::furthest_dist(&w, ef);
 							}
 						}
 					}
 				}
 			}
+			// The traversal would visit most of the graph before finding enough matches
+			if w.len() < ef && chk.is_highly_selective() {
+				return Ok(None);
+			}
+		}
+		Ok(Some(w))
+	}
+
+	/// The distance bounding the search. Until `ef` matching elements are found,
+	/// the search is not bounded, as the elements which don't match the condition
+	/// are not part of the dynamic list of found nearest neighbors.
+	fn furthest_dist(w: &DoublePriorityQueue, ef: usize) -> f64 {
+		if w.len() < ef {
+			f64::MAX
+		} else {
+			w.peek_last_dist().unwrap_or(f64::MAX)
 		}
-		Ok(w)
 	}
 
 	#[allow(clippy::too_many_arguments)]
@@ -250,7 +266,12 @@ where
 				w.push(e_dist, e_id);
 				if w.len() > search.ef() {
 					if let Some((_, id)) = w.pop_last() {
-						chk.expire(id);
+						// The evicted documents are no longer part of the result
+						if let Some(pt) = search.elements().get_vector(tx, &id).await? {
+							if let Some(docs) = search.vec_docs().get_docs(tx, &pt).await? {
+								chk.expires(docs);
+							}
+						}
 					}
 				}
 				return Ok(true);
@@ -368,16 +368,46 @@ where
 					&search.pt,
 					search.ef,
 				);
-				let w = self
+				let w = match self
 					.layer0
 					.search_single_checked(tx, stk, &search_ctx, &ep_pt, ep_dist, ep_id, chk)
-					.await?;
+					.await?
+				{
+					Some(w) => w,
+					// The condition is highly selective, so scan the matching vectors instead
+					None => self.knn_search_brute_force(tx, stk, &search_ctx, chk).await?,
+				};
 				return Ok(w.to_vec_limit(search.k));
 			}
 		}
 		Ok(vec![])
 	}
 
+	/// Computes the distance to every element which matches the condition,
+	/// rather than traversing the graph. The condition of the documents
+	/// which were already evaluated during the traversal is cached.
+	async fn knn_search_brute_force(
+		&self,
+		tx: &Transaction,
+		stk: &mut Stk,
+		search: &HnswCheckedSearchContext<'_>,
+		chk: &mut HnswConditionChecker<'_>,
+	) -> Result<DoublePriorityQueue, Error> {
+		let mut w = DoublePriorityQueue::default();
+		for e_id in 0..self.elements.next_element_id() {
+			if let Some(e_pt) = self.elements.get_vector(tx, &e_id).await? {
+				let e_dist = self.elements.distance(&e_pt, search.pt());
+				if w.len() < search.ef() || e_dist < w.peek_last_dist().unwrap_or(f64::MAX) {
+					HnswLayer::<L0>::add_if_truthy(
+						tx, stk, search, &mut w, &e_pt, e_dist, e_id, chk,
+					)
+					.await?;
+				}
+			}
+		}
+		Ok(w)
+	}
+
 	async fn search_ep(
 		&self,
 		tx: &Transaction,
@@ -569,6 +599,8 @@ mod tests {
 			efc as u16,
 			extend_candidates,
 			keep_pruned_connections,
+			None,
+			None,
 		)
 	}
 
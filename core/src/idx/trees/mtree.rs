@@ -24,7 +24,7 @@ use crate::idx::trees::store::{
 use crate::idx::trees::vector::{SharedVector, Vector};
 use crate::idx::{IndexKeyBase, VersionedStore};
 use crate::kvs::{Key, Transaction, TransactionType, Val};
-use crate::sql::index::{Distance, MTreeParams, VectorType};
+use crate::sql::index::{Distance, MTreeParams, Quantization, VectorType};
 use crate::sql::{Number, Object, Thing, Value};
 
 #[non_exhaustive]
@@ -33,6 +33,7 @@ pub struct MTreeIndex {
 	state_key: Key,
 	dim: usize,
 	vector_type: VectorType,
+	quantization: Option<Quantization>,
 	store: MTreeStore,
 	doc_ids: Arc<RwLock<DocIds>>,
 	mtree: Arc<RwLock<MTree>>,
@@ -76,6 +77,7 @@ impl MTreeIndex {
 			state_key,
 			dim: p.dimension as usize,
 			vector_type: p.vector_type,
+			quantization: p.quantization,
 			doc_ids,
 			mtree,
 			store,
@@ -100,6 +102,7 @@ impl MTreeIndex {
 			// Extract the vector
 			let vector = Vector::try_from_value(self.vector_type, self.dim, v)?;
 			vector.check_dimension(self.dim)?;
+			let vector = vector.quantize(self.quantization);
 			// Insert the vector in the index
 			mtree.insert(stk, txn, &mut self.store, vector.into(), doc_id).await?;
 		}
@@ -124,6 +127,7 @@ impl MTreeIndex {
 				// Extract the vector
 				let vector = Vector::try_from_value(self.vector_type, self.dim, v)?;
 				vector.check_dimension(self.dim)?;
+				let vector = vector.quantize(self.quantization);
 				// Remove the vector
 				mtree.delete(stk, txn, &mut self.store, vector.into(), doc_id).await?;
 			}
@@ -143,6 +147,7 @@ impl MTreeIndex {
 		// Extract the vector
 		let vector = Vector::try_from_vector(self.vector_type, v)?;
 		vector.check_dimension(self.dim)?;
+		let vector = vector.quantize(self.quantization);
 		// Build the search context
 		let search = MTreeSearchContext {
 			ctx,
@@ -1,3 +1,4 @@
+use crate::kvs::metrics;
 use ahash::{HashMap, HashMapExt};
 use futures::future::join_all;
 use std::sync::atomic::Ordering::Relaxed;
@@ -48,7 +49,7 @@ where
 		// Locate the shard
 		let n = key as usize % self.shards_count;
 		// Get and promote the key
-		self.shards[n].lock().await.get_and_promote(key)
+		metrics::record_index_cache(self.shards[n].lock().await.get_and_promote(key))
 	}
 
 	pub(super) async fn insert<K: Into<CacheKey>>(&self, key: K, val: V) {
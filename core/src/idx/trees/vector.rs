@@ -1,7 +1,7 @@
 use crate::err::Error;
 use crate::fnc::util::math::ToFloat;
 use crate::idx::VersionedStore;
-use crate::sql::index::{Distance, VectorType};
+use crate::sql::index::{Distance, Quantization, VectorType};
 use crate::sql::{Number, Value};
 use ahash::AHasher;
 use ahash::HashSet;
@@ -26,9 +26,13 @@ pub enum Vector {
 	I64(Array1<i64>),
 	I32(Array1<i32>),
 	I16(Array1<i16>),
+	/// Scalar quantized vector, with the scale of its values
+	Int8(Array1<i8>, f32),
+	/// Binary quantized vector, with one bit per dimension
+	Binary(Array1<u8>),
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum SerializedVector {
@@ -37,6 +41,10 @@ pub enum SerializedVector {
 	I64(Vec<i64>),
 	I32(Vec<i32>),
 	I16(Vec<i16>),
+	#[revision(start = 2)]
+	Int8(Vec<i8>, f32),
+	#[revision(start = 2)]
+	Binary(Vec<u8>),
 }
 
 impl VersionedStore for SerializedVector {}
@@ -49,6 +57,8 @@ impl From<&Vector> for SerializedVector {
 			Vector::I64(v) => Self::I64(v.to_vec()),
 			Vector::I32(v) => Self::I32(v.to_vec()),
 			Vector::I16(v) => Self::I16(v.to_vec()),
+			Vector::Int8(v, s) => Self::Int8(v.to_vec(), *s),
+			Vector::Binary(v) => Self::Binary(v.to_vec()),
 		}
 	}
 }
@@ -61,6 +71,8 @@ impl From<SerializedVector> for Vector {
 			SerializedVector::I64(v) => Self::I64(Array1::from_vec(v)),
 			SerializedVector::I32(v) => Self::I32(Array1::from_vec(v)),
 			SerializedVector::I16(v) => Self::I16(Array1::from_vec(v)),
+			SerializedVector::Int8(v, s) => Self::Int8(Array1::from_vec(v), s),
+			SerializedVector::Binary(v) => Self::Binary(Array1::from_vec(v)),
 		}
 	}
 }
@@ -394,6 +406,15 @@ impl Hash for Vector {
 				let h = v.iter().fold(0, |acc, &x| acc ^ x);
 				state.write_i16(h);
 			}
+			Vector::Int8(v, s) => {
+				let h = v.iter().fold(0, |acc, &x| acc ^ x);
+				state.write_i8(h);
+				state.write_u32(s.to_bits());
+			}
+			Vector::Binary(v) => {
+				let h = v.iter().fold(0, |acc, &x| acc ^ x);
+				state.write_u8(h);
+			}
 		}
 	}
 }
@@ -414,6 +435,8 @@ impl From<&Vector> for Value {
 			Vector::I64(a) => a.iter().map(|i| Number::Int(*i)).collect(),
 			Vector::I32(a) => a.iter().map(|i| Number::Int(*i as i64)).collect(),
 			Vector::I16(a) => a.iter().map(|i| Number::Int(*i as i64)).collect(),
+			Vector::Int8(a, s) => a.iter().map(|i| Number::Float(*i as f64 * *s as f64)).collect(),
+			Vector::Binary(a) => a.iter().map(|i| Number::Int(*i as i64)).collect(),
 		};
 		Value::from(vec)
 	}
@@ -518,6 +541,8 @@ impl Vector {
 			Self::I64(v) => v.len(),
 			Self::I32(v) => v.len(),
 			Self::I16(v) => v.len(),
+			Self::Int8(v, _) => v.len(),
+			Self::Binary(v) => v.len(),
 		}
 	}
 
@@ -537,8 +562,93 @@ impl Vector {
 	}
 }
 
+impl Vector {
+	/// Quantizes a full-precision vector. Int8 quantization scales the values of the
+	/// vector to the range of a signed byte, keeping the scale to compute distances.
+	/// Binary quantization keeps one bit per dimension, set when the value is positive.
+	pub(super) fn quantize(self, q: Option<Quantization>) -> Self {
+		let Some(q) = q else {
+			return self;
+		};
+		let v: Vec<f64> = match &self {
+			Self::F64(v) => v.iter().map(ToFloat::to_float).collect(),
+			Self::F32(v) => v.iter().map(ToFloat::to_float).collect(),
+			Self::I64(v) => v.iter().map(ToFloat::to_float).collect(),
+			Self::I32(v) => v.iter().map(ToFloat::to_float).collect(),
+			Self::I16(v) => v.iter().map(ToFloat::to_float).collect(),
+			// The vector is already quantized
+			Self::Int8(..) | Self::Binary(_) => return self,
+		};
+		match q {
+			Quantization::Int8 => {
+				let max = v.iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+				let scale = if max > 0.0 {
+					max / i8::MAX as f64
+				} else {
+					1.0
+				};
+				let v = v.iter().map(|x| (x / scale).round() as i8).collect();
+				Self::Int8(v, scale as f32)
+			}
+			Quantization::Binary => {
+				let mut bits = vec![0u8; v.len().div_ceil(8)];
+				for (i, x) in v.iter().enumerate() {
+					if *x > 0.0 {
+						bits[i / 8] |= 1 << (i % 8);
+					}
+				}
+				Self::Binary(Array1::from_vec(bits))
+			}
+		}
+	}
+
+	#[inline]
+	fn dequantize(a: &Array1<i8>, s: f32) -> Array1<f64> {
+		a.mapv(|x| x as f64 * s as f64)
+	}
+
+	/// The distance between two int8 quantized vectors. The common
+	/// distances are computed without dequantizing the vectors.
+	fn int8_distance(dist: &Distance, a: &Array1<i8>, sa: f32, b: &Array1<i8>, sb: f32) -> f64 {
+		let (fa, fb) = (sa as f64, sb as f64);
+		let diff = || a.iter().zip(b.iter()).map(|(x, y)| (*x as f64 * fa - *y as f64 * fb).abs());
+		match dist {
+			Distance::Euclidean => diff().map(|d| d * d).sum::<f64>().sqrt(),
+			Distance::Manhattan => diff().sum(),
+			Distance::Chebyshev => diff().fold(0.0_f64, f64::max),
+			Distance::Cosine => {
+				// The scales are positive, so they don't change the angle
+				let (dot, na, nb) =
+					a.iter().zip(b.iter()).fold((0, 0, 0), |(d, na, nb), (x, y)| {
+						let (x, y) = (*x as i64, *y as i64);
+						(d + x * y, na + x * x, nb + y * y)
+					});
+				1.0 - dot as f64 / ((na as f64).sqrt() * (nb as f64).sqrt())
+			}
+			_ => dist.calculate(
+				&Self::F64(Self::dequantize(a, sa)),
+				&Self::F64(Self::dequantize(b, sb)),
+			),
+		}
+	}
+
+	/// The hamming distance between two binary quantized vectors, which
+	/// is used as the approximation of every distance.
+	#[inline]
+	fn binary_distance(a: &Array1<u8>, b: &Array1<u8>) -> f64 {
+		a.iter().zip(b.iter()).map(|(x, y)| (x ^ y).count_ones()).sum::<u32>() as f64
+	}
+}
+
 impl Distance {
 	pub(super) fn calculate(&self, a: &Vector, b: &Vector) -> f64 {
+		match (a, b) {
+			(Vector::Int8(a, sa), Vector::Int8(b, sb)) => {
+				return Vector::int8_distance(self, a, *sa, b, *sb);
+			}
+			(Vector::Binary(a), Vector::Binary(b)) => return Vector::binary_distance(a, b),
+			_ => {}
+		}
 		match self {
 			Distance::Chebyshev => a.chebyshev_distance(b),
 			Distance::Cosine => a.cosine_distance(b),
@@ -556,7 +666,8 @@ impl Distance {
 mod tests {
 	use crate::idx::trees::knn::tests::{get_seed_rnd, new_random_vec, RandomItemGenerator};
 	use crate::idx::trees::vector::{SharedVector, Vector};
-	use crate::sql::index::{Distance, VectorType};
+	use crate::sql::index::{Distance, Quantization, VectorType};
+	use ndarray::Array1;
 
 	fn test_distance(dist: Distance, a1: &[f64], a2: &[f64], res: f64) {
 		// Convert the arrays to Vec<Number>
@@ -651,4 +762,44 @@ mod tests {
 		test_distance_collection(Distance::Pearson, 100, 1536);
 		test_distance(Distance::Pearson, &[1.0, 2.0, 3.0], &[2.0, 3.0, 4.0], 1.0);
 	}
+
+	#[test]
+	fn test_quantize_int8() {
+		let v = Vector::F64(Array1::from_vec(vec![-2.0, 0.5, 1.0]));
+		let q = v.quantize(Some(Quantization::Int8));
+		let Vector::Int8(values, scale) = &q else {
+			panic!("{q:?}");
+		};
+		assert_eq!(values.to_vec(), vec![-127, 32, 64]);
+		assert_eq!(*scale, (2.0 / 127.0) as f32);
+		// A quantized vector is not quantized again
+		assert_eq!(q.clone().quantize(Some(Quantization::Binary)), q);
+		// The distances are approximations of the full-precision distances
+		let a = Vector::F64(Array1::from_vec(vec![1.0, 2.0, 3.0]));
+		let b = Vector::F64(Array1::from_vec(vec![2.0, 3.0, 4.0]));
+		for dist in
+			[Distance::Euclidean, Distance::Manhattan, Distance::Chebyshev, Distance::Cosine]
+		{
+			let expected = dist.calculate(&a, &b);
+			let qa = a.clone().quantize(Some(Quantization::Int8));
+			let qb = b.clone().quantize(Some(Quantization::Int8));
+			let d = dist.calculate(&qa, &qb);
+			assert!((d - expected).abs() < 0.05, "{dist}: {d} - {expected}");
+		}
+	}
+
+	#[test]
+	fn test_quantize_binary() {
+		let v = Vector::F32(Array1::from_vec(vec![1.0, -1.0, 0.0, 2.0, 0.5, -3.0, 1.0, 1.0, 4.0]));
+		let q = v.quantize(Some(Quantization::Binary));
+		assert_eq!(q, Vector::Binary(Array1::from_vec(vec![0b1101_1001, 0b0000_0001])));
+		let o = Vector::F32(Array1::from_vec(vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0]));
+		let o = o.quantize(Some(Quantization::Binary));
+		// The hamming distance between the bits is used for every distance
+		assert_eq!(Distance::Euclidean.calculate(&q, &o), 4.0);
+		assert_eq!(Distance::Cosine.calculate(&q, &o), 4.0);
+		// Without quantization, the vector is unchanged
+		let v = Vector::I16(Array1::from_vec(vec![1, 2]));
+		assert_eq!(v.clone().quantize(None), v);
+	}
 }
@@ -12,6 +12,8 @@ pub(crate) trait Categorise {
 pub enum Category {
 	/// crate::key::storage::version         /sv
 	Version,
+	/// crate::key::encryption               !e
+	Encryption,
 	/// crate::key::root::all                /
 	Root,
 	/// crate::key::root::access::ac         /!ac{ac}
@@ -26,6 +28,8 @@ pub enum Category {
 	NamespaceIdentifier,
 	/// crate::key::root::ns                 /!ns{ns}
 	Namespace,
+	/// crate::key::root::rl                 /!rl{rl}
+	Role,
 	/// crate::key::root::us                 /!us{us}
 	User,
 	///
@@ -55,6 +59,8 @@ pub enum Category {
 	NamespaceAccessRoot,
 	/// crate::key::namespace::access::gr    /*{ns}*{ac}!gr{gr}
 	NamespaceAccessGrant,
+	/// crate::key::namespace::rl            /*{ns}!rl{rl}
+	NamespaceRole,
 	/// crate::key::namespace::us            /*{ns}!us{us}
 	NamespaceUser,
 	///
@@ -70,12 +76,22 @@ pub enum Category {
 	DatabaseAccessGrant,
 	/// crate::key::database::az             /*{ns}*{db}!az{az}
 	DatabaseAnalyzer,
+	/// crate::key::database::cg             /*{ns}*{db}!cg
+	DatabaseChangefeedWatermark,
 	/// crate::key::database::fc             /*{ns}*{db}!fn{fc}
 	DatabaseFunction,
 	/// crate::key::database::ml             /*{ns}*{db}!ml{ml}{vn}
 	DatabaseModel,
 	/// crate::key::database::pa             /*{ns}*{db}!pa{pa}
 	DatabaseParameter,
+	/// crate::key::database::rl             /*{ns}*{db}!rl{rl}
+	DatabaseRole,
+	/// crate::key::database::rp             /*{ns}*{db}!rp
+	DatabaseReplica,
+	/// crate::key::database::sq             /*{ns}*{db}!sq{sq}
+	DatabaseSequence,
+	/// crate::key::database::sv             /*{ns}*{db}!sv{sq}
+	DatabaseSequenceValue,
 	/// crate::key::database::tb             /*{ns}*{db}!tb{tb}
 	DatabaseTable,
 	/// crate::key::database::ts             /*{ns}*{db}!ts{ts}
@@ -89,8 +105,12 @@ pub enum Category {
 	///
 	/// crate::key::table::all               /*{ns}*{db}*{tb}
 	TableRoot,
+	/// crate::key::table::ei                /*{ns}*{db}*{tb}!ei{id}
+	TableExpiryRecord,
 	/// crate::key::table::ev                /*{ns}*{db}*{tb}!ev{ev}
 	TableEvent,
+	/// crate::key::table::ex                /*{ns}*{db}*{tb}!ex{ts}{id}
+	TableExpiry,
 	/// crate::key::table::fd                /*{ns}*{db}*{tb}!fd{fd}
 	TableField,
 	/// crate::key::table::ft                /*{ns}*{db}*{tb}!ft{ft}
@@ -151,12 +171,18 @@ pub enum Category {
 	///
 	/// crate::key::graph                    /*{ns}*{db}*{tb}~{id}{eg}{fk}
 	Graph,
+	///
+	/// ------------------------------
+	///
+	/// crate::key::reference                /*{ns}*{db}*{tb}&{id}{ft}{ff}{fk}
+	Reference,
 }
 
 impl Display for Category {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		let name = match self {
 			Self::Version => "StorageVersion",
+			Self::Encryption => "EncryptionCheck",
 			Self::Root => "Root",
 			Self::Access => "Access",
 			Self::AccessRoot => "AccessRoot",
@@ -164,6 +190,7 @@ impl Display for Category {
 			Self::Node => "Node",
 			Self::NamespaceIdentifier => "NamespaceIdentifier",
 			Self::Namespace => "Namespace",
+			Self::Role => "Role",
 			Self::User => "User",
 			Self::NodeRoot => "NodeRoot",
 			Self::NodeLiveQuery => "NodeLiveQuery",
@@ -173,22 +200,30 @@ impl Display for Category {
 			Self::NamespaceAccess => "NamespaceAccess",
 			Self::NamespaceAccessRoot => "NamespaceAccessRoot",
 			Self::NamespaceAccessGrant => "NamespaceAccessGrant",
+			Self::NamespaceRole => "NamespaceRole",
 			Self::NamespaceUser => "NamespaceUser",
 			Self::DatabaseRoot => "DatabaseRoot",
 			Self::DatabaseAccess => "DatabaseAccess",
 			Self::DatabaseAccessRoot => "DatabaseAccessRoot",
 			Self::DatabaseAccessGrant => "DatabaseAccessGrant",
 			Self::DatabaseAnalyzer => "DatabaseAnalyzer",
+			Self::DatabaseChangefeedWatermark => "DatabaseChangefeedWatermark",
 			Self::DatabaseFunction => "DatabaseFunction",
 			Self::DatabaseModel => "DatabaseModel",
 			Self::DatabaseParameter => "DatabaseParameter",
+			Self::DatabaseRole => "DatabaseRole",
+			Self::DatabaseReplica => "DatabaseReplica",
+			Self::DatabaseSequence => "DatabaseSequence",
+			Self::DatabaseSequenceValue => "DatabaseSequenceValue",
 			Self::DatabaseTable => "DatabaseTable",
 			Self::DatabaseTableIdentifier => "DatabaseTableIdentifier",
 			Self::DatabaseTimestamp => "DatabaseTimestamp",
 			Self::DatabaseUser => "DatabaseUser",
 			Self::DatabaseVersionstamp => "DatabaseVersionstamp",
 			Self::TableRoot => "TableRoot",
+			Self::TableExpiryRecord => "TableExpiryRecord",
 			Self::TableEvent => "TableEvent",
+			Self::TableExpiry => "TableExpiry",
 			Self::TableField => "TableField",
 			Self::TableView => "TableView",
 			Self::IndexDefinition => "IndexDefinition",
@@ -213,6 +248,7 @@ impl Display for Category {
 			Self::ChangeFeed => "ChangeFeed",
 			Self::Thing => "Thing",
 			Self::Graph => "Graph",
+			Self::Reference => "Reference",
 		};
 		write!(f, "{}", name)
 	}
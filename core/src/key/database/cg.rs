@@ -0,0 +1,61 @@
+//! Stores the latest versionstamp which has been garbage collected from a change feed
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+// Cg stands for Change feed Garbage collection watermark
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+#[non_exhaustive]
+pub struct Cg<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str) -> Cg<'a> {
+	Cg::new(ns, db)
+}
+
+impl Categorise for Cg<'_> {
+	fn categorise(&self) -> Category {
+		Category::DatabaseChangefeedWatermark
+	}
+}
+
+impl<'a> Cg<'a> {
+	pub fn new(ns: &'a str, db: &'a str) -> Self {
+		Cg {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'!',
+			_d: b'c',
+			_e: b'g',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Cg::new(
+			"testns",
+			"testdb",
+		);
+		let enc = Cg::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0!cg");
+		let dec = Cg::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
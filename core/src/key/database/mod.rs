@@ -2,9 +2,14 @@ pub mod ac;
 pub mod access;
 pub mod all;
 pub mod az;
+pub mod cg;
 pub mod fc;
 pub mod ml;
 pub mod pa;
+pub mod rl;
+pub mod rp;
+pub mod sq;
+pub mod sv;
 pub mod tb;
 pub mod ti;
 pub mod ts;
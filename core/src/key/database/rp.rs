@@ -0,0 +1,61 @@
+//! Stores the replication state of a read only replica database
+use crate::key::category::Categorise;
+use crate::key::category::Category;
+use derive::Key;
+use serde::{Deserialize, Serialize};
+
+// Rp stands for Replica state
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Key)]
+#[non_exhaustive]
+pub struct Rp<'a> {
+	__: u8,
+	_a: u8,
+	pub ns: &'a str,
+	_b: u8,
+	pub db: &'a str,
+	_c: u8,
+	_d: u8,
+	_e: u8,
+}
+
+pub fn new<'a>(ns: &'a str, db: &'a str) -> Rp<'a> {
+	Rp::new(ns, db)
+}
+
+impl Categorise for Rp<'_> {
+	fn categorise(&self) -> Category {
+		Category::DatabaseReplica
+	}
+}
+
+impl<'a> Rp<'a> {
+	pub fn new(ns: &'a str, db: &'a str) -> Self {
+		Rp {
+			__: b'/',
+			_a: b'*',
+			ns,
+			_b: b'*',
+			db,
+			_c: b'!',
+			_d: b'r',
+			_e: b'p',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn key() {
+		use super::*;
+		#[rustfmt::skip]
+		let val = Rp::new(
+			"testns",
+			"testdb",
+		);
+		let enc = Rp::encode(&val).unwrap();
+		assert_eq!(enc, b"/*testns\0*testdb\0!rp");
+		let dec = Rp::decode(&enc).unwrap();
+		assert_eq!(val, dec);
+	}
+}
@@ -170,6 +170,26 @@ impl<'a> Index<'a> {
 		beg.extend_from_slice(&[0xff]);
 		beg
 	}
+
+	/// The prefix of the keys whose leading columns match the given values
+	pub fn prefix_composite(ns: &str, db: &str, tb: &str, ix: &str, fd: &Array) -> Vec<u8> {
+		let mut k = Self::prefix_ids(ns, db, tb, ix, fd);
+		// Remove the terminator of the array, so that any following column matches
+		k.pop();
+		k
+	}
+
+	pub fn prefix_composite_beg(ns: &str, db: &str, tb: &str, ix: &str, fd: &Array) -> Vec<u8> {
+		let mut beg = Self::prefix_composite(ns, db, tb, ix, fd);
+		beg.extend_from_slice(&[0x00]);
+		beg
+	}
+
+	pub fn prefix_composite_end(ns: &str, db: &str, tb: &str, ix: &str, fd: &Array) -> Vec<u8> {
+		let mut beg = Self::prefix_composite(ns, db, tb, ix, fd);
+		beg.extend_from_slice(&[0xff]);
+		beg
+	}
 }
 
 #[cfg(test)]
@@ -190,4 +210,21 @@ mod tests {
 		let dec = Index::decode(&enc).unwrap();
 		assert_eq!(val, dec);
 	}
+
+	#[test]
+	fn prefix_composite() {
+		use super::*;
+		let fd = vec!["testfd1", "testfd2"].into();
+		let id = "testid".into();
+		let enc =
+			Index::new("testns", "testdb", "testtb", "testix", &fd, Some(&id)).encode().unwrap();
+		let fd = vec!["testfd1"].into();
+		let beg = Index::prefix_composite_beg("testns", "testdb", "testtb", "testix", &fd);
+		let end = Index::prefix_composite_end("testns", "testdb", "testtb", "testix", &fd);
+		assert_eq!(beg, b"/*testns\0*testdb\0*testtb\0+testix\0*\0\0\0\x04testfd1\0\0");
+		assert!(beg < enc && enc < end);
+		let fd = vec!["testfd2"].into();
+		let beg = Index::prefix_composite_beg("testns", "testdb", "testtb", "testix", &fd);
+		assert!(enc < beg);
+	}
 }
@@ -1,6 +1,7 @@
 //! How the keys are structured in the key value store
 ///
 /// crate::key::version                  !v
+/// crate::key::encryption               !e
 ///
 /// crate::key::root::all                /
 /// crate::key::root::ac                 /!ac{ac}
@@ -8,6 +9,7 @@
 /// crate::key::root::nd                 /!nd{nd}
 /// crate::key::root::ni                 /!ni
 /// crate::key::root::ns                 /!ns{ns}
+/// crate::key::root::rl                 /!rl{rl}
 /// crate::key::root::us                 /!us{us}
 ///
 /// crate::key::node::all                /${nd}
@@ -21,6 +23,7 @@
 /// crate::key::namespace::db            /*{ns}!db{db}
 /// crate::key::namespace::di            /+{ns id}!di
 /// crate::key::namespace::lg            /*{ns}!lg{lg}
+/// crate::key::namespace::rl            /*{ns}!rl{rl}
 /// crate::key::namespace::us            /*{ns}!us{us}
 ///
 /// crate::key::namespace::access::all   /*{ns}&{ac}
@@ -29,9 +32,14 @@
 /// crate::key::database::all            /*{ns}*{db}
 /// crate::key::database::ac             /*{ns}*{db}!ac{ac}
 /// crate::key::database::az             /*{ns}*{db}!az{az}
+/// crate::key::database::cg             /*{ns}*{db}!cg
 /// crate::key::database::fc             /*{ns}*{db}!fn{fc}
 /// crate::key::database::ml             /*{ns}*{db}!ml{ml}{vn}
 /// crate::key::database::pa             /*{ns}*{db}!pa{pa}
+/// crate::key::database::rl             /*{ns}*{db}!rl{rl}
+/// crate::key::database::rp             /*{ns}*{db}!rp
+/// crate::key::database::sq             /*{ns}*{db}!sq{sq}
+/// crate::key::database::sv             /*{ns}*{db}!sv{sq}
 /// crate::key::database::tb             /*{ns}*{db}!tb{tb}
 /// crate::key::database::ti             /+{ns id}*{db id}!ti
 /// crate::key::database::ts             /*{ns}*{db}!ts{ts}
@@ -42,7 +50,9 @@
 /// crate::key::database::access::gr     /*{ns}*{db}&{ac}!gr{gr}
 ///
 /// crate::key::table::all               /*{ns}*{db}*{tb}
+/// crate::key::table::ei                /*{ns}*{db}*{tb}!ei{id}
 /// crate::key::table::ev                /*{ns}*{db}*{tb}!ev{ev}
+/// crate::key::table::ex                /*{ns}*{db}*{tb}!ex{ts}{id}
 /// crate::key::table::fd                /*{ns}*{db}*{tb}!fd{fd}
 /// crate::key::table::ft                /*{ns}*{db}*{tb}!ft{ft}
 /// crate::key::table::ix                /*{ns}*{db}*{tb}!ix{ix}
@@ -68,14 +78,18 @@
 ///
 /// crate::key::graph                    /*{ns}*{db}*{tb}~{id}{eg}{fk}
 ///
+/// crate::key::reference                /*{ns}*{db}*{tb}&{id}{ft}{ff}{fk}
+///
 pub(crate) mod category;
 pub(crate) mod change;
 pub(crate) mod database;
 pub(crate) mod debug;
+pub(crate) mod encryption;
 pub(crate) mod graph;
 pub(crate) mod index;
 pub(crate) mod namespace;
 pub(crate) mod node;
+pub(crate) mod reference;
 pub(crate) mod root;
 pub(crate) mod table;
 pub(crate) mod thing;
@@ -3,4 +3,5 @@ pub mod access;
 pub mod all;
 pub mod db;
 pub mod di;
+pub mod rl;
 pub mod us;
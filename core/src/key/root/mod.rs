@@ -4,4 +4,5 @@ pub mod all;
 pub mod nd;
 pub mod ni;
 pub mod ns;
+pub mod rl;
 pub mod us;
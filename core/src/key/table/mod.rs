@@ -1,5 +1,7 @@
 pub mod all;
+pub mod ei;
 pub mod ev;
+pub mod ex;
 pub mod fd;
 pub mod ft;
 pub mod ix;
@@ -7,6 +7,7 @@ use crate::kvs::batch::Batch;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use crate::vs::Versionstamp;
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::ops::Range;
 
@@ -107,6 +108,22 @@ pub trait Transaction {
 	where
 		K: Into<Key> + Sprintable + Debug;
 
+	/// Retrieve all the versions of a specific range of keys from the datastore.
+	///
+	/// This function fetches every stored version of the key-value pairs, along with the
+	/// version timestamp, and whether the version marks a deletion. It is only supported
+	/// by datastores which keep the history of their keys.
+	async fn scan_all_versions<K>(
+		&mut self,
+		_rng: Range<K>,
+		_limit: u32,
+	) -> Result<Vec<(Key, Val, u64, bool)>, Error>
+	where
+		K: Into<Key> + Sprintable + Debug,
+	{
+		Err(Error::UnsupportedVersionedQueries)
+	}
+
 	/// Fetch many keys from the datastore.
 	///
 	/// This function fetches all matching keys pairs from the underlying datastore concurrently.
@@ -176,6 +193,64 @@ pub trait Transaction {
 		Ok(out)
 	}
 
+	/// Retrieve a range of keys from the datastore, in reverse order.
+	///
+	/// This function fetches the last keys of the range, starting with the greatest key. Datastores
+	/// which can iterate backwards should override this function, as it otherwise scans the whole range.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self), fields(rng = rng.sprint()))]
+	async fn keysr<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key> + Sprintable + Debug,
+	{
+		// Check to see if transaction is closed
+		if self.closed() {
+			return Err(Error::TxFinished);
+		}
+		// Continue with function logic
+		let mut out = VecDeque::with_capacity(limit as usize);
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			let res = self.batch(rng, *NORMAL_FETCH_SIZE, false).await?;
+			next = res.next;
+			for (k, _) in res.values.into_iter() {
+				if out.len() == limit as usize {
+					out.pop_front();
+				}
+				if limit > 0 {
+					out.push_back(k);
+				}
+			}
+		}
+		Ok(out.into_iter().rev().collect())
+	}
+
+	/// Count the keys in a range of the datastore.
+	///
+	/// This function counts the matching keys, in grouped batches, without fetching the values.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self), fields(rng = rng.sprint()))]
+	async fn count<K>(&mut self, rng: Range<K>) -> Result<usize, Error>
+	where
+		K: Into<Key> + Sprintable + Debug,
+	{
+		// Check to see if transaction is closed
+		if self.closed() {
+			return Err(Error::TxFinished);
+		}
+		// Continue with function logic
+		let mut out = 0;
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			let res = self.batch(rng, *NORMAL_FETCH_SIZE, false).await?;
+			next = res.next;
+			out += res.values.len();
+		}
+		Ok(out)
+	}
+
 	/// Delete a range of prefixed keys from the datastore.
 	///
 	/// This function deletes all matching key-value pairs from the underlying datastore in grouped batches.
@@ -11,6 +11,8 @@ use crate::sql::statements::DefineIndexStatement;
 use crate::sql::statements::DefineModelStatement;
 use crate::sql::statements::DefineNamespaceStatement;
 use crate::sql::statements::DefineParamStatement;
+use crate::sql::statements::DefineRoleStatement;
+use crate::sql::statements::DefineSequenceStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
@@ -52,6 +54,8 @@ pub(super) enum Entry {
 	Nds(Arc<[Node]>),
 	/// A slice of DefineUserStatement specified at the root.
 	Rus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified at the root.
+	Rrs(Arc<[DefineRoleStatement]>),
 	/// A slice of DefineAccessStatement specified at the root.
 	Ras(Arc<[DefineAccessStatement]>),
 	/// A slice of AccessGrant specified at the root.
@@ -60,6 +64,8 @@ pub(super) enum Entry {
 	Nss(Arc<[DefineNamespaceStatement]>),
 	/// A slice of DefineUserStatement specified on a namespace.
 	Nus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified on a namespace.
+	Nrs(Arc<[DefineRoleStatement]>),
 	/// A slice of DefineAccessStatement specified on a namespace.
 	Nas(Arc<[DefineAccessStatement]>),
 	/// A slice of AccessGrant specified at on a namespace.
@@ -74,6 +80,8 @@ pub(super) enum Entry {
 	Dag(Arc<[AccessGrant]>),
 	/// A slice of DefineUserStatement specified on a database.
 	Dus(Arc<[DefineUserStatement]>),
+	/// A slice of DefineRoleStatement specified on a database.
+	Drs(Arc<[DefineRoleStatement]>),
 	/// A slice of DefineFunctionStatement specified on a database.
 	Fcs(Arc<[DefineFunctionStatement]>),
 	/// A slice of DefineTableStatement specified on a database.
@@ -82,6 +90,8 @@ pub(super) enum Entry {
 	Mls(Arc<[DefineModelStatement]>),
 	/// A slice of DefineParamStatement specified on a database.
 	Pas(Arc<[DefineParamStatement]>),
+	/// A slice of DefineSequenceStatement specified on a database.
+	Sqs(Arc<[DefineSequenceStatement]>),
 	/// A slice of DefineTableStatement specified on a database.
 	Tbs(Arc<[DefineTableStatement]>),
 	/// A slice of DefineEventStatement specified on a table.
@@ -119,6 +129,14 @@ impl Entry {
 			_ => unreachable!(),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Rrs`].
+	pub(super) fn into_rrs(self) -> Arc<[DefineRoleStatement]> {
+		match self {
+			Entry::Rrs(v) => v,
+			_ => unreachable!(),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineAccessStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Ras`].
 	pub(super) fn into_ras(self) -> Arc<[DefineAccessStatement]> {
@@ -167,6 +185,14 @@ impl Entry {
 			_ => unreachable!(),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Nrs`].
+	pub(super) fn into_nrs(self) -> Arc<[DefineRoleStatement]> {
+		match self {
+			Entry::Nrs(v) => v,
+			_ => unreachable!(),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineDatabaseStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Dbs`].
 	pub(super) fn into_dbs(self) -> Arc<[DefineDatabaseStatement]> {
@@ -199,6 +225,14 @@ impl Entry {
 			_ => unreachable!(),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineRoleStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Drs`].
+	pub(super) fn into_drs(self) -> Arc<[DefineRoleStatement]> {
+		match self {
+			Entry::Drs(v) => v,
+			_ => unreachable!(),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineAnalyzerStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Azs`].
 	pub(super) fn into_azs(self) -> Arc<[DefineAnalyzerStatement]> {
@@ -223,6 +257,14 @@ impl Entry {
 			_ => unreachable!(),
 		}
 	}
+	/// Converts this cache entry into a slice of [`DefineSequenceStatement`].
+	/// This panics if called on a cache entry that is not an [`Entry::Sqs`].
+	pub(super) fn into_sqs(self) -> Arc<[DefineSequenceStatement]> {
+		match self {
+			Entry::Sqs(v) => v,
+			_ => unreachable!(),
+		}
+	}
 	/// Converts this cache entry into a slice of [`DefineModelStatement`].
 	/// This panics if called on a cache entry that is not an [`Entry::Mls`].
 	pub(super) fn into_mls(self) -> Arc<[DefineModelStatement]> {
@@ -0,0 +1,409 @@
+#![cfg(any(feature = "kv-rocksdb", feature = "kv-surrealkv"))]
+
+//! Encryption at rest for the values which are stored by the on-disk storage engines.
+//! Values are encrypted with XChaCha20-Poly1305, using a key which is derived from the
+//! key specified when starting the datastore. Its 192-bit nonces are generated randomly
+//! for every value, which is safe for any number of values. The storage key of each value
+//! is used as the additional authenticated data, so that a value can not be moved to
+//! another key.
+//!
+//! Keys are only encrypted when key encryption is enabled, as the datastore depends on
+//! their order for range scans. Keys are encrypted deterministically, so that a key can
+//! be found again, and in a way which preserves prefixes: each byte is combined with a
+//! keyed BLAKE3 hash of the bytes before it. Keys with a common prefix therefore have a
+//! common encrypted prefix, but encrypted keys are not in the order of the keys. A range
+//! scan reads every key with the common prefix of the start and end of the range, and
+//! sorts the keys in the range once they are decrypted. Scans of large tables, and paged
+//! scans in particular, are therefore much slower. Encrypted keys still reveal which keys
+//! share a prefix, and the length of every key.
+
+use crate::err::Error;
+use crate::kvs::{Key, Val};
+use chacha20poly1305::aead::{AeadInPlace, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha256;
+use std::fs;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// The salt used when deriving the encryption key
+const SALT: &[u8] = b"surrealdb";
+/// The format version which prefixes every encrypted value
+const VERSION: u8 = 1;
+/// The format version which prefixes every encrypted key
+const KEY_VERSION: u8 = 1;
+/// The length of the nonce which prefixes every encrypted value
+const NONCE_LEN: usize = 24;
+/// The plaintext of the encryption check record
+const CHECK: &[u8] = b"surrealdb";
+/// The plaintext of the encryption check record when keys are encrypted
+const CHECK_KEYS: &[u8] = b"surrealdb:keys";
+/// The number of values which are re-encrypted in each transaction
+pub(crate) const REKEY_BATCH_SIZE: u32 = 1000;
+
+pub(crate) struct Cipher {
+	aead: XChaCha20Poly1305,
+	/// The key used to encrypt keys, if keys are encrypted
+	keys: Option<[u8; 32]>,
+}
+
+impl Cipher {
+	/// Derives a new cipher from an encryption key
+	pub(crate) fn new(key: &str, encrypt_keys: bool) -> Result<Self, Error> {
+		let hkdf = Hkdf::<Sha256>::new(Some(SALT), key.as_bytes());
+		let mut okm = [0u8; 32];
+		hkdf.expand(b"encryption", &mut okm)
+			.map_err(|_| Error::Encryption("Unable to derive the encryption key".to_owned()))?;
+		// Keys are encrypted with a separate key
+		let keys = match encrypt_keys {
+			true => {
+				let mut okm = [0u8; 32];
+				hkdf.expand(b"keys", &mut okm).map_err(|_| {
+					Error::Encryption("Unable to derive the key encryption key".to_owned())
+				})?;
+				Some(okm)
+			}
+			false => None,
+		};
+		Ok(Self {
+			aead: XChaCha20Poly1305::new(&okm.into()),
+			keys,
+		})
+	}
+
+	/// Checks if keys are encrypted as well as values
+	pub(crate) fn encrypts_keys(&self) -> bool {
+		self.keys.is_some()
+	}
+
+	/// Encrypts a key, if keys are encrypted
+	pub(crate) fn encrypt_key(&self, key: &[u8]) -> Key {
+		let Some(k) = &self.keys else {
+			return key.to_vec();
+		};
+		let mut prf = blake3::Hasher::new_keyed(k);
+		let mut out = Vec::with_capacity(1 + key.len());
+		out.push(KEY_VERSION);
+		for b in key {
+			// Each byte depends on the bytes before it
+			out.push(b ^ prf.finalize().as_bytes()[0]);
+			prf.update(&[*b]);
+		}
+		out
+	}
+
+	/// Decrypts a key, if keys are encrypted
+	pub(crate) fn decrypt_key(&self, key: &[u8]) -> Result<Key, Error> {
+		let Some(k) = &self.keys else {
+			return Ok(key.to_vec());
+		};
+		let Some((&KEY_VERSION, key)) = key.split_first() else {
+			return Err(Error::Encryption("Found a key which is not encrypted".to_owned()));
+		};
+		let mut prf = blake3::Hasher::new_keyed(k);
+		let mut out = Vec::with_capacity(key.len());
+		for c in key {
+			let b = c ^ prf.finalize().as_bytes()[0];
+			out.push(b);
+			prf.update(&[b]);
+		}
+		Ok(out)
+	}
+
+	/// Returns the range of encrypted keys which contains every key in a
+	/// range, which is every key with the common prefix of the range
+	pub(crate) fn encrypt_range(&self, rng: &Range<Key>) -> Range<Key> {
+		let len = rng.start.iter().zip(rng.end.iter()).take_while(|(a, b)| a == b).count();
+		let beg = self.encrypt_key(&rng.start[..len]);
+		// The encrypted prefix starts with the version, so it can always be incremented
+		let mut end = beg.clone();
+		while end.last() == Some(&0xff) {
+			end.pop();
+		}
+		if let Some(b) = end.last_mut() {
+			*b += 1;
+		}
+		beg..end
+	}
+
+	/// Decrypts the keys which were scanned from an encrypted range, and
+	/// returns the entries which are in the range, in the order of the keys
+	pub(crate) fn decrypt_range<T>(
+		&self,
+		rng: &Range<Key>,
+		res: impl IntoIterator<Item = (Key, T)>,
+	) -> Result<Vec<(Key, T)>, Error> {
+		let mut out = Vec::new();
+		for (k, v) in res {
+			let k = self.decrypt_key(&k)?;
+			if rng.contains(&k) {
+				out.push((k, v));
+			}
+		}
+		out.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+		Ok(out)
+	}
+
+	/// Encrypts the value which is stored at a key
+	pub(crate) fn encrypt(&self, key: &[u8], mut val: Val) -> Result<Val, Error> {
+		// Generate a random nonce for this value
+		let mut nonce = [0u8; NONCE_LEN];
+		OsRng
+			.try_fill_bytes(&mut nonce)
+			.map_err(|_| Error::Encryption("Unable to generate a nonce".to_owned()))?;
+		// Encrypt the value in place
+		self.aead
+			.encrypt_in_place(XNonce::from_slice(&nonce), key, &mut val)
+			.map_err(|_| Error::Encryption("Unable to encrypt a value".to_owned()))?;
+		// Output the version, nonce, and ciphertext
+		let mut out = Vec::with_capacity(1 + NONCE_LEN + val.len());
+		out.push(VERSION);
+		out.extend_from_slice(&nonce);
+		out.extend(val);
+		Ok(out)
+	}
+
+	/// Decrypts the value which is stored at a key
+	pub(crate) fn decrypt(&self, key: &[u8], val: Val) -> Result<Val, Error> {
+		// Check the format of the encrypted value
+		if val.len() < 1 + NONCE_LEN || val[0] != VERSION {
+			return Err(Error::Encryption("Found a value which is not encrypted".to_owned()));
+		}
+		let nonce = XNonce::from_slice(&val[1..1 + NONCE_LEN]);
+		// Decrypt the value in place
+		let mut buf = val[1 + NONCE_LEN..].to_vec();
+		self.aead
+			.decrypt_in_place(nonce, key, &mut buf)
+			.map_err(|_| Error::Encryption("Unable to decrypt a value".to_owned()))?;
+		Ok(buf)
+	}
+
+	/// Creates the encryption check record for this key
+	pub(crate) fn check(&self) -> Result<Val, Error> {
+		self.encrypt(&check_key(), self.check_text().to_vec())
+	}
+
+	/// The plaintext of the encryption check record, which records whether
+	/// keys are encrypted
+	fn check_text(&self) -> &'static [u8] {
+		match self.encrypts_keys() {
+			true => CHECK_KEYS,
+			false => CHECK,
+		}
+	}
+
+	/// Decrypts a value, if encryption is enabled
+	pub(crate) fn decrypt_opt(
+		cipher: Option<&Self>,
+		key: &[u8],
+		val: Option<Val>,
+	) -> Result<Option<Val>, Error> {
+		match (cipher, val) {
+			(Some(c), Some(v)) => c.decrypt(key, v).map(Some),
+			(_, v) => Ok(v),
+		}
+	}
+}
+
+/// The key of the encryption check record
+pub(crate) fn check_key() -> Key {
+	crate::key::encryption::new().into()
+}
+
+/// Verifies that a datastore is opened with the key it was encrypted with. The
+/// check record is decrypted with the key, so that a wrong key is detected when
+/// the datastore is opened, rather than when the first value is read. The check
+/// record is never stored at an encrypted key, and also records whether keys are
+/// encrypted. Returns a new check record to store when encryption is enabled on
+/// an empty datastore.
+pub(crate) fn verify(
+	cipher: Option<&Cipher>,
+	check: Option<Val>,
+	empty: bool,
+) -> Result<Option<Val>, Error> {
+	match (cipher, check) {
+		// The datastore is not encrypted
+		(None, None) => Ok(None),
+		// The datastore is encrypted, but there is no key
+		(None, Some(_)) => Err(Error::EncryptionKeyMissing),
+		// The datastore is encrypted, so check the key
+		(Some(c), Some(v)) => match c.decrypt(&check_key(), v) {
+			Ok(v) if v == c.check_text() => Ok(None),
+			Ok(v) if v == CHECK || v == CHECK_KEYS => Err(Error::EncryptionKeysMismatch),
+			_ => Err(Error::EncryptionKeyMismatch),
+		},
+		// This is a new datastore, so store the check record
+		(Some(c), None) if empty => c.check().map(Some),
+		// The datastore has existing unencrypted data
+		(Some(_), None) => Err(Error::EncryptionNotEnabled),
+	}
+}
+
+/// The directories which are used while a datastore is re-encrypted
+///
+/// The values are copied into a new datastore next to the existing one, which
+/// then replaces it, so that no values remain encrypted with the previous key.
+/// The existing datastore is only moved aside once the copy is complete, so an
+/// interrupted rekey either starts again, or completes the replacement.
+pub(crate) struct Rekey {
+	/// The existing datastore
+	path: PathBuf,
+	/// The datastore which the values are copied into
+	pub(crate) new: PathBuf,
+	/// The existing datastore, while it is being replaced
+	old: PathBuf,
+}
+
+impl Rekey {
+	pub(crate) fn new(path: &str) -> Self {
+		Self {
+			path: PathBuf::from(path),
+			new: PathBuf::from(format!("{path}.rekey")),
+			old: PathBuf::from(format!("{path}.old")),
+		}
+	}
+
+	/// Checks that a datastore is not opened while it is being re-encrypted
+	pub(crate) fn check(path: &str) -> Result<(), Error> {
+		match Self::new(path).new.exists() {
+			true => Err(Error::EncryptionRekeyInterrupted),
+			false => Ok(()),
+		}
+	}
+
+	/// Cleans up after an interrupted rekey, returning whether the new
+	/// datastore has now replaced the existing one
+	pub(crate) fn recover(&self) -> Result<bool, Error> {
+		// The copy was complete, but was not moved into place
+		if self.old.exists() && self.new.exists() && !self.path.exists() {
+			fs::rename(&self.new, &self.path)?;
+			fs::remove_dir_all(&self.old)?;
+			return Ok(true);
+		}
+		// The replacement was complete, but was not cleaned up
+		if self.old.exists() {
+			fs::remove_dir_all(&self.old)?;
+		}
+		// The copy was not complete, so it starts again
+		if self.new.exists() {
+			fs::remove_dir_all(&self.new)?;
+		}
+		Ok(false)
+	}
+
+	/// Replaces the existing datastore with the new one
+	pub(crate) fn replace(&self) -> Result<(), Error> {
+		fs::rename(&self.path, &self.old)?;
+		fs::rename(&self.new, &self.path)?;
+		fs::remove_dir_all(&self.old)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn encrypt_decrypt() {
+		let cipher = Cipher::new("0123456789abcdef", false).unwrap();
+		let enc = cipher.encrypt(b"key", b"value".to_vec()).unwrap();
+		assert_ne!(enc, b"value");
+		assert_eq!(cipher.decrypt(b"key", enc.clone()).unwrap(), b"value");
+		// The value can not be read from another key
+		assert!(cipher.decrypt(b"other", enc.clone()).is_err());
+		// The value can not be read with another key
+		let other = Cipher::new("fedcba9876543210", false).unwrap();
+		assert!(other.decrypt(b"key", enc).is_err());
+	}
+
+	#[test]
+	fn verify_check() {
+		let cipher = Cipher::new("0123456789abcdef", false).unwrap();
+		let other = Cipher::new("fedcba9876543210", false).unwrap();
+		// A new datastore is not encrypted without a key
+		assert_eq!(verify(None, None, true).unwrap(), None);
+		// A new datastore stores the check record
+		let check = verify(Some(&cipher), None, true).unwrap().unwrap();
+		assert_eq!(verify(Some(&cipher), Some(check.clone()), false).unwrap(), None);
+		// The wrong key is detected
+		assert!(matches!(
+			verify(Some(&other), Some(check.clone()), false),
+			Err(Error::EncryptionKeyMismatch)
+		));
+		// A missing key is detected
+		assert!(matches!(
+			verify(None, Some(check.clone()), false),
+			Err(Error::EncryptionKeyMissing)
+		));
+		// Existing unencrypted data is detected
+		assert!(matches!(verify(Some(&cipher), None, false), Err(Error::EncryptionNotEnabled)));
+		// A different key encryption setting is detected
+		let keys = Cipher::new("0123456789abcdef", true).unwrap();
+		assert!(matches!(
+			verify(Some(&keys), Some(check), false),
+			Err(Error::EncryptionKeysMismatch)
+		));
+	}
+
+	#[test]
+	fn encrypt_decrypt_keys() {
+		let cipher = Cipher::new("0123456789abcdef", true).unwrap();
+		let a = cipher.encrypt_key(b"/*test*person");
+		let b = cipher.encrypt_key(b"/*test*user");
+		assert_eq!(cipher.decrypt_key(&a).unwrap(), b"/*test*person");
+		// Keys are encrypted deterministically
+		assert_eq!(a, cipher.encrypt_key(b"/*test*person"));
+		// Keys with a common prefix have a common encrypted prefix
+		assert_eq!(a[..8], b[..8]);
+		assert_ne!(a[8], b[8]);
+		// Keys are not encrypted without key encryption
+		let other = Cipher::new("0123456789abcdef", false).unwrap();
+		assert_eq!(other.encrypt_key(b"/*test"), b"/*test");
+		// A key which is not encrypted is detected
+		assert!(cipher.decrypt_key(b"/*test").is_err());
+	}
+
+	#[test]
+	fn encrypt_decrypt_range() {
+		let cipher = Cipher::new("0123456789abcdef", true).unwrap();
+		let rng = b"/*a".to_vec()..b"/*c".to_vec();
+		let enc = cipher.encrypt_range(&rng);
+		// Every key in the range is in the encrypted range
+		for k in [&b"/*a"[..], b"/*ab", b"/*b", b"/*b\xff"] {
+			assert!(enc.contains(&cipher.encrypt_key(k)));
+		}
+		// The keys in the range are decrypted in order
+		let keys = [&b"/*d"[..], b"/*b", b"/*a", b"/*c"];
+		let res = keys.iter().map(|k| (cipher.encrypt_key(k), ())).collect::<Vec<_>>();
+		let res = cipher.decrypt_range(&rng, res).unwrap();
+		assert_eq!(res, vec![(b"/*a".to_vec(), ()), (b"/*b".to_vec(), ())]);
+	}
+
+	#[test]
+	fn rekey_recover() {
+		let dir = temp_dir::TempDir::new().unwrap();
+		let path = dir.child("db");
+		let rekey = Rekey::new(path.to_str().unwrap());
+		// An incomplete copy is removed
+		fs::create_dir_all(&path).unwrap();
+		fs::create_dir_all(&rekey.new).unwrap();
+		assert!(matches!(
+			Rekey::check(path.to_str().unwrap()),
+			Err(Error::EncryptionRekeyInterrupted)
+		));
+		assert!(!rekey.recover().unwrap());
+		assert!(path.exists() && !rekey.new.exists());
+		// A complete copy replaces the existing datastore
+		fs::create_dir_all(&rekey.new).unwrap();
+		fs::write(rekey.new.join("data"), b"new").unwrap();
+		fs::rename(&path, &rekey.old).unwrap();
+		assert!(rekey.recover().unwrap());
+		assert_eq!(fs::read(path.join("data")).unwrap(), b"new");
+		assert!(!rekey.new.exists() && !rekey.old.exists());
+		Rekey::check(path.to_str().unwrap()).unwrap();
+	}
+}
@@ -2,6 +2,7 @@ use super::tr::Transactor;
 use super::tx::Transaction;
 use super::version::Version;
 use crate::cf;
+use crate::cnf;
 use crate::ctx::MutableContext;
 #[cfg(feature = "jwks")]
 use crate::dbs::capabilities::NetTarget;
@@ -10,6 +11,7 @@ use crate::dbs::{
 	Attach, Capabilities, Executor, Notification, Options, Response, Session, Variables,
 };
 use crate::err::Error;
+use crate::iam::grant;
 #[cfg(feature = "jwks")]
 use crate::iam::jwks::JwksCache;
 use crate::iam::{Action, Auth, Error as IamError, Resource, Role};
@@ -17,10 +19,17 @@ use crate::idx::trees::store::IndexStores;
 use crate::kvs::clock::SizedClock;
 #[allow(unused_imports)]
 use crate::kvs::clock::SystemClock;
+#[cfg(any(feature = "kv-surrealkv", feature = "kv-rocksdb"))]
+use crate::kvs::crypto::Cipher;
 #[cfg(not(target_arch = "wasm32"))]
+use crate::kvs::export;
 use crate::kvs::index::IndexBuilder;
+use crate::kvs::metrics::Metrics;
+use crate::kvs::replica::{self, Replication};
+use crate::kvs::sequences::Sequences;
 use crate::kvs::{LockType, LockType::*, TransactionType, TransactionType::*};
-use crate::sql::{statements::DefineUserStatement, Base, Query, Value};
+use crate::sql::statements::show::ShowSince;
+use crate::sql::{statements::DefineUserStatement, Base, Query, Thing, Value};
 use crate::syn;
 use crate::vs::{conv, Versionstamp};
 use channel::{Receiver, Sender};
@@ -59,13 +68,15 @@ const INITIAL_USER_ROLE: &str = "owner";
 #[allow(dead_code)]
 #[non_exhaustive]
 pub struct Datastore {
-	transaction_factory: TransactionFactory,
+	pub(super) transaction_factory: TransactionFactory,
 	// The unique id of this datastore, used in notifications
 	id: Uuid,
 	// Whether this datastore runs in strict mode by default
 	strict: bool,
 	// Whether authentication is enabled on this datastore.
 	auth_enabled: bool,
+	// Whether this datastore only accepts the changes replicated from a primary
+	read_only: bool,
 	// The maximum duration timeout for running multiple statements in a query
 	query_timeout: Option<Duration>,
 	// The maximum duration timeout for running multiple statements in a transaction
@@ -79,6 +90,8 @@ pub struct Datastore {
 	// The index asynchronous builder
 	#[cfg(not(target_arch = "wasm32"))]
 	index_builder: IndexBuilder,
+	// The sequence value allocator
+	sequences: Sequences,
 	#[cfg(feature = "jwks")]
 	// The JWKS object cache
 	jwks_cache: Arc<RwLock<JwksCache>>,
@@ -245,6 +258,115 @@ impl Datastore {
 		Self::new_with_clock(path, None).await
 	}
 
+	/// Creates a new datastore instance, which encrypts its values at rest
+	///
+	/// Encryption is supported by the `rocksdb` and `surrealkv` storage
+	/// engines. A datastore which was created with an encryption key must
+	/// always be opened with the same key, and a datastore which contains
+	/// unencrypted data must first be encrypted with [`Datastore::rekey`].
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use surrealdb_core::kvs::Datastore;
+	/// # use surrealdb_core::err::Error;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Error> {
+	/// let ds = Datastore::new_with_encryption_key("rocksdb://temp.db", Some("secret")).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn new_with_encryption_key(path: &str, key: Option<&str>) -> Result<Self, Error> {
+		Self::init(path, None, key, false).await
+	}
+
+	/// Creates a new datastore instance, which encrypts its values, and
+	/// optionally its keys, at rest
+	///
+	/// Keys are encrypted so that keys with a common prefix have a common
+	/// encrypted prefix, but encrypted keys are not stored in order. Every
+	/// range scan therefore reads all the keys with the common prefix of the
+	/// range, which makes scans of large tables much slower. Key encryption
+	/// has no effect without an encryption key. A datastore must always be
+	/// opened with the same key encryption setting, which can be changed with
+	/// [`Datastore::rekey`].
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// # use surrealdb_core::kvs::Datastore;
+	/// # use surrealdb_core::err::Error;
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Error> {
+	/// let ds = Datastore::new_with_encryption("rocksdb://temp.db", Some("secret"), true).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn new_with_encryption(
+		path: &str,
+		key: Option<&str>,
+		encrypt_keys: bool,
+	) -> Result<Self, Error> {
+		Self::init(path, None, key, encrypt_keys).await
+	}
+
+	/// Re-encrypts all values in a datastore with a new encryption key
+	///
+	/// The datastore is opened with the current key, which is `None` when the
+	/// datastore is not yet encrypted, and with its current key encryption
+	/// setting. When the new key is `None`, the values are decrypted and the
+	/// datastore is no longer encrypted. The keys are encrypted when the new
+	/// key encryption setting is enabled. The values are copied into a new
+	/// datastore which then replaces the existing one, so an interrupted rekey
+	/// is resumed by running it again with the same keys. The datastore must
+	/// not be in use by another process while it is re-encrypted.
+	#[allow(unused_variables)]
+	pub async fn rekey(
+		path: &str,
+		key: Option<&str>,
+		encrypt_keys: bool,
+		new_key: Option<&str>,
+		new_encrypt_keys: bool,
+	) -> Result<(), Error> {
+		match path {
+			// Re-encrypt a RocksDB datastore
+			s if s.starts_with("file:") || s.starts_with("rocksdb:") => {
+				#[cfg(feature = "kv-rocksdb")]
+				{
+					info!(target: TARGET, "Re-encrypting kvs store at {}", path);
+					let s = s.trim_start_matches("file://").trim_start_matches("file:");
+					let s = s.trim_start_matches("rocksdb://").trim_start_matches("rocksdb:");
+					let cipher = key.map(|k| Cipher::new(k, encrypt_keys)).transpose()?;
+					let new_cipher =
+						new_key.map(|k| Cipher::new(k, new_encrypt_keys)).transpose()?;
+					super::rocksdb::Datastore::rekey(s, cipher, new_cipher).await?;
+					info!(target: TARGET, "Re-encrypted kvs store at {}", path);
+					Ok(())
+				}
+				#[cfg(not(feature = "kv-rocksdb"))]
+                return Err(Error::Ds("Cannot connect to the `rocksdb` storage engine as it is not enabled in this build of SurrealDB".to_owned()));
+			}
+			// Re-encrypt a SurrealKV datastore
+			s if s.starts_with("surrealkv:") => {
+				#[cfg(feature = "kv-surrealkv")]
+				{
+					info!(target: TARGET, "Re-encrypting kvs store at {}", path);
+					let s = s.trim_start_matches("surrealkv://").trim_start_matches("surrealkv:");
+					let cipher = key.map(|k| Cipher::new(k, encrypt_keys)).transpose()?;
+					let new_cipher =
+						new_key.map(|k| Cipher::new(k, new_encrypt_keys)).transpose()?;
+					super::surrealkv::Datastore::rekey(s, cipher, new_cipher).await?;
+					info!(target: TARGET, "Re-encrypted kvs store at {}", path);
+					Ok(())
+				}
+				#[cfg(not(feature = "kv-surrealkv"))]
+                return Err(Error::Ds("Cannot connect to the `surrealkv` storage engine as it is not enabled in this build of SurrealDB".to_owned()));
+			}
+			// The datastore does not support encryption
+			_ => Err(Error::EncryptionUnsupported),
+		}
+	}
+
 	#[cfg(debug_assertions)]
 	/// Create a new datastore with the same persistent data (inner), with flushed cache.
 	/// Simulating a server restart
@@ -253,6 +375,7 @@ impl Datastore {
 			id: self.id,
 			strict: self.strict,
 			auth_enabled: self.auth_enabled,
+			read_only: self.read_only,
 			query_timeout: self.query_timeout,
 			transaction_timeout: self.transaction_timeout,
 			capabilities: self.capabilities,
@@ -260,6 +383,7 @@ impl Datastore {
 			index_stores: Default::default(),
 			#[cfg(not(target_arch = "wasm32"))]
 			index_builder: IndexBuilder::new(self.transaction_factory.clone()),
+			sequences: Sequences::new(self.transaction_factory.clone()),
 			#[cfg(feature = "jwks")]
 			jwks_cache: Arc::new(Default::default()),
 			#[cfg(any(
@@ -274,11 +398,25 @@ impl Datastore {
 		}
 	}
 
-	#[allow(unused_variables)]
 	pub async fn new_with_clock(
 		path: &str,
 		clock: Option<Arc<SizedClock>>,
 	) -> Result<Datastore, Error> {
+		Self::init(path, clock, None, false).await
+	}
+
+	#[allow(unused_variables)]
+	async fn init(
+		path: &str,
+		clock: Option<Arc<SizedClock>>,
+		key: Option<&str>,
+		encrypt_keys: bool,
+	) -> Result<Datastore, Error> {
+		// Check that the datastore supports encryption
+		if key.is_some() && !["file:", "rocksdb:", "surrealkv:"].iter().any(|p| path.starts_with(p))
+		{
+			return Err(Error::EncryptionUnsupported);
+		}
 		// Initiate the desired datastore
 		let (flavor, clock): (Result<DatastoreFlavor, Error>, Arc<SizedClock>) = match path {
 			// Initiate an in-memory datastore
@@ -302,7 +440,10 @@ impl Datastore {
 					warn!("file:// is deprecated, please use surrealkv:// or rocksdb://");
 					let s = s.trim_start_matches("file://");
 					let s = s.trim_start_matches("file:");
-					let v = super::rocksdb::Datastore::new(s).await.map(DatastoreFlavor::RocksDB);
+					let cipher = key.map(|k| Cipher::new(k, encrypt_keys)).transpose()?;
+					let v = super::rocksdb::Datastore::new(s, cipher)
+						.await
+						.map(DatastoreFlavor::RocksDB);
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started kvs store at {}", path);
 					Ok((v, c))
@@ -317,7 +458,10 @@ impl Datastore {
 					info!(target: TARGET, "Starting kvs store at {}", path);
 					let s = s.trim_start_matches("rocksdb://");
 					let s = s.trim_start_matches("rocksdb:");
-					let v = super::rocksdb::Datastore::new(s).await.map(DatastoreFlavor::RocksDB);
+					let cipher = key.map(|k| Cipher::new(k, encrypt_keys)).transpose()?;
+					let v = super::rocksdb::Datastore::new(s, cipher)
+						.await
+						.map(DatastoreFlavor::RocksDB);
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started kvs store at {}", path);
 					Ok((v, c))
@@ -377,8 +521,10 @@ impl Datastore {
 					info!(target: TARGET, "Starting kvs store at {}", path);
 					let s = s.trim_start_matches("surrealkv://");
 					let s = s.trim_start_matches("surrealkv:");
-					let v =
-						super::surrealkv::Datastore::new(s).await.map(DatastoreFlavor::SurrealKV);
+					let cipher = key.map(|k| Cipher::new(k, encrypt_keys)).transpose()?;
+					let v = super::surrealkv::Datastore::new(s, cipher)
+						.await
+						.map(DatastoreFlavor::SurrealKV);
 					let c = clock.unwrap_or_else(|| Arc::new(SizedClock::system()));
 					info!(target: TARGET, "Started to kvs store at {}", path);
 					Ok((v, c))
@@ -403,11 +549,13 @@ impl Datastore {
 				transaction_factory: tf.clone(),
 				strict: false,
 				auth_enabled: false,
+				read_only: false,
 				query_timeout: None,
 				transaction_timeout: None,
 				notification_channel: None,
 				capabilities: Capabilities::default(),
 				index_stores: IndexStores::default(),
+				sequences: Sequences::new(tf.clone()),
 				#[cfg(not(target_arch = "wasm32"))]
 				index_builder: IndexBuilder::new(tf),
 				#[cfg(feature = "jwks")]
@@ -460,6 +608,15 @@ impl Datastore {
 		self
 	}
 
+	/// Set whether this Datastore is a read only replica of another datastore
+	///
+	/// Queries which write to a read only Datastore are rejected, and the
+	/// changes of its primary are applied with [`Datastore::replicate`].
+	pub fn with_read_only(mut self, read_only: bool) -> Self {
+		self.read_only = read_only;
+		self
+	}
+
 	/// Set specific capabilities for this Datastore
 	pub fn with_capabilities(mut self, caps: Capabilities) -> Self {
 		self.capabilities = caps;
@@ -632,10 +789,32 @@ impl Datastore {
 		self.cleanup_nodes().await?;
 		// Garbage collect other data
 		self.garbage_collect().await?;
+		// Delete expired table records
+		self.purge_expired_records(ts).await?;
+		// Delete expired refresh tokens
+		self.purge_expired_refresh_grants(ts).await?;
 		// Everything ok
 		Ok(())
 	}
 
+	/// Returns a snapshot of the activity of this datastore
+	#[instrument(err, level = "trace", target = "surrealdb::core::kvs::ds", skip(self))]
+	pub async fn metrics(&self) -> Result<Metrics, Error> {
+		let mut metrics = Metrics::counters();
+		// Count the live queries registered on this node
+		let txn = self.transaction(Read, Optimistic).await?;
+		let beg = crate::key::node::lq::prefix(self.id);
+		let end = crate::key::node::lq::suffix(self.id);
+		metrics.live_queries = catch!(txn, txn.count(beg..end));
+		txn.cancel().await?;
+		// Fetch the progress of the index builds
+		#[cfg(not(target_arch = "wasm32"))]
+		{
+			metrics.index_builds = self.index_builder.progress().await;
+		}
+		Ok(metrics)
+	}
+
 	// save_timestamp_for_versionstamp saves the current timestamp for the each database's current versionstamp.
 	// Note: the returned VS is flawed, as there are multiple {ts: vs} mappings per (ns, db)
 	pub(crate) async fn save_timestamp_for_versionstamp(
@@ -706,6 +885,153 @@ impl Datastore {
 		Ok(())
 	}
 
+	// purge_expired_records deletes all table records which have expired at the given timestamp.
+	pub(crate) async fn purge_expired_records(&self, ts: u64) -> Result<(), Error> {
+		// Replicas never modify the data
+		if self.read_only {
+			return Ok(());
+		}
+		// The expiry index is ordered in nanoseconds
+		let ts = ts.saturating_mul(1_000_000_000);
+		// Fetch all tables which have an expiry
+		let mut tables = Vec::new();
+		let tx = self.transaction(Read, Optimistic).await?;
+		for ns in catch!(tx, tx.all_ns()).iter() {
+			for db in catch!(tx, tx.all_db(&ns.name)).iter() {
+				for tb in catch!(tx, tx.all_tb(&ns.name, &db.name)).iter() {
+					if tb.expire.is_some() {
+						tables.push((ns.name.to_raw(), db.name.to_raw(), tb.name.to_raw()));
+					}
+				}
+			}
+		}
+		tx.cancel().await?;
+		// Delete the expired records of each table
+		for (ns, db, tb) in tables.iter() {
+			// A failing table must not stop the other tables from being purged
+			if let Err(e) = self.purge_expired_table(ns, db, tb, ts).await {
+				warn!(target: TARGET, "Failed to delete the expired records of table '{tb}' in {ns}/{db}: {e}");
+			}
+		}
+		Ok(())
+	}
+
+	// purge_expired_refresh_grants deletes all refresh token grants which have expired at the given timestamp.
+	pub(crate) async fn purge_expired_refresh_grants(&self, ts: u64) -> Result<(), Error> {
+		// Replicas never modify the data
+		if self.read_only {
+			return Ok(());
+		}
+		let now = chrono::DateTime::from_timestamp(ts as i64, 0).ok_or_else(|| {
+			Error::Internal(format!("The timestamp {ts} is out of range for a datetime"))
+		})?;
+		crate::iam::refresh::purge_expired(self, &now.into()).await
+	}
+
+	// purge_expired_table deletes the records of a table which have expired at the given timestamp in nanoseconds.
+	async fn purge_expired_table(
+		&self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		ts: u64,
+	) -> Result<(), Error> {
+		// Delete the records, so that events and live queries are processed
+		let ast = syn::parse("DELETE $ids RETURN NONE")?;
+		let sess = Session::owner().with_ns(ns).with_db(db);
+		let batch = *cnf::EXPIRY_BATCH_SIZE;
+		let mut beg = crate::key::table::ex::prefix(ns, db, tb);
+		let end = crate::key::table::ex::suffix(ns, db, tb, ts);
+		loop {
+			// Fetch the next batch of expired records
+			let tx = self.transaction(Read, Optimistic).await?;
+			let keys = catch!(tx, tx.keys(beg.clone()..end.clone(), batch));
+			tx.cancel().await?;
+			let exs = keys
+				.iter()
+				.map(|k| crate::key::table::ex::Ex::decode(k))
+				.collect::<Result<Vec<_>, _>>()?;
+			// Delete the whole batch in a single transaction
+			if let Err(e) = self.purge_expired_batch(&ast, &sess, &exs).await {
+				debug!(target: TARGET, "Failed to delete a batch of expired records of table '{tb}' in {ns}/{db}, deleting them one at a time: {e}");
+				// Delete each record on its own, so that a record which
+				// can not be deleted doesn't prevent the others from expiring
+				for ex in exs.iter() {
+					if let Err(e) =
+						self.purge_expired_batch(&ast, &sess, std::slice::from_ref(ex)).await
+					{
+						warn!(target: TARGET, "Failed to delete the expired record {tb}:{} in {ns}/{db}: {e}", ex.id);
+					}
+				}
+			}
+			// Check if this was the last batch
+			match keys.last() {
+				Some(k) if keys.len() == batch as usize => {
+					beg.clone_from(k);
+					beg.push(0x00);
+				}
+				_ => break,
+			}
+		}
+		Ok(())
+	}
+
+	// purge_expired_batch deletes expired records in one transaction, skipping any record whose expiry has changed since it was read.
+	async fn purge_expired_batch(
+		&self,
+		ast: &Query,
+		sess: &Session,
+		exs: &[crate::key::table::ex::Ex<'_>],
+	) -> Result<(), Error> {
+		let txn = Arc::new(self.transaction(Write, Optimistic).await?);
+		let notifications = channel::unbounded();
+		// Check the expiry of each record in the same transaction as the delete
+		let mut ids = Vec::with_capacity(exs.len());
+		for ex in exs.iter() {
+			let key = crate::key::table::ei::new(ex.ns, ex.db, ex.tb, &ex.id);
+			let expiry = catch!(txn, txn.get(key, None));
+			if expiry.as_deref() == Some(ex.ts.to_be_bytes().as_slice()) {
+				ids.push(Value::Thing(Thing::from((ex.tb.to_owned(), ex.id.clone()))));
+			} else {
+				// The record no longer exists, or its expiry has been changed
+				catch!(txn, txn.del(ex.to_owned()));
+			}
+		}
+		// Delete the records which are still expired
+		if !ids.is_empty() {
+			let vars = map! {
+				String::from("ids") => Value::from(ids),
+			};
+			let res =
+				self.process_in(ast.clone(), sess, Some(vars), txn.clone(), notifications.0).await;
+			if let Err(e) = res.and_then(|r| r.into_iter().try_for_each(|r| r.result.map(|_| ()))) {
+				txn.cancel().await?;
+				return Err(e);
+			}
+		}
+		// Commit the transaction
+		{
+			let mut txn = txn.lock().await;
+			if let Err(e) = txn.complete_changes(false).await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+			if let Err(e) = txn.commit().await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+		}
+		// Deliver the notifications of the deleted records
+		if let Some(chn) = self.notification_sender() {
+			while let Ok(notification) = notifications.1.try_recv() {
+				if chn.send(notification).await.is_err() {
+					break;
+				}
+			}
+		}
+		Ok(())
+	}
+
 	/// Create a new transaction on this datastore
 	///
 	/// ```rust,no_run
@@ -781,6 +1107,72 @@ impl Datastore {
 		ast: Query,
 		sess: &Session,
 		vars: Variables,
+	) -> Result<Vec<Response>, Error> {
+		// Check if the query writes to a read only replica
+		self.check_read_only(ast.iter().any(|v| v.writeable()))?;
+		// Create a new query executor
+		let exe = Executor::new(self);
+		// Process all statements
+		self.process_with(exe, ast, sess, vars).await
+	}
+
+	/// Starts a transaction which applies the changes of a primary to a read only replica
+	///
+	/// Unlike [`Datastore::process`], this writes to a read only replica.
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn replication(&self, sess: &Session) -> Result<Replication<'_>, Error> {
+		Replication::new(self, sess).await
+	}
+
+	/// Returns the replication state of a database, if it replicates a primary
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip(self))]
+	pub async fn replica_state(&self, ns: &str, db: &str) -> Result<Option<replica::State>, Error> {
+		let txn = self.transaction(Read, Optimistic).await?;
+		let key = crate::key::database::rp::new(ns, db);
+		let val = catch!(txn, txn.get(key, None));
+		txn.cancel().await?;
+		Ok(val.map(replica::State::from))
+	}
+
+	/// Execute a pre-parsed SQL query within an interactive transaction
+	///
+	/// The transaction is neither committed nor cancelled, and the live query
+	/// notifications are sent to the given channel instead of the datastore.
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub(crate) async fn process_in(
+		&self,
+		ast: Query,
+		sess: &Session,
+		vars: Variables,
+		txn: Arc<Transaction>,
+		notifications: Sender<Notification>,
+	) -> Result<Vec<Response>, Error> {
+		// Check if the query writes to a read only replica
+		self.check_read_only(ast.iter().any(|v| v.writeable()))?;
+		// Create a new query executor
+		let exe = Executor::new_interactive(self, txn, notifications);
+		// Process all statements
+		self.process_with(exe, ast, sess, vars).await
+	}
+
+	/// Returns the authentication of a session for running a query
+	///
+	/// The grants of custom roles are resolved again for every query, so
+	/// that a role which is changed or removed applies to existing sessions.
+	async fn session_auth(&self, sess: &Session) -> Result<Arc<Auth>, Error> {
+		if sess.au.roles().iter().any(|r| matches!(r, Role::Custom(_))) {
+			Ok(Arc::new(grant::grants(self, sess.au.as_ref().to_owned()).await?))
+		} else {
+			Ok(sess.au.clone())
+		}
+	}
+
+	pub(super) async fn process_with(
+		&self,
+		mut exe: Executor<'_>,
+		ast: Query,
+		sess: &Session,
+		vars: Variables,
 	) -> Result<Vec<Response>, Error> {
 		// Check if the session has expired
 		if sess.expired() {
@@ -802,11 +1194,9 @@ impl Datastore {
 			.with_ns(sess.ns())
 			.with_db(sess.db())
 			.with_live(sess.live())
-			.with_auth(sess.au.clone())
+			.with_auth(self.session_auth(sess).await?)
 			.with_strict(self.strict)
 			.with_auth_enabled(self.auth_enabled);
-		// Create a new query executor
-		let mut exe = Executor::new(self);
 		// Create a default context
 		let mut ctx = MutableContext::from_ds(
 			self.query_timeout,
@@ -814,6 +1204,7 @@ impl Datastore {
 			self.index_stores.clone(),
 			#[cfg(not(target_arch = "wasm32"))]
 			self.index_builder.clone(),
+			self.sequences.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
 				feature = "kv-surrealkv",
@@ -874,6 +1265,8 @@ impl Datastore {
 			}
 			.into());
 		}
+		// Check if the value writes to a read only replica
+		self.check_read_only(val.writeable())?;
 		// Create a new memory stack
 		let mut stack = TreeStack::new();
 		// Create a new query options
@@ -882,7 +1275,7 @@ impl Datastore {
 			.with_ns(sess.ns())
 			.with_db(sess.db())
 			.with_live(sess.live())
-			.with_auth(sess.au.clone())
+			.with_auth(self.session_auth(sess).await?)
 			.with_strict(self.strict)
 			.with_auth_enabled(self.auth_enabled);
 		// Create a default context
@@ -953,6 +1346,8 @@ impl Datastore {
 		if sess.expired() {
 			return Err(Error::ExpiredSession);
 		}
+		// Check if the value writes to a read only replica
+		self.check_read_only(val.writeable())?;
 		// Create a new memory stack
 		let mut stack = TreeStack::new();
 		// Create a new query options
@@ -961,7 +1356,7 @@ impl Datastore {
 			.with_ns(sess.ns())
 			.with_db(sess.db())
 			.with_live(sess.live())
-			.with_auth(sess.au.clone())
+			.with_auth(self.session_auth(sess).await?)
 			.with_strict(self.strict)
 			.with_auth_enabled(self.auth_enabled);
 		// Create a default context
@@ -1023,6 +1418,11 @@ impl Datastore {
 		self.notification_channel.as_ref().map(|v| v.1.clone())
 	}
 
+	/// Get a sender for the live query notification channel, if it is enabled
+	pub(crate) fn notification_sender(&self) -> Option<Sender<Notification>> {
+		self.notification_channel.as_ref().map(|v| v.0.clone())
+	}
+
 	/// Performs a database import from SQL
 	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn import(&self, sql: &str, sess: &Session) -> Result<Vec<Response>, Error> {
@@ -1034,6 +1434,30 @@ impl Datastore {
 		self.execute(sql, sess, None).await
 	}
 
+	/// Performs a database import from an export in any format
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn import_bytes(&self, data: &[u8], sess: &Session) -> Result<Vec<Response>, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Check the format of the import
+		match export::Format::detect(data) {
+			// Execute the SQL import
+			export::Format::Sql => {
+				let sql = std::str::from_utf8(data).map_err(|_| {
+					Error::InvalidImport("The SQL import is not valid UTF-8".into())
+				})?;
+				self.execute(sql, sess, None).await
+			}
+			// Process the statements of the import
+			format => {
+				let ast = export::parse(format, data)?;
+				self.process(ast, sess, None).await
+			}
+		}
+	}
+
 	/// Performs a full database export as SQL
 	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
 	pub async fn export(
@@ -1058,6 +1482,137 @@ impl Datastore {
 		})
 	}
 
+	/// Performs a database export with the specified configuration
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn export_with_config(
+		&self,
+		sess: &Session,
+		chn: Sender<Vec<u8>>,
+		cfg: export::Config,
+	) -> Result<impl Future<Output = Result<(), Error>>, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Create a new readonly transaction
+		let txn = self.transaction(Read, Optimistic).await?;
+		// Return an async export job
+		Ok(async move {
+			// Process the export
+			txn.export_with_config(&ns, &db, cfg, chn).await?;
+			// Everything ok
+			Ok(())
+		})
+	}
+
+	/// Performs a database export, along with the replication state at which it is taken
+	///
+	/// The export includes the changes up to and including the returned
+	/// versionstamp, which is 0 if the change feed never contained any changes.
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn export_snapshot(
+		&self,
+		sess: &Session,
+		chn: Sender<Vec<u8>>,
+		cfg: export::Config,
+	) -> Result<(replica::State, impl Future<Output = Result<(), Error>>), Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Create a new readonly transaction
+		let txn = self.transaction(Read, Optimistic).await?;
+		// Read the change feed with the same transaction as the export
+		let latest = catch!(txn, cf::latest(&txn, &ns, &db)).map(conv::to_u128_be);
+		// The export also includes the changes which have been garbage collected
+		let watermark = catch!(txn, cf::watermark(&txn, &ns, &db)).map(conv::to_u128_be);
+		let vs = latest.max(watermark).unwrap_or(0);
+		// Read the schema with the same transaction as the export
+		let schema = catch!(txn, replica::fingerprint(&txn, &ns, &db));
+		// Return an async export job
+		Ok((replica::State::new(vs, schema), async move {
+			// Process the export
+			txn.export_with_config(&ns, &db, cfg, chn).await?;
+			// Everything ok
+			Ok(())
+		}))
+	}
+
+	/// Reads the change sets of the database change feed after the specified versionstamp
+	///
+	/// At most `limit` table changes are read, so that the last change set
+	/// may be incomplete when the result is full. In that case, the last
+	/// change set is only returned if it is the only one.
+	///
+	/// An error is returned if the changes after the versionstamp have been
+	/// garbage collected, as the change sets would otherwise be incomplete.
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip(self, sess))]
+	pub async fn changes_since(
+		&self,
+		sess: &Session,
+		since: u128,
+		limit: u32,
+	) -> Result<Vec<Value>, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// The change feed reader reads at most 1000 table changes at once
+		let limit = limit.min(1000);
+		// Read the change feed from the versionstamp
+		let txn = self.transaction(Read, Optimistic).await?;
+		// Check that no changes after the versionstamp have been garbage collected
+		let watermark = catch!(txn, cf::watermark(&txn, &ns, &db)).map(conv::to_u128_be);
+		if watermark.is_some_and(|v| v > since) {
+			txn.cancel().await?;
+			return Err(Error::ChangefeedGap(since));
+		}
+		let start = ShowSince::Versionstamp((since >> 16) as u64);
+		let mut res = catch!(txn, cf::read(&txn, &ns, &db, None, start, Some(limit)));
+		txn.cancel().await?;
+		// Skip the last change set if it could be incomplete
+		let count: usize = res.iter().map(|c| c.1 .0.len()).sum();
+		if count >= limit as usize && res.len() > 1 {
+			res.pop();
+		}
+		// Only return the changes after the versionstamp
+		Ok(res
+			.into_iter()
+			.filter(|c| conv::to_u128_be(c.0) > since)
+			.map(|c| c.into_value())
+			.collect())
+	}
+
+	/// Returns the fingerprint of the schema of the database, which changes whenever its schema changes
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn schema_fingerprint(&self, sess: &Session) -> Result<u64, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		// Retrieve the provided NS and DB
+		let (ns, db) = crate::iam::check::check_ns_db(sess)?;
+		// Read the definitions of the database
+		let txn = self.transaction(Read, Optimistic).await?;
+		let schema = catch!(txn, replica::fingerprint(&txn, &ns, &db));
+		txn.cancel().await?;
+		Ok(schema)
+	}
+
+	/// Checks that a query does not write to a read only replica
+	fn check_read_only(&self, writeable: bool) -> Result<(), Error> {
+		if self.read_only && writeable {
+			return Err(Error::ReplicaReadonly);
+		}
+		Ok(())
+	}
+
 	/// Checks the required permissions level for this session
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::ds", skip(self, sess))]
 	pub fn check(&self, sess: &Session, action: Action, resource: Resource) -> Result<(), Error> {
@@ -1129,4 +1684,37 @@ mod test {
 		assert_eq!(res, Value::Number(Number::Int(2)));
 		Ok(())
 	}
+
+	#[tokio::test]
+	async fn purge_expired_batch_rechecks_expiry() -> Result<(), Error> {
+		use crate::key::table::ex;
+		let dbs = Datastore::new("memory").await?;
+		let ses = Session::owner().with_ns("test").with_db("test");
+		let sql = "
+			DEFINE TABLE session EXPIRE 1h ON touched;
+			CREATE session:one SET touched = d'2020-01-01T00:00:00Z';
+			CREATE session:two SET touched = d'2020-01-01T00:00:00Z';
+		";
+		for res in dbs.execute(sql, &ses, None).await? {
+			res.result?;
+		}
+		// Read the expiry of the records
+		let beg = ex::prefix("test", "test", "session");
+		let end = ex::suffix("test", "test", "session", u64::MAX);
+		let tx = dbs.transaction(Read, Optimistic).await?;
+		let keys = tx.keys(beg..end, 10).await?;
+		tx.cancel().await?;
+		assert_eq!(keys.len(), 2);
+		// One record is touched before the batch is purged
+		let sql = "UPDATE session:one SET touched = time::now()";
+		for res in dbs.execute(sql, &ses, None).await? {
+			res.result?;
+		}
+		let ast = syn::parse("DELETE $ids RETURN NONE")?;
+		let exs = keys.iter().map(|k| ex::Ex::decode(k)).collect::<Result<Vec<_>, _>>()?;
+		dbs.purge_expired_batch(&ast, &ses, &exs).await?;
+		let res = &mut dbs.execute("SELECT VALUE id FROM session", &ses, None).await?;
+		assert_eq!(res.remove(0).result?.to_string(), "[session:one]");
+		Ok(())
+	}
 }
@@ -1,208 +1,696 @@
 use super::Transaction;
 use crate::cnf::EXPORT_BATCH_SIZE;
 use crate::err::Error;
+use crate::kvs::sequences::SequenceState;
+use crate::rpc::format::cbor::Cbor;
 use crate::sql::paths::EDGE;
 use crate::sql::paths::IN;
 use crate::sql::paths::OUT;
-use crate::sql::Value;
+use crate::sql::statements::{
+	BeginStatement, CommitStatement, DefineSequenceStatement, InsertStatement, OptionStatement,
+};
+use crate::sql::{Data, Datetime, Ident, Query, Statement, Statements, Value, Version};
 use channel::Sender;
+use chrono::{TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::mem;
 
-impl Transaction {
-	/// Writes the full database contents as binary SQL.
-	pub async fn export(&self, ns: &str, db: &str, chn: Sender<Vec<u8>>) -> Result<(), Error> {
-		// Output OPTIONS
-		{
-			chn.send(bytes!("-- ------------------------------")).await?;
-			chn.send(bytes!("-- OPTION")).await?;
-			chn.send(bytes!("-- ------------------------------")).await?;
-			chn.send(bytes!("")).await?;
-			chn.send(bytes!("OPTION IMPORT;")).await?;
-			chn.send(bytes!("")).await?;
+/// The version of the NDJSON and CBOR export formats
+const FORMAT_VERSION: u16 = 1;
+
+/// The CBOR self-describe tag, which starts every CBOR export
+const CBOR_MAGIC: &[u8] = &[0xd9, 0xd9, 0xf7];
+
+/// The first bytes of every NDJSON export
+const NDJSON_MAGIC: &[u8] = b"{\"header\":";
+
+/// The configuration of a database export
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+	/// The tables to export, or all the tables when not specified.
+	/// The database level definitions (users, accesses, params, functions,
+	/// analyzers, sequences...) are only exported along with all the tables.
+	pub tables: Option<Vec<String>>,
+	/// Whether to export the definitions
+	pub schema: bool,
+	/// Whether to export the records
+	pub records: bool,
+	/// Whether to export every stored version of the records
+	pub versions: bool,
+	/// The format of the export
+	pub format: Format,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			tables: None,
+			schema: true,
+			records: true,
+			versions: false,
+			format: Format::Sql,
 		}
-		// Output USERS
-		{
-			let dus = self.all_db_users(ns, db).await?;
-			if !dus.is_empty() {
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- USERS")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				for us in dus.iter() {
-					chn.send(bytes!(format!("{us};"))).await?;
-				}
-				chn.send(bytes!("")).await?;
-			}
+	}
+}
+
+impl Config {
+	fn includes_table(&self, tb: &str) -> bool {
+		match &self.tables {
+			Some(tables) => tables.iter().any(|t| t == tb),
+			None => true,
 		}
-		// Output ACCESSES
-		{
-			let dts = self.all_db_accesses(ns, db).await?;
-			if !dts.is_empty() {
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- ACCESSES")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				for dt in dts.iter() {
-					chn.send(bytes!(format!("{dt};"))).await?;
-				}
-				chn.send(bytes!("")).await?;
+	}
+}
+
+/// The format of a database export
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+	/// SurrealQL statements
+	#[default]
+	Sql,
+	/// Newline delimited JSON entries
+	///
+	/// The records are written as SurrealQL values, so that the values which
+	/// JSON can not represent, such as record links and datetimes, keep their type.
+	Ndjson,
+	/// Concatenated CBOR entries
+	Cbor,
+}
+
+impl Format {
+	/// Detects the format of an export from its first bytes
+	pub fn detect(data: &[u8]) -> Self {
+		if data.starts_with(CBOR_MAGIC) {
+			Self::Cbor
+		} else if data.starts_with(NDJSON_MAGIC) {
+			Self::Ndjson
+		} else {
+			Self::Sql
+		}
+	}
+}
+
+/// An entry of a NDJSON or CBOR export
+///
+/// The records are written as SurrealQL values in NDJSON, and with the
+/// public CBOR representation of their values, which is also used by the
+/// RPC protocol, in CBOR.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Entry<V> {
+	/// The first entry of every export
+	Header {
+		format: String,
+		version: u16,
+	},
+	/// A definition, as a SurrealQL statement
+	Define(String),
+	/// A record, optionally at a specific version
+	Record {
+		relation: bool,
+		version: Option<u64>,
+		value: V,
+	},
+}
+
+impl<V> Entry<V> {
+	/// Converts the value of a record entry
+	fn try_map<T>(self, f: impl FnOnce(V, bool) -> Result<T, Error>) -> Result<Entry<T>, Error> {
+		Ok(match self {
+			Self::Header {
+				format,
+				version,
+			} => Entry::Header {
+				format,
+				version,
+			},
+			Self::Define(sql) => Entry::Define(sql),
+			Self::Record {
+				relation,
+				version,
+				value,
+			} => Entry::Record {
+				relation,
+				version,
+				value: f(value, relation)?,
+			},
+		})
+	}
+}
+
+/// Writes the export to the channel, in the configured format
+struct Writer {
+	format: Format,
+	chn: Sender<Vec<u8>>,
+}
+
+impl Writer {
+	async fn send<V: Serialize>(&self, entry: Entry<V>) -> Result<(), Error> {
+		let mut buf = Vec::new();
+		match self.format {
+			Format::Sql => unreachable!(),
+			Format::Ndjson => {
+				serde_json::to_writer(&mut buf, &entry)
+					.map_err(|e| Error::Serialization(e.to_string()))?;
+				buf.push(b'\n');
+			}
+			Format::Cbor => {
+				ciborium::into_writer(&entry, &mut buf)
+					.map_err(|e| Error::Serialization(e.to_string()))?;
 			}
 		}
-		// Output PARAMS
-		{
-			let pas = self.all_db_params(ns, db).await?;
-			if !pas.is_empty() {
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- PARAMS")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				for pa in pas.iter() {
-					chn.send(bytes!(format!("{pa};"))).await?;
-				}
-				chn.send(bytes!("")).await?;
+		self.chn.send(buf).await?;
+		Ok(())
+	}
+
+	async fn header(&self) -> Result<(), Error> {
+		match self.format {
+			Format::Sql => {
+				self.section("OPTION").await?;
+				self.chn.send(bytes!("OPTION IMPORT;")).await?;
+				self.chn.send(bytes!("")).await?;
+			}
+			Format::Ndjson => {
+				self.send(Entry::<()>::Header {
+					format: "surrealdb".to_string(),
+					version: FORMAT_VERSION,
+				})
+				.await?;
+			}
+			Format::Cbor => {
+				self.chn.send(CBOR_MAGIC.to_vec()).await?;
+				self.send(Entry::<()>::Header {
+					format: "surrealdb".to_string(),
+					version: FORMAT_VERSION,
+				})
+				.await?;
 			}
 		}
-		// Output FUNCTIONS
-		{
-			let fcs = self.all_db_functions(ns, db).await?;
-			if !fcs.is_empty() {
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- FUNCTIONS")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				for fc in fcs.iter() {
-					chn.send(bytes!(format!("{fc};"))).await?;
+		Ok(())
+	}
+
+	async fn section(&self, title: impl Display) -> Result<(), Error> {
+		if self.format == Format::Sql {
+			self.chn.send(bytes!("-- ------------------------------")).await?;
+			self.chn.send(bytes!(format!("-- {title}"))).await?;
+			self.chn.send(bytes!("-- ------------------------------")).await?;
+			self.chn.send(bytes!("")).await?;
+		}
+		Ok(())
+	}
+
+	async fn define(&self, stm: impl Display) -> Result<(), Error> {
+		match self.format {
+			Format::Sql => self.chn.send(bytes!(format!("{stm};"))).await?,
+			_ => self.send(Entry::<()>::Define(stm.to_string())).await?,
+		}
+		Ok(())
+	}
+
+	async fn blank(&self) -> Result<(), Error> {
+		if self.format == Format::Sql {
+			self.chn.send(bytes!("")).await?;
+		}
+		Ok(())
+	}
+
+	async fn begin(&self) -> Result<(), Error> {
+		if self.format == Format::Sql {
+			self.section("TRANSACTION").await?;
+			self.chn.send(bytes!("BEGIN TRANSACTION;")).await?;
+			self.chn.send(bytes!("")).await?;
+		}
+		Ok(())
+	}
+
+	async fn commit(&self) -> Result<(), Error> {
+		if self.format == Format::Sql {
+			self.section("TRANSACTION").await?;
+			self.chn.send(bytes!("COMMIT TRANSACTION;")).await?;
+			self.chn.send(bytes!("")).await?;
+		}
+		Ok(())
+	}
+
+	async fn records(
+		&self,
+		records: Vec<Value>,
+		relation: bool,
+		version: Option<u64>,
+	) -> Result<(), Error> {
+		if records.is_empty() {
+			return Ok(());
+		}
+		match self.format {
+			// Add batches of INSERT or INSERT RELATION statements
+			Format::Sql => {
+				let values = records.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+				let kind = if relation {
+					"INSERT RELATION"
+				} else {
+					"INSERT"
+				};
+				let sql = match version {
+					Some(v) => format!("{kind} [ {values} ] {};", version_at(v)),
+					None => format!("{kind} [ {values} ];"),
+				};
+				self.chn.send(bytes!(sql)).await?;
+			}
+			Format::Ndjson => {
+				for value in records {
+					self.send(Entry::Record {
+						relation,
+						version,
+						value: value.to_string(),
+					})
+					.await?;
 				}
-				chn.send(bytes!("")).await?;
 			}
-		}
-		// Output ANALYZERS
-		{
-			let azs = self.all_db_analyzers(ns, db).await?;
-			if !azs.is_empty() {
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- ANALYZERS")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				for az in azs.iter() {
-					chn.send(bytes!(format!("{az};"))).await?;
+			Format::Cbor => {
+				for value in records {
+					let value =
+						Cbor::try_from(value).map_err(|e| Error::Serialization(e.into()))?;
+					self.send(Entry::Record {
+						relation,
+						version,
+						value: value.0,
+					})
+					.await?;
 				}
-				chn.send(bytes!("")).await?;
 			}
 		}
+		Ok(())
+	}
+}
+
+impl Transaction {
+	/// Writes the full database contents as binary SQL.
+	pub async fn export(&self, ns: &str, db: &str, chn: Sender<Vec<u8>>) -> Result<(), Error> {
+		self.export_with_config(ns, db, Config::default(), chn).await
+	}
+
+	/// Writes the database contents selected by the configuration, in the configured format.
+	pub async fn export_with_config(
+		&self,
+		ns: &str,
+		db: &str,
+		cfg: Config,
+		chn: Sender<Vec<u8>>,
+	) -> Result<(), Error> {
+		let out = Writer {
+			format: cfg.format,
+			chn,
+		};
+		// Output OPTIONS
+		out.header().await?;
+		// Output the database level definitions
+		if cfg.schema && cfg.tables.is_none() {
+			self.export_db_definitions(ns, db, &out).await?;
+		}
 		// Output TABLES
 		{
 			let tbs = self.all_tb(ns, db).await?;
+			let tbs: Vec<_> = tbs.iter().filter(|tb| cfg.includes_table(&tb.name)).collect();
 			if !tbs.is_empty() {
-				for tb in tbs.iter() {
-					// Output TABLE
-					chn.send(bytes!("-- ------------------------------")).await?;
-					chn.send(bytes!(format!("-- TABLE: {}", tb.name))).await?;
-					chn.send(bytes!("-- ------------------------------")).await?;
-					chn.send(bytes!("")).await?;
-					chn.send(bytes!(format!("{tb};"))).await?;
-					chn.send(bytes!("")).await?;
-					// Output FIELDS
-					let fds = self.all_tb_fields(ns, db, &tb.name).await?;
-					if !fds.is_empty() {
-						for fd in fds.iter() {
-							chn.send(bytes!(format!("{fd};"))).await?;
+				if cfg.schema {
+					for tb in tbs.iter() {
+						// Output TABLE
+						out.section(format!("TABLE: {}", tb.name)).await?;
+						out.define(tb).await?;
+						out.blank().await?;
+						// Output FIELDS
+						let fds = self.all_tb_fields(ns, db, &tb.name).await?;
+						if !fds.is_empty() {
+							for fd in fds.iter() {
+								out.define(fd).await?;
+							}
+							out.blank().await?;
 						}
-						chn.send(bytes!("")).await?;
-					}
-					// Output INDEXES
-					let ixs = self.all_tb_indexes(ns, db, &tb.name).await?;
-					if !ixs.is_empty() {
-						for ix in ixs.iter() {
-							chn.send(bytes!(format!("{ix};"))).await?;
+						// Output INDEXES
+						let ixs = self.all_tb_indexes(ns, db, &tb.name).await?;
+						if !ixs.is_empty() {
+							for ix in ixs.iter() {
+								out.define(ix).await?;
+							}
+							out.blank().await?;
 						}
-						chn.send(bytes!("")).await?;
-					}
-					// Output EVENTS
-					let evs = self.all_tb_events(ns, db, &tb.name).await?;
-					if !evs.is_empty() {
-						for ev in evs.iter() {
-							chn.send(bytes!(format!("{ev};"))).await?;
+						// Output EVENTS
+						let evs = self.all_tb_events(ns, db, &tb.name).await?;
+						if !evs.is_empty() {
+							for ev in evs.iter() {
+								out.define(ev).await?;
+							}
+							out.blank().await?;
 						}
-						chn.send(bytes!("")).await?;
 					}
 				}
-				// Start transaction
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- TRANSACTION")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				chn.send(bytes!("BEGIN TRANSACTION;")).await?;
-				chn.send(bytes!("")).await?;
-				// Records to be exported, categorised by the type of INSERT statement
-				let mut records_normal: Vec<String> =
-					Vec::with_capacity(*EXPORT_BATCH_SIZE as usize);
-				let mut records_relate: Vec<String> =
-					Vec::with_capacity(*EXPORT_BATCH_SIZE as usize);
-				// Output TABLE data
-				for tb in tbs.iter() {
-					// Start records
-					chn.send(bytes!("-- ------------------------------")).await?;
-					chn.send(bytes!(format!("-- TABLE DATA: {}", tb.name))).await?;
-					chn.send(bytes!("-- ------------------------------")).await?;
-					chn.send(bytes!("")).await?;
-					// Fetch records
-					let beg = crate::key::thing::prefix(ns, db, &tb.name);
-					let end = crate::key::thing::suffix(ns, db, &tb.name);
-					let mut next = Some(beg..end);
-					while let Some(rng) = next {
-						// Get the next batch of records
-						let batch = self.batch(rng, *EXPORT_BATCH_SIZE, true).await?;
-						// Set the next scan range
-						next = batch.next;
-						// Check there are records
-						if batch.values.is_empty() {
-							break;
-						}
-						// Categorize the record types
-						for (_, v) in batch.values.into_iter() {
-							// Parse the key and the value
-							let v: Value = (&v).into();
-							// Check if this is a graph edge
-							match (v.pick(&*EDGE), v.pick(&*IN), v.pick(&*OUT)) {
-								// This is a graph edge record
-								(Value::Bool(true), Value::Thing(_), Value::Thing(_)) => {
-									records_relate.push(v.to_string());
-								}
-								// This is a normal record
-								_ => {
-									records_normal.push(v.to_string());
-								}
-							}
-						}
-						// Add batches of INSERT statements
-						if !records_normal.is_empty() {
-							let values = records_normal.join(", ");
-							let sql = format!("INSERT [ {values} ];");
-							chn.send(bytes!(sql)).await?;
-							records_normal.clear();
+				if cfg.records {
+					// Start transaction
+					out.begin().await?;
+					// Output TABLE data
+					for tb in tbs.iter() {
+						// Start records
+						out.section(format!("TABLE DATA: {}", tb.name)).await?;
+						if cfg.versions {
+							self.export_versioned_records(ns, db, &tb.name, &out).await?;
+						} else {
+							self.export_records(ns, db, &tb.name, &out).await?;
 						}
-						// Add batches of INSERT RELATION statements
-						if !records_relate.is_empty() {
-							let values = records_relate.join(", ");
-							let sql = format!("INSERT RELATION [ {values} ];");
-							chn.send(bytes!(sql)).await?;
-							records_relate.clear()
-						}
-						// Fetch more records
-						continue;
+						out.blank().await?;
 					}
-					chn.send(bytes!("")).await?;
+					// Commit transaction
+					out.commit().await?;
 				}
-				// Commit transaction
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("-- TRANSACTION")).await?;
-				chn.send(bytes!("-- ------------------------------")).await?;
-				chn.send(bytes!("")).await?;
-				chn.send(bytes!("COMMIT TRANSACTION;")).await?;
-				chn.send(bytes!("")).await?;
 			}
 		}
 		// Everything exported
 		Ok(())
 	}
+
+	async fn export_db_definitions(&self, ns: &str, db: &str, out: &Writer) -> Result<(), Error> {
+		// Output ROLES
+		let drs = self.all_db_roles(ns, db).await?;
+		export_definitions(out, "ROLES", drs.iter()).await?;
+		// Output USERS
+		let dus = self.all_db_users(ns, db).await?;
+		export_definitions(out, "USERS", dus.iter()).await?;
+		// Output ACCESSES
+		let dts = self.all_db_accesses(ns, db).await?;
+		export_definitions(out, "ACCESSES", dts.iter()).await?;
+		// Output PARAMS
+		let pas = self.all_db_params(ns, db).await?;
+		export_definitions(out, "PARAMS", pas.iter()).await?;
+		// Output FUNCTIONS
+		let fcs = self.all_db_functions(ns, db).await?;
+		export_definitions(out, "FUNCTIONS", fcs.iter()).await?;
+		// Output ANALYZERS
+		let azs = self.all_db_analyzers(ns, db).await?;
+		export_definitions(out, "ANALYZERS", azs.iter()).await?;
+		// Output SEQUENCES, starting from the next value which has not been allocated
+		let sqs = self.all_db_sequences(ns, db).await?;
+		let mut exported = Vec::with_capacity(sqs.len());
+		for sq in sqs.iter() {
+			let key = crate::key::database::sv::new(ns, db, &sq.name);
+			let start = match self.get(key, None).await? {
+				Some(v) => SequenceState::from(v).next,
+				None => sq.start,
+			};
+			exported.push(DefineSequenceStatement {
+				start,
+				..sq.clone()
+			});
+		}
+		export_definitions(out, "SEQUENCES", exported.iter()).await?;
+		Ok(())
+	}
+
+	async fn export_records(
+		&self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		out: &Writer,
+	) -> Result<(), Error> {
+		// Records to be exported, categorised by the type of INSERT statement
+		let mut records_normal = Vec::with_capacity(*EXPORT_BATCH_SIZE as usize);
+		let mut records_relate = Vec::with_capacity(*EXPORT_BATCH_SIZE as usize);
+		// Fetch records
+		let beg = crate::key::thing::prefix(ns, db, tb);
+		let end = crate::key::thing::suffix(ns, db, tb);
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			// Get the next batch of records
+			let batch = self.batch(rng, *EXPORT_BATCH_SIZE, true).await?;
+			// Set the next scan range
+			next = batch.next;
+			// Check there are records
+			if batch.values.is_empty() {
+				break;
+			}
+			// Categorize the record types
+			for (_, v) in batch.values.into_iter() {
+				// Parse the key and the value
+				let v: Value = (&v).into();
+				// Check if this is a graph edge
+				if is_relation(&v) {
+					records_relate.push(v);
+				} else {
+					records_normal.push(v);
+				}
+			}
+			// Add batches of records
+			out.records(mem::take(&mut records_normal), false, None).await?;
+			out.records(mem::take(&mut records_relate), true, None).await?;
+		}
+		Ok(())
+	}
+
+	async fn export_versioned_records(
+		&self,
+		ns: &str,
+		db: &str,
+		tb: &str,
+		out: &Writer,
+	) -> Result<(), Error> {
+		let beg = crate::key::thing::prefix(ns, db, tb);
+		let end = crate::key::thing::suffix(ns, db, tb);
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			// Get the next batch of keys
+			let batch = self.batch(rng, *EXPORT_BATCH_SIZE, false).await?;
+			// Set the next scan range
+			next = batch.next;
+			// Check there are records
+			let (Some((first, _)), Some((last, _))) = (batch.values.first(), batch.values.last())
+			else {
+				break;
+			};
+			// Fetch all the versions of the keys in this batch
+			let mut end = last.clone();
+			end.push(0x00);
+			let versions = self.scan_all_versions(first.clone()..end, u32::MAX).await?;
+			// Group the versions of each key
+			let mut current: Option<Vec<u8>> = None;
+			let mut history = Vec::new();
+			for (k, v, ts, deleted) in versions {
+				if current.as_ref() != Some(&k) {
+					export_history(out, mem::take(&mut history)).await?;
+					current = Some(k);
+				}
+				// Only the versions since the last deletion are exported
+				if deleted {
+					history.clear();
+				} else {
+					history.push(((&v).into(), ts));
+				}
+			}
+			export_history(out, history).await?;
+		}
+		Ok(())
+	}
+}
+
+async fn export_definitions<T: Display>(
+	out: &Writer,
+	title: &str,
+	mut defs: impl ExactSizeIterator<Item = T>,
+) -> Result<(), Error> {
+	if defs.len() > 0 {
+		out.section(title).await?;
+		for def in defs.by_ref() {
+			out.define(def).await?;
+		}
+		out.blank().await?;
+	}
+	Ok(())
+}
+
+/// Outputs the versions of a record, oldest first
+///
+/// The versioned INSERT statements are imported under OPTION IMPORT as the
+/// records were stored, without applying the table permissions, fields or events.
+async fn export_history(out: &Writer, history: Vec<(Value, u64)>) -> Result<(), Error> {
+	for (v, ts) in history {
+		let relation = is_relation(&v);
+		out.records(vec![v], relation, Some(ts)).await?;
+	}
+	Ok(())
+}
+
+fn is_relation(v: &Value) -> bool {
+	matches!(
+		(v.pick(&*EDGE), v.pick(&*IN), v.pick(&*OUT)),
+		(Value::Bool(true), Value::Thing(_), Value::Thing(_))
+	)
+}
+
+fn version_at(ts: u64) -> Version {
+	Version(Datetime(Utc.timestamp_nanos(ts as i64)))
+}
+
+/// Converts a NDJSON or CBOR export into the statements which import it
+pub(crate) fn parse(format: Format, data: &[u8]) -> Result<Query, Error> {
+	let entries = match format {
+		Format::Sql => unreachable!(),
+		Format::Ndjson => data
+			.split(|b| *b == b'\n')
+			.filter(|l| !l.is_empty())
+			.map(|l| {
+				serde_json::from_slice::<Entry<String>>(l)
+					.map_err(|e| Error::InvalidImport(e.to_string()))?
+					.try_map(|v, _| {
+						crate::syn::value(&v).map_err(|e| Error::InvalidImport(e.to_string()))
+					})
+			})
+			.collect::<Result<Vec<_>, Error>>()?,
+		Format::Cbor => {
+			let mut rest = &data[CBOR_MAGIC.len()..];
+			let mut entries = Vec::new();
+			while !rest.is_empty() {
+				let entry: Entry<ciborium::Value> = ciborium::from_reader(&mut rest)
+					.map_err(|e| Error::InvalidImport(e.to_string()))?;
+				entries.push(entry.try_map(|v, _| {
+					Value::try_from(Cbor(v)).map_err(|e| Error::InvalidImport(e.to_string()))
+				})?);
+			}
+			entries
+		}
+	};
+	let mut entries = entries.into_iter();
+	// Check the header
+	match entries.next() {
+		Some(Entry::Header {
+			version,
+			..
+		}) if version <= FORMAT_VERSION => {}
+		Some(Entry::Header {
+			version,
+			..
+		}) => {
+			return Err(Error::InvalidImport(format!("Unsupported export version {version}")));
+		}
+		_ => return Err(Error::InvalidImport("Missing export header".to_string())),
+	}
+	// The definitions are processed before the records
+	let mut stms = vec![Statement::Option(OptionStatement {
+		name: Ident::from("IMPORT"),
+		what: true,
+	})];
+	let mut records: Vec<Statement> = Vec::new();
+	let mut batch: Vec<Value> = Vec::new();
+	let mut last = None;
+	for entry in entries {
+		match entry {
+			Entry::Header {
+				..
+			} => return Err(Error::InvalidImport("Unexpected export header".to_string())),
+			Entry::Define(sql) => stms.extend(crate::syn::parse(&sql)?.0 .0),
+			Entry::Record {
+				relation,
+				version,
+				value,
+			} => {
+				// Consecutive records of the same kind are inserted together
+				let kind = Some((relation, version));
+				if last != kind || batch.len() >= *EXPORT_BATCH_SIZE as usize {
+					if let Some((relation, version)) = last {
+						records.push(insert(mem::take(&mut batch), relation, version));
+					}
+					last = kind;
+				}
+				batch.push(value);
+			}
+		}
+	}
+	if let Some((relation, version)) = last {
+		records.push(insert(batch, relation, version));
+	}
+	if !records.is_empty() {
+		stms.push(Statement::Begin(BeginStatement));
+		stms.extend(records);
+		stms.push(Statement::Commit(CommitStatement));
+	}
+	Ok(Query(Statements(stms)))
+}
+
+fn insert(records: Vec<Value>, relation: bool, version: Option<u64>) -> Statement {
+	Statement::Insert(InsertStatement {
+		data: Data::SingleExpression(Value::from(records)),
+		relation,
+		version: version.map(version_at),
+		..Default::default()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn detect_format() {
+		assert_eq!(Format::detect(b"-- ----\nOPTION IMPORT;"), Format::Sql);
+		assert_eq!(
+			Format::detect(b"{\"header\":{\"format\":\"surrealdb\",\"version\":1}}\n"),
+			Format::Ndjson
+		);
+		assert_eq!(Format::detect(&[0xd9, 0xd9, 0xf7, 0xa1]), Format::Cbor);
+	}
+
+	#[test]
+	fn parse_ndjson() {
+		let data = concat!(
+			"{\"header\":{\"format\":\"surrealdb\",\"version\":1}}\n",
+			"{\"define\":\"DEFINE TABLE person TYPE NORMAL SCHEMALESS PERMISSIONS NONE\"}\n",
+		);
+		let query = parse(Format::Ndjson, data.as_bytes()).unwrap();
+		let stms = query.0 .0;
+		assert_eq!(stms.len(), 2);
+		assert!(matches!(stms[0], Statement::Option(_)));
+		assert!(matches!(stms[1], Statement::Define(_)));
+	}
+
+	#[tokio::test]
+	async fn round_trip() {
+		use crate::dbs::Session;
+		use crate::kvs::Datastore;
+		for format in [Format::Ndjson, Format::Cbor] {
+			let ds = Datastore::new("memory").await.unwrap();
+			let ses = Session::owner().with_ns("test").with_db("test");
+			let sql = "
+				CREATE person:tobie SET name = 'Tobie', born = d'1990-01-01T00:00:00Z', id_str = '1';
+				CREATE person:⟨jaime rey⟩ SET name = 'Jaime', friend = person:tobie, score = 1.5dec,
+					uuid = u'018b2d6b-2f8c-7a1d-b2c8-52f3b9c7bd2c', ttl = 1h, data = <bytes>'abc';
+				RELATE person:tobie->knows:1->person:⟨jaime rey⟩ SET since = 2010;
+			";
+			ds.execute(sql, &ses, None).await.unwrap();
+			let select = "SELECT * FROM person, knows";
+			let expected = ds.execute(select, &ses, None).await.unwrap().remove(0).result.unwrap();
+			let (snd, rcv) = channel::unbounded();
+			let cfg = Config {
+				format,
+				..Default::default()
+			};
+			ds.export_with_config(&ses, snd, cfg).await.unwrap().await.unwrap();
+			let mut data = Vec::new();
+			while let Ok(v) = rcv.try_recv() {
+				data.extend(v);
+			}
+			ds.execute("REMOVE TABLE person; REMOVE TABLE knows", &ses, None).await.unwrap();
+			for res in ds.import_bytes(&data, &ses).await.unwrap() {
+				res.result.unwrap();
+			}
+			let actual = ds.execute(select, &ses, None).await.unwrap().remove(0).result.unwrap();
+			// The record links, datetimes and other values keep their type
+			assert_eq!(actual, expected, "{format:?}");
+		}
+	}
+
+	#[test]
+	fn parse_missing_header() {
+		let data = "{\"define\":\"DEFINE TABLE person\"}\n";
+		assert!(matches!(parse(Format::Ndjson, data.as_bytes()), Err(Error::InvalidImport(_))));
+	}
 }
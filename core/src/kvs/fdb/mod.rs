@@ -209,7 +209,14 @@ impl super::api::Transaction for Transaction {
 		self.done = true;
 		// Commit this transaction
 		match self.inner.take() {
-			Some(inner) => inner.commit().await?,
+			Some(inner) => inner.commit().await.map_err(|e| {
+				// The transaction was not committed, so it can be retried
+				if e.is_retryable_not_committed() {
+					Error::TxConflict
+				} else {
+					e.into()
+				}
+			})?,
 			None => unreachable!(),
 		};
 		// Continue
@@ -6,6 +6,7 @@ use crate::err::Error;
 use crate::idx::index::IndexOperation;
 use crate::key::thing;
 use crate::kvs::ds::TransactionFactory;
+use crate::kvs::metrics::IndexBuild;
 use crate::kvs::LockType::Optimistic;
 use crate::kvs::{Transaction, TransactionType};
 use crate::sql::statements::DefineIndexStatement;
@@ -129,6 +130,31 @@ impl IndexBuilder {
 		Ok(ConsumeResult::Ignored(old_values, new_values))
 	}
 
+	/// Returns the progress of every index build
+	pub(crate) async fn progress(&self) -> Vec<IndexBuild> {
+		// Collect the builds first, to avoid holding the map locks while waiting
+		let buildings: Vec<_> = self.indexes.iter().map(|e| e.value().0.clone()).collect();
+		let mut builds = Vec::with_capacity(buildings.len());
+		for b in buildings {
+			let (status, count) = match &*b.status.lock().await {
+				BuildingStatus::Started => ("started", 0),
+				BuildingStatus::InitialIndexing(count) => ("initial", *count),
+				BuildingStatus::UpdatesIndexing(count) => ("updates", *count),
+				BuildingStatus::Error(_) => ("error", 0),
+				BuildingStatus::Built => ("built", 0),
+			};
+			builds.push(IndexBuild {
+				ns: b.opt.ns().unwrap_or_default().to_string(),
+				db: b.opt.db().unwrap_or_default().to_string(),
+				tb: b.tb.clone(),
+				ix: b.ix.name.to_string(),
+				status,
+				count,
+			});
+		}
+		builds
+	}
+
 	pub(crate) async fn get_status(&self, ix: &DefineIndexStatement) -> Option<BuildingStatus> {
 		if let Some(a) = self.indexes.get(ix) {
 			Some(a.value().0.status.lock().await.clone())
@@ -126,7 +126,7 @@ impl super::api::Transaction for Transaction {
 		}
 		// Mark this transaction as done
 		self.done = true;
-		// Cancel this transaction
+		// Commit this transaction, which holds the write lock, so it can not conflict
 		self.inner.commit()?;
 		// Continue
 		Ok(())
@@ -0,0 +1,127 @@
+//! Counters which describe the activity of the datastores of this process
+use crate::err::Error;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// The number of transactions which were committed
+static TRANSACTION_COMMITS: AtomicU64 = AtomicU64::new(0);
+/// The number of transactions which failed to commit due to a conflict with another transaction
+static TRANSACTION_CONFLICTS: AtomicU64 = AtomicU64::new(0);
+/// The number of transactions which failed to commit for any other reason
+static TRANSACTION_FAILURES: AtomicU64 = AtomicU64::new(0);
+/// The number of definitions which were found in the transaction cache
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+/// The number of definitions which were fetched from the datastore
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+/// The number of index tree nodes which were found in the index cache
+static INDEX_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+/// The number of index tree nodes which were fetched from the datastore
+static INDEX_CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Records the outcome of a transaction commit
+pub(crate) fn record_commit(res: &Result<(), Error>) {
+	match res {
+		Ok(_) => {
+			TRANSACTION_COMMITS.fetch_add(1, Relaxed);
+		}
+		// These transactions never attempted to commit
+		Err(Error::TxFinished | Error::TxReadonly) => {}
+		// The storage engines report their commit conflicts with these errors
+		Err(Error::TxConflict | Error::TxConditionNotMet | Error::TxKeyAlreadyExists) => {
+			TRANSACTION_CONFLICTS.fetch_add(1, Relaxed);
+		}
+		Err(_) => {
+			TRANSACTION_FAILURES.fetch_add(1, Relaxed);
+		}
+	}
+}
+
+/// Records a lookup in the transaction cache
+pub(crate) fn record_cache<T, E>(res: Result<T, E>) -> Result<T, E> {
+	match res {
+		Ok(_) => CACHE_HITS.fetch_add(1, Relaxed),
+		Err(_) => CACHE_MISSES.fetch_add(1, Relaxed),
+	};
+	res
+}
+
+/// Records a lookup in the cache of an index tree
+pub(crate) fn record_index_cache<T>(res: Option<T>) -> Option<T> {
+	match res {
+		Some(_) => INDEX_CACHE_HITS.fetch_add(1, Relaxed),
+		None => INDEX_CACHE_MISSES.fetch_add(1, Relaxed),
+	};
+	res
+}
+
+/// The progress of an index which is being built
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct IndexBuild {
+	pub ns: String,
+	pub db: String,
+	pub tb: String,
+	pub ix: String,
+	/// The stage of the build, as shown by `INFO FOR INDEX`
+	pub status: &'static str,
+	/// The number of records indexed so far
+	pub count: usize,
+}
+
+/// A snapshot of the activity of a datastore
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct Metrics {
+	/// The number of transactions which were committed
+	pub transaction_commits: u64,
+	/// The number of transactions which failed to commit due to a conflict
+	pub transaction_conflicts: u64,
+	/// The number of transactions which failed to commit for any other reason
+	pub transaction_failures: u64,
+	/// The number of live queries registered on this node
+	pub live_queries: usize,
+	/// The indexes which are being built, or were built since the datastore started
+	pub index_builds: Vec<IndexBuild>,
+	/// The number of hits in the transaction cache
+	pub cache_hits: u64,
+	/// The number of misses in the transaction cache
+	pub cache_misses: u64,
+	/// The number of hits in the index tree caches
+	pub index_cache_hits: u64,
+	/// The number of misses in the index tree caches
+	pub index_cache_misses: u64,
+}
+
+impl Metrics {
+	/// Reads the counters which are shared by the datastores of this process
+	pub fn counters() -> Self {
+		Self {
+			transaction_commits: TRANSACTION_COMMITS.load(Relaxed),
+			transaction_conflicts: TRANSACTION_CONFLICTS.load(Relaxed),
+			transaction_failures: TRANSACTION_FAILURES.load(Relaxed),
+			cache_hits: CACHE_HITS.load(Relaxed),
+			cache_misses: CACHE_MISSES.load(Relaxed),
+			index_cache_hits: INDEX_CACHE_HITS.load(Relaxed),
+			index_cache_misses: INDEX_CACHE_MISSES.load(Relaxed),
+			..Default::default()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn commit_conflicts() {
+		// The counters are shared with the other tests, so they can only grow
+		let before = Metrics::counters();
+		record_commit(&Err(Error::TxConflict));
+		record_commit(&Err(Error::TxConditionNotMet));
+		record_commit(&Err(Error::Tx("IO error: No space left on device".into())));
+		record_commit(&Err(Error::TxFinished));
+		let after = Metrics::counters();
+		assert!(after.transaction_conflicts >= before.transaction_conflicts + 2);
+		assert!(after.transaction_failures > before.transaction_failures);
+	}
+}
@@ -16,11 +16,15 @@ mod api;
 mod batch;
 mod cache;
 mod clock;
+mod crypto;
 mod ds;
-mod export;
+pub mod export;
 mod live;
+pub mod metrics;
 mod node;
+pub mod replica;
 mod scanner;
+mod sequences;
 mod stash;
 mod tr;
 mod tx;
@@ -44,5 +48,6 @@ pub use self::ds::*;
 pub(crate) use self::index::*;
 pub use self::kv::*;
 pub use self::live::*;
+pub(crate) use self::sequences::*;
 pub use self::tr::*;
 pub use self::tx::*;
@@ -0,0 +1,191 @@
+//! Applies the changes of a primary datastore to a read only replica
+use super::{Datastore, LockType::*, Transaction, TransactionType::*};
+use crate::dbs::{Executor, Notification, Session};
+use crate::err::Error;
+use crate::sql::{Query, Statement, Statements};
+use crate::syn::parser::{Parser, PartialResult};
+use channel::{Receiver, Sender};
+use derive::Store;
+use reblessive::Stack;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
+use std::sync::Arc;
+
+/// The state of a replicated database, which is stored along with the replicated changes
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store)]
+#[non_exhaustive]
+pub struct State {
+	/// The versionstamp of the last change which has been applied from the primary
+	pub versionstamp: u128,
+	/// The fingerprint of the schema of the primary at that versionstamp
+	pub schema: u64,
+}
+
+impl State {
+	pub fn new(versionstamp: u128, schema: u64) -> Self {
+		Self {
+			versionstamp,
+			schema,
+		}
+	}
+}
+
+/// A transaction which applies the changes of a primary datastore to a read only replica
+///
+/// The changes are only visible once they are committed, along with the
+/// state of the replica, so that a replica never exposes partial changes.
+pub struct Replication<'a> {
+	kvs: &'a Datastore,
+	sess: Session,
+	txn: Arc<Transaction>,
+	/// The live query notifications, which are only delivered on commit
+	notifications: (Sender<Notification>, Receiver<Notification>),
+	/// The bytes of a SQL statement which has not been fully received yet
+	pending: Vec<u8>,
+}
+
+impl<'a> Replication<'a> {
+	pub(super) async fn new(kvs: &'a Datastore, sess: &Session) -> Result<Self, Error> {
+		// Check if the session has expired
+		if sess.expired() {
+			return Err(Error::ExpiredSession);
+		}
+		Ok(Self {
+			kvs,
+			sess: sess.clone(),
+			txn: Arc::new(kvs.transaction(Write, Optimistic).await?),
+			notifications: channel::unbounded(),
+			pending: Vec::new(),
+		})
+	}
+
+	/// Applies a query within the replication transaction
+	///
+	/// Transaction statements are skipped, as all the changes are applied in one transaction.
+	pub async fn apply(&self, ast: Query) -> Result<(), Error> {
+		let stms: Vec<Statement> = ast
+			.0
+			 .0
+			.into_iter()
+			.filter(|v| {
+				!matches!(v, Statement::Begin(_) | Statement::Cancel(_) | Statement::Commit(_))
+			})
+			.collect();
+		if stms.is_empty() {
+			return Ok(());
+		}
+		let exe =
+			Executor::new_interactive(self.kvs, self.txn.clone(), self.notifications.0.clone());
+		for res in self.kvs.process_with(exe, Query(Statements(stms)), &self.sess, None).await? {
+			res.result?;
+		}
+		Ok(())
+	}
+
+	/// Applies the complete statements of the next chunk of a SQL export
+	///
+	/// A statement which is split across chunks is applied once its last chunk is received.
+	pub async fn apply_sql(&mut self, chunk: &[u8]) -> Result<(), Error> {
+		self.pending.extend_from_slice(chunk);
+		// Statements are only parsed up to the end of the last full line
+		let Some(end) = self.pending.iter().rposition(|b| *b == b'\n') else {
+			return Ok(());
+		};
+		let mut stms = Vec::new();
+		let mut used = 0;
+		let mut stack = Stack::new();
+		while used <= end {
+			let src = &self.pending[used..=end];
+			let mut parser = Parser::new(src);
+			match stack.enter(|stk| parser.parse_partial_statement(stk)).finish() {
+				PartialResult::Pending {
+					..
+				} => break,
+				PartialResult::Ready {
+					value,
+					used: len,
+				} => {
+					let stm = value.map_err(|e| {
+						Error::InvalidQuery(e.render_on(&String::from_utf8_lossy(src)))
+					})?;
+					stms.push(stm);
+					used += len;
+				}
+			}
+		}
+		self.pending.drain(..used);
+		self.apply(Query(Statements(stms))).await
+	}
+
+	/// Commits the replicated changes, along with the state of the replica
+	pub async fn commit(mut self, state: State) -> Result<(), Error> {
+		// Apply any remaining statement of a SQL export
+		let pending = mem::take(&mut self.pending);
+		let sql = String::from_utf8(pending)
+			.map_err(|_| Error::InvalidImport("The SQL import is not valid UTF-8".into()))?;
+		if !sql.trim().is_empty() {
+			if let Err(e) = self.apply(crate::syn::parse(&sql)?).await {
+				let _ = self.txn.cancel().await;
+				return Err(e);
+			}
+		}
+		// Store the state of the replica
+		let (ns, db) = crate::iam::check::check_ns_db(&self.sess)?;
+		let key = crate::key::database::rp::new(&ns, &db);
+		if let Err(e) = self.txn.set(key, state, None).await {
+			let _ = self.txn.cancel().await;
+			return Err(e);
+		}
+		// Commit the transaction
+		{
+			let mut txn = self.txn.lock().await;
+			if let Err(e) = txn.complete_changes(false).await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+			if let Err(e) = txn.commit().await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+		}
+		// Deliver the notifications of the committed changes
+		if let Some(chn) = self.kvs.notification_sender() {
+			while let Ok(notification) = self.notifications.1.try_recv() {
+				if chn.send(notification).await.is_err() {
+					break;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Cancels the replicated changes
+	pub async fn cancel(self) -> Result<(), Error> {
+		self.txn.cancel().await
+	}
+}
+
+/// Calculates a fingerprint of the definitions of a database, which changes whenever its schema changes
+pub(super) async fn fingerprint(txn: &Transaction, ns: &str, db: &str) -> Result<u64, Error> {
+	let mut hasher = DefaultHasher::new();
+	let mut add = |def: &dyn Display| def.to_string().hash(&mut hasher);
+	txn.all_db_roles(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_users(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_accesses(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_params(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_functions(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_analyzers(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_models(ns, db).await?.iter().for_each(|v| add(v));
+	txn.all_db_sequences(ns, db).await?.iter().for_each(|v| add(v));
+	for tb in txn.all_tb(ns, db).await?.iter() {
+		add(tb);
+		txn.all_tb_fields(ns, db, &tb.name).await?.iter().for_each(|v| add(v));
+		txn.all_tb_indexes(ns, db, &tb.name).await?.iter().for_each(|v| add(v));
+		txn.all_tb_events(ns, db, &tb.name).await?.iter().for_each(|v| add(v));
+	}
+	Ok(hasher.finish())
+}
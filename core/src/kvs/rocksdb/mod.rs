@@ -4,12 +4,13 @@ mod cnf;
 
 use crate::err::Error;
 use crate::key::debug::Sprintable;
+use crate::kvs::crypto::{self, Cipher};
 use crate::kvs::Check;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use rocksdb::{
-	DBCompactionStyle, DBCompressionType, LogLevel, OptimisticTransactionDB,
-	OptimisticTransactionOptions, Options, ReadOptions, WriteOptions,
+	DBCompactionStyle, DBCompressionType, ErrorKind, IteratorMode, LogLevel,
+	OptimisticTransactionDB, OptimisticTransactionOptions, Options, ReadOptions, WriteOptions,
 };
 use std::fmt::Debug;
 use std::ops::Range;
@@ -20,6 +21,7 @@ use std::sync::Arc;
 #[non_exhaustive]
 pub struct Datastore {
 	db: Pin<Arc<OptimisticTransactionDB>>,
+	cipher: Option<Arc<Cipher>>,
 }
 
 #[non_exhaustive]
@@ -34,6 +36,8 @@ pub struct Transaction {
 	inner: Option<rocksdb::Transaction<'static, OptimisticTransactionDB>>,
 	/// The read options containing the Snapshot
 	ro: ReadOptions,
+	/// The cipher used to encrypt values at rest
+	cipher: Option<Arc<Cipher>>,
 	// The above, supposedly 'static transaction
 	// actually points here, so we need to ensure
 	// the memory is kept alive. This pointer must
@@ -73,7 +77,7 @@ impl Drop for Transaction {
 
 impl Datastore {
 	/// Open a new database
-	pub(crate) async fn new(path: &str) -> Result<Datastore, Error> {
+	pub(crate) async fn new(path: &str, cipher: Option<Cipher>) -> Result<Datastore, Error> {
 		// Configure custom options
 		let mut opts = Options::default();
 		// Ensure we use fdatasync
@@ -112,11 +116,91 @@ impl Datastore {
 			DBCompressionType::Lz4hc,
 			DBCompressionType::Lz4hc,
 		]);
+		// Check that the datastore is not being re-encrypted
+		crypto::Rekey::check(path)?;
+		// Open the datastore
+		let db = OptimisticTransactionDB::open(&opts, path)?;
+		// Check the encryption key
+		let key = crypto::check_key();
+		let empty = db.iterator(IteratorMode::Start).next().is_none();
+		if let Some(v) = crypto::verify(cipher.as_ref(), db.get(&key)?, empty)? {
+			db.put(key, v)?;
+		}
 		// Create the datastore
 		Ok(Datastore {
-			db: Arc::pin(OptimisticTransactionDB::open(&opts, path)?),
+			db: Arc::pin(db),
+			cipher: cipher.map(Arc::new),
 		})
 	}
+	/// Re-encrypt all values in the datastore with a new key
+	///
+	/// The values are copied into a new datastore with the new key, which then
+	/// replaces this one. The keys are encrypted again if the new datastore
+	/// encrypts keys. An interrupted rekey is resumed when it is run again.
+	pub(crate) async fn rekey(
+		path: &str,
+		cipher: Option<Cipher>,
+		new: Option<Cipher>,
+	) -> Result<(), Error> {
+		use super::api::Transaction as _;
+		let rekey = crypto::Rekey::new(path);
+		// Complete an interrupted rekey, and check the new key
+		if rekey.recover()? {
+			Self::new(path, new).await?;
+			return Ok(());
+		}
+		// Open the existing and new datastores
+		let src = Self::new(path, cipher).await?;
+		let dst = Self::new(&rekey.new.to_string_lossy(), new).await?;
+		let check = crypto::check_key();
+		// The first key of the next batch
+		let mut beg = vec![];
+		loop {
+			// Read the next batch with the current key, up to the end of the keyspace
+			let mut txn = src.transaction(false, false).await?;
+			let mut res = Vec::new();
+			let mut iter = txn.inner.as_ref().unwrap().raw_iterator();
+			iter.seek(&beg);
+			let mut last = None;
+			let mut count = 0;
+			while count < crypto::REKEY_BATCH_SIZE as usize {
+				let Some((k, v)) = iter.item() else {
+					break;
+				};
+				if k != check.as_slice() {
+					let key = txn.decrypt_key(k)?;
+					let val = txn.decrypt(&key, Some(v.to_vec()))?.unwrap_or_default();
+					res.push((key, val));
+				}
+				last = Some(k.to_vec());
+				count += 1;
+				iter.next();
+			}
+			iter.status()?;
+			drop(iter);
+			txn.cancel().await?;
+			// Write the batch with the new key
+			let mut txn = dst.transaction(true, false).await?;
+			for (k, v) in res {
+				txn.set(k, v, None).await?;
+			}
+			txn.commit().await?;
+			// Move to the next batch
+			match last {
+				Some(k) if count == crypto::REKEY_BATCH_SIZE as usize => {
+					beg = k;
+					beg.push(0x00);
+				}
+				_ => break,
+			}
+		}
+		// Persist the new datastore before it replaces this one
+		dst.db.flush()?;
+		dst.db.flush_wal(true)?;
+		drop(src);
+		drop(dst);
+		rekey.replace()
+	}
 	/// Start a new transaction
 	pub(crate) async fn transaction(&self, write: bool, _: bool) -> Result<Transaction, Error> {
 		// Set the transaction options
@@ -155,11 +239,73 @@ impl Datastore {
 			check,
 			inner: Some(inner),
 			ro,
+			cipher: self.cipher.clone(),
 			_db: self.db.clone(),
 		})
 	}
 }
 
+impl Transaction {
+	/// Encrypt a value, if encryption is enabled
+	fn encrypt(&self, key: &[u8], val: Val) -> Result<Val, Error> {
+		match &self.cipher {
+			Some(c) => c.encrypt(key, val),
+			None => Ok(val),
+		}
+	}
+	/// Decrypt a value, if encryption is enabled
+	fn decrypt(&self, key: &[u8], val: Option<Val>) -> Result<Option<Val>, Error> {
+		Cipher::decrypt_opt(self.cipher.as_deref(), key, val)
+	}
+	/// Encrypt a key, if keys are encrypted
+	fn key(&self, key: &[u8]) -> Key {
+		match &self.cipher {
+			Some(c) => c.encrypt_key(key),
+			None => key.to_vec(),
+		}
+	}
+	/// Decrypt a key, if keys are encrypted
+	fn decrypt_key(&self, key: &[u8]) -> Result<Key, Error> {
+		match &self.cipher {
+			Some(c) => c.decrypt_key(key),
+			None => Ok(key.to_vec()),
+		}
+	}
+	/// The cipher, if keys are encrypted
+	fn key_cipher(&self) -> Option<&Cipher> {
+		self.cipher.as_deref().filter(|c| c.encrypts_keys())
+	}
+	/// Retrieve every key with the prefix of a range when keys are encrypted,
+	/// and return the keys and values in the range, in order
+	fn scan_encrypted(&self, cipher: &Cipher, rng: &Range<Key>) -> Result<Vec<(Key, Val)>, Error> {
+		// Get the transaction
+		let inner = self.inner.as_ref().unwrap();
+		// Set the range of encrypted keys
+		let enc = cipher.encrypt_range(rng);
+		// Set the ReadOptions with the snapshot
+		let mut ro = ReadOptions::default();
+		ro.set_snapshot(&inner.snapshot());
+		ro.set_async_io(true);
+		ro.fill_cache(true);
+		// Create the iterator
+		let mut iter = inner.raw_iterator_opt(ro);
+		// Seek to the start key
+		iter.seek(&enc.start);
+		// Retrieve the whole range
+		let mut res = vec![];
+		while let Some((k, v)) = iter.item() {
+			if k >= enc.end.as_slice() {
+				break;
+			}
+			res.push((k.to_vec(), v.to_vec()));
+			iter.next();
+		}
+		iter.status()?;
+		// Decrypt the keys, and keep the keys in the range
+		cipher.decrypt_range(rng, res)
+	}
+}
+
 impl super::api::Transaction for Transaction {
 	/// Behaviour if unclosed
 	fn check_level(&mut self, check: Check) {
@@ -209,7 +355,12 @@ impl super::api::Transaction for Transaction {
 		self.done = true;
 		// Commit this transaction
 		match self.inner.take() {
-			Some(inner) => inner.commit()?,
+			Some(inner) => inner.commit().map_err(|e| match e.kind() {
+				// Optimistic transactions report a conflict as busy, or as try again
+				// when the memtable no longer holds the history needed to check it
+				ErrorKind::Busy | ErrorKind::TryAgain => Error::TxConflict,
+				_ => e.into(),
+			})?,
 			None => unreachable!(),
 		};
 		// Continue
@@ -227,7 +378,7 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxFinished);
 		}
 		// Check the key
-		let res = self.inner.as_ref().unwrap().get_opt(key.into(), &self.ro)?.is_some();
+		let res = self.inner.as_ref().unwrap().get_opt(self.key(&key.into()), &self.ro)?.is_some();
 		// Return result
 		Ok(res)
 	}
@@ -248,9 +399,10 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxFinished);
 		}
 		// Get the key
-		let res = self.inner.as_ref().unwrap().get_opt(key.into(), &self.ro)?;
+		let key = key.into();
+		let res = self.inner.as_ref().unwrap().get_opt(self.key(&key), &self.ro)?;
 		// Return result
-		Ok(res)
+		self.decrypt(&key, res)
 	}
 
 	/// Insert or update a key in the database
@@ -273,8 +425,12 @@ impl super::api::Transaction for Transaction {
 		if !self.write {
 			return Err(Error::TxReadonly);
 		}
+		// Encrypt the key and value
+		let key = key.into();
+		let val = self.encrypt(&key, val.into())?;
+		let key = self.key(&key);
 		// Set the key
-		self.inner.as_ref().unwrap().put(key.into(), val.into())?;
+		self.inner.as_ref().unwrap().put(key, val)?;
 		// Return result
 		Ok(())
 	}
@@ -299,11 +455,12 @@ impl super::api::Transaction for Transaction {
 		if !self.write {
 			return Err(Error::TxReadonly);
 		}
-		// Get the transaction
-		let inner = self.inner.as_ref().unwrap();
 		// Get the arguments
 		let key = key.into();
-		let val = val.into();
+		let val = self.encrypt(&key, val.into())?;
+		let key = self.key(&key);
+		// Get the transaction
+		let inner = self.inner.as_ref().unwrap();
 		// Set the key if empty
 		match inner.get_opt(&key, &self.ro)? {
 			None => inner.put(key, val)?,
@@ -328,16 +485,19 @@ impl super::api::Transaction for Transaction {
 		if !self.write {
 			return Err(Error::TxReadonly);
 		}
-		// Get the transaction
-		let inner = self.inner.as_ref().unwrap();
 		// Get the arguments
 		let key = key.into();
-		let val = val.into();
+		let val = self.encrypt(&key, val.into())?;
 		let chk = chk.map(Into::into);
+		// Get the transaction
+		let inner = self.inner.as_ref().unwrap();
+		// Get the current value
+		let enc = self.key(&key);
+		let cur = self.decrypt(&key, inner.get_opt(&enc, &self.ro)?)?;
 		// Set the key if valid
-		match (inner.get_opt(&key, &self.ro)?, chk) {
-			(Some(v), Some(w)) if v == w => inner.put(key, val)?,
-			(None, None) => inner.put(key, val)?,
+		match (cur, chk) {
+			(Some(v), Some(w)) if v == w => inner.put(enc, val)?,
+			(None, None) => inner.put(enc, val)?,
 			_ => return Err(Error::TxConditionNotMet),
 		};
 		// Return result
@@ -359,7 +519,7 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxReadonly);
 		}
 		// Remove the key
-		self.inner.as_ref().unwrap().delete(key.into())?;
+		self.inner.as_ref().unwrap().delete(self.key(&key.into()))?;
 		// Return result
 		Ok(())
 	}
@@ -384,10 +544,13 @@ impl super::api::Transaction for Transaction {
 		// Get the arguments
 		let key = key.into();
 		let chk = chk.map(Into::into);
+		// Get the current value
+		let enc = self.key(&key);
+		let cur = self.decrypt(&key, inner.get_opt(&enc, &self.ro)?)?;
 		// Delete the key if valid
-		match (inner.get_opt(&key, &self.ro)?, chk) {
-			(Some(v), Some(w)) if v == w => inner.delete(key)?,
-			(None, None) => inner.delete(key)?,
+		match (cur, chk) {
+			(Some(v), Some(w)) if v == w => inner.delete(enc)?,
+			(None, None) => inner.delete(enc)?,
 			_ => return Err(Error::TxConditionNotMet),
 		};
 		// Return result
@@ -411,6 +574,11 @@ impl super::api::Transaction for Transaction {
 			start: rng.start.into(),
 			end: rng.end.into(),
 		};
+		// Scan every key with the prefix of the range, if keys are encrypted
+		if let Some(c) = self.key_cipher() {
+			let res = self.scan_encrypted(c, &rng)?;
+			return Ok(res.into_iter().take(limit as usize).map(|(k, _)| k).collect());
+		}
 		// Create result set
 		let mut res = vec![];
 		// Set the key range
@@ -443,6 +611,65 @@ impl super::api::Transaction for Transaction {
 		Ok(res)
 	}
 
+	/// Retrieve a range of keys from the databases, in reverse order
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self), fields(rng = rng.sprint()))]
+	async fn keysr<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key> + Sprintable + Debug,
+	{
+		// Check to see if transaction is closed
+		if self.done {
+			return Err(Error::TxFinished);
+		}
+		// Get the transaction
+		let inner = self.inner.as_ref().unwrap();
+		// Convert the range to bytes
+		let rng: Range<Key> = Range {
+			start: rng.start.into(),
+			end: rng.end.into(),
+		};
+		// Scan every key with the prefix of the range, if keys are encrypted
+		if let Some(c) = self.key_cipher() {
+			let res = self.scan_encrypted(c, &rng)?;
+			return Ok(res.into_iter().rev().take(limit as usize).map(|(k, _)| k).collect());
+		}
+		// Create result set
+		let mut res = vec![];
+		// Set the key range
+		let beg = rng.start.as_slice();
+		let end = rng.end.as_slice();
+		// Set the ReadOptions with the snapshot
+		let mut ro = ReadOptions::default();
+		ro.set_snapshot(&inner.snapshot());
+		ro.set_async_io(true);
+		ro.fill_cache(true);
+		// Create the iterator
+		let mut iter = inner.raw_iterator_opt(ro);
+		// Seek to the end key
+		iter.seek_for_prev(&rng.end);
+		// Check the scan limit
+		while res.len() < limit as usize {
+			// Check the key and value
+			if let Some(k) = iter.key() {
+				// The end of the range is exclusive
+				if k >= end {
+					iter.prev();
+					continue;
+				}
+				// Check the range validity
+				if k >= beg {
+					res.push(k.to_vec());
+					iter.prev();
+					continue;
+				}
+			}
+			// Exit
+			break;
+		}
+		// Return result
+		Ok(res)
+	}
+
 	/// Retrieve a range of keys from the databases
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self), fields(rng = rng.sprint()))]
 	async fn scan<K>(
@@ -470,6 +697,18 @@ impl super::api::Transaction for Transaction {
 			start: rng.start.into(),
 			end: rng.end.into(),
 		};
+		// Scan every key with the prefix of the range, if keys are encrypted
+		if let Some(c) = self.key_cipher() {
+			let res = self.scan_encrypted(c, &rng)?;
+			return res
+				.into_iter()
+				.take(limit as usize)
+				.map(|(k, v)| {
+					let v = self.decrypt(&k, Some(v))?.unwrap_or_default();
+					Ok((k, v))
+				})
+				.collect();
+		}
 		// Create result set
 		let mut res = vec![];
 		// Set the key range
@@ -490,7 +729,8 @@ impl super::api::Transaction for Transaction {
 			if let Some((k, v)) = iter.item() {
 				// Check the range validity
 				if k >= beg && k < end {
-					res.push((k.to_vec(), v.to_vec()));
+					let v = self.decrypt(k, Some(v.to_vec()))?.unwrap_or_default();
+					res.push((k.to_vec(), v));
 					iter.next();
 					continue;
 				}
@@ -0,0 +1,155 @@
+use crate::err::Error;
+use crate::kvs::ds::TransactionFactory;
+use crate::kvs::LockType::Optimistic;
+use crate::kvs::TransactionType::Write;
+use crate::sql::statements::DefineSequenceStatement;
+use crate::sql::Uuid;
+use dashmap::DashMap;
+use derive::Store;
+use futures::lock::Mutex;
+use rand::Rng;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The number of times a batch reservation is attempted when it conflicts
+const ALLOCATE_ATTEMPTS: u32 = 10;
+
+/// The initial delay before a conflicting batch reservation is retried
+const ALLOCATE_RETRY_DELAY: Duration = Duration::from_millis(5);
+
+/// The next sequence value which has not yet been allocated to any node
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Store)]
+#[non_exhaustive]
+pub(crate) struct SequenceState {
+	pub(crate) next: i64,
+}
+
+/// A range of sequence values allocated to this node
+#[derive(Default)]
+struct Batch {
+	generation: Uuid,
+	next: i64,
+	end: i64,
+}
+
+type SequenceKey = (String, String, String);
+
+/// Hands out sequence values from batches allocated to this node. Each
+/// batch is reserved in a separate transaction, so the datastore is only
+/// written to once per batch. Values which are allocated but not used,
+/// for instance when a node restarts, are skipped. A batch belongs to a
+/// generation of the sequence definition, so that every node discards its
+/// batch once the sequence is removed and defined again.
+#[derive(Clone)]
+pub(crate) struct Sequences {
+	tf: TransactionFactory,
+	batches: Arc<DashMap<SequenceKey, Arc<Mutex<Batch>>>>,
+}
+
+impl Sequences {
+	pub(super) fn new(tf: TransactionFactory) -> Self {
+		Self {
+			tf,
+			batches: Default::default(),
+		}
+	}
+
+	/// Returns the next value of a sequence
+	pub(crate) async fn next_val(
+		&self,
+		ns: &str,
+		db: &str,
+		sq: &DefineSequenceStatement,
+	) -> Result<i64, Error> {
+		let key = (ns.to_owned(), db.to_owned(), sq.name.to_raw());
+		let batch = self.batches.entry(key).or_default().clone();
+		let mut batch = batch.lock().await;
+		if batch.generation != sq.generation || batch.next >= batch.end {
+			let (next, end) = self.allocate(ns, db, sq).await?;
+			batch.generation = sq.generation;
+			batch.next = next;
+			batch.end = end;
+		}
+		let val = batch.next;
+		batch.next += 1;
+		Ok(val)
+	}
+
+	/// Reserves the next batch of values of a sequence, retrying with
+	/// an increasing delay when another node reserves a batch at the
+	/// same time
+	async fn allocate(
+		&self,
+		ns: &str,
+		db: &str,
+		sq: &DefineSequenceStatement,
+	) -> Result<(i64, i64), Error> {
+		let mut delay = ALLOCATE_RETRY_DELAY;
+		let mut attempt = 1;
+		loop {
+			match self.reserve(ns, db, sq).await {
+				Err(Error::TxConflict) if attempt < ALLOCATE_ATTEMPTS => {
+					// Add some jitter so that conflicting nodes do not retry in lockstep
+					let jitter = rand::thread_rng().gen_range(0..=delay.as_millis() as u64);
+					let wait = delay + Duration::from_millis(jitter);
+					#[cfg(target_arch = "wasm32")]
+					wasmtimer::tokio::sleep(wait).await;
+					#[cfg(not(target_arch = "wasm32"))]
+					tokio::time::sleep(wait).await;
+					delay *= 2;
+					attempt += 1;
+				}
+				res => return res,
+			}
+		}
+	}
+
+	/// Reserves the next batch of values of a sequence in a single transaction
+	async fn reserve(
+		&self,
+		ns: &str,
+		db: &str,
+		sq: &DefineSequenceStatement,
+	) -> Result<(i64, i64), Error> {
+		let batch = sq.batch.clamp(1, i64::MAX as u64) as i64;
+		let key = crate::key::database::sv::new(ns, db, &sq.name);
+		let txn = self.tf.transaction(Write, Optimistic).await?;
+		// Ensure the sequence has not been defined again since it was read
+		let def = crate::key::database::sq::new(ns, db, &sq.name);
+		match catch!(txn, txn.get(def, None)).map(DefineSequenceStatement::from) {
+			Some(v) if v.generation == sq.generation => (),
+			Some(_) => {
+				txn.cancel().await?;
+				return Err(Error::SequenceRedefined {
+					name: sq.name.to_raw(),
+				});
+			}
+			None => {
+				txn.cancel().await?;
+				return Err(Error::SqNotFound {
+					value: sq.name.to_raw(),
+				});
+			}
+		}
+		let next = match catch!(txn, txn.get(key.clone(), None)) {
+			Some(v) => SequenceState::from(v).next,
+			None => sq.start,
+		};
+		// The batch ends before the largest value, so the next value never overflows
+		if next == i64::MAX {
+			txn.cancel().await?;
+			return Err(Error::SequenceExhausted {
+				name: sq.name.to_raw(),
+			});
+		}
+		let end = next.checked_add(batch).unwrap_or(i64::MAX);
+		let val = SequenceState {
+			next: end,
+		};
+		run!(txn, txn.set(key, val, None))?;
+		Ok((next, end))
+	}
+}
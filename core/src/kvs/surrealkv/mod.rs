@@ -2,11 +2,14 @@
 
 use crate::err::Error;
 use crate::key::debug::Sprintable;
+use crate::kvs::crypto::{self, Cipher};
 use crate::kvs::Check;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use std::fmt::Debug;
 use std::ops::Range;
+use std::sync::Arc;
+use surrealkv::Mode;
 use surrealkv::Options;
 use surrealkv::Store;
 use surrealkv::Transaction as Tx;
@@ -14,6 +17,7 @@ use surrealkv::Transaction as Tx;
 #[non_exhaustive]
 pub struct Datastore {
 	db: Store,
+	cipher: Option<Arc<Cipher>>,
 }
 
 #[non_exhaustive]
@@ -26,6 +30,8 @@ pub struct Transaction {
 	check: Check,
 	/// The underlying datastore transaction
 	inner: Tx,
+	/// The cipher used to encrypt values at rest
+	cipher: Option<Arc<Cipher>>,
 }
 
 impl Drop for Transaction {
@@ -60,16 +66,108 @@ impl Drop for Transaction {
 
 impl Datastore {
 	/// Open a new database
-	pub(crate) async fn new(path: &str) -> Result<Datastore, Error> {
+	pub(crate) async fn new(path: &str, cipher: Option<Cipher>) -> Result<Datastore, Error> {
+		// Check that the datastore is not being re-encrypted
+		crypto::Rekey::check(path)?;
 		let mut opts = Options::new();
 		opts.dir = path.to_string().into();
 
-		match Store::new(opts) {
-			Ok(db) => Ok(Datastore {
-				db,
-			}),
-			Err(e) => Err(Error::Ds(e.to_string())),
+		let db = match Store::new(opts) {
+			Ok(db) => db,
+			Err(e) => return Err(Error::Ds(e.to_string())),
+		};
+		// Check the encryption key
+		let mut txn = db.begin().map_err(|e| Error::Tx(e.to_string()))?;
+		let key = crypto::check_key();
+		let empty = txn.scan(&[0x00][..]..&[0xff][..], Some(1))?.is_empty();
+		if let Some(v) = crypto::verify(cipher.as_ref(), txn.get(&key)?, empty)? {
+			txn.set(&key, &v)?;
+			txn.commit().await?;
+		} else {
+			txn.rollback();
+		}
+		// Create the datastore
+		Ok(Datastore {
+			db,
+			cipher: cipher.map(Arc::new),
+		})
+	}
+	/// Re-encrypt all values in the datastore with a new key
+	///
+	/// Every stored version of the values is copied into a new datastore with
+	/// the new key, which then replaces this one. As with versioned exports,
+	/// only the versions since the last deletion of a key are kept. The keys
+	/// are encrypted again if the new datastore encrypts keys. An interrupted
+	/// rekey is resumed when it is run again.
+	pub(crate) async fn rekey(
+		path: &str,
+		cipher: Option<Cipher>,
+		new: Option<Cipher>,
+	) -> Result<(), Error> {
+		use super::api::Transaction as _;
+		let rekey = crypto::Rekey::new(path);
+		// Complete an interrupted rekey, and check the new key
+		if rekey.recover()? {
+			Self::new(path, new).await?;
+			return Ok(());
+		}
+		// Open the existing and new datastores
+		let src = Self::new(path, cipher).await?;
+		let dst = Self::new(&rekey.new.to_string_lossy(), new).await?;
+		let check = crypto::check_key();
+		// The first key of the next batch
+		let mut beg = vec![];
+		loop {
+			// Read the next batch of keys, up to the end of the keyspace
+			let mut txn = src.transaction(false, false).await?;
+			let res: Vec<Key> = txn
+				.inner
+				.scan(beg.as_slice().., Some(crypto::REKEY_BATCH_SIZE as usize))?
+				.into_iter()
+				.map(|(k, _, _, _)| k)
+				.collect();
+			let Some(last) = res.last() else {
+				txn.cancel().await?;
+				break;
+			};
+			// Read all the versions of the keys in this batch, deletions are not versioned
+			let mut history: Vec<(Key, Vec<(Val, u64)>)> = Vec::new();
+			for k in res.iter().filter(|k| **k != check) {
+				let key = txn.decrypt_key(k)?;
+				let mut h = Vec::new();
+				for (v, ts) in txn.inner.get_history(k)? {
+					h.push((txn.decrypt(&key, Some(v))?.unwrap_or_default(), ts));
+				}
+				history.push((key, h));
+			}
+			txn.cancel().await?;
+			// Order the versions of each key, oldest first
+			history.iter_mut().for_each(|(_, h)| h.sort_by_key(|(_, ts)| *ts));
+			// Write each version of the keys with the new key, in a
+			// separate transaction, so that they are stored in order
+			let depth = history.iter().map(|(_, h)| h.len()).max().unwrap_or_default();
+			for i in 0..depth {
+				let mut txn = dst.transaction(true, false).await?;
+				for (k, h) in history.iter() {
+					if let Some((v, ts)) = h.get(i) {
+						txn.set(k.clone(), v.clone(), Some(*ts)).await?;
+					}
+				}
+				txn.commit().await?;
+			}
+			// Move to the next batch
+			match res.len() == crypto::REKEY_BATCH_SIZE as usize {
+				true => {
+					beg.clone_from(last);
+					beg.push(0x00);
+				}
+				false => break,
+			}
 		}
+		// Close the datastores before the new one replaces this one
+		drop(src);
+		drop(dst);
+		rekey.replace()
 	}
 	/// Start a new transaction
 	pub(crate) async fn transaction(&self, write: bool, _: bool) -> Result<Transaction, Error> {
@@ -78,19 +176,57 @@ impl Datastore {
 		let check = Check::Warn;
 		#[cfg(debug_assertions)]
 		let check = Check::Panic;
+		// Versioned reads need a read-only transaction
+		let mode = match write {
+			true => Mode::ReadWrite,
+			false => Mode::ReadOnly,
+		};
 		// Create a new transaction
-		match self.db.begin() {
+		match self.db.begin_with_mode(mode) {
 			Ok(inner) => Ok(Transaction {
 				done: false,
 				check,
 				write,
 				inner,
+				cipher: self.cipher.clone(),
 			}),
 			Err(e) => Err(Error::Tx(e.to_string())),
 		}
 	}
 }
 
+impl Transaction {
+	/// Encrypt a value, if encryption is enabled
+	fn encrypt(&self, key: &[u8], val: Val) -> Result<Val, Error> {
+		match &self.cipher {
+			Some(c) => c.encrypt(key, val),
+			None => Ok(val),
+		}
+	}
+	/// Decrypt a value, if encryption is enabled
+	fn decrypt(&self, key: &[u8], val: Option<Val>) -> Result<Option<Val>, Error> {
+		Cipher::decrypt_opt(self.cipher.as_deref(), key, val)
+	}
+	/// Encrypt a key, if keys are encrypted
+	fn key(&self, key: &[u8]) -> Key {
+		match &self.cipher {
+			Some(c) => c.encrypt_key(key),
+			None => key.to_vec(),
+		}
+	}
+	/// Decrypt a key, if keys are encrypted
+	fn decrypt_key(&self, key: &[u8]) -> Result<Key, Error> {
+		match &self.cipher {
+			Some(c) => c.decrypt_key(key),
+			None => Ok(key.to_vec()),
+		}
+	}
+	/// The cipher, if keys are encrypted
+	fn key_cipher(&self) -> Option<&Cipher> {
+		self.cipher.as_deref().filter(|c| c.encrypts_keys())
+	}
+}
+
 impl super::api::Transaction for Transaction {
 	/// Behaviour if unclosed
 	fn check_level(&mut self, check: Check) {
@@ -136,7 +272,10 @@ impl super::api::Transaction for Transaction {
 		// Mark the transaction as done.
 		self.done = true;
 		// Commit the transaction.
-		self.inner.commit().await?;
+		self.inner.commit().await.map_err(|e| match e {
+			surrealkv::Error::TransactionReadConflict => Error::TxConflict,
+			e => e.into(),
+		})?;
 		// Continue
 		Ok(())
 	}
@@ -152,7 +291,7 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxFinished);
 		}
 		// Check the key
-		let res = self.inner.get(&key.into())?.is_some();
+		let res = self.inner.get(&self.key(&key.into()))?.is_some();
 		// Return result
 		Ok(res)
 	}
@@ -169,13 +308,14 @@ impl super::api::Transaction for Transaction {
 		}
 
 		// Fetch the value from the database.
+		let key = key.into();
 		let res = match version {
-			Some(ts) => self.inner.get_at_ts(&key.into(), ts)?,
-			None => self.inner.get(&key.into())?,
+			Some(ts) => self.inner.get_at_ts(&self.key(&key), ts)?,
+			None => self.inner.get(&self.key(&key))?,
 		};
 
 		// Return result
-		Ok(res)
+		self.decrypt(&key, res)
 	}
 
 	/// Insert or update a key in the database
@@ -193,10 +333,14 @@ impl super::api::Transaction for Transaction {
 		if !self.write {
 			return Err(Error::TxReadonly);
 		}
+		// Encrypt the key and value
+		let key = key.into();
+		let val = self.encrypt(&key, val.into())?;
+		let key = self.key(&key);
 		// Set the key
 		match version {
-			Some(ts) => self.inner.set_at_ts(&key.into(), &val.into(), ts)?,
-			None => self.inner.set(&key.into(), &val.into())?,
+			Some(ts) => self.inner.set_at_ts(&key, &val, ts)?,
+			None => self.inner.set(&key, &val)?,
 		}
 		// Return result
 		Ok(())
@@ -219,7 +363,8 @@ impl super::api::Transaction for Transaction {
 		}
 		// Get the arguments
 		let key = key.into();
-		let val = val.into();
+		let val = self.encrypt(&key, val.into())?;
+		let key = self.key(&key);
 		// Set the key if empty
 		if let Some(ts) = version {
 			self.inner.set_at_ts(&key, &val, ts)?;
@@ -250,12 +395,15 @@ impl super::api::Transaction for Transaction {
 		}
 		// Get the arguments
 		let key = key.into();
-		let val = val.into();
+		let val = self.encrypt(&key, val.into())?;
 		let chk = chk.map(Into::into);
+		// Get the current value
+		let enc = self.key(&key);
+		let cur = self.decrypt(&key, self.inner.get(&enc)?)?;
 		// Set the key if valid
-		match (self.inner.get(&key)?, chk) {
-			(Some(v), Some(w)) if v == w => self.inner.set(&key, &val)?,
-			(None, None) => self.inner.set(&key, &val)?,
+		match (cur, chk) {
+			(Some(v), Some(w)) if v == w => self.inner.set(&enc, &val)?,
+			(None, None) => self.inner.set(&enc, &val)?,
 			_ => return Err(Error::TxConditionNotMet),
 		};
 		// Return result
@@ -277,7 +425,7 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxReadonly);
 		}
 		// Remove the key
-		self.inner.delete(&key.into())?;
+		self.inner.delete(&self.key(&key.into()))?;
 		// Return result
 		Ok(())
 	}
@@ -300,10 +448,13 @@ impl super::api::Transaction for Transaction {
 		// Get the arguments
 		let key = key.into();
 		let chk = chk.map(Into::into);
+		// Get the current value
+		let enc = self.key(&key);
+		let cur = self.decrypt(&key, self.inner.get(&enc)?)?;
 		// Delete the key if valid
-		match (self.inner.get(&key)?, chk) {
-			(Some(v), Some(w)) if v == w => self.inner.delete(&key)?,
-			(None, None) => self.inner.delete(&key)?,
+		match (cur, chk) {
+			(Some(v), Some(w)) if v == w => self.inner.delete(&enc)?,
+			(None, None) => self.inner.delete(&enc)?,
 			_ => return Err(Error::TxConditionNotMet),
 		};
 		// Return result
@@ -321,10 +472,17 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxFinished);
 		}
 		// Set the key range
-		let beg = rng.start.into();
-		let end = rng.end.into();
+		let rng: Range<Key> = rng.start.into()..rng.end.into();
+		// Scan every key with the prefix of the range, if keys are encrypted
+		if let Some(c) = self.key_cipher() {
+			let enc = c.encrypt_range(&rng);
+			let res = self.inner.scan(enc.start.as_slice()..enc.end.as_slice(), None)?;
+			let res = c.decrypt_range(&rng, res.into_iter().map(|kv| (kv.0, ())))?;
+			return Ok(res.into_iter().take(limit as usize).map(|(k, _)| k).collect());
+		}
 		// Retrieve the scan range
-		let res = self.inner.scan(beg.as_slice()..end.as_slice(), Some(limit as usize))?;
+		let res =
+			self.inner.scan(rng.start.as_slice()..rng.end.as_slice(), Some(limit as usize))?;
 		// Convert the keys and values
 		let res = res.into_iter().map(|kv| Key::from(kv.0)).collect();
 		// Return result
@@ -347,21 +505,61 @@ impl super::api::Transaction for Transaction {
 			return Err(Error::TxFinished);
 		}
 		// Set the key range
-		let beg = rng.start.into();
-		let end = rng.end.into();
-		let range = beg.as_slice()..end.as_slice();
-
+		let rng: Range<Key> = rng.start.into()..rng.end.into();
+		// Scan every key with the prefix of the range, if keys are encrypted
+		let (range, max) = match self.key_cipher() {
+			Some(c) => (c.encrypt_range(&rng), None),
+			None => (rng.clone(), Some(limit as usize)),
+		};
+		let range = range.start.as_slice()..range.end.as_slice();
 		// Retrieve the scan range
 		let res = match version {
-			Some(ts) => self.inner.scan_at_ts(range, ts, Some(limit as usize))?,
-			None => self
-				.inner
-				.scan(range, Some(limit as usize))?
-				.into_iter()
-				.map(|kv| (kv.0, kv.1))
-				.collect(),
+			Some(ts) => self.inner.scan_at_ts(range, ts, max)?,
+			None => self.inner.scan(range, max)?.into_iter().map(|kv| (kv.0, kv.1)).collect(),
+		};
+		// Decrypt the keys, and keep the keys in the range
+		let res = match self.key_cipher() {
+			Some(c) => {
+				let mut res = c.decrypt_range(&rng, res)?;
+				res.truncate(limit as usize);
+				res
+			}
+			None => res,
 		};
+		// Decrypt the values
+		res.into_iter()
+			.map(|(k, v)| {
+				let v = self.decrypt(&k, Some(v))?.unwrap_or_default();
+				Ok((k, v))
+			})
+			.collect()
+	}
 
+	/// Retrieves all the versions of a range of key-value pairs from the database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self), fields(rng = rng.sprint()))]
+	async fn scan_all_versions<K>(
+		&mut self,
+		rng: Range<K>,
+		limit: u32,
+	) -> Result<Vec<(Key, Val, u64, bool)>, Error>
+	where
+		K: Into<Key> + Sprintable + Debug,
+	{
+		// Check to see if transaction is closed
+		if self.done {
+			return Err(Error::TxFinished);
+		}
+		// Retrieve the keys in the scan range
+		let keys = self.keys(rng, limit).await?;
+		// Retrieve the history of every key, deletions are not versioned
+		let mut res = Vec::new();
+		for k in keys {
+			for (v, ts) in self.inner.get_history(&self.key(&k))? {
+				let v = self.decrypt(&k, Some(v))?.unwrap_or_default();
+				res.push((k.clone(), v, ts, false));
+			}
+		}
+		// Return result
 		Ok(res)
 	}
 }
@@ -0,0 +1,74 @@
+#[tokio::test]
+#[serial]
+async fn encryption() {
+	use crate::err::Error;
+	// Create a new encrypted datastore
+	let path = new_path();
+	let ds = Datastore::new_with_encryption_key(&path, Some("secret")).await.unwrap();
+	// Insert some keys, including one at the end of the keyspace
+	let mut tx = ds.transaction(Write, Optimistic).await.unwrap().inner();
+	tx.set("test", "some text", None).await.unwrap();
+	tx.set(vec![0xff, 0xff], "last", None).await.unwrap();
+	tx.commit().await.unwrap();
+	drop(ds);
+	// Open the datastore with the wrong key
+	let res = Datastore::new_with_encryption_key(&path, Some("other")).await;
+	assert!(matches!(res, Err(Error::EncryptionKeyMismatch)), "{:?}", res.err());
+	// Open the datastore without a key
+	let res = Datastore::new(&path).await;
+	assert!(matches!(res, Err(Error::EncryptionKeyMissing)), "{:?}", res.err());
+	// Re-encrypt the datastore with a new key
+	Datastore::rekey(&path, Some("secret"), false, Some("other"), false).await.unwrap();
+	// The previous key is no longer valid
+	let res = Datastore::new_with_encryption_key(&path, Some("secret")).await;
+	assert!(matches!(res, Err(Error::EncryptionKeyMismatch)), "{:?}", res.err());
+	// Read the keys with the new key
+	let ds = Datastore::new_with_encryption_key(&path, Some("other")).await.unwrap();
+	let mut tx = ds.transaction(Read, Optimistic).await.unwrap().inner();
+	let val = tx.get("test", None).await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"some text")));
+	let val = tx.get(vec![0xff, 0xff], None).await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"last")));
+	tx.cancel().await.unwrap();
+}
+
+#[tokio::test]
+#[serial]
+async fn encryption_keys() {
+	use crate::err::Error;
+	// Create a new datastore which encrypts its keys
+	let path = new_path();
+	let ds = Datastore::new_with_encryption(&path, Some("secret"), true).await.unwrap();
+	let mut tx = ds.transaction(Write, Optimistic).await.unwrap().inner();
+	for k in ["b", "a2", "c", "a1", "a3"] {
+		tx.set(k, k, None).await.unwrap();
+	}
+	tx.commit().await.unwrap();
+	// Range scans return the keys in the range, in order
+	let mut tx = ds.transaction(Read, Optimistic).await.unwrap().inner();
+	let val = tx.scan("a".."b", 2, None).await.unwrap();
+	assert_eq!(val, vec![(b"a1".to_vec(), b"a1".to_vec()), (b"a2".to_vec(), b"a2".to_vec())]);
+	let val = tx.keys("a2".."c", u32::MAX).await.unwrap();
+	assert_eq!(val, vec![b"a2".to_vec(), b"a3".to_vec(), b"b".to_vec()]);
+	let val = tx.keysr("a".."z", 2).await.unwrap();
+	assert_eq!(val, vec![b"c".to_vec(), b"b".to_vec()]);
+	let val = tx.get("a3", None).await.unwrap();
+	assert!(matches!(val.as_deref(), Some(b"a3")));
+	tx.cancel().await.unwrap();
+	drop(ds);
+	// Open the datastore without key encryption
+	let res = Datastore::new_with_encryption_key(&path, Some("secret")).await;
+	assert!(matches!(res, Err(Error::EncryptionKeysMismatch)), "{:?}", res.err());
+	// Re-encrypt the datastore without key encryption
+	Datastore::rekey(&path, Some("secret"), true, Some("other"), false).await.unwrap();
+	let res = Datastore::new_with_encryption(&path, Some("other"), true).await;
+	assert!(matches!(res, Err(Error::EncryptionKeysMismatch)), "{:?}", res.err());
+	// Read the keys with the new key
+	let ds = Datastore::new_with_encryption_key(&path, Some("other")).await.unwrap();
+	let mut tx = ds.transaction(Read, Optimistic).await.unwrap().inner();
+	let val = tx.scan("a".."z", u32::MAX, None).await.unwrap();
+	let keys: Vec<_> = val.iter().map(|(k, _)| k.as_slice()).collect();
+	assert_eq!(keys, vec![&b"a1"[..], b"a2", b"a3", b"b", b"c"]);
+	assert!(val.iter().all(|(k, v)| k == v));
+	tx.cancel().await.unwrap();
+}
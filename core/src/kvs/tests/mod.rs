@@ -51,6 +51,7 @@ mod mem {
 	include!("snapshot.rs");
 	include!("multireader.rs");
 	include!("timestamp_to_versionstamp.rs");
+	include!("nextval.rs");
 }
 
 #[cfg(feature = "kv-rocksdb")]
@@ -58,10 +59,14 @@ mod rocksdb {
 
 	use temp_dir::TempDir;
 
-	async fn new_ds(id: Uuid, clock: ClockType) -> (Datastore, Kvs) {
+	fn new_path() -> String {
 		// Setup the temporary data storage path
 		let path = TempDir::new().unwrap().path().to_string_lossy().to_string();
-		let path = format!("rocksdb:{path}");
+		format!("rocksdb:{path}")
+	}
+
+	async fn new_ds(id: Uuid, clock: ClockType) -> (Datastore, Kvs) {
+		let path = new_path();
 		// Setup the RocksDB datastore
 		let ds = Datastore::new_with_clock(&path, Some(clock)).await.unwrap().with_node_id(id);
 		// Return the datastore
@@ -75,12 +80,14 @@ mod rocksdb {
 	}
 
 	include!("helper.rs");
+	include!("encryption.rs");
 	include!("raw.rs");
 	include!("snapshot.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_conflict.rs");
 	include!("timestamp_to_versionstamp.rs");
+	include!("nextval.rs");
 }
 
 #[cfg(feature = "kv-surrealkv")]
@@ -88,10 +95,14 @@ mod surrealkv {
 
 	use temp_dir::TempDir;
 
-	async fn new_ds(id: Uuid, clock: ClockType) -> (Datastore, Kvs) {
+	fn new_path() -> String {
 		// Setup the temporary data storage path
 		let path = TempDir::new().unwrap().path().to_string_lossy().to_string();
-		let path = format!("surrealkv:{path}");
+		format!("surrealkv:{path}")
+	}
+
+	async fn new_ds(id: Uuid, clock: ClockType) -> (Datastore, Kvs) {
+		let path = new_path();
 		// Setup the SurrealKV datastore
 		let ds = Datastore::new_with_clock(&path, Some(clock)).await.unwrap().with_node_id(id);
 		// Return the datastore
@@ -107,6 +118,7 @@ mod surrealkv {
 
 	include!("raw.rs");
 	include!("helper.rs");
+	include!("encryption.rs");
 	include!("snapshot.rs");
 	include!("multireader.rs");
 	include!("multiwriter_different_keys.rs");
@@ -143,6 +155,7 @@ mod tikv {
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_conflict.rs");
 	include!("timestamp_to_versionstamp.rs");
+	include!("nextval.rs");
 }
 
 #[cfg(feature = "kv-fdb")]
@@ -174,4 +187,5 @@ mod fdb {
 	include!("multiwriter_different_keys.rs");
 	include!("multiwriter_same_keys_allow.rs");
 	include!("timestamp_to_versionstamp.rs");
+	include!("nextval.rs");
 }
@@ -0,0 +1,32 @@
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[serial]
+async fn nextval_concurrent_nodes() {
+	// Create a new datastore
+	let node_id = Uuid::parse_str("4f6d7c3e-2a51-4b8e-9d0f-6a1c2e3b4d5f").unwrap();
+	let clock = Arc::new(SizedClock::Fake(FakeClock::new(Timestamp::default())));
+	let (ds, _) = new_ds(node_id, clock).await;
+	// Define a sequence with small batches
+	let ses = crate::dbs::Session::owner().with_ns("test").with_db("test");
+	ds.execute("DEFINE SEQUENCE seq BATCH 10", &ses, None).await.unwrap();
+	let tx = ds.transaction(Read, Optimistic).await.unwrap();
+	let sq = tx.get_db_sequence("test", "test", "seq").await.unwrap();
+	tx.cancel().await.unwrap();
+	// Each set of batches takes values like a separate node
+	let nodes: Vec<_> = (0..4)
+		.map(|_| crate::kvs::sequences::Sequences::new(ds.transaction_factory.clone()))
+		.collect();
+	let tasks: Vec<_> = (0..400)
+		.map(|i| {
+			let (node, sq) = (nodes[i % nodes.len()].clone(), sq.clone());
+			tokio::spawn(async move { node.next_val("test", "test", &sq).await })
+		})
+		.collect();
+	// Every call succeeds
+	let mut vals = Vec::with_capacity(tasks.len());
+	for task in tasks {
+		vals.push(task.await.unwrap().unwrap());
+	}
+	// Every value is handed out exactly once
+	vals.sort_unstable();
+	assert_eq!(vals, (0..400).collect::<Vec<i64>>());
+}
@@ -160,7 +160,11 @@ impl super::api::Transaction for Transaction {
 			if let Err(inner_err) = self.inner.rollback().await {
 				error!("Transaction commit failed {} and rollback failed: {}", err, inner_err);
 			}
-			return Err(err.into());
+			return Err(match err {
+				// Another transaction wrote to a key which this transaction wrote to
+				tikv::Error::KeyError(ke) if ke.conflict.is_some() => Error::TxConflict,
+				err => err.into(),
+			});
 		}
 		// Continue
 		Ok(())
@@ -144,7 +144,7 @@ impl Transactor {
 			feature = "kv-fdb",
 			feature = "kv-surrealkv",
 		)),
-		allow(unused_variables)
+		allow(unused_variables, unreachable_code)
 	)]
 	// --------------------------------------------------
 	// Integral methods
@@ -187,7 +187,9 @@ impl Transactor {
 	/// This attempts to commit all changes made within the transaction.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tr", skip_all)]
 	pub async fn commit(&mut self) -> Result<(), Error> {
-		expand_inner!(&mut self.inner, v => { v.commit().await })
+		let res = expand_inner!(&mut self.inner, v => { v.commit().await });
+		super::metrics::record_commit(&res);
+		res
 	}
 
 	/// Check if a key exists in the datastore.
@@ -337,6 +339,32 @@ impl Transactor {
 		expand_inner!(&mut self.inner, v => { v.keys(beg..end, limit).await })
 	}
 
+	/// Retrieve a specific range of keys from the datastore, in reverse order.
+	///
+	/// This function fetches the last keys of the range, starting with the greatest key.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tr", skip_all)]
+	pub async fn keysr<K>(&mut self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		expand_inner!(&mut self.inner, v => { v.keysr(beg..end, limit).await })
+	}
+
+	/// Count the keys in a specific range of the datastore.
+	///
+	/// This function counts the keys without fetching their values, in grouped batches.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tr", skip_all)]
+	pub async fn count<K>(&mut self, rng: Range<K>) -> Result<usize, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		expand_inner!(&mut self.inner, v => { v.count(beg..end).await })
+	}
+
 	/// Retrieve a specific range of keys from the datastore.
 	///
 	/// This function fetches the full range of key-value pairs, in a single request to the underlying datastore.
@@ -355,6 +383,23 @@ impl Transactor {
 		expand_inner!(&mut self.inner, v => { v.scan(beg..end, limit, version).await })
 	}
 
+	/// Retrieve all the versions of a specific range of keys from the datastore.
+	///
+	/// This function fetches every stored version of the key-value pairs, in a single request to the underlying datastore.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tr", skip_all)]
+	pub async fn scan_all_versions<K>(
+		&mut self,
+		rng: Range<K>,
+		limit: u32,
+	) -> Result<Vec<(Key, Val, u64, bool)>, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		let beg: Key = rng.start.into();
+		let end: Key = rng.end.into();
+		expand_inner!(&mut self.inner, v => { v.scan_all_versions(beg..end, limit).await })
+	}
+
 	/// Retrieve a batched scan over a specific range of keys in the datastore.
 	///
 	/// This function fetches keys or key-value pairs, in batches, with multiple requests to the underlying datastore.
@@ -9,6 +9,7 @@ use crate::dbs::node::Node;
 use crate::err::Error;
 use crate::kvs::cache::Entry;
 use crate::kvs::cache::EntryWeighter;
+use crate::kvs::metrics;
 use crate::kvs::scanner::Scanner;
 use crate::kvs::Transactor;
 use crate::sql::statements::AccessGrant;
@@ -22,6 +23,8 @@ use crate::sql::statements::DefineIndexStatement;
 use crate::sql::statements::DefineModelStatement;
 use crate::sql::statements::DefineNamespaceStatement;
 use crate::sql::statements::DefineParamStatement;
+use crate::sql::statements::DefineRoleStatement;
+use crate::sql::statements::DefineSequenceStatement;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::statements::DefineUserStatement;
 use crate::sql::statements::LiveStatement;
@@ -230,6 +233,28 @@ impl Transaction {
 		self.lock().await.keys(rng, limit).await
 	}
 
+	/// Retrieve a specific range of keys from the datastore, in reverse order.
+	///
+	/// This function fetches the last keys of the range, starting with the greatest key.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip_all)]
+	pub async fn keysr<K>(&self, rng: Range<K>, limit: u32) -> Result<Vec<Key>, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		self.lock().await.keysr(rng, limit).await
+	}
+
+	/// Count the keys in a specific range of the datastore.
+	///
+	/// This function counts the keys without fetching their values, in grouped batches.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip_all)]
+	pub async fn count<K>(&self, rng: Range<K>) -> Result<usize, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		self.lock().await.count(rng).await
+	}
+
 	/// Retrieve a specific range of keys from the datastore.
 	///
 	/// This function fetches the full range of key-value pairs, in a single request to the underlying datastore.
@@ -246,6 +271,21 @@ impl Transaction {
 		self.lock().await.scan(rng, limit, version).await
 	}
 
+	/// Retrieve all the versions of a specific range of keys from the datastore.
+	///
+	/// This function fetches every stored version of the key-value pairs, in a single request to the underlying datastore.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip_all)]
+	pub async fn scan_all_versions<K>(
+		&self,
+		rng: Range<K>,
+		limit: u32,
+	) -> Result<Vec<(Key, Val, u64, bool)>, Error>
+	where
+		K: Into<Key> + Debug,
+	{
+		self.lock().await.scan_all_versions(rng, limit).await
+	}
+
 	/// Retrieve a batched scan over a specific range of keys in the datastore.
 	///
 	/// This function fetches the key-value pairs in batches, with multiple requests to the underlying datastore.
@@ -310,7 +350,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_nodes(&self) -> Result<Arc<[Node]>, Error> {
 		let key = crate::key::root::nd::prefix();
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -329,7 +369,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_root_users(&self) -> Result<Arc<[DefineUserStatement]>, Error> {
 		let key = crate::key::root::us::prefix();
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -344,11 +384,30 @@ impl Transaction {
 		.into_rus())
 	}
 
+	/// Retrieve all ROOT level roles in a datastore.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_root_roles(&self) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let key = crate::key::root::rl::prefix();
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let end = crate::key::root::rl::suffix();
+				let val = self.getr(key..end).await?;
+				let val = val.convert().into();
+				let val = Entry::Rrs(Arc::clone(&val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_rrs())
+	}
+
 	/// Retrieve all ROOT level accesses in a datastore.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_root_accesses(&self) -> Result<Arc<[DefineAccessStatement]>, Error> {
 		let key = crate::key::root::ac::prefix();
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -367,7 +426,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_root_access_grants(&self, ra: &str) -> Result<Arc<[AccessGrant]>, Error> {
 		let key = crate::key::root::access::gr::prefix(ra);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -386,7 +445,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_ns(&self) -> Result<Arc<[DefineNamespaceStatement]>, Error> {
 		let key = crate::key::root::ns::prefix();
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -405,7 +464,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_ns_users(&self, ns: &str) -> Result<Arc<[DefineUserStatement]>, Error> {
 		let key = crate::key::namespace::us::prefix(ns);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -420,11 +479,30 @@ impl Transaction {
 		.into_nus())
 	}
 
+	/// Retrieve all namespace role definitions for a specific namespace.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_ns_roles(&self, ns: &str) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let key = crate::key::namespace::rl::prefix(ns);
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let end = crate::key::namespace::rl::suffix(ns);
+				let val = self.getr(key..end).await?;
+				let val = val.convert().into();
+				let val = Entry::Nrs(Arc::clone(&val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_nrs())
+	}
+
 	/// Retrieve all namespace access definitions for a specific namespace.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_ns_accesses(&self, ns: &str) -> Result<Arc<[DefineAccessStatement]>, Error> {
 		let key = crate::key::namespace::ac::prefix(ns);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -447,7 +525,7 @@ impl Transaction {
 		na: &str,
 	) -> Result<Arc<[AccessGrant]>, Error> {
 		let key = crate::key::namespace::access::gr::prefix(ns, na);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -466,7 +544,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_db(&self, ns: &str) -> Result<Arc<[DefineDatabaseStatement]>, Error> {
 		let key = crate::key::namespace::db::prefix(ns);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -489,7 +567,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineUserStatement]>, Error> {
 		let key = crate::key::database::us::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -504,6 +582,29 @@ impl Transaction {
 		.into_dus())
 	}
 
+	/// Retrieve all database role definitions for a specific database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_db_roles(
+		&self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineRoleStatement]>, Error> {
+		let key = crate::key::database::rl::prefix(ns, db);
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let end = crate::key::database::rl::suffix(ns, db);
+				let val = self.getr(key..end).await?;
+				let val = val.convert().into();
+				let val = Entry::Drs(Arc::clone(&val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_drs())
+	}
+
 	/// Retrieve all database access definitions for a specific database.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_db_accesses(
@@ -512,7 +613,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineAccessStatement]>, Error> {
 		let key = crate::key::database::ac::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -536,7 +637,7 @@ impl Transaction {
 		da: &str,
 	) -> Result<Arc<[AccessGrant]>, Error> {
 		let key = crate::key::database::access::gr::prefix(ns, db, da);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -559,7 +660,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineAnalyzerStatement]>, Error> {
 		let key = crate::key::database::az::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -582,7 +683,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineFunctionStatement]>, Error> {
 		let key = crate::key::database::fc::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -605,7 +706,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineParamStatement]>, Error> {
 		let key = crate::key::database::pa::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -620,6 +721,29 @@ impl Transaction {
 		.into_pas())
 	}
 
+	/// Retrieve all sequence definitions for a specific database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn all_db_sequences(
+		&self,
+		ns: &str,
+		db: &str,
+	) -> Result<Arc<[DefineSequenceStatement]>, Error> {
+		let key = crate::key::database::sq::prefix(ns, db);
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let end = crate::key::database::sq::suffix(ns, db);
+				let val = self.getr(key..end).await?;
+				let val = val.convert().into();
+				let val = Entry::Sqs(Arc::clone(&val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_sqs())
+	}
+
 	/// Retrieve all model definitions for a specific database.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_db_models(
@@ -628,7 +752,7 @@ impl Transaction {
 		db: &str,
 	) -> Result<Arc<[DefineModelStatement]>, Error> {
 		let key = crate::key::database::ml::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -647,7 +771,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn all_tb(&self, ns: &str, db: &str) -> Result<Arc<[DefineTableStatement]>, Error> {
 		let key = crate::key::database::tb::prefix(ns, db);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -671,7 +795,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineEventStatement]>, Error> {
 		let key = crate::key::table::ev::prefix(ns, db, tb);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -695,7 +819,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineFieldStatement]>, Error> {
 		let key = crate::key::table::fd::prefix(ns, db, tb);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -719,7 +843,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineIndexStatement]>, Error> {
 		let key = crate::key::table::ix::prefix(ns, db, tb);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -743,7 +867,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[DefineTableStatement]>, Error> {
 		let key = crate::key::table::ft::prefix(ns, db, tb);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -767,7 +891,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<[LiveStatement]>, Error> {
 		let key = crate::key::table::lq::prefix(ns, db, tb);
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -786,7 +910,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_node(&self, id: Uuid) -> Result<Arc<Node>, Error> {
 		let key = crate::key::root::nd::new(id).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -806,7 +930,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_root_user(&self, us: &str) -> Result<Arc<DefineUserStatement>, Error> {
 		let key = crate::key::root::us::new(us).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -822,11 +946,31 @@ impl Transaction {
 		.into_type())
 	}
 
+	/// Retrieve a specific root role definition.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_root_role(&self, rl: &str) -> Result<Arc<DefineRoleStatement>, Error> {
+		let key = crate::key::root::rl::new(rl).encode()?;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleRootNotFound {
+					value: rl.to_owned(),
+				})?;
+				let val: DefineRoleStatement = val.into();
+				let val = Entry::Any(Arc::new(val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_type())
+	}
+
 	/// Retrieve a specific root access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_root_access(&self, ra: &str) -> Result<Arc<DefineAccessStatement>, Error> {
 		let key = crate::key::root::ac::new(ra).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -850,7 +994,7 @@ impl Transaction {
 		gr: &str,
 	) -> Result<Arc<AccessGrant>, Error> {
 		let key = crate::key::root::access::gr::new(ac, gr).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -872,7 +1016,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_ns(&self, ns: &str) -> Result<Arc<DefineNamespaceStatement>, Error> {
 		let key = crate::key::root::ns::new(ns).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -892,7 +1036,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_ns_user(&self, ns: &str, us: &str) -> Result<Arc<DefineUserStatement>, Error> {
 		let key = crate::key::namespace::us::new(ns, us).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -909,6 +1053,27 @@ impl Transaction {
 		.into_type())
 	}
 
+	/// Retrieve a specific namespace role definition.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_ns_role(&self, ns: &str, rl: &str) -> Result<Arc<DefineRoleStatement>, Error> {
+		let key = crate::key::namespace::rl::new(ns, rl).encode()?;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleNsNotFound {
+					value: rl.to_owned(),
+					ns: ns.to_owned(),
+				})?;
+				let val: DefineRoleStatement = val.into();
+				let val = Entry::Any(Arc::new(val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_type())
+	}
+
 	/// Retrieve a specific namespace access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_ns_access(
@@ -917,7 +1082,7 @@ impl Transaction {
 		na: &str,
 	) -> Result<Arc<DefineAccessStatement>, Error> {
 		let key = crate::key::namespace::ac::new(ns, na).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -943,7 +1108,7 @@ impl Transaction {
 		gr: &str,
 	) -> Result<Arc<AccessGrant>, Error> {
 		let key = crate::key::namespace::access::gr::new(ns, ac, gr).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -966,7 +1131,7 @@ impl Transaction {
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_db(&self, ns: &str, db: &str) -> Result<Arc<DefineDatabaseStatement>, Error> {
 		let key = crate::key::namespace::db::new(ns, db).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -991,7 +1156,7 @@ impl Transaction {
 		us: &str,
 	) -> Result<Arc<DefineUserStatement>, Error> {
 		let key = crate::key::database::us::new(ns, db, us).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1009,6 +1174,33 @@ impl Transaction {
 		.into_type())
 	}
 
+	/// Retrieve a specific role definition from a database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_db_role(
+		&self,
+		ns: &str,
+		db: &str,
+		rl: &str,
+	) -> Result<Arc<DefineRoleStatement>, Error> {
+		let key = crate::key::database::rl::new(ns, db, rl).encode()?;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let val = self.get(key, None).await?.ok_or_else(|| Error::RoleDbNotFound {
+					value: rl.to_owned(),
+					ns: ns.to_owned(),
+					db: db.to_owned(),
+				})?;
+				let val: DefineRoleStatement = val.into();
+				let val = Entry::Any(Arc::new(val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_type())
+	}
+
 	/// Retrieve a specific database access definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_db_access(
@@ -1018,7 +1210,7 @@ impl Transaction {
 		da: &str,
 	) -> Result<Arc<DefineAccessStatement>, Error> {
 		let key = crate::key::database::ac::new(ns, db, da).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1046,7 +1238,7 @@ impl Transaction {
 		gr: &str,
 	) -> Result<Arc<AccessGrant>, Error> {
 		let key = crate::key::database::access::gr::new(ns, db, ac, gr).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1076,7 +1268,7 @@ impl Transaction {
 		vn: &str,
 	) -> Result<Arc<DefineModelStatement>, Error> {
 		let key = crate::key::database::ml::new(ns, db, ml, vn).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1101,7 +1293,7 @@ impl Transaction {
 		az: &str,
 	) -> Result<Arc<DefineAnalyzerStatement>, Error> {
 		let key = crate::key::database::az::new(ns, db, az).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1126,7 +1318,7 @@ impl Transaction {
 		fc: &str,
 	) -> Result<Arc<DefineFunctionStatement>, Error> {
 		let key = crate::key::database::fc::new(ns, db, fc).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1151,7 +1343,7 @@ impl Transaction {
 		pa: &str,
 	) -> Result<Arc<DefineParamStatement>, Error> {
 		let key = crate::key::database::pa::new(ns, db, pa).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1167,6 +1359,31 @@ impl Transaction {
 		.into_type())
 	}
 
+	/// Retrieve a specific sequence definition from a database.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
+	pub async fn get_db_sequence(
+		&self,
+		ns: &str,
+		db: &str,
+		sq: &str,
+	) -> Result<Arc<DefineSequenceStatement>, Error> {
+		let key = crate::key::database::sq::new(ns, db, sq).encode()?;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
+		Ok(match res {
+			Ok(val) => val,
+			Err(cache) => {
+				let val = self.get(key, None).await?.ok_or_else(|| Error::SqNotFound {
+					value: sq.to_owned(),
+				})?;
+				let val: DefineSequenceStatement = val.into();
+				let val = Entry::Any(Arc::new(val));
+				let _ = cache.insert(val.clone());
+				val
+			}
+		}
+		.into_type())
+	}
+
 	/// Retrieve a specific table definition.
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::tx", skip(self))]
 	pub async fn get_tb(
@@ -1176,7 +1393,7 @@ impl Transaction {
 		tb: &str,
 	) -> Result<Arc<DefineTableStatement>, Error> {
 		let key = crate::key::database::tb::new(ns, db, tb).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1202,7 +1419,7 @@ impl Transaction {
 		ev: &str,
 	) -> Result<Arc<DefineEventStatement>, Error> {
 		let key = crate::key::table::ev::new(ns, db, tb, ev).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1228,7 +1445,7 @@ impl Transaction {
 		fd: &str,
 	) -> Result<Arc<DefineFieldStatement>, Error> {
 		let key = crate::key::table::fd::new(ns, db, tb, fd).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1254,7 +1471,7 @@ impl Transaction {
 		ix: &str,
 	) -> Result<Arc<DefineIndexStatement>, Error> {
 		let key = crate::key::table::ix::new(ns, db, tb, ix).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		Ok(match res {
 			Ok(val) => val,
 			Err(cache) => {
@@ -1280,7 +1497,7 @@ impl Transaction {
 		id: &Id,
 	) -> Result<Arc<Value>, Error> {
 		let key = crate::key::thing::new(ns, db, tb, id).encode()?;
-		let res = self.cache.get_value_or_guard_async(&key).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&key).await);
 		match res {
 			// The entry is in the cache
 			Ok(val) => Ok(val.into_val()),
@@ -1441,7 +1658,7 @@ impl Transaction {
 	) -> Result<Arc<DefineNamespaceStatement>, Error> {
 		let key = crate::key::root::ns::new(ns);
 		let enc = crate::key::root::ns::new(ns).encode()?;
-		let res = self.cache.get_value_or_guard_async(&enc).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&enc).await);
 		Ok(match res {
 			// The entry is in the cache
 			Ok(val) => val,
@@ -1494,7 +1711,7 @@ impl Transaction {
 	) -> Result<Arc<DefineDatabaseStatement>, Error> {
 		let key = crate::key::namespace::db::new(ns, db);
 		let enc = crate::key::namespace::db::new(ns, db).encode()?;
-		let res = self.cache.get_value_or_guard_async(&enc).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&enc).await);
 		Ok(match res {
 			// The entry is in the cache
 			Ok(val) => val,
@@ -1562,7 +1779,7 @@ impl Transaction {
 	) -> Result<Arc<DefineTableStatement>, Error> {
 		let key = crate::key::database::tb::new(ns, db, tb);
 		let enc = crate::key::database::tb::new(ns, db, tb).encode()?;
-		let res = self.cache.get_value_or_guard_async(&enc).await;
+		let res = metrics::record_cache(self.cache.get_value_or_guard_async(&enc).await);
 		Ok(match res {
 			// The entry is in the cache
 			Ok(val) => val,
@@ -6,6 +6,7 @@ pub enum Method {
 	Use,
 	Signup,
 	Signin,
+	Refresh,
 	Invalidate,
 	Authenticate,
 	Kill,
@@ -25,6 +26,9 @@ pub enum Method {
 	Relate,
 	Run,
 	GraphQL,
+	Begin,
+	Commit,
+	Cancel,
 }
 
 impl Method {
@@ -38,6 +42,7 @@ impl Method {
 			"use" => Self::Use,
 			"signup" => Self::Signup,
 			"signin" => Self::Signin,
+			"refresh" => Self::Refresh,
 			"invalidate" => Self::Invalidate,
 			"authenticate" => Self::Authenticate,
 			"kill" => Self::Kill,
@@ -57,6 +62,9 @@ impl Method {
 			"relate" => Self::Relate,
 			"run" => Self::Run,
 			"graphql" => Self::GraphQL,
+			"begin" => Self::Begin,
+			"commit" => Self::Commit,
+			"cancel" => Self::Cancel,
 			_ => Self::Unknown,
 		}
 	}
@@ -71,6 +79,7 @@ impl Method {
 			Self::Use => "use",
 			Self::Signup => "signup",
 			Self::Signin => "signin",
+			Self::Refresh => "refresh",
 			Self::Invalidate => "invalidate",
 			Self::Authenticate => "authenticate",
 			Self::Kill => "kill",
@@ -90,6 +99,9 @@ impl Method {
 			Self::Relate => "relate",
 			Self::Run => "run",
 			Self::GraphQL => "graphql",
+			Self::Begin => "begin",
+			Self::Commit => "commit",
+			Self::Cancel => "cancel",
 		}
 	}
 }
@@ -108,13 +120,23 @@ impl Method {
 		matches!(
 			self,
 			Method::Ping
-				| Method::Info | Method::Select
-				| Method::Insert | Method::Create
-				| Method::Update | Method::Upsert
-				| Method::Merge | Method::Patch
-				| Method::Delete | Method::Version
-				| Method::Query | Method::Relate
-				| Method::Run | Method::GraphQL
+				| Method::Info
+				| Method::Select
+				| Method::Insert
+				| Method::Create
+				| Method::Update
+				| Method::Upsert
+				| Method::Merge
+				| Method::Patch
+				| Method::Delete
+				| Method::Version
+				| Method::Query
+				| Method::Relate
+				| Method::Run
+				| Method::GraphQL
+				| Method::Begin
+				| Method::Commit
+				| Method::Cancel
 				| Method::Unknown
 		)
 	}
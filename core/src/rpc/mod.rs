@@ -6,8 +6,10 @@ pub mod request;
 mod response;
 pub mod rpc_context;
 mod rpc_error;
+pub mod transactions;
 
 pub use basic_context::BasicRpcContext;
 pub use response::Data;
 pub use rpc_context::RpcContext;
 pub use rpc_error::RpcError;
+pub use transactions::Transactions;
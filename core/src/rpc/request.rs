@@ -4,16 +4,19 @@ use crate::rpc::RpcError;
 use crate::sql::Part;
 use crate::sql::{Array, Value};
 use once_cell::sync::Lazy;
+use uuid::Uuid;
 
 pub static ID: Lazy<[Part; 1]> = Lazy::new(|| [Part::from("id")]);
 pub static METHOD: Lazy<[Part; 1]> = Lazy::new(|| [Part::from("method")]);
 pub static PARAMS: Lazy<[Part; 1]> = Lazy::new(|| [Part::from("params")]);
+pub static TXN: Lazy<[Part; 1]> = Lazy::new(|| [Part::from("txn")]);
 
 #[derive(Debug)]
 pub struct Request {
 	pub id: Option<Value>,
 	pub method: String,
 	pub params: Array,
+	pub txn: Option<Uuid>,
 }
 
 impl TryFrom<Cbor> for Request {
@@ -53,11 +56,22 @@ impl TryFrom<Value> for Request {
 			Value::Array(v) => v,
 			_ => Array::new(),
 		};
+		// Fetch the 'txn' argument
+		let txn = match val.pick(&*TXN) {
+			Value::None | Value::Null => None,
+			Value::Uuid(v) => Some(v.0),
+			Value::Strand(v) => match Uuid::try_parse(v.as_str()) {
+				Ok(v) => Some(v),
+				Err(_) => return Err(RpcError::InvalidRequest),
+			},
+			_ => return Err(RpcError::InvalidRequest),
+		};
 		// Return the parsed request
 		Ok(Request {
 			id,
 			method,
 			params,
+			txn,
 		})
 	}
 }
@@ -10,10 +10,10 @@ use crate::{
 	dbs::{QueryType, Response, Session},
 	kvs::Datastore,
 	rpc::args::Take,
-	sql::{Array, Function, Model, Statement, Strand, Value},
+	sql::{Array, Function, Model, Query, Statement, Strand, Value},
 };
 
-use super::{method::Method, response::Data, rpc_error::RpcError};
+use super::{method::Method, response::Data, rpc_error::RpcError, transactions::Transactions};
 
 #[allow(async_fn_in_trait)]
 pub trait RpcContext {
@@ -40,13 +40,24 @@ pub trait RpcContext {
 		unimplemented!("graphql_schema_cache must be implemented if GQL_SUPPORT = true")
 	}
 
-	async fn execute(&mut self, method: Method, params: Array) -> Result<Data, RpcError> {
+	const TXN_SUPPORT: bool = false;
+	fn transactions(&self) -> &Transactions {
+		unimplemented!("transactions must be implemented if TXN_SUPPORT = true")
+	}
+
+	async fn execute(
+		&mut self,
+		method: Method,
+		params: Array,
+		txn: Option<Uuid>,
+	) -> Result<Data, RpcError> {
 		match method {
 			Method::Ping => Ok(Value::None.into()),
-			Method::Info => self.info().await.map(Into::into).map_err(Into::into),
+			Method::Info => self.info(txn).await.map(Into::into).map_err(Into::into),
 			Method::Use => self.yuse(params).await.map(Into::into).map_err(Into::into),
 			Method::Signup => self.signup(params).await.map(Into::into).map_err(Into::into),
 			Method::Signin => self.signin(params).await.map(Into::into).map_err(Into::into),
+			Method::Refresh => self.refresh(params).await.map(Into::into).map_err(Into::into),
 			Method::Invalidate => self.invalidate().await.map(Into::into).map_err(Into::into),
 			Method::Authenticate => {
 				self.authenticate(params).await.map(Into::into).map_err(Into::into)
@@ -55,40 +66,51 @@ pub trait RpcContext {
 			Method::Live => self.live(params).await.map(Into::into).map_err(Into::into),
 			Method::Set => self.set(params).await.map(Into::into).map_err(Into::into),
 			Method::Unset => self.unset(params).await.map(Into::into).map_err(Into::into),
-			Method::Select => self.select(params).await.map(Into::into).map_err(Into::into),
-			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
-			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
-			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
-			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
-			Method::Merge => self.merge(params).await.map(Into::into).map_err(Into::into),
-			Method::Patch => self.patch(params).await.map(Into::into).map_err(Into::into),
-			Method::Delete => self.delete(params).await.map(Into::into).map_err(Into::into),
+			Method::Select => self.select(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Insert => self.insert(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Create => self.create(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Upsert => self.upsert(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Update => self.update(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Merge => self.merge(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Patch => self.patch(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Delete => self.delete(params, txn).await.map(Into::into).map_err(Into::into),
 			Method::Version => self.version(params).await.map(Into::into).map_err(Into::into),
-			Method::Query => self.query(params).await.map(Into::into).map_err(Into::into),
-			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
-			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
+			Method::Query => self.query(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Relate => self.relate(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Run => self.run(params, txn).await.map(Into::into).map_err(Into::into),
 			Method::GraphQL => self.graphql(params).await.map(Into::into).map_err(Into::into),
+			Method::Begin => self.begin().await.map(Into::into).map_err(Into::into),
+			Method::Commit => self.commit(params).await.map(Into::into).map_err(Into::into),
+			Method::Cancel => self.cancel(params).await.map(Into::into).map_err(Into::into),
 			Method::Unknown => Err(RpcError::MethodNotFound),
 		}
 	}
 
-	async fn execute_immut(&self, method: Method, params: Array) -> Result<Data, RpcError> {
+	async fn execute_immut(
+		&self,
+		method: Method,
+		params: Array,
+		txn: Option<Uuid>,
+	) -> Result<Data, RpcError> {
 		match method {
 			Method::Ping => Ok(Value::None.into()),
-			Method::Info => self.info().await.map(Into::into).map_err(Into::into),
-			Method::Select => self.select(params).await.map(Into::into).map_err(Into::into),
-			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
-			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
-			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
-			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
-			Method::Merge => self.merge(params).await.map(Into::into).map_err(Into::into),
-			Method::Patch => self.patch(params).await.map(Into::into).map_err(Into::into),
-			Method::Delete => self.delete(params).await.map(Into::into).map_err(Into::into),
+			Method::Info => self.info(txn).await.map(Into::into).map_err(Into::into),
+			Method::Select => self.select(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Insert => self.insert(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Create => self.create(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Upsert => self.upsert(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Update => self.update(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Merge => self.merge(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Patch => self.patch(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Delete => self.delete(params, txn).await.map(Into::into).map_err(Into::into),
 			Method::Version => self.version(params).await.map(Into::into).map_err(Into::into),
-			Method::Query => self.query(params).await.map(Into::into).map_err(Into::into),
-			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
-			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
+			Method::Query => self.query(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Relate => self.relate(params, txn).await.map(Into::into).map_err(Into::into),
+			Method::Run => self.run(params, txn).await.map(Into::into).map_err(Into::into),
 			Method::GraphQL => self.graphql(params).await.map(Into::into).map_err(Into::into),
+			Method::Begin => self.begin().await.map(Into::into).map_err(Into::into),
+			Method::Commit => self.commit(params).await.map(Into::into).map_err(Into::into),
+			Method::Cancel => self.cancel(params).await.map(Into::into).map_err(Into::into),
 			Method::Unknown => Err(RpcError::MethodNotFound),
 			_ => Err(RpcError::MethodNotFound),
 		}
@@ -135,7 +157,7 @@ pub trait RpcContext {
 		let out: Result<Value, RpcError> =
 			crate::iam::signup::signup(self.kvs(), &mut tmp_session, v)
 				.await
-				.map(Into::into)
+				.map(|v| v.map(Value::from).into())
 				.map_err(Into::into);
 
 		*self.session_mut() = tmp_session;
@@ -156,6 +178,20 @@ pub trait RpcContext {
 		out.map(Into::into)
 	}
 
+	async fn refresh(&mut self, params: Array) -> Result<Data, RpcError> {
+		let Ok(Value::Object(v)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let mut tmp_session = mem::take(self.session_mut());
+		let out: Result<Value, RpcError> =
+			crate::iam::refresh::refresh(self.kvs(), &mut tmp_session, v)
+				.await
+				.map(Into::into)
+				.map_err(Into::into);
+		*self.session_mut() = tmp_session;
+		out.map(Into::into)
+	}
+
 	async fn invalidate(&mut self) -> Result<Data, RpcError> {
 		crate::iam::clear::clear(self.session_mut())?;
 		Ok(Value::None.into())
@@ -175,11 +211,11 @@ pub trait RpcContext {
 	// Methods for identification
 	// ------------------------------
 
-	async fn info(&self) -> Result<Data, RpcError> {
+	async fn info(&self, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		// Specify the SQL query string
 		let sql = "SELECT * FROM $auth";
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), None).await?;
+		let mut res = self.execute_in(txn, sql, None).await?;
 		// Extract the first value from the result
 		let res = res.remove(0).result?.first();
 		// Return the result to the client
@@ -232,7 +268,7 @@ pub trait RpcContext {
 		};
 		// Execute the query on the database
 		// let mut res = self.query_with(Value::from(sql), Object::from(var)).await?;
-		let mut res = self.query_inner(Value::from(sql), Some(var)).await?;
+		let mut res = self.query_inner(Value::from(sql), Some(var), None).await?;
 		// Extract the first query result
 		let response = res.remove(0);
 		response.result.map_err(Into::into).map(Into::into)
@@ -251,7 +287,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		};
 		// Execute the query on the database
-		let mut res = self.query_inner(Value::from(sql), Some(var)).await?;
+		let mut res = self.query_inner(Value::from(sql), Some(var), None).await?;
 		// Extract the first query result
 		let response = res.remove(0);
 		response.result.map_err(Into::into).map(Into::into)
@@ -261,7 +297,7 @@ pub trait RpcContext {
 	// Methods for selecting
 	// ------------------------------
 
-	async fn select(&self, params: Array) -> Result<Data, RpcError> {
+	async fn select(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok(what) = params.needs_one() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -275,7 +311,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -289,7 +325,7 @@ pub trait RpcContext {
 	// Methods for inserting
 	// ------------------------------
 
-	async fn insert(&self, params: Array) -> Result<Data, RpcError> {
+	async fn insert(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data)) = params.needs_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -304,7 +340,7 @@ pub trait RpcContext {
 					String::from("data") => data,
 					=> &self.vars()
 				});
-				self.kvs().execute(sql, self.session(), var).await?
+				self.execute_in(txn, sql, var).await?
 			}
 			what => {
 				let sql = "INSERT INTO $what $data RETURN AFTER";
@@ -313,7 +349,7 @@ pub trait RpcContext {
 					String::from("data") => data,
 					=> &self.vars()
 				});
-				self.kvs().execute(sql, self.session(), var).await?
+				self.execute_in(txn, sql, var).await?
 			}
 		};
 
@@ -330,7 +366,7 @@ pub trait RpcContext {
 	// Methods for creating
 	// ------------------------------
 
-	async fn create(&self, params: Array) -> Result<Data, RpcError> {
+	async fn create(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -349,7 +385,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -363,7 +399,7 @@ pub trait RpcContext {
 	// Methods for upserting
 	// ------------------------------
 
-	async fn upsert(&self, params: Array) -> Result<Data, RpcError> {
+	async fn upsert(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -382,7 +418,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -396,7 +432,7 @@ pub trait RpcContext {
 	// Methods for updating
 	// ------------------------------
 
-	async fn update(&self, params: Array) -> Result<Data, RpcError> {
+	async fn update(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -415,7 +451,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -429,7 +465,7 @@ pub trait RpcContext {
 	// Methods for merging
 	// ------------------------------
 
-	async fn merge(&self, params: Array) -> Result<Data, RpcError> {
+	async fn merge(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -448,7 +484,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -462,7 +498,7 @@ pub trait RpcContext {
 	// Methods for patching
 	// ------------------------------
 
-	async fn patch(&self, params: Array) -> Result<Data, RpcError> {
+	async fn patch(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((what, data, diff)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -480,7 +516,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -494,7 +530,7 @@ pub trait RpcContext {
 	// Methods for relating
 	// ------------------------------
 
-	async fn relate(&self, params: Array) -> Result<Data, RpcError> {
+	async fn relate(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((from, kind, to, data)) = params.needs_three_or_four() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -515,7 +551,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -529,7 +565,7 @@ pub trait RpcContext {
 	// Methods for deleting
 	// ------------------------------
 
-	async fn delete(&self, params: Array) -> Result<Data, RpcError> {
+	async fn delete(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok(what) = params.needs_one() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -543,7 +579,7 @@ pub trait RpcContext {
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.execute_in(txn, sql, var).await?;
 		// Extract the first query result
 		let res = match one {
 			true => res.remove(0).result?.first(),
@@ -568,7 +604,7 @@ pub trait RpcContext {
 	// Methods for querying
 	// ------------------------------
 
-	async fn query(&self, params: Array) -> Result<Data, RpcError> {
+	async fn query(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((query, o)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -587,14 +623,14 @@ pub trait RpcContext {
 			Some(mut v) => Some(mrg! {v.0, &self.vars()}),
 			None => Some(self.vars().clone()),
 		};
-		self.query_inner(query, vars).await.map(Into::into)
+		self.query_inner(query, vars, txn).await.map(Into::into)
 	}
 
 	// ------------------------------
 	// Methods for running functions
 	// ------------------------------
 
-	async fn run(&self, params: Array) -> Result<Data, RpcError> {
+	async fn run(&self, params: Array, txn: Option<Uuid>) -> Result<Data, RpcError> {
 		let Ok((Value::Strand(Strand(func_name)), version, args)) = params.needs_one_two_or_three()
 		else {
 			return Err(RpcError::InvalidParams);
@@ -623,10 +659,8 @@ pub trait RpcContext {
 			_ => Function::Normal(func_name, args).into(),
 		};
 
-		let mut res = self
-			.kvs()
-			.process(Statement::Value(func).into(), self.session(), Some(self.vars().clone()))
-			.await?;
+		let mut res =
+			self.process_in(txn, Statement::Value(func).into(), Some(self.vars().clone())).await?;
 		res.remove(0).result.map_err(Into::into).map(Into::into)
 	}
 
@@ -743,6 +777,42 @@ pub trait RpcContext {
 		Ok(Value::Strand(out.into()))
 	}
 
+	// ------------------------------
+	// Methods for transactions
+	// ------------------------------
+
+	async fn begin(&self) -> Result<Data, RpcError> {
+		if !Self::TXN_SUPPORT {
+			return Err(RpcError::MethodNotFound);
+		}
+		let id = self.transactions().begin(self.kvs()).await?;
+		Ok(Value::Uuid(id.into()).into())
+	}
+
+	async fn commit(&self, params: Array) -> Result<Data, RpcError> {
+		if !Self::TXN_SUPPORT {
+			return Err(RpcError::MethodNotFound);
+		}
+		let Ok(id) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let id = Transactions::parse_id(id)?;
+		self.transactions().commit(self.kvs(), id).await?;
+		Ok(Value::None.into())
+	}
+
+	async fn cancel(&self, params: Array) -> Result<Data, RpcError> {
+		if !Self::TXN_SUPPORT {
+			return Err(RpcError::MethodNotFound);
+		}
+		let Ok(id) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let id = Transactions::parse_id(id)?;
+		self.transactions().cancel(id).await?;
+		Ok(Value::None.into())
+	}
+
 	// ------------------------------
 	// Private methods
 	// ------------------------------
@@ -751,6 +821,7 @@ pub trait RpcContext {
 		&self,
 		query: Value,
 		vars: Option<BTreeMap<String, Value>>,
+		txn: Option<Uuid>,
 	) -> Result<Vec<Response>, RpcError> {
 		// If no live query handler force realtime off
 		if !Self::LQ_SUPPORT && self.session().rt {
@@ -758,8 +829,8 @@ pub trait RpcContext {
 		}
 		// Execute the query on the database
 		let res = match query {
-			Value::Query(sql) => self.kvs().process(sql, self.session(), vars).await?,
-			Value::Strand(sql) => self.kvs().execute(&sql, self.session(), vars).await?,
+			Value::Query(sql) => self.process_in(txn, sql, vars).await?,
+			Value::Strand(sql) => self.execute_in(txn, &sql, vars).await?,
 			_ => unreachable!(),
 		};
 
@@ -772,6 +843,35 @@ pub trait RpcContext {
 		Ok(res)
 	}
 
+	/// Executes a query, within the given interactive transaction if any
+	async fn execute_in(
+		&self,
+		txn: Option<Uuid>,
+		sql: &str,
+		vars: Option<BTreeMap<String, Value>>,
+	) -> Result<Vec<Response>, RpcError> {
+		match txn {
+			None => Ok(self.kvs().execute(sql, self.session(), vars).await?),
+			Some(_) => self.process_in(txn, crate::syn::parse(sql)?, vars).await,
+		}
+	}
+
+	/// Executes a parsed query, within the given interactive transaction if any
+	async fn process_in(
+		&self,
+		txn: Option<Uuid>,
+		ast: Query,
+		vars: Option<BTreeMap<String, Value>>,
+	) -> Result<Vec<Response>, RpcError> {
+		match txn {
+			None => Ok(self.kvs().process(ast, self.session(), vars).await?),
+			Some(_) if !Self::TXN_SUPPORT => Err(RpcError::MethodNotFound),
+			Some(id) => {
+				Ok(self.transactions().process(self.kvs(), id, ast, self.session(), vars).await?)
+			}
+		}
+	}
+
 	async fn handle_live_query_results(&self, res: &Response) {
 		match &res.query_type {
 			QueryType::Live => {
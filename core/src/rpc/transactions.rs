@@ -0,0 +1,226 @@
+use crate::dbs::{Notification, Response, Session};
+use crate::err::Error;
+use crate::kvs::{Datastore, LockType::*, Transaction, TransactionType::*};
+use crate::rpc::RpcError;
+use crate::sql::{Query, Value};
+use channel::{Receiver, Sender};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{Mutex as AsyncMutex, RwLock};
+use trice::Instant;
+use uuid::Uuid;
+
+/// A transaction which spans several RPC requests
+struct Interactive {
+	/// The underlying datastore transaction
+	txn: Arc<Transaction>,
+	/// The live query notifications, which are only delivered on commit
+	notifications: (Sender<Notification>, Receiver<Notification>),
+	/// The time at which the transaction was last used
+	used: Mutex<Instant>,
+	/// Whether a query within the transaction has failed
+	failed: AtomicBool,
+	/// Held while a query runs, or while the transaction is closed
+	running: AsyncMutex<()>,
+	/// Whether the transaction has been committed or cancelled
+	closed: AtomicBool,
+}
+
+impl Interactive {
+	fn is_expired(&self, timeout: Duration) -> bool {
+		self.used.lock().map(|v| v.elapsed() > timeout).unwrap_or(true)
+	}
+
+	fn touch(&self) {
+		if let Ok(mut v) = self.used.lock() {
+			*v = Instant::now();
+		}
+	}
+}
+
+/// The interactive transactions of an RPC connection
+pub struct Transactions {
+	/// How long a transaction can stay idle before it is cancelled
+	timeout: Duration,
+	/// The transactions which are currently running
+	inner: RwLock<HashMap<Uuid, Arc<Interactive>>>,
+}
+
+impl Transactions {
+	pub fn new(timeout: Duration) -> Self {
+		Self {
+			timeout,
+			inner: RwLock::new(HashMap::new()),
+		}
+	}
+
+	/// Parses the id of a transaction from an RPC parameter
+	pub fn parse_id(id: Value) -> Result<Uuid, RpcError> {
+		match id {
+			Value::Uuid(v) => Ok(v.0),
+			Value::Strand(v) => Uuid::try_parse(v.as_str()).map_err(|_| RpcError::InvalidParams),
+			_ => Err(RpcError::InvalidParams),
+		}
+	}
+
+	/// Starts a new writeable transaction, returning its id
+	pub async fn begin(&self, kvs: &Datastore) -> Result<Uuid, Error> {
+		let txn = kvs.transaction(Write, Optimistic).await?;
+		let id = Uuid::new_v4();
+		self.inner.write().await.insert(
+			id,
+			Arc::new(Interactive {
+				txn: Arc::new(txn),
+				notifications: channel::unbounded(),
+				used: Mutex::new(Instant::now()),
+				failed: AtomicBool::new(false),
+				running: AsyncMutex::new(()),
+				closed: AtomicBool::new(false),
+			}),
+		);
+		Ok(id)
+	}
+
+	/// Commits a transaction, and delivers its live query notifications
+	pub async fn commit(&self, kvs: &Datastore, id: Uuid) -> Result<(), Error> {
+		let Some(tx) = self.inner.write().await.remove(&id) else {
+			return Err(Error::TxNotFound(id));
+		};
+		// Wait for any query which is still running
+		let _running = tx.running.lock().await;
+		tx.closed.store(true, Ordering::Release);
+		// Check if the transaction can still be committed
+		if tx.is_expired(self.timeout) {
+			let _ = tx.txn.cancel().await;
+			return Err(Error::TxNotFound(id));
+		}
+		if tx.failed.load(Ordering::Acquire) {
+			let _ = tx.txn.cancel().await;
+			return Err(Error::QueryNotExecuted);
+		}
+		// Commit the transaction
+		{
+			let mut txn = tx.txn.lock().await;
+			if let Err(e) = txn.complete_changes(false).await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+			if let Err(e) = txn.commit().await {
+				let _ = txn.cancel().await;
+				return Err(e);
+			}
+		}
+		// Deliver the notifications of the committed changes
+		if let Some(chn) = kvs.notification_sender() {
+			while let Ok(notification) = tx.notifications.1.try_recv() {
+				if chn.send(notification).await.is_err() {
+					break;
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Cancels a transaction, discarding its live query notifications
+	pub async fn cancel(&self, id: Uuid) -> Result<(), Error> {
+		let Some(tx) = self.inner.write().await.remove(&id) else {
+			return Err(Error::TxNotFound(id));
+		};
+		// Wait for any query which is still running
+		let _running = tx.running.lock().await;
+		tx.closed.store(true, Ordering::Release);
+		tx.txn.cancel().await
+	}
+
+	/// Executes a query within a transaction
+	pub async fn process(
+		&self,
+		kvs: &Datastore,
+		id: Uuid,
+		ast: Query,
+		sess: &Session,
+		vars: Option<BTreeMap<String, Value>>,
+	) -> Result<Vec<Response>, Error> {
+		let tx = match self.inner.read().await.get(&id) {
+			Some(tx) if !tx.is_expired(self.timeout) => tx.clone(),
+			_ => return Err(Error::TxNotFound(id)),
+		};
+		// Queries within a transaction run one at a time
+		let _running = tx.running.lock().await;
+		// The transaction may have been closed while waiting
+		if tx.closed.load(Ordering::Acquire) || tx.is_expired(self.timeout) {
+			return Err(Error::TxNotFound(id));
+		}
+		tx.touch();
+		// Like a BEGIN statement, a failure prevents any further query
+		if tx.failed.load(Ordering::Acquire) {
+			return Err(Error::QueryNotExecuted);
+		}
+		let res = kvs.process_in(ast, sess, vars, tx.txn.clone(), tx.notifications.0.clone()).await;
+		if !matches!(&res, Ok(v) if v.iter().all(|r| r.result.is_ok())) {
+			tx.failed.store(true, Ordering::Release);
+		}
+		tx.touch();
+		res
+	}
+
+	/// Cancels the transactions which have been idle for longer than the timeout
+	pub async fn expire(&self) {
+		let expired: Vec<_> = self
+			.inner
+			.read()
+			.await
+			.iter()
+			.filter(|(_, tx)| tx.is_expired(self.timeout))
+			.map(|(id, tx)| (*id, tx.clone()))
+			.collect();
+		for (id, tx) in expired {
+			// A transaction with a running query is not idle
+			let Ok(_running) = tx.running.try_lock() else {
+				continue;
+			};
+			if tx.closed.load(Ordering::Acquire) || !tx.is_expired(self.timeout) {
+				continue;
+			}
+			self.inner.write().await.remove(&id);
+			tx.closed.store(true, Ordering::Release);
+			trace!("Cancelling an expired interactive transaction");
+			let _ = tx.txn.cancel().await;
+		}
+	}
+
+	/// Cancels all the transactions, when the connection is closed
+	pub async fn clear(&self) {
+		let txns: Vec<_> = self.inner.write().await.drain().map(|(_, tx)| tx).collect();
+		for tx in txns {
+			let _running = tx.running.lock().await;
+			tx.closed.store(true, Ordering::Release);
+			let _ = tx.txn.cancel().await;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn commit_waits_for_running_query() {
+		let kvs = Datastore::new("memory").await.unwrap();
+		let sess = Session::owner().with_ns("test").with_db("test");
+		let txs = Transactions::new(Duration::from_secs(60));
+		let id = txs.begin(&kvs).await.unwrap();
+		let ast = crate::syn::parse("SLEEP 100ms; CREATE person:tobie").unwrap();
+		let (res, commit) = tokio::join!(txs.process(&kvs, id, ast, &sess, None), async {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+			txs.commit(&kvs, id).await
+		});
+		// The query completes before the transaction is committed
+		assert!(res.unwrap().into_iter().all(|r| r.result.is_ok()));
+		commit.unwrap();
+		let res = kvs.execute("SELECT * FROM person", &sess, None).await.unwrap();
+		assert_eq!(res[0].result.as_ref().unwrap().to_string(), "[{ id: person:tobie }]");
+	}
+}
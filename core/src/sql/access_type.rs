@@ -61,6 +61,9 @@ impl Display for AccessType {
 					write!(f, " SIGNIN {v}")?
 				}
 				write!(f, " WITH JWT {}", ac.jwt)?;
+				if ac.refresh {
+					f.write_str(" WITH REFRESH")?;
+				}
 			}
 			AccessType::Bearer(ac) => {
 				write!(f, "BEARER")?;
@@ -85,6 +88,7 @@ impl InfoStructure for AccessType {
 				"jwt".to_string() => v.jwt.structure(),
 				"signup".to_string(), if let Some(v) = v.signup => v.structure(),
 				"signin".to_string(), if let Some(v) = v.signin => v.structure(),
+				"refresh".to_string(), if v.refresh => Value::Bool(true),
 			}),
 			AccessType::Bearer(ac) => Value::from(map! {
 					"kind".to_string() => "BEARER".into(),
@@ -106,8 +110,10 @@ impl AccessType {
 	#[allow(unreachable_patterns)]
 	pub fn can_issue_grants(&self) -> bool {
 		match self {
-			// The grants for JWT and record access methods are JWT
-			AccessType::Jwt(_) | AccessType::Record(_) => false,
+			// The grants for JWT access methods are JWT
+			AccessType::Jwt(_) => false,
+			// Record access methods can issue refresh tokens as grants
+			AccessType::Record(ac) => ac.refresh,
 			AccessType::Bearer(_) => true,
 		}
 	}
@@ -297,7 +303,7 @@ pub struct JwtAccessVerifyJwks {
 	pub url: String,
 }
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 4)]
 #[derive(Debug, Serialize, Deserialize, Hash, Clone, Eq, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RecordAccess {
@@ -306,6 +312,9 @@ pub struct RecordAccess {
 	pub jwt: JwtAccess,
 	#[revision(start = 2, end = 3, convert_fn = "authenticate_revision")]
 	pub authenticate: Option<Value>,
+	// Whether signin and signup also issue a refresh token
+	#[revision(start = 4)]
+	pub refresh: bool,
 }
 
 impl RecordAccess {
@@ -328,6 +337,7 @@ impl Default for RecordAccess {
 			jwt: JwtAccess {
 				..Default::default()
 			},
+			refresh: false,
 		}
 	}
 }
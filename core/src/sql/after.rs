@@ -0,0 +1,44 @@
+use crate::ctx::Context;
+use crate::dbs::{Cursor, Options};
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::sql::value::Value;
+use reblessive::tree::Stk;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct After(pub Value);
+
+impl After {
+	pub(crate) async fn process(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: Option<&CursorDoc>,
+	) -> Result<Option<Cursor>, Error> {
+		match self.0.compute(stk, ctx, opt, doc).await {
+			// There is no cursor, so this is the first page
+			Ok(Value::None | Value::Null) => Ok(None),
+			// This is a cursor from a previous page
+			Ok(Value::Strand(v)) => Cursor::decode(&v).map(Some),
+			// An invalid value was specified
+			Ok(v) => Err(Error::InvalidCursor {
+				value: v.as_string(),
+			}),
+			// A different error occurred
+			Err(e) => Err(e),
+		}
+	}
+}
+
+impl fmt::Display for After {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "AFTER {}", self.0)
+	}
+}
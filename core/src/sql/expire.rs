@@ -0,0 +1,56 @@
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Datetime, Duration, Idiom, Value};
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// The time to live of the records in a table
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Expire {
+	/// The time after which a record expires
+	pub duration: Duration,
+	/// The datetime field which the duration is added to, instead of the time of the last write
+	pub field: Option<Idiom>,
+}
+
+impl Expire {
+	/// Calculates the time at which a record expires, in nanoseconds since the UNIX epoch.
+	/// Records without a datetime in the expiry field never expire.
+	pub(crate) fn expiry(&self, doc: &Value) -> Option<u64> {
+		let base = match &self.field {
+			Some(field) => match doc.pick(field) {
+				Value::Datetime(v) => v,
+				_ => return None,
+			},
+			None => Datetime::default(),
+		};
+		let base = u64::try_from(base.timestamp_nanos_opt()?).ok()?;
+		Some(base.saturating_add(self.nanos()))
+	}
+	/// Returns the time to live in nanoseconds
+	pub(crate) fn nanos(&self) -> u64 {
+		u64::try_from(self.duration.as_nanos()).unwrap_or(u64::MAX)
+	}
+}
+
+impl Display for Expire {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "EXPIRE {}", self.duration)?;
+		if let Some(ref v) = self.field {
+			write!(f, " ON {v}")?;
+		}
+		Ok(())
+	}
+}
+
+impl InfoStructure for Expire {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"duration".to_string() => self.duration.structure(),
+			"field".to_string(), if let Some(v) = self.field => v.structure(),
+		})
+	}
+}
@@ -1,10 +1,12 @@
+use crate::sql::escape::quote_str;
+use crate::sql::fmt::Fmt;
 use crate::sql::language::Language;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Display;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -15,6 +17,12 @@ pub enum Filter {
 	Ngram(u16, u16),
 	Snowball(Language),
 	Uppercase,
+	#[revision(start = 2)]
+	Stopwords(Language),
+	#[revision(start = 2)]
+	StopwordList(Vec<String>),
+	#[revision(start = 2)]
+	Synonyms(Vec<Vec<String>>),
 }
 
 impl Display for Filter {
@@ -26,6 +34,20 @@ impl Display for Filter {
 			Self::Ngram(min, max) => write!(f, "NGRAM({},{})", min, max),
 			Self::Snowball(lang) => write!(f, "SNOWBALL({})", lang),
 			Self::Uppercase => f.write_str("UPPERCASE"),
+			Self::Stopwords(lang) => write!(f, "STOPWORDS({})", lang),
+			Self::StopwordList(words) => {
+				write!(f, "STOPWORDS({})", Fmt::comma_separated(words.iter().map(|w| quote_str(w))))
+			}
+			Self::Synonyms(groups) => {
+				f.write_str("SYNONYMS(")?;
+				for (i, g) in groups.iter().enumerate() {
+					if i > 0 {
+						f.write_str(", ")?;
+					}
+					write!(f, "[{}]", Fmt::comma_separated(g.iter().map(|w| quote_str(w))))?;
+				}
+				f.write_str(")")
+			}
 		}
 	}
 }
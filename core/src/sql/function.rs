@@ -248,7 +248,7 @@ impl Function {
 				// Get the function definition
 				let val = ctx.tx().get_db_function(opt.ns()?, opt.db()?, s).await?;
 				// Check permissions
-				if opt.check_perms(Action::View)? {
+				if opt.check_perms(Action::Read)? {
 					match &val.permissions {
 						Permission::Full => (),
 						Permission::None => {
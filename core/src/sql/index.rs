@@ -53,7 +53,7 @@ pub struct SearchParams {
 	pub terms_cache: u32,
 }
 
-#[revisioned(revision = 2)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -70,9 +70,14 @@ pub struct MTreeParams {
 	pub doc_ids_cache: u32,
 	#[revision(start = 2)]
 	pub mtree_cache: u32,
+	#[revision(start = 3)]
+	pub quantization: Option<Quantization>,
+	#[revision(start = 3)]
+	pub rerank: Option<u16>,
 }
 
 impl MTreeParams {
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		dimension: u16,
 		distance: Distance,
@@ -81,6 +86,8 @@ impl MTreeParams {
 		doc_ids_order: u32,
 		doc_ids_cache: u32,
 		mtree_cache: u32,
+		quantization: Option<Quantization>,
+		rerank: Option<u16>,
 	) -> Self {
 		Self {
 			dimension,
@@ -90,6 +97,8 @@ impl MTreeParams {
 			doc_ids_order,
 			doc_ids_cache,
 			mtree_cache,
+			quantization,
+			rerank,
 		}
 	}
 
@@ -122,7 +131,7 @@ pub enum Distance1 {
 	Minkowski(Number),
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -136,6 +145,10 @@ pub struct HnswParams {
 	pub extend_candidates: bool,
 	pub keep_pruned_connections: bool,
 	pub ml: Number,
+	#[revision(start = 2)]
+	pub quantization: Option<Quantization>,
+	#[revision(start = 2)]
+	pub rerank: Option<u16>,
 }
 
 impl HnswParams {
@@ -150,6 +163,8 @@ impl HnswParams {
 		ef_construction: u16,
 		extend_candidates: bool,
 		keep_pruned_connections: bool,
+		quantization: Option<Quantization>,
+		rerank: Option<u16>,
 	) -> Self {
 		Self {
 			dimension,
@@ -161,6 +176,8 @@ impl HnswParams {
 			ml,
 			extend_candidates,
 			keep_pruned_connections,
+			quantization,
+			rerank,
 		}
 	}
 }
@@ -236,6 +253,43 @@ impl Display for VectorType {
 	}
 }
 
+/// The compression of the vectors which are stored in a vector index.
+/// The candidates can be re-ranked against the full-precision vectors
+/// of the records, as the distances between quantized vectors are approximations.
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Quantization {
+	/// Scalar quantization, one signed byte per dimension
+	Int8,
+	/// Binary quantization, one bit per dimension
+	Binary,
+}
+
+impl Display for Quantization {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Int8 => f.write_str("INT8"),
+			Self::Binary => f.write_str("BINARY"),
+		}
+	}
+}
+
+fn fmt_quantization(
+	f: &mut Formatter<'_>,
+	quantization: &Option<Quantization>,
+	rerank: &Option<u16>,
+) -> fmt::Result {
+	if let Some(q) = quantization {
+		write!(f, " QUANTIZE {q}")?;
+		if let Some(r) = rerank {
+			write!(f, " RERANK {r}")?;
+		}
+	}
+	Ok(())
+}
+
 impl Display for Index {
 	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
 		match self {
@@ -266,7 +320,8 @@ impl Display for Index {
 					f,
 					"MTREE DIMENSION {} DIST {} TYPE {} CAPACITY {} DOC_IDS_ORDER {} DOC_IDS_CACHE {} MTREE_CACHE {}",
 					p.dimension, p.distance, p.vector_type, p.capacity, p.doc_ids_order, p.doc_ids_cache, p.mtree_cache
-				)
+				)?;
+				fmt_quantization(f, &p.quantization, &p.rerank)
 			}
 			Self::Hnsw(p) => {
 				write!(
@@ -280,7 +335,7 @@ impl Display for Index {
 				if p.keep_pruned_connections {
 					f.write_str(" KEEP_PRUNED_CONNECTIONS")?
 				}
-				Ok(())
+				fmt_quantization(f, &p.quantization, &p.rerank)
 			}
 		}
 	}
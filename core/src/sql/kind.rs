@@ -57,6 +57,16 @@ impl Kind {
 		matches!(self, Kind::Record(_))
 	}
 
+	// Returns true if this type can hold a record, directly or within a collection
+	pub(crate) fn holds_record(&self) -> bool {
+		match self {
+			Kind::Record(_) => true,
+			Kind::Option(x) | Kind::Array(x, _) | Kind::Set(x, _) => x.holds_record(),
+			Kind::Either(x) => x.iter().any(Self::holds_record),
+			_ => false,
+		}
+	}
+
 	// return the kind of the contained value.
 	//
 	// For example: for `array<number>` or `set<number>` this returns `number`.
@@ -2,6 +2,7 @@
 
 pub(crate) mod access;
 pub(crate) mod access_type;
+pub(crate) mod after;
 pub(crate) mod algorithm;
 #[cfg(feature = "arbitrary")]
 pub(crate) mod arbitrary;
@@ -21,6 +22,7 @@ pub(crate) mod dir;
 pub(crate) mod duration;
 pub(crate) mod edges;
 pub(crate) mod escape;
+pub(crate) mod expire;
 pub(crate) mod explain;
 pub(crate) mod expression;
 pub(crate) mod fetch;
@@ -52,6 +54,7 @@ pub(crate) mod paths;
 pub(crate) mod permission;
 pub(crate) mod query;
 pub(crate) mod range;
+pub(crate) mod reference;
 pub(crate) mod regex;
 pub(crate) mod scoring;
 pub(crate) mod script;
@@ -81,6 +84,7 @@ pub mod statements;
 pub use self::access::Access;
 pub use self::access::Accesses;
 pub use self::access_type::{AccessType, JwtAccess, RecordAccess};
+pub use self::after::After;
 pub use self::algorithm::Algorithm;
 pub use self::array::Array;
 pub use self::base::Base;
@@ -97,6 +101,7 @@ pub use self::datetime::Datetime;
 pub use self::dir::Dir;
 pub use self::duration::Duration;
 pub use self::edges::Edges;
+pub use self::expire::Expire;
 pub use self::explain::Explain;
 pub use self::expression::Expression;
 pub use self::fetch::Fetch;
@@ -133,6 +138,7 @@ pub use self::permission::Permission;
 pub use self::permission::Permissions;
 pub use self::query::Query;
 pub use self::range::Range;
+pub use self::reference::{Reference, ReferenceDeleteStrategy};
 pub use self::regex::Regex;
 pub use self::scoring::Scoring;
 pub use self::script::Script;
@@ -24,9 +24,34 @@ use futures::future::try_join_all;
 #[cfg(feature = "ml")]
 use std::collections::HashMap;
 
+#[cfg(feature = "ml")]
+use crate::cnf::MODEL_CACHE_SIZE;
+#[cfg(feature = "ml")]
+use crate::sql::statements::{DefineModelStatement, ModelFormat};
+#[cfg(feature = "ml")]
+use ndarray::{ArrayD, CowArray, IxDyn};
+#[cfg(feature = "ml")]
+use once_cell::sync::Lazy;
+#[cfg(feature = "ml")]
+use ort::tensor::{TensorDataToType, TensorElementDataType};
+#[cfg(feature = "ml")]
+use ort::{Environment, OrtError, Session, SessionBuilder, Value as OrtValue};
+#[cfg(feature = "ml")]
+use quick_cache::sync::Cache;
+#[cfg(feature = "ml")]
+use std::collections::BTreeMap;
+#[cfg(feature = "ml")]
+use std::io::Write;
+#[cfg(feature = "ml")]
+use std::sync::Arc;
+
 #[cfg(feature = "ml")]
 const ARGUMENTS: &str = "The model expects 1 argument. The argument can be either a number, an object, or an array of numbers.";
 
+#[cfg(feature = "ml")]
+const ONNX_ARGUMENTS: &str =
+	"The model expects 1 argument. The argument must be an object of named input tensors.";
+
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Model";
 
 #[revisioned(revision = 1)]
@@ -71,16 +96,9 @@ impl Model {
 		// Get the model definition
 		let val = ctx.tx().get_db_model(opt.ns()?, opt.db()?, &self.name, &self.version).await?;
 		// Calculate the model path
-		let path = format!(
-			"ml/{}/{}/{}-{}-{}.surml",
-			opt.ns()?,
-			opt.db()?,
-			self.name,
-			self.version,
-			val.hash
-		);
+		let path = val.path(opt.ns()?, opt.db()?);
 		// Check permissions
-		if opt.check_perms(Action::View)? {
+		if opt.check_perms(Action::Read)? {
 			match &val.permissions {
 				Permission::Full => (),
 				Permission::None => {
@@ -113,6 +131,10 @@ impl Model {
 				message: ARGUMENTS.into(),
 			});
 		}
+		// Perform named tensor compute for raw ONNX models
+		if val.format == ModelFormat::Onnx {
+			return self.compute_onnx(&val, &path, args.swap_remove(0)).await;
+		}
 		// Take the first and only specified argument
 		match args.swap_remove(0) {
 			// Perform bufferered compute
@@ -213,6 +235,113 @@ impl Model {
 		}
 	}
 
+	/// Runs a raw ONNX model, mapping the fields of the argument
+	/// object to the declared input tensors, and returning the
+	/// declared output tensors as an object
+	#[cfg(feature = "ml")]
+	async fn compute_onnx(
+		&self,
+		val: &DefineModelStatement,
+		path: &str,
+		arg: Value,
+	) -> Result<Value, Error> {
+		// The argument must be an object of named inputs
+		let Value::Object(mut arg) = arg else {
+			return Err(Error::InvalidArguments {
+				name: format!("ml::{}<{}>", self.name, self.version),
+				message: ONNX_ARGUMENTS.into(),
+			});
+		};
+		// Load the model session
+		let session = onnx_session(path).await?;
+		// Convert each named input to a tensor of the element type the model expects
+		let mut tensors = Vec::with_capacity(session.inputs.len());
+		for model_input in session.inputs.iter() {
+			let declared = val.inputs.iter().find(|v| v.name.as_str() == model_input.name);
+			let Some(input) = declared else {
+				return Err(Error::ModelComputation(format!(
+					"The model input '{}' has not been declared",
+					model_input.name
+				)));
+			};
+			let size = input.size().ok_or_else(|| Error::InvalidModel {
+				message: format!("The model tensor '{}' has an invalid shape.", input.name),
+			})?;
+			let mut data = Vec::new();
+			let value = arg.remove(input.name.as_str()).unwrap_or_default();
+			let tensor = match flatten(value, &mut data, size) && data.len() == size {
+				true => OnnxTensor::new(model_input.input_type, &input.shape, data)?,
+				false => None,
+			};
+			let Some(tensor) = tensor else {
+				return Err(Error::InvalidArguments {
+					name: format!("ml::{}<{}>", self.name, self.version),
+					message: format!(
+						"The model input '{}' expects {} values of type {:?} with the shape {:?}.",
+						input.name, size, model_input.input_type, input.shape
+					),
+				});
+			};
+			tensors.push(tensor);
+		}
+		// Run the compute in a blocking task
+		let outputs = val.outputs.clone();
+		let outcome: Vec<Vec<Value>> = tokio::task::spawn_blocking(move || {
+			let err = |e: OrtError| Error::ModelComputation(e.to_string());
+			let tensors = tensors.iter().map(OnnxTensor::view).collect::<Vec<_>>();
+			let values = tensors
+				.iter()
+				.map(|v| v.value(&session))
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(err)?;
+			let results = session.run(values).map_err(err)?;
+			// Extract the declared output tensors by name
+			outputs
+				.iter()
+				.map(|o| {
+					let idx = session.outputs.iter().position(|v| v.name == o.name.as_str());
+					let Some(idx) = idx else {
+						return Err(Error::ModelComputation(format!(
+							"The model output '{}' does not exist",
+							o.name
+						)));
+					};
+					let result = &results[idx];
+					let data = match session.outputs[idx].output_type {
+						TensorElementDataType::Float32 => extract(result, |v: f32| v.into()),
+						TensorElementDataType::Float64 => extract(result, |v: f64| v.into()),
+						TensorElementDataType::Int32 => extract(result, |v: i32| v.into()),
+						TensorElementDataType::Int64 => extract(result, |v: i64| v.into()),
+						TensorElementDataType::Bool => extract(result, |v: bool| v.into()),
+						t => {
+							return Err(Error::ModelComputation(format!(
+								"The model output '{}' has the unsupported type {t:?}",
+								o.name
+							)))
+						}
+					};
+					data.map_err(err)
+				})
+				.collect::<Result<Vec<_>, Error>>()
+		})
+		.await
+		.map_err(|e| Error::ModelComputation(e.to_string()))??;
+		// Convert the outputs to an object
+		let res = val
+			.outputs
+			.iter()
+			.zip(outcome)
+			.map(|(o, mut v)| {
+				let v = match v.len() {
+					1 => v.swap_remove(0),
+					_ => Value::from(v),
+				};
+				(o.name.to_raw(), v)
+			})
+			.collect::<BTreeMap<_, _>>();
+		Ok(res.into())
+	}
+
 	#[cfg(not(feature = "ml"))]
 	pub(crate) async fn compute(
 		&self,
@@ -226,3 +355,124 @@ impl Model {
 		})
 	}
 }
+
+/// Loads the ONNX session of a model file, which is cached until the model file changes
+#[cfg(feature = "ml")]
+async fn onnx_session(path: &str) -> Result<Arc<Session>, Error> {
+	// The path of a model file includes the hash of its content
+	static ONNX_SESSIONS: Lazy<Cache<String, Arc<Session>>> =
+		Lazy::new(|| Cache::new(*MODEL_CACHE_SIZE));
+	match ONNX_SESSIONS.get_value_or_guard_async(path).await {
+		Ok(v) => Ok(v),
+		Err(guard) => {
+			// Get the model file as bytes
+			let bytes = crate::obs::get(path).await?;
+			// Load the model in a blocking task
+			let session = tokio::task::spawn_blocking(move || {
+				let err = |e: OrtError| Error::ModelComputation(e.to_string());
+				// A session loaded from memory borrows the model bytes, so
+				// the model is loaded from a temporary file which is removed
+				// once the session has been created.
+				let mut file = tempfile::NamedTempFile::new()?;
+				file.write_all(&bytes)?;
+				let env = Environment::builder().with_name("surrealdb").build().map_err(err)?;
+				SessionBuilder::new(&env.into_arc())
+					.map_err(err)?
+					.with_model_from_file(file.path())
+					.map_err(err)
+			})
+			.await
+			.map_err(|e| Error::ModelComputation(e.to_string()))??;
+			let session = Arc::new(session);
+			let _ = guard.insert(session.clone());
+			Ok(session)
+		}
+	}
+}
+
+/// Flattens a number or boolean, or a nested array of them, in to a tensor buffer of at most `size` values
+#[cfg(feature = "ml")]
+fn flatten(value: Value, data: &mut Vec<Value>, size: usize) -> bool {
+	match value {
+		Value::Number(_) | Value::Bool(_) if data.len() < size => {
+			data.push(value);
+			true
+		}
+		Value::Array(v) => v.into_iter().all(|v| flatten(v, data, size)),
+		_ => false,
+	}
+}
+
+/// An input tensor of an ONNX model, with the element type which the model expects
+#[cfg(feature = "ml")]
+enum OnnxTensor<'a> {
+	Float(CowArray<'a, f32, IxDyn>),
+	Double(CowArray<'a, f64, IxDyn>),
+	Int32(CowArray<'a, i32, IxDyn>),
+	Int64(CowArray<'a, i64, IxDyn>),
+	Bool(CowArray<'a, bool, IxDyn>),
+}
+
+#[cfg(feature = "ml")]
+impl<'a> OnnxTensor<'a> {
+	/// Converts the values to a tensor of the specified element type, if they are all of that type
+	fn new(
+		kind: TensorElementDataType,
+		shape: &[u64],
+		data: Vec<Value>,
+	) -> Result<Option<Self>, Error> {
+		fn array<'a, T: TryFrom<Value>>(
+			shape: &[u64],
+			data: Vec<Value>,
+		) -> Result<Option<CowArray<'a, T, IxDyn>>, Error> {
+			let Ok(data) = data.into_iter().map(T::try_from).collect::<Result<Vec<_>, _>>() else {
+				return Ok(None);
+			};
+			let shape: Vec<usize> = shape.iter().map(|v| *v as usize).collect();
+			let array = ArrayD::from_shape_vec(IxDyn(&shape), data)
+				.map_err(|e| Error::ModelComputation(e.to_string()))?;
+			Ok(Some(CowArray::from(array)))
+		}
+		Ok(match kind {
+			TensorElementDataType::Float32 => array(shape, data)?.map(Self::Float),
+			TensorElementDataType::Float64 => array(shape, data)?.map(Self::Double),
+			TensorElementDataType::Int32 => array(shape, data)?.map(Self::Int32),
+			TensorElementDataType::Int64 => array(shape, data)?.map(Self::Int64),
+			TensorElementDataType::Bool => array(shape, data)?.map(Self::Bool),
+			_ => None,
+		})
+	}
+
+	/// Borrows the data of this tensor
+	fn view(&self) -> OnnxTensor<'_> {
+		match self {
+			Self::Float(v) => OnnxTensor::Float(v.view().into()),
+			Self::Double(v) => OnnxTensor::Double(v.view().into()),
+			Self::Int32(v) => OnnxTensor::Int32(v.view().into()),
+			Self::Int64(v) => OnnxTensor::Int64(v.view().into()),
+			Self::Bool(v) => OnnxTensor::Bool(v.view().into()),
+		}
+	}
+
+	/// Creates the ONNX runtime value of this tensor
+	fn value(&'a self, session: &Session) -> Result<OrtValue<'a>, OrtError> {
+		match self {
+			Self::Float(v) => OrtValue::from_array(session.allocator(), v),
+			Self::Double(v) => OrtValue::from_array(session.allocator(), v),
+			Self::Int32(v) => OrtValue::from_array(session.allocator(), v),
+			Self::Int64(v) => OrtValue::from_array(session.allocator(), v),
+			Self::Bool(v) => OrtValue::from_array(session.allocator(), v),
+		}
+	}
+}
+
+/// Extracts the values of an output tensor of an ONNX model
+#[cfg(feature = "ml")]
+fn extract<T: TensorDataToType>(
+	value: &OrtValue,
+	convert: impl Fn(T) -> Value,
+) -> Result<Vec<Value>, OrtError> {
+	let tensor = value.try_extract::<T>()?;
+	let data = tensor.view().iter().cloned().map(convert).collect();
+	Ok(data)
+}
@@ -78,7 +78,7 @@ impl Param {
 						// The param has been set globally
 						Ok(val) => {
 							// Check permissions
-							if opt.check_perms(Action::View)? {
+							if opt.check_perms(Action::Read)? {
 								match &val.permissions {
 									Permission::Full => (),
 									Permission::None => {
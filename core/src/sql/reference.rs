@@ -0,0 +1,56 @@
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::Value;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// A record reference, stored for a record type field
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct Reference {
+	/// What happens to the referencing record when the referenced record is deleted
+	pub on_delete: ReferenceDeleteStrategy,
+}
+
+impl Display for Reference {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "REFERENCE ON DELETE {}", self.on_delete)
+	}
+}
+
+impl InfoStructure for Reference {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"on_delete".to_string() => self.on_delete.to_string().into(),
+		})
+	}
+}
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ReferenceDeleteStrategy {
+	/// The referenced record can not be deleted
+	Reject,
+	/// The reference is left in place
+	#[default]
+	Ignore,
+	/// The referencing record is deleted
+	Cascade,
+	/// The reference is removed from the referencing record
+	Unset,
+}
+
+impl Display for ReferenceDeleteStrategy {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Reject => f.write_str("REJECT"),
+			Self::Ignore => f.write_str("IGNORE"),
+			Self::Cascade => f.write_str("CASCADE"),
+			Self::Unset => f.write_str("UNSET"),
+		}
+	}
+}
@@ -121,7 +121,10 @@ impl Statement {
 			Self::Access(_) => true,
 			Self::Alter(_) => true,
 			Self::Analyze(_) => false,
+			Self::Begin(_) => false,
 			Self::Break(_) => false,
+			Self::Cancel(_) => false,
+			Self::Commit(_) => false,
 			Self::Continue(_) => false,
 			Self::Create(v) => v.writeable(),
 			Self::Define(_) => true,
@@ -145,7 +148,6 @@ impl Statement {
 			Self::Upsert(v) => v.writeable(),
 			Self::Update(v) => v.writeable(),
 			Self::Use(_) => false,
-			_ => unreachable!(),
 		}
 	}
 	/// Process this type returning a computed simple Value
@@ -4,7 +4,9 @@ use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
 use crate::sql::access_type::BearerAccessLevel;
-use crate::sql::{AccessType, Array, Base, Datetime, Id, Ident, Object, Strand, Uuid, Value};
+use crate::sql::{
+	AccessType, Array, Base, Datetime, Id, Ident, Object, Strand, Thing, Uuid, Value,
+};
 use derive::Store;
 use rand::Rng;
 use revision::revisioned;
@@ -25,6 +27,11 @@ pub static GRANT_BEARER_KEY_LENGTH: usize = 24;
 // Total bearer key length.
 pub static GRANT_BEARER_LENGTH: usize =
 	GRANT_BEARER_PREFIX.len() + 1 + GRANT_BEARER_ID_LENGTH + 1 + GRANT_BEARER_KEY_LENGTH;
+// Refresh tokens are bearer keys with their own prefix.
+pub static GRANT_REFRESH_PREFIX: &str = "surreal-refresh";
+// Total refresh token length.
+pub static GRANT_REFRESH_LENGTH: usize =
+	GRANT_REFRESH_PREFIX.len() + 1 + GRANT_BEARER_ID_LENGTH + 1 + GRANT_BEARER_KEY_LENGTH;
 
 // TODO(gguillemas): Document once bearer access is no longer experimental.
 #[doc(hidden)]
@@ -160,15 +167,28 @@ impl From<AccessGrant> for Object {
 	}
 }
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
 pub enum Subject {
-	Record(Id),
+	#[revision(end = 2, convert_fn = "convert_record_id")]
+	RecordId(Id),
+	#[revision(start = 2)]
+	Record(Thing),
 	User(Ident),
 }
 
+impl Subject {
+	// Record subjects were stored without their table before revision 2
+	fn convert_record_id(
+		fields: SubjectRecordIdFields,
+		_revision: u16,
+	) -> Result<Self, revision::Error> {
+		Ok(Self::Record(Thing::from((String::new(), fields.0))))
+	}
+}
+
 #[revisioned(revision = 1)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -210,11 +230,27 @@ pub struct GrantBearer {
 impl GrantBearer {
 	#[doc(hidden)]
 	pub fn new() -> Self {
+		Self::with_prefix(GRANT_BEARER_PREFIX)
+	}
+	/// Creates a new refresh token for a record access method
+	pub(crate) fn new_refresh() -> Self {
+		Self::with_prefix(GRANT_REFRESH_PREFIX)
+	}
+	/// Creates a new key for an existing refresh token, keeping its identifier
+	pub(crate) fn rotate_refresh(&self) -> Self {
+		let id = self.id.to_raw();
+		let secret = random_string(GRANT_BEARER_KEY_LENGTH);
+		Self {
+			id: self.id.clone(),
+			key: format!("{GRANT_REFRESH_PREFIX}-{id}-{secret}").into(),
+		}
+	}
+	fn with_prefix(prefix: &str) -> Self {
 		let id = random_string(GRANT_BEARER_ID_LENGTH);
 		let secret = random_string(GRANT_BEARER_KEY_LENGTH);
 		Self {
 			id: id.clone().into(),
-			key: format!("{GRANT_BEARER_PREFIX}-{id}-{secret}").into(),
+			key: format!("{prefix}-{id}-{secret}").into(),
 		}
 	}
 }
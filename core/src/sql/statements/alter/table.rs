@@ -4,7 +4,7 @@ use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
 use crate::sql::fmt::{is_pretty, pretty_indent};
-use crate::sql::{changefeed::ChangeFeed, Base, Ident, Permissions, Strand, Value};
+use crate::sql::{changefeed::ChangeFeed, Base, Expire, Ident, Permissions, Strand, Value};
 use crate::sql::{Kind, TableType};
 use derive::Store;
 use reblessive::tree::Stk;
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Write};
 use std::ops::Deref;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -26,6 +26,8 @@ pub struct AlterTableStatement {
 	pub changefeed: Option<Option<ChangeFeed>>,
 	pub comment: Option<Option<Strand>>,
 	pub kind: Option<TableType>,
+	#[revision(start = 2)]
+	pub expire: Option<Option<Expire>>,
 }
 
 impl AlterTableStatement {
@@ -37,7 +39,7 @@ impl AlterTableStatement {
 		_doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+		opt.is_allowed(Action::Define, ResourceKind::Table, &Base::Db)?;
 		// Fetch the transaction
 		let txn = ctx.tx();
 		// Get the table definition
@@ -50,6 +52,7 @@ impl AlterTableStatement {
 		};
 		// Process the statement
 		let key = crate::key::database::tb::new(opt.ns()?, opt.db()?, &self.name);
+		let expire = dt.expire.clone();
 		if let Some(ref drop) = &self.drop {
 			dt.drop = *drop;
 		}
@@ -68,12 +71,19 @@ impl AlterTableStatement {
 		if let Some(ref kind) = &self.kind {
 			dt.kind = kind.clone();
 		}
+		if let Some(ref expire) = &self.expire {
+			dt.expire.clone_from(expire);
+		}
 
 		txn.set(key, &dt, None).await?;
 		// Add table relational fields
 		if matches!(self.kind, Some(TableType::Relation(_))) {
 			dt.add_in_out_fields(&txn, opt).await?;
 		}
+		// Recalculate the expiry data
+		if self.expire.is_some() {
+			dt.set_expiry(&txn, opt, expire.as_ref()).await?;
+		}
 		// Record definition change
 		if self.changefeed.is_some() && dt.changefeed.is_some() {
 			txn.lock().await.record_table_change(opt.ns()?, opt.db()?, &self.name, &dt);
@@ -136,6 +146,12 @@ impl Display for AlterTableStatement {
 		if let Some(changefeed) = &self.changefeed {
 			write!(f, " CHANGEFEED {}", changefeed.map_or("NONE".into(), |v| v.to_string()))?
 		}
+		if let Some(expire) = &self.expire {
+			match expire {
+				Some(v) => write!(f, " {v}")?,
+				None => f.write_str(" EXPIRE NONE")?,
+			}
+		}
 		let _indent = if is_pretty() {
 			Some(pretty_indent())
 		} else {
@@ -66,7 +66,7 @@ impl DefineAccessStatement {
 		_doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Actor, &self.base)?;
+		opt.is_allowed(Action::Define, ResourceKind::Actor, &self.base)?;
 		// Check the statement type
 		match &self.base {
 			Base::Root => {
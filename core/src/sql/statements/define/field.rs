@@ -1,5 +1,6 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
+use crate::doc::reference::references;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
@@ -7,14 +8,14 @@ use crate::sql::fmt::{is_pretty, pretty_indent};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::statements::DefineTableStatement;
 use crate::sql::Part;
-use crate::sql::{Base, Ident, Idiom, Kind, Permissions, Strand, Value};
+use crate::sql::{Base, Ident, Idiom, Kind, Permissions, Reference, Strand, Value};
 use crate::sql::{Relation, TableType};
 use derive::Store;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Write};
 
-#[revisioned(revision = 4)]
+#[revisioned(revision = 5)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -34,6 +35,8 @@ pub struct DefineFieldStatement {
 	pub if_not_exists: bool,
 	#[revision(start = 4)]
 	pub overwrite: bool,
+	#[revision(start = 5)]
+	pub reference: Option<Reference>,
 }
 
 impl DefineFieldStatement {
@@ -45,7 +48,7 @@ impl DefineFieldStatement {
 		_doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Field, &Base::Db)?;
+		opt.is_allowed(Action::Define, ResourceKind::Field, &Base::Db)?;
 		// Get the NS and DB
 		let ns = opt.ns()?;
 		let db = opt.db()?;
@@ -54,7 +57,8 @@ impl DefineFieldStatement {
 		// Get the name of the field
 		let fd = self.name.to_string();
 		// Check if the definition exists
-		if txn.get_tb_field(ns, db, &self.what, &fd).await.is_ok() {
+		let existing = txn.get_tb_field(ns, db, &self.what, &fd).await.ok();
+		if existing.is_some() {
 			if self.if_not_exists {
 				return Ok(Value::None);
 			} else if !self.overwrite {
@@ -63,6 +67,12 @@ impl DefineFieldStatement {
 				});
 			}
 		}
+		// Check that a reference holds records
+		if self.reference.is_some() && !self.kind.as_ref().is_some_and(Kind::holds_record) {
+			return Err(Error::FdReferenceKind {
+				name: fd,
+			});
+		}
 		// Process the statement
 		let key = crate::key::table::fd::new(ns, db, &self.what, &fd);
 		txn.get_or_add_ns(ns, opt.strict).await?;
@@ -79,6 +89,15 @@ impl DefineFieldStatement {
 			None,
 		)
 		.await?;
+		// Keep the stored references in step with the field definition
+		let referenced = existing.as_ref().is_some_and(|v| v.reference.is_some());
+		match (referenced, self.reference.is_some()) {
+			// Store the references held by the existing records
+			(false, true) => references(&txn, ns, db, &self.what, &self.name, true).await?,
+			// Remove the references which are no longer tracked
+			(true, false) => references(&txn, ns, db, &self.what, &self.name, false).await?,
+			_ => (),
+		}
 
 		// find existing field definitions.
 		let fields = txn.all_tb_fields(ns, db, &self.what).await.ok();
@@ -204,6 +223,9 @@ impl Display for DefineFieldStatement {
 		if let Some(ref v) = self.kind {
 			write!(f, " TYPE {v}")?
 		}
+		if let Some(ref v) = self.reference {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.default {
 			write!(f, " DEFAULT {v}")?
 		}
@@ -237,6 +259,7 @@ impl InfoStructure for DefineFieldStatement {
 			"what".to_string() => self.what.structure(),
 			"flex".to_string() => self.flex.into(),
 			"kind".to_string(), if let Some(v) = self.kind => v.structure(),
+			"reference".to_string(), if let Some(v) = self.reference => v.structure(),
 			"value".to_string(), if let Some(v) = self.value => v.structure(),
 			"assert".to_string(), if let Some(v) = self.assert => v.structure(),
 			"default".to_string(), if let Some(v) = self.default => v.structure(),
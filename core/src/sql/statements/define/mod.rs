@@ -8,6 +8,8 @@ mod index;
 mod model;
 mod namespace;
 mod param;
+mod role;
+mod sequence;
 mod table;
 mod user;
 
@@ -18,9 +20,11 @@ pub use event::DefineEventStatement;
 pub use field::DefineFieldStatement;
 pub use function::DefineFunctionStatement;
 pub use index::DefineIndexStatement;
-pub use model::DefineModelStatement;
+pub use model::{DefineModelStatement, ModelFormat, ModelTensor};
 pub use namespace::DefineNamespaceStatement;
 pub use param::DefineParamStatement;
+pub use role::DefineRoleStatement;
+pub use sequence::DefineSequenceStatement;
 pub use table::DefineTableStatement;
 pub use user::DefineUserStatement;
 
@@ -35,7 +39,7 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 3)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -52,6 +56,10 @@ pub enum DefineStatement {
 	User(DefineUserStatement),
 	Model(DefineModelStatement),
 	Access(DefineAccessStatement),
+	#[revision(start = 2)]
+	Role(DefineRoleStatement),
+	#[revision(start = 3)]
+	Sequence(DefineSequenceStatement),
 }
 
 impl DefineStatement {
@@ -78,8 +86,10 @@ impl DefineStatement {
 			Self::Index(ref v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Analyzer(ref v) => v.compute(ctx, opt, doc).await,
 			Self::User(ref v) => v.compute(ctx, opt, doc).await,
-			Self::Model(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Model(ref v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Access(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Role(ref v) => v.compute(ctx, opt, doc).await,
+			Self::Sequence(ref v) => v.compute(ctx, opt, doc).await,
 		}
 	}
 }
@@ -99,6 +109,8 @@ impl Display for DefineStatement {
 			Self::Analyzer(v) => Display::fmt(v, f),
 			Self::Model(v) => Display::fmt(v, f),
 			Self::Access(v) => Display::fmt(v, f),
+			Self::Role(v) => Display::fmt(v, f),
+			Self::Sequence(v) => Display::fmt(v, f),
 		}
 	}
 }
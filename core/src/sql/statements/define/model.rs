@@ -3,15 +3,16 @@ use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
-use crate::sql::fmt::{is_pretty, pretty_indent};
+use crate::sql::fmt::{is_pretty, pretty_indent, Fmt};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::{Base, Ident, Permission, Strand, Value};
 use derive::Store;
+use reblessive::tree::Stk;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
-use std::fmt::{self, Write};
+use std::fmt::{self, Display, Write};
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -25,18 +26,94 @@ pub struct DefineModelStatement {
 	pub if_not_exists: bool,
 	#[revision(start = 3)]
 	pub overwrite: bool,
+	#[revision(start = 4)]
+	pub format: ModelFormat,
+	#[revision(start = 4)]
+	pub inputs: Vec<ModelTensor>,
+	#[revision(start = 4)]
+	pub outputs: Vec<ModelTensor>,
+	/// The model file content, which is moved to the object store when the model is defined
+	#[revision(start = 4)]
+	pub content: Option<Value>,
+}
+
+/// The file format of a machine learning model
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ModelFormat {
+	/// A SurrealML file, imported with the `/ml/import` endpoint
+	#[default]
+	Surml,
+	/// A raw ONNX file, with declared input and output tensors
+	Onnx,
+}
+
+impl ModelFormat {
+	/// The file extension of models in this format
+	pub fn extension(&self) -> &'static str {
+		match self {
+			Self::Surml => "surml",
+			Self::Onnx => "onnx",
+		}
+	}
+}
+
+/// A named input or output tensor of a machine learning model
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ModelTensor {
+	pub name: Ident,
+	pub shape: Vec<u64>,
+}
+
+impl ModelTensor {
+	/// The number of values in this tensor, if its shape is valid
+	pub fn size(&self) -> Option<usize> {
+		self.shape
+			.iter()
+			.try_fold(1usize, |acc, v| acc.checked_mul(usize::try_from(*v).ok()?))
+			// Tensors can not be empty, nor larger than the address space
+			.filter(|v| *v > 0 && *v <= isize::MAX as usize)
+	}
+}
+
+impl Display for ModelTensor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} [", self.name)?;
+		for (i, v) in self.shape.iter().enumerate() {
+			if i != 0 {
+				f.write_str(", ")?;
+			}
+			write!(f, "{v}")?;
+		}
+		f.write_char(']')
+	}
+}
+
+impl InfoStructure for ModelTensor {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"name".to_string() => self.name.structure(),
+			"shape".to_string() => self.shape.into_iter().map(Value::from).collect::<Vec<_>>().into(),
+		})
+	}
 }
 
 impl DefineModelStatement {
 	/// Process this type returning a computed simple Value
 	pub(crate) async fn compute(
 		&self,
+		stk: &mut Stk,
 		ctx: &Context,
 		opt: &Options,
-		_doc: Option<&CursorDoc>,
+		doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Model, &Base::Db)?;
+		opt.is_allowed(Action::Define, ResourceKind::Model, &Base::Db)?;
 		// Fetch the transaction
 		let txn = ctx.tx();
 		// Check if the definition exists
@@ -49,6 +126,19 @@ impl DefineModelStatement {
 				});
 			}
 		}
+		// Check the shapes of the model tensors
+		for tensor in self.inputs.iter().chain(self.outputs.iter()) {
+			if tensor.size().is_none() {
+				return Err(Error::InvalidModel {
+					message: format!("The model tensor '{}' has an invalid shape.", tensor.name),
+				});
+			}
+		}
+		// Store the model file content
+		let hash = match &self.content {
+			Some(content) => self.upload(stk, ctx, opt, doc, content).await?,
+			None => self.hash.clone(),
+		};
 		// Process the statement
 		let key = crate::key::database::ml::new(opt.ns()?, opt.db()?, &self.name, &self.version);
 		txn.get_or_add_ns(opt.ns()?, opt.strict).await?;
@@ -56,9 +146,12 @@ impl DefineModelStatement {
 		txn.set(
 			key,
 			DefineModelStatement {
+				hash,
 				// Don't persist the `IF NOT EXISTS` clause to schema
 				if_not_exists: false,
 				overwrite: false,
+				// The model content is kept in the object store
+				content: None,
 				..self.clone()
 			},
 			None,
@@ -71,6 +164,71 @@ impl DefineModelStatement {
 	}
 }
 
+impl DefineModelStatement {
+	/// The path of the model file in the object store
+	pub fn path(&self, ns: &str, db: &str) -> String {
+		let ext = self.format.extension();
+		format!("ml/{ns}/{db}/{}-{}-{}.{ext}", self.name, self.version, self.hash)
+	}
+	/// Checks and writes the model file content to the object store, returning its hash
+	#[cfg(feature = "ml")]
+	async fn upload(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: Option<&CursorDoc>,
+		content: &Value,
+	) -> Result<String, Error> {
+		// Only raw ONNX models can be defined with their content
+		if self.format != ModelFormat::Onnx {
+			return Err(Error::InvalidModel {
+				message: String::from("Only ONNX models can be defined with a file content."),
+			});
+		}
+		// The tensors of the model must be declared
+		if self.inputs.is_empty() || self.outputs.is_empty() {
+			return Err(Error::InvalidModel {
+				message: String::from("ONNX models must declare their INPUT and OUTPUT tensors."),
+			});
+		}
+		// Compute the model file content
+		let data = match content.compute(stk, ctx, opt, doc).await? {
+			Value::Bytes(v) => v.into_inner(),
+			v => {
+				return Err(Error::InvalidModel {
+					message: format!("Expected the ONNX model content as bytes, but found {v}."),
+				})
+			}
+		};
+		// Calculate the hash of the model file
+		let hash = crate::obs::hash(&data);
+		// Insert the file data in to the store
+		let path = DefineModelStatement {
+			hash: hash.clone(),
+			..self.clone()
+		}
+		.path(opt.ns()?, opt.db()?);
+		crate::obs::put(&path, data).await?;
+		// Return the model hash
+		Ok(hash)
+	}
+	/// Models can not be stored without machine learning support
+	#[cfg(not(feature = "ml"))]
+	async fn upload(
+		&self,
+		_stk: &mut Stk,
+		_ctx: &Context,
+		_opt: &Options,
+		_doc: Option<&CursorDoc>,
+		_content: &Value,
+	) -> Result<String, Error> {
+		Err(Error::InvalidModel {
+			message: String::from("Machine learning computation is not enabled."),
+		})
+	}
+}
+
 impl fmt::Display for DefineModelStatement {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "DEFINE MODEL")?;
@@ -81,6 +239,18 @@ impl fmt::Display for DefineModelStatement {
 			write!(f, " OVERWRITE")?
 		}
 		write!(f, " ml::{}<{}>", self.name, self.version)?;
+		if self.format == ModelFormat::Onnx {
+			write!(f, " ONNX")?;
+			if let Some(ref v) = self.content {
+				write!(f, " CONTENT {v}")?;
+			}
+			if !self.inputs.is_empty() {
+				write!(f, " INPUT {}", Fmt::comma_separated(&self.inputs))?;
+			}
+			if !self.outputs.is_empty() {
+				write!(f, " OUTPUT {}", Fmt::comma_separated(&self.outputs))?;
+			}
+		}
 		if let Some(comment) = self.comment.as_ref() {
 			write!(f, " COMMENT {}", comment)?;
 		}
@@ -100,6 +270,9 @@ impl InfoStructure for DefineModelStatement {
 		Value::from(map! {
 			"name".to_string() => self.name.structure(),
 			"version".to_string() => self.version.into(),
+			"format".to_string() => self.format.extension().into(),
+			"inputs".to_string(), if !self.inputs.is_empty() => self.inputs.into_iter().map(InfoStructure::structure).collect::<Vec<_>>().into(),
+			"outputs".to_string(), if !self.outputs.is_empty() => self.outputs.into_iter().map(InfoStructure::structure).collect::<Vec<_>>().into(),
 			"permissions".to_string() => self.permissions.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 		})
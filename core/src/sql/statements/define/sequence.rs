@@ -0,0 +1,131 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::iam::{Action, ResourceKind};
+use crate::sql::statements::info::InfoStructure;
+use crate::sql::{Base, Ident, Permission, Strand, Uuid, Value};
+use derive::Store;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DefineSequenceStatement {
+	pub name: Ident,
+	pub batch: u64,
+	pub start: i64,
+	pub comment: Option<Strand>,
+	/// Who can take values from the sequence
+	pub permissions: Permission,
+	pub if_not_exists: bool,
+	pub overwrite: bool,
+	/// Identifies this definition of the sequence, so that the values
+	/// allocated to a previous definition are never handed out again
+	pub generation: Uuid,
+}
+
+impl Default for DefineSequenceStatement {
+	fn default() -> Self {
+		Self {
+			name: Ident::default(),
+			batch: 1000,
+			start: 0,
+			comment: None,
+			permissions: Permission::None,
+			if_not_exists: false,
+			overwrite: false,
+			generation: Uuid::default(),
+		}
+	}
+}
+
+impl DefineSequenceStatement {
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		ctx: &Context,
+		opt: &Options,
+		_doc: Option<&CursorDoc>,
+	) -> Result<Value, Error> {
+		// Allowed to run?
+		opt.is_allowed(Action::Define, ResourceKind::Sequence, &Base::Db)?;
+		// Fetch the transaction
+		let txn = ctx.tx();
+		// Get the NS and DB
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		// Check if the definition exists
+		let existing = txn.get_db_sequence(ns, db, &self.name).await.ok();
+		if existing.is_some() {
+			if self.if_not_exists {
+				return Ok(Value::None);
+			} else if !self.overwrite {
+				return Err(Error::SqAlreadyExists {
+					value: self.name.to_string(),
+				});
+			}
+		}
+		// Process the statement
+		let key = crate::key::database::sq::new(ns, db, &self.name);
+		txn.get_or_add_ns(ns, opt.strict).await?;
+		txn.get_or_add_db(ns, db, opt.strict).await?;
+		txn.set(
+			key,
+			DefineSequenceStatement {
+				// Don't persist the `IF NOT EXISTS` clause to schema
+				if_not_exists: false,
+				overwrite: false,
+				// Keep handing out the values of an overwritten sequence
+				generation: match &existing {
+					Some(sq) => sq.generation,
+					None => Uuid::new_v4(),
+				},
+				..self.clone()
+			},
+			None,
+		)
+		.await?;
+		// A new sequence starts from its start value
+		if existing.is_none() {
+			let key = crate::key::database::sv::new(ns, db, &self.name);
+			txn.del(key).await?;
+		}
+		// Clear the cache
+		txn.clear();
+		// Ok all good
+		Ok(Value::None)
+	}
+}
+
+impl Display for DefineSequenceStatement {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "DEFINE SEQUENCE")?;
+		if self.if_not_exists {
+			write!(f, " IF NOT EXISTS")?
+		}
+		if self.overwrite {
+			write!(f, " OVERWRITE")?
+		}
+		write!(f, " {} BATCH {} START {}", self.name, self.batch, self.start)?;
+		if let Some(ref v) = self.comment {
+			write!(f, " COMMENT {v}")?
+		}
+		write!(f, " PERMISSIONS {}", self.permissions)?;
+		Ok(())
+	}
+}
+
+impl InfoStructure for DefineSequenceStatement {
+	fn structure(self) -> Value {
+		Value::from(map! {
+			"name".to_string() => self.name.structure(),
+			"batch".to_string() => Value::from(self.batch),
+			"start".to_string() => Value::from(self.start),
+			"permissions".to_string() => self.permissions.structure(),
+			"comment".to_string(), if let Some(v) = self.comment => v.into(),
+		})
+	}
+}
@@ -1,4 +1,5 @@
 use super::DefineFieldStatement;
+use crate::cnf::NORMAL_FETCH_SIZE;
 use crate::ctx::Context;
 use crate::dbs::{Force, Options};
 use crate::doc::CursorDoc;
@@ -9,8 +10,8 @@ use crate::sql::fmt::{is_pretty, pretty_indent};
 use crate::sql::paths::{IN, OUT};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::{
-	changefeed::ChangeFeed, statements::UpdateStatement, Base, Ident, Output, Permissions, Strand,
-	Value, Values, View,
+	changefeed::ChangeFeed, statements::UpdateStatement, Base, Expire, Ident, Output, Permissions,
+	Strand, Value, Values, View,
 };
 use crate::sql::{Idiom, Kind, TableType};
 use derive::Store;
@@ -20,7 +21,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Write};
 use std::sync::Arc;
 
-#[revisioned(revision = 4)]
+#[revisioned(revision = 5)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -39,6 +40,8 @@ pub struct DefineTableStatement {
 	pub kind: TableType,
 	#[revision(start = 4)]
 	pub overwrite: bool,
+	#[revision(start = 5)]
+	pub expire: Option<Expire>,
 }
 
 impl DefineTableStatement {
@@ -50,11 +53,12 @@ impl DefineTableStatement {
 		doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+		opt.is_allowed(Action::Define, ResourceKind::Table, &Base::Db)?;
 		// Fetch the transaction
 		let txn = ctx.tx();
 		// Check if the definition exists
-		if txn.get_tb(opt.ns()?, opt.db()?, &self.name).await.is_ok() {
+		let existing = txn.get_tb(opt.ns()?, opt.db()?, &self.name).await.ok();
+		if existing.is_some() {
 			if self.if_not_exists {
 				return Ok(Value::None);
 			} else if !self.overwrite {
@@ -81,6 +85,9 @@ impl DefineTableStatement {
 		txn.set(key, &dt, None).await?;
 		// Add table relational fields
 		self.add_in_out_fields(&txn, opt).await?;
+		// Recalculate the expiry data
+		let expire = existing.and_then(|v| v.expire.clone());
+		dt.set_expiry(&txn, opt, expire.as_ref()).await?;
 		// Clear the cache
 		txn.clear();
 		// Record definition change
@@ -170,6 +177,67 @@ impl DefineTableStatement {
 		}
 		Ok(())
 	}
+	/// Used to recalculate the expiry times of the table records, when the expiry changes
+	pub async fn set_expiry(
+		&self,
+		txn: &Transaction,
+		opt: &Options,
+		old: Option<&Expire>,
+	) -> Result<(), Error> {
+		let (ns, db) = (opt.ns()?, opt.db()?);
+		// Check if the expiry has changed
+		if self.expire.as_ref() == old {
+			return Ok(());
+		}
+		// Remove the expiry data
+		let Some(expire) = &self.expire else {
+			let beg = crate::key::table::ex::prefix(ns, db, &self.name);
+			let end = crate::key::table::ex::suffix(ns, db, &self.name, u64::MAX);
+			txn.delr(beg..end).await?;
+			let beg = crate::key::table::ei::prefix(ns, db, &self.name);
+			let end = crate::key::table::ei::suffix(ns, db, &self.name);
+			txn.delr(beg..end).await?;
+			return Ok(());
+		};
+		// Recalculate the expiry time of every record
+		let beg = crate::key::thing::prefix(ns, db, &self.name);
+		let end = crate::key::thing::suffix(ns, db, &self.name);
+		let mut next = Some(beg..end);
+		while let Some(rng) = next {
+			let batch = txn.batch(rng, *NORMAL_FETCH_SIZE, true).await?;
+			next = batch.next;
+			for (k, v) in batch.values.into_iter() {
+				let key: crate::key::thing::Thing = (&k).into();
+				let val: Value = (&v).into();
+				// Remove any previous expiry time
+				let ei = crate::key::table::ei::new(ns, db, &self.name, &key.id);
+				let prev = match txn.get(ei.clone(), None).await? {
+					Some(v) => <[u8; 8]>::try_from(v.as_slice()).ok().map(u64::from_be_bytes),
+					None => None,
+				};
+				if let Some(ts) = prev {
+					txn.del(crate::key::table::ex::new(ns, db, &self.name, ts, &key.id)).await?;
+				}
+				// Keep counting from the last write of the record, when it is known
+				let ts = match (&expire.field, old, prev) {
+					(None, Some(old), Some(prev)) if old.field.is_none() => {
+						Some(prev.saturating_sub(old.nanos()).saturating_add(expire.nanos()))
+					}
+					_ => expire.expiry(&val),
+				};
+				// Store the new expiry time
+				match ts {
+					Some(ts) => {
+						let ex = crate::key::table::ex::new(ns, db, &self.name, ts, &key.id);
+						txn.set(ex, vec![], None).await?;
+						txn.set(ei, ts.to_be_bytes().to_vec(), None).await?;
+					}
+					None => txn.del(ei).await?,
+				}
+			}
+		}
+		Ok(())
+	}
 }
 
 impl Display for DefineTableStatement {
@@ -225,6 +293,9 @@ impl Display for DefineTableStatement {
 		if let Some(ref v) = self.changefeed {
 			write!(f, " {v}")?;
 		}
+		if let Some(ref v) = self.expire {
+			write!(f, " {v}")?;
+		}
 		let _indent = if is_pretty() {
 			Some(pretty_indent())
 		} else {
@@ -245,6 +316,7 @@ impl InfoStructure for DefineTableStatement {
 			"kind".to_string() => self.kind.structure(),
 			"view".to_string(), if let Some(v) = self.view => v.structure(),
 			"changefeed".to_string(), if let Some(v) = self.changefeed => v.structure(),
+			"expire".to_string(), if let Some(v) = self.expire => v.structure(),
 			"permissions".to_string() => self.permissions.structure(),
 			"comment".to_string(), if let Some(v) = self.comment => v.into(),
 		})
@@ -2,7 +2,7 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
-use crate::iam::{Action, ResourceKind};
+use crate::iam::{Action, ResourceKind, Role};
 use crate::sql::statements::info::InfoStructure;
 use crate::sql::{
 	escape::quote_str, fmt::Fmt, user::UserDuration, Base, Duration, Ident, Strand, Value,
@@ -16,6 +16,7 @@ use rand::{distributions::Alphanumeric, rngs::OsRng, Rng};
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display};
+use std::str::FromStr;
 
 #[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
@@ -99,6 +100,24 @@ impl DefineUserStatement {
 		self.duration.session = duration;
 	}
 
+	/// Checks that the custom roles of the user are defined on the same level
+	async fn check_roles(&self, ctx: &Context, opt: &Options) -> Result<(), Error> {
+		let txn = ctx.tx();
+		for role in self.roles.iter() {
+			// Built-in roles are always defined
+			if Role::from_str(role).is_ok() {
+				continue;
+			}
+			match self.base {
+				Base::Root => txn.get_root_role(role).await?,
+				Base::Ns => txn.get_ns_role(opt.ns()?, role).await?,
+				Base::Db => txn.get_db_role(opt.ns()?, opt.db()?, role).await?,
+				_ => return Err(Error::InvalidLevel(self.base.to_string())),
+			};
+		}
+		Ok(())
+	}
+
 	/// Process this type returning a computed simple Value
 	pub(crate) async fn compute(
 		&self,
@@ -107,7 +126,7 @@ impl DefineUserStatement {
 		_doc: Option<&CursorDoc>,
 	) -> Result<Value, Error> {
 		// Allowed to run?
-		opt.is_allowed(Action::Edit, ResourceKind::Actor, &self.base)?;
+		opt.is_allowed(Action::Define, ResourceKind::Actor, &self.base)?;
 		// Check the statement type
 		match self.base {
 			Base::Root => {
@@ -123,6 +142,8 @@ impl DefineUserStatement {
 						});
 					}
 				}
+				// Check that the custom roles are defined
+				self.check_roles(ctx, opt).await?;
 				// Process the statement
 				let key = crate::key::root::us::new(&self.name);
 				txn.set(
@@ -155,6 +176,8 @@ impl DefineUserStatement {
 						});
 					}
 				}
+				// Check that the custom roles are defined
+				self.check_roles(ctx, opt).await?;
 				// Process the statement
 				let key = crate::key::namespace::us::new(opt.ns()?, &self.name);
 				txn.get_or_add_ns(opt.ns()?, opt.strict).await?;
@@ -189,6 +212,8 @@ impl DefineUserStatement {
 						});
 					}
 				}
+				// Check that the custom roles are defined
+				self.check_roles(ctx, opt).await?;
 				// Process the statement
 				let key = crate::key::database::us::new(opt.ns()?, opt.db()?, &self.name);
 				txn.get_or_add_ns(opt.ns()?, opt.strict).await?;
@@ -231,7 +256,15 @@ impl Display for DefineUserStatement {
 			self.base,
 			quote_str(&self.hash),
 			Fmt::comma_separated(
-				&self.roles.iter().map(|r| r.to_string().to_uppercase()).collect::<Vec<String>>()
+				&self
+					.roles
+					.iter()
+					.map(|r| match Role::from(r).is_builtin() {
+						// Custom role names are case-sensitive
+						true => r.to_string().to_uppercase(),
+						false => r.to_string(),
+					})
+					.collect::<Vec<String>>()
 			),
 		)?;
 		// Always print relevant durations so defaults can be changed in the future
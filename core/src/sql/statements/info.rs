@@ -58,6 +58,7 @@ impl InfoStatement {
 						"accesses".to_string() => process(txn.all_root_accesses().await?.iter().map(|v| v.redacted()).collect()),
 						"namespaces".to_string() => process(txn.all_ns().await?),
 						"nodes".to_string() => process(txn.all_nodes().await?),
+						"roles".to_string() => process(txn.all_root_roles().await?),
 						"users".to_string() => process(txn.all_root_users().await?),
 					}),
 					false => Value::from(map! {
@@ -82,6 +83,13 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_root_roles().await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"users".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_root_users().await?.iter() {
@@ -104,6 +112,7 @@ impl InfoStatement {
 					true => Value::from(map! {
 						"accesses".to_string() => process(txn.all_ns_accesses(ns).await?.iter().map(|v| v.redacted()).collect()),
 						"databases".to_string() => process(txn.all_db(ns).await?),
+						"roles".to_string() => process(txn.all_ns_roles(ns).await?),
 						"users".to_string() => process(txn.all_ns_users(ns).await?),
 					}),
 					false => Value::from(map! {
@@ -121,6 +130,13 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_ns_roles(ns).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"users".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_ns_users(ns).await?.iter() {
@@ -147,6 +163,8 @@ impl InfoStatement {
 						"functions".to_string() => process(txn.all_db_functions(ns, db).await?),
 						"models".to_string() => process(txn.all_db_models(ns, db).await?),
 						"params".to_string() => process(txn.all_db_params(ns, db).await?),
+						"roles".to_string() => process(txn.all_db_roles(ns, db).await?),
+						"sequences".to_string() => process(txn.all_db_sequences(ns, db).await?),
 						"tables".to_string() => process(txn.all_tb(ns, db).await?),
 						"users".to_string() => process(txn.all_db_users(ns, db).await?),
 					}),
@@ -186,6 +204,20 @@ impl InfoStatement {
 							}
 							out.into()
 						},
+						"roles".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_db_roles(ns, db).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
+						"sequences".to_string() => {
+							let mut out = Object::default();
+							for v in txn.all_db_sequences(ns, db).await?.iter() {
+								out.insert(v.name.to_raw(), v.to_string().into());
+							}
+							out.into()
+						},
 						"tables".to_string() => {
 							let mut out = Object::default();
 							for v in txn.all_tb(ns, db).await?.iter() {
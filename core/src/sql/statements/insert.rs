@@ -1,10 +1,11 @@
 use crate::ctx::Context;
 use crate::dbs::{Iterable, Iterator, Options, Statement};
-use crate::doc::CursorDoc;
+use crate::doc::{CursorDoc, Document};
 use crate::err::Error;
+use crate::iam::{Action, ResourceKind};
 use crate::sql::paths::IN;
 use crate::sql::paths::OUT;
-use crate::sql::{Data, Id, Output, Table, Thing, Timeout, Value, Version};
+use crate::sql::{Base, Data, Id, Output, Table, Thing, Timeout, Value, Version};
 use derive::Store;
 use reblessive::tree::Stk;
 use revision::revisioned;
@@ -62,6 +63,30 @@ impl InsertStatement {
 				}
 			},
 		};
+		// Versioned records are imported as they were exported
+		if opt.import && version.is_some() {
+			if let Data::SingleExpression(v) = &self.data {
+				// Check that the records can be written without permissions
+				opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+				// Assign the statement
+				let stm = Statement::from(self);
+				// Import each of the records
+				let records = match v.compute(stk, ctx, opt, doc).await? {
+					Value::Array(v) => v.0,
+					v @ Value::Object(_) => vec![v],
+					v => {
+						return Err(Error::InsertStatement {
+							value: v.to_string(),
+						})
+					}
+				};
+				for v in records {
+					let id = gen_id(&v, &into)?;
+					Document::import_version(stk, ctx, opt, &stm, id, v, self.relation).await?;
+				}
+				return Ok(Value::None);
+			}
+		}
 		// Parse the data expression
 		match &self.data {
 			// Check if this is a traditional statement
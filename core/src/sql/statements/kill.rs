@@ -2,8 +2,9 @@ use crate::ctx::Context;
 use crate::dbs::Options;
 use crate::doc::CursorDoc;
 use crate::err::Error;
+use crate::iam::{Action, ResourceKind};
 use crate::kvs::Live;
-use crate::sql::Value;
+use crate::sql::{Base, Value};
 use derive::Store;
 use reblessive::tree::Stk;
 use revision::revisioned;
@@ -33,6 +34,10 @@ impl KillStatement {
 		opt.realtime()?;
 		// Valid options?
 		opt.valid_for_db()?;
+		// Allowed to run? Record users can always kill their own live queries
+		if !opt.auth.is_anon() && !opt.auth.is_record() {
+			opt.is_allowed(Action::Kill, ResourceKind::Table, &Base::Db)?;
+		}
 		// Resolve live query id
 		let lid = match self.id.compute(stk, ctx, opt, None).await?.convert_to_uuid() {
 			Err(_) => {
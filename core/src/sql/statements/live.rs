@@ -12,7 +12,7 @@ use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -23,6 +23,10 @@ pub struct LiveStatement {
 	pub what: Value,
 	pub cond: Option<Cond>,
 	pub fetch: Option<Fetchs>,
+	// Whether notifications for this live query should
+	// also include the record content before the change.
+	#[revision(start = 2)]
+	pub before: bool,
 	// When a live query is created, we must also store the
 	// authenticated session of the user who made the query,
 	// so we can check it later when sending notifications.
@@ -54,6 +58,7 @@ impl LiveStatement {
 		what: Value,
 		cond: Option<Cond>,
 		fetch: Option<Fetchs>,
+		before: bool,
 	) -> Self {
 		LiveStatement {
 			id: Uuid::new_v4(),
@@ -62,6 +67,7 @@ impl LiveStatement {
 			what,
 			cond,
 			fetch,
+			before,
 			..Default::default()
 		}
 	}
@@ -135,6 +141,9 @@ impl LiveStatement {
 impl fmt::Display for LiveStatement {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "LIVE SELECT {} FROM {}", self.expr, self.what)?;
+		if self.before {
+			write!(f, " WITH BEFORE")?
+		}
 		if let Some(ref v) = self.cond {
 			write!(f, " {v}")?
 		}
@@ -152,6 +161,7 @@ impl InfoStructure for LiveStatement {
 			"what".to_string() => self.what.structure(),
 			"cond".to_string(), if let Some(v) = self.cond => v.structure(),
 			"fetch".to_string(), if let Some(v) = self.fetch => v.structure(),
+			"before".to_string(), if self.before => true.into(),
 		})
 	}
 }
@@ -231,6 +241,7 @@ mod tests {
 			Notification::new(
 				live_id,
 				Action::Create,
+				Value::parse(&format!("{}:test_true", tb)),
 				Value::parse(&format!(
 					"{{
 						id: {}:test_true,
@@ -63,13 +63,13 @@ pub use self::alter::{AlterStatement, AlterTableStatement};
 pub use self::define::{
 	DefineAccessStatement, DefineAnalyzerStatement, DefineDatabaseStatement, DefineEventStatement,
 	DefineFieldStatement, DefineFunctionStatement, DefineIndexStatement, DefineModelStatement,
-	DefineNamespaceStatement, DefineParamStatement, DefineStatement, DefineTableStatement,
-	DefineUserStatement,
+	DefineNamespaceStatement, DefineParamStatement, DefineRoleStatement, DefineSequenceStatement,
+	DefineStatement, DefineTableStatement, DefineUserStatement, ModelFormat, ModelTensor,
 };
 
 pub use self::remove::{
 	RemoveAccessStatement, RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
 	RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement, RemoveModelStatement,
-	RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-	RemoveUserStatement,
+	RemoveNamespaceStatement, RemoveParamStatement, RemoveRoleStatement, RemoveSequenceStatement,
+	RemoveStatement, RemoveTableStatement, RemoveUserStatement,
 };
@@ -24,7 +24,7 @@ impl RemoveEventStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Event, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Event, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Get the definition
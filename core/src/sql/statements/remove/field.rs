@@ -1,5 +1,6 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
+use crate::doc::reference::references;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
 use crate::sql::{Base, Ident, Idiom, Value};
@@ -24,7 +25,7 @@ impl RemoveFieldStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Field, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Field, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Get the field name
@@ -34,6 +35,10 @@ impl RemoveFieldStatement {
 			// Delete the definition
 			let key = crate::key::table::fd::new(opt.ns()?, opt.db()?, &fd.what, &na);
 			txn.del(key).await?;
+			// Remove the references held in this field
+			if fd.reference.is_some() {
+				references(&txn, opt.ns()?, opt.db()?, &fd.what, &fd.name, false).await?;
+			}
 			// Clear the cache
 			txn.clear();
 			// Ok all good
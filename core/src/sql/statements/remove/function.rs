@@ -23,7 +23,7 @@ impl RemoveFunctionStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Function, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Function, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Get the definition
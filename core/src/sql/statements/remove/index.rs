@@ -24,7 +24,7 @@ impl RemoveIndexStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Index, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Index, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Clear the index store cache
@@ -23,7 +23,7 @@ impl RemoveNamespaceStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Namespace, &Base::Root)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Namespace, &Base::Root)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Remove the index stores
@@ -23,7 +23,7 @@ impl RemoveParamStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Parameter, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Parameter, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Get the definition
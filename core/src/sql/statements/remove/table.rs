@@ -1,5 +1,6 @@
 use crate::ctx::Context;
 use crate::dbs::Options;
+use crate::doc::reference::references;
 use crate::err::Error;
 use crate::iam::{Action, ResourceKind};
 use crate::sql::{Base, Ident, Value};
@@ -23,13 +24,19 @@ impl RemoveTableStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Table, &Base::Db)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Table, &Base::Db)?;
 			// Get the transaction
 			let txn = ctx.tx();
 			// Remove the index stores
 			ctx.get_index_stores().table_removed(&txn, opt.ns()?, opt.db()?, &self.name).await?;
 			// Get the defined table
 			let tb = txn.get_tb(opt.ns()?, opt.db()?, &self.name).await?;
+			// Remove the references which the records of this table hold to other records
+			for fd in txn.all_tb_fields(opt.ns()?, opt.db()?, &self.name).await?.iter() {
+				if fd.reference.is_some() {
+					references(&txn, opt.ns()?, opt.db()?, &self.name, &fd.name, false).await?;
+				}
+			}
 			// Delete the definition
 			let key = crate::key::database::tb::new(opt.ns()?, opt.db()?, &self.name);
 			txn.del(key).await?;
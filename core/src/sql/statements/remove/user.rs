@@ -24,7 +24,7 @@ impl RemoveUserStatement {
 	pub(crate) async fn compute(&self, ctx: &Context, opt: &Options) -> Result<Value, Error> {
 		let future = async {
 			// Allowed to run?
-			opt.is_allowed(Action::Edit, ResourceKind::Actor, &self.base)?;
+			opt.is_allowed(Action::Remove, ResourceKind::Actor, &self.base)?;
 			// Check the statement type
 			match self.base {
 				Base::Root => {
@@ -4,7 +4,7 @@ use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::idx::planner::QueryPlanner;
 use crate::sql::{
-	Cond, Explain, Fetchs, Field, Fields, Groups, Id, Idioms, Limit, Orders, Splits, Start,
+	After, Cond, Explain, Fetchs, Field, Fields, Groups, Id, Idioms, Limit, Orders, Splits, Start,
 	Timeout, Value, Values, Version, With,
 };
 use derive::Store;
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::sync::Arc;
 
-#[revisioned(revision = 3)]
+#[revisioned(revision = 4)]
 #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -38,6 +38,8 @@ pub struct SelectStatement {
 	pub explain: Option<Explain>,
 	#[revision(start = 3)]
 	pub tempfiles: bool,
+	#[revision(start = 4)]
+	pub after: Option<After>,
 }
 
 impl SelectStatement {
@@ -85,6 +87,13 @@ impl SelectStatement {
 		);
 		// Extract the limit
 		let limit = i.setup_limit(stk, ctx, &opt, &stm).await?;
+		// Extract the start
+		i.setup_start(stk, ctx, &opt, &stm).await?;
+		// Paginate the results after any cursor
+		if let Some(after) = &self.after {
+			self.check_after()?;
+			i.paginate(after.process(stk, ctx, &opt, doc).await?);
+		}
 		// Used for ONLY: is the limit 1?
 		let limit_is_one_or_zero = match limit {
 			Some(l) => l <= 1,
@@ -102,6 +111,12 @@ impl SelectStatement {
 					if self.only && !limit_is_one_or_zero {
 						return Err(Error::SingleOnlyOutput);
 					}
+					// Aggregates over a single table may not need to iterate it
+					if self.what.0.len() == 1
+						&& planner.add_aggregate(ctx, self, &t, &mut i).await?
+					{
+						continue;
+					}
 					planner.add_iterables(stk, ctx, t, &mut i).await?;
 				}
 				Value::Thing(v) => match &v.id {
@@ -156,20 +171,51 @@ impl SelectStatement {
 		}
 		let ctx = ctx.freeze();
 		// Output the results
-		match i.output(stk, &ctx, &opt, &stm).await? {
+		let res = match i.output(stk, &ctx, &opt, &stm).await? {
 			// This is a single record result
 			Value::Array(mut a) if self.only => match a.len() {
 				// There were no results
-				0 => Ok(Value::None),
+				0 => Value::None,
 				// There was exactly one result
-				1 => Ok(a.remove(0)),
+				1 => a.remove(0),
 				// There were no results
-				_ => Err(Error::SingleOnlyOutput),
+				_ => return Err(Error::SingleOnlyOutput),
 			},
 			// This is standard query result
-			v => Ok(v),
+			v => v,
+		};
+		// Output the cursor of the next page
+		match self.after {
+			Some(_) => Ok(Value::from(map! {
+				"cursor".to_string() => match i.cursor() {
+					Some(v) => v.encode()?.into(),
+					None => Value::None,
+				},
+				"result".to_string() => res,
+			})),
+			None => Ok(res),
 		}
 	}
+
+	/// Check that the results can be paginated with a cursor
+	fn check_after(&self) -> Result<(), Error> {
+		let clause = if self.split.is_some() {
+			"SPLIT"
+		} else if self.group.is_some() {
+			"GROUP"
+		} else if self.order.is_some() {
+			"ORDER"
+		} else if self.parallel {
+			"PARALLEL"
+		} else if self.explain.is_some() {
+			"EXPLAIN"
+		} else {
+			return Ok(());
+		};
+		Err(Error::InvalidAfter {
+			clause: clause.to_string(),
+		})
+	}
 }
 
 impl fmt::Display for SelectStatement {
@@ -198,6 +244,9 @@ impl fmt::Display for SelectStatement {
 		if let Some(ref v) = self.order {
 			write!(f, " {v}")?
 		}
+		if let Some(ref v) = self.after {
+			write!(f, " {v}")?
+		}
 		if let Some(ref v) = self.limit {
 			write!(f, " {v}")?
 		}
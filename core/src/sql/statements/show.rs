@@ -55,6 +55,15 @@ impl ShowStatement {
 		opt.is_allowed(Action::View, ResourceKind::Table, &Base::Db)?;
 		// Get the transaction
 		let txn = ctx.tx();
+		// Check that no changes after the versionstamp have been garbage collected,
+		// unless the changes are read from the start of the change feed
+		if let ShowSince::Versionstamp(since @ 1..) = self.since {
+			if let Some(vs) = crate::cf::watermark(&txn, opt.ns()?, opt.db()?).await? {
+				if conv::versionstamp_to_u64(&vs) > since {
+					return Err(Error::ChangefeedGap(since.into()));
+				}
+			}
+		}
 		// Process the show query
 		let r = crate::cf::read(
 			&txn,
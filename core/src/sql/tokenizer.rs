@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Display;
 
-#[revisioned(revision = 1)]
+#[revisioned(revision = 2)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -12,6 +12,10 @@ pub enum Tokenizer {
 	Camel,
 	Class,
 	Punct,
+	#[revision(start = 2)]
+	Unicode,
+	#[revision(start = 2)]
+	Cjk,
 }
 
 impl Display for Tokenizer {
@@ -21,6 +25,8 @@ impl Display for Tokenizer {
 			Self::Camel => "CAMEL",
 			Self::Class => "CLASS",
 			Self::Punct => "PUNCT",
+			Self::Unicode => "UNICODE",
+			Self::Cjk => "CJK",
 		})
 	}
 }
@@ -419,10 +419,16 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("http::patch") => PathKind::Function,
 		UniCase::ascii("http::delete") => PathKind::Function,
 		//
+		UniCase::ascii("record::refs") => PathKind::Function,
+		//
 		UniCase::ascii("search::analyze") => PathKind::Function,
 		UniCase::ascii("search::score") => PathKind::Function,
 		UniCase::ascii("search::highlight") => PathKind::Function,
 		UniCase::ascii("search::offsets") => PathKind::Function,
+		UniCase::ascii("search::rrf") => PathKind::Function,
+		UniCase::ascii("search::linear") => PathKind::Function,
+		//
+		UniCase::ascii("sequence::nextval") => PathKind::Function,
 		//
 		UniCase::ascii("sleep") => PathKind::Function,
 		//
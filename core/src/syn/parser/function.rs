@@ -52,6 +52,34 @@ impl Parser<'_> {
 	///
 	/// Expects `ml` to already be called.
 	pub async fn parse_model(&mut self, ctx: &mut Stk) -> ParseResult<Model> {
+		let (name, version) = self.parse_model_name()?;
+		let start = expected!(self, t!("(")).span;
+		let mut args = Vec::new();
+		loop {
+			if self.eat(t!(")")) {
+				break;
+			}
+
+			let arg = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
+			args.push(arg);
+
+			if !self.eat(t!(",")) {
+				self.expect_closing_delimiter(t!(")"), start)?;
+				break;
+			}
+		}
+		Ok(Model {
+			name,
+			version,
+			args,
+		})
+	}
+
+	/// Parses the name and version of a model
+	///
+	/// # Parser State
+	/// Expects the parser to have already eaten the `ml` keyword
+	pub fn parse_model_name(&mut self) -> ParseResult<(String, String)> {
 		expected!(self, t!("::"));
 		let mut name = self.next_token_value::<Ident>()?.0;
 		while self.eat(t!("::")) {
@@ -96,26 +124,7 @@ impl Parser<'_> {
 
 		self.expect_closing_delimiter(t!(">"), start)?;
 
-		let start = expected!(self, t!("(")).span;
-		let mut args = Vec::new();
-		loop {
-			if self.eat(t!(")")) {
-				break;
-			}
-
-			let arg = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
-			args.push(arg);
-
-			if !self.eat(t!(",")) {
-				self.expect_closing_delimiter(t!(")"), start)?;
-				break;
-			}
-		}
-		Ok(Model {
-			name,
-			version: format!("{}.{}.{}", major, minor, patch),
-			args,
-		})
+		Ok((name, format!("{}.{}.{}", major, minor, patch)))
 	}
 }
 
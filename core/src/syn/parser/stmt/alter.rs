@@ -92,6 +92,14 @@ impl Parser<'_> {
 						res.changefeed = Some(Some(self.parse_changefeed()?));
 					}
 				}
+				t!("EXPIRE") => {
+					self.pop_peek();
+					if self.eat(t!("NONE")) {
+						res.expire = Some(None);
+					} else {
+						res.expire = Some(Some(self.parse_expire(ctx).await?));
+					}
+				}
 				_ => break,
 			}
 		}
@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use reblessive::Stk;
 
 use crate::cnf::EXPERIMENTAL_BEARER_ACCESS;
+use crate::iam::{Action, Grant, ResourceKind};
 use crate::sql::access_type::JwtAccessVerify;
 use crate::sql::index::HnswParams;
 use crate::{
@@ -12,12 +15,13 @@ use crate::{
 		statements::{
 			DefineAccessStatement, DefineAnalyzerStatement, DefineDatabaseStatement,
 			DefineEventStatement, DefineFieldStatement, DefineFunctionStatement,
-			DefineIndexStatement, DefineNamespaceStatement, DefineParamStatement, DefineStatement,
-			DefineTableStatement, DefineUserStatement,
+			DefineIndexStatement, DefineModelStatement, DefineNamespaceStatement,
+			DefineParamStatement, DefineRoleStatement, DefineSequenceStatement, DefineStatement,
+			DefineTableStatement, DefineUserStatement, ModelFormat, ModelTensor,
 		},
 		table_type,
 		tokenizer::Tokenizer,
-		user, AccessType, Ident, Idioms, Index, Kind, Param, Permissions, Scoring, Strand,
+		user, AccessType, Ident, Idioms, Index, Kind, Number, Param, Permissions, Scoring, Strand,
 		TableType, Values,
 	},
 	syn::{
@@ -38,9 +42,11 @@ impl Parser<'_> {
 			t!("DATABASE") => self.parse_define_database().map(DefineStatement::Database),
 			t!("FUNCTION") => self.parse_define_function(ctx).await.map(DefineStatement::Function),
 			t!("USER") => self.parse_define_user().map(DefineStatement::User),
+			t!("ROLE") => self.parse_define_role().map(DefineStatement::Role),
 			t!("TOKEN") => self.parse_define_token().map(DefineStatement::Access),
 			t!("SCOPE") => self.parse_define_scope(ctx).await.map(DefineStatement::Access),
 			t!("PARAM") => self.parse_define_param(ctx).await.map(DefineStatement::Param),
+			t!("SEQUENCE") => self.parse_define_sequence(ctx).await.map(DefineStatement::Sequence),
 			t!("TABLE") => self.parse_define_table(ctx).await.map(DefineStatement::Table),
 			t!("EVENT") => {
 				ctx.run(|ctx| self.parse_define_event(ctx)).await.map(DefineStatement::Event)
@@ -53,6 +59,7 @@ impl Parser<'_> {
 			}
 			t!("ANALYZER") => self.parse_define_analyzer().map(DefineStatement::Analyzer),
 			t!("ACCESS") => self.parse_define_access(ctx).await.map(DefineStatement::Access),
+			t!("MODEL") => self.parse_define_model(ctx).await.map(DefineStatement::Model),
 			x => unexpected!(self, x, "a define statement keyword"),
 		}
 	}
@@ -273,6 +280,206 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	pub fn parse_define_role(&mut self) -> ParseResult<DefineRoleStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		let name = self.next_token_value()?;
+		expected!(self, t!("ON"));
+		let base = self.parse_base(false)?;
+
+		let mut res = DefineRoleStatement {
+			name,
+			base,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		loop {
+			match self.peek_kind() {
+				t!("COMMENT") => {
+					self.pop_peek();
+					res.comment = Some(self.next_token_value()?);
+				}
+				t!("GRANT") => {
+					self.pop_peek();
+					let mut actions = vec![self.parse_grant_action()?];
+					while self.eat(t!(",")) {
+						actions.push(self.parse_grant_action()?);
+					}
+					let mut resources = Vec::new();
+					if self.eat(t!("ON")) {
+						resources.push(self.parse_grant_resource()?);
+						while self.eat(t!(",")) {
+							resources.push(self.parse_grant_resource()?);
+						}
+					}
+					res.grants.push(Grant::new(actions, resources));
+				}
+				_ => break,
+			}
+		}
+
+		Ok(res)
+	}
+
+	pub async fn parse_define_sequence(
+		&mut self,
+		ctx: &mut Stk,
+	) -> ParseResult<DefineSequenceStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		let name = self.next_token_value()?;
+
+		let mut res = DefineSequenceStatement {
+			name,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		loop {
+			match self.peek_kind() {
+				t!("BATCH") => {
+					self.pop_peek();
+					res.batch = self.next_token_value()?;
+				}
+				t!("START") => {
+					self.pop_peek();
+					let peek = self.peek_kind();
+					res.start = match self.next_token_value()? {
+						Number::Int(x) => x,
+						_ => unexpected!(self, peek, "an integer"),
+					};
+				}
+				t!("COMMENT") => {
+					self.pop_peek();
+					res.comment = Some(self.next_token_value()?);
+				}
+				t!("PERMISSIONS") => {
+					self.pop_peek();
+					res.permissions = ctx.run(|ctx| self.parse_permission_value(ctx)).await?;
+				}
+				_ => break,
+			}
+		}
+
+		Ok(res)
+	}
+
+	pub async fn parse_define_model(&mut self, ctx: &mut Stk) -> ParseResult<DefineModelStatement> {
+		let (if_not_exists, overwrite) = if self.eat(t!("IF")) {
+			expected!(self, t!("NOT"));
+			expected!(self, t!("EXISTS"));
+			(true, false)
+		} else if self.eat(t!("OVERWRITE")) {
+			(false, true)
+		} else {
+			(false, false)
+		};
+		expected!(self, t!("ml"));
+		let (name, version) = self.parse_model_name()?;
+
+		let mut res = DefineModelStatement {
+			name: Ident(name),
+			version,
+			if_not_exists,
+			overwrite,
+			..Default::default()
+		};
+
+		if self.eat(t!("ONNX")) {
+			res.format = ModelFormat::Onnx;
+		}
+
+		loop {
+			match self.peek_kind() {
+				t!("CONTENT") => {
+					self.pop_peek();
+					res.content = Some(ctx.run(|ctx| self.parse_value(ctx)).await?);
+				}
+				t!("INPUT") => {
+					self.pop_peek();
+					res.inputs = self.parse_model_tensors()?;
+				}
+				t!("OUTPUT") => {
+					self.pop_peek();
+					res.outputs = self.parse_model_tensors()?;
+				}
+				t!("COMMENT") => {
+					self.pop_peek();
+					res.comment = Some(self.next_token_value()?);
+				}
+				t!("PERMISSIONS") => {
+					self.pop_peek();
+					res.permissions = ctx.run(|ctx| self.parse_permission_value(ctx)).await?;
+				}
+				_ => break,
+			}
+		}
+
+		Ok(res)
+	}
+
+	fn parse_model_tensors(&mut self) -> ParseResult<Vec<ModelTensor>> {
+		let mut res = Vec::new();
+		loop {
+			let name = self.next_token_value()?;
+			let start = expected!(self, t!("[")).span;
+			let mut shape = Vec::new();
+			loop {
+				if self.eat(t!("]")) {
+					break;
+				}
+				shape.push(self.next_token_value()?);
+				if !self.eat(t!(",")) {
+					self.expect_closing_delimiter(t!("]"), start)?;
+					break;
+				}
+			}
+			res.push(ModelTensor {
+				name,
+				shape,
+			});
+			if !self.eat(t!(",")) {
+				break;
+			}
+		}
+		Ok(res)
+	}
+
+	fn parse_grant_action(&mut self) -> ParseResult<Action> {
+		let kind = self.peek_kind();
+		let name: Ident = self.next_token_value()?;
+		match Action::from_str(&name) {
+			Ok(x) => Ok(x),
+			Err(_) => unexpected!(self, kind, "a role action"),
+		}
+	}
+
+	fn parse_grant_resource(&mut self) -> ParseResult<ResourceKind> {
+		let kind = self.peek_kind();
+		let name: Ident = self.next_token_value()?;
+		match ResourceKind::from_str(&name) {
+			Ok(x) => Ok(x),
+			Err(_) => unexpected!(self, kind, "a resource kind"),
+		}
+	}
+
 	pub async fn parse_define_access(
 		&mut self,
 		stk: &mut Stk,
@@ -340,9 +547,18 @@ impl Parser<'_> {
 									_ => break,
 								}
 							}
-							if self.eat(t!("WITH")) {
-								expected!(self, t!("JWT"));
-								ac.jwt = self.parse_jwt()?;
+							while self.eat(t!("WITH")) {
+								match self.peek_kind() {
+									t!("JWT") => {
+										self.pop_peek();
+										ac.jwt = self.parse_jwt()?;
+									}
+									t!("REFRESH") => {
+										self.pop_peek();
+										ac.refresh = true;
+									}
+									x => unexpected!(self, x, "`JWT` or `REFRESH`"),
+								}
 							}
 							res.kind = AccessType::Record(ac);
 						}
@@ -695,6 +911,10 @@ impl Parser<'_> {
 					self.pop_peek();
 					res.changefeed = Some(self.parse_changefeed()?);
 				}
+				t!("EXPIRE") => {
+					self.pop_peek();
+					res.expire = Some(self.parse_expire(ctx).await?);
+				}
 				t!("AS") => {
 					self.pop_peek();
 					match self.peek_kind() {
@@ -800,6 +1020,10 @@ impl Parser<'_> {
 					self.pop_peek();
 					res.kind = Some(ctx.run(|ctx| self.parse_inner_kind(ctx)).await?);
 				}
+				t!("REFERENCE") => {
+					self.pop_peek();
+					res.reference = Some(self.parse_reference()?);
+				}
 				t!("READONLY") => {
 					self.pop_peek();
 					res.readonly = true;
@@ -973,6 +1197,8 @@ impl Parser<'_> {
 					let mut doc_ids_cache = 100;
 					let mut doc_ids_order = 100;
 					let mut mtree_cache = 100;
+					let mut quantization = None;
+					let mut rerank = None;
 					loop {
 						match self.peek_kind() {
 							t!("DISTANCE") => {
@@ -999,6 +1225,12 @@ impl Parser<'_> {
 								self.pop_peek();
 								mtree_cache = self.next_token_value()?
 							}
+							t!("QUANTIZE") => {
+								self.pop_peek();
+								let (q, r) = self.parse_quantization()?;
+								quantization = Some(q);
+								rerank = r;
+							}
 							_ => break,
 						}
 					}
@@ -1010,6 +1242,8 @@ impl Parser<'_> {
 						doc_ids_order,
 						doc_ids_cache,
 						mtree_cache,
+						quantization,
+						rerank,
 					))
 				}
 				t!("HNSW") => {
@@ -1024,6 +1258,8 @@ impl Parser<'_> {
 					let mut ef_construction = 150;
 					let mut extend_candidates = false;
 					let mut keep_pruned_connections = false;
+					let mut quantization = None;
+					let mut rerank = None;
 					loop {
 						match self.peek_kind() {
 							t!("DISTANCE") => {
@@ -1058,6 +1294,12 @@ impl Parser<'_> {
 								self.pop_peek();
 								keep_pruned_connections = true;
 							}
+							t!("QUANTIZE") => {
+								self.pop_peek();
+								let (q, r) = self.parse_quantization()?;
+								quantization = Some(q);
+								rerank = r;
+							}
 							_ => {
 								break;
 							}
@@ -1077,6 +1319,8 @@ impl Parser<'_> {
 						ef_construction,
 						extend_candidates,
 						keep_pruned_connections,
+						quantization,
+						rerank,
 					));
 				}
 				t!("CONCURRENTLY") => {
@@ -1154,6 +1398,32 @@ impl Parser<'_> {
 								self.expect_closing_delimiter(t!(")"), open_span)?;
 								filters.push(Filter::Snowball(language))
 							}
+							t!("STOPWORDS") => {
+								let open_span = expected!(self, t!("(")).span;
+								if let TokenKind::Language(_) = self.peek_kind() {
+									let language = self.next_token_value()?;
+									self.expect_closing_delimiter(t!(")"), open_span)?;
+									filters.push(Filter::Stopwords(language))
+								} else {
+									let words = self.parse_filter_words()?;
+									self.expect_closing_delimiter(t!(")"), open_span)?;
+									filters.push(Filter::StopwordList(words))
+								}
+							}
+							t!("SYNONYMS") => {
+								let open_span = expected!(self, t!("(")).span;
+								let mut groups = Vec::new();
+								loop {
+									let group_span = expected!(self, t!("[")).span;
+									groups.push(self.parse_filter_words()?);
+									self.expect_closing_delimiter(t!("]"), group_span)?;
+									if !self.eat(t!(",")) {
+										break;
+									}
+								}
+								self.expect_closing_delimiter(t!(")"), open_span)?;
+								filters.push(Filter::Synonyms(groups))
+							}
 							x => unexpected!(self, x, "a filter"),
 						}
 						if !self.eat(t!(",")) {
@@ -1172,6 +1442,8 @@ impl Parser<'_> {
 							t!("CAMEL") => Tokenizer::Camel,
 							t!("CLASS") => Tokenizer::Class,
 							t!("PUNCT") => Tokenizer::Punct,
+							t!("UNICODE") => Tokenizer::Unicode,
+							t!("CJK") => Tokenizer::Cjk,
 							x => unexpected!(self, x, "a tokenizer"),
 						};
 						tokenizers.push(tokenizer);
@@ -1204,6 +1476,18 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	/// Parses a comma separated list of strings used as analyzer filter arguments.
+	fn parse_filter_words(&mut self) -> ParseResult<Vec<String>> {
+		let mut words = Vec::new();
+		loop {
+			words.push(self.next_token_value::<Strand>()?.0);
+			if !self.eat(t!(",")) {
+				break;
+			}
+		}
+		Ok(words)
+	}
+
 	pub fn parse_relation_schema(&mut self) -> ParseResult<table_type::Relation> {
 		let mut res = table_type::Relation {
 			from: None,
@@ -1291,8 +1575,10 @@ impl Parser<'_> {
 			x => unexpected!(self, x, "`ALGORITHM`, or `URL`"),
 		}
 
-		if self.eat(t!("WITH")) {
-			expected!(self, t!("ISSUER"));
+		// The issuer clause may be followed by other clauses starting with `WITH`
+		if self.peek_kind() == t!("WITH") && self.peek_token_at(1).kind == t!("ISSUER") {
+			self.pop_peek();
+			self.pop_peek();
 			loop {
 				match self.peek_kind() {
 					t!("ALGORITHM") => {
@@ -582,10 +582,16 @@ impl Parser<'_> {
 			t!("$param") => Value::Param(self.next_token_value()?),
 			_ => Value::Table(self.next_token_value()?),
 		};
+		let before = if self.eat(t!("WITH")) {
+			expected!(self, t!("BEFORE"));
+			true
+		} else {
+			false
+		};
 		let cond = self.try_parse_condition(stk).await?;
 		let fetch = self.try_parse_fetch(stk).await?;
 
-		Ok(LiveStatement::from_source_parts(expr, what, cond, fetch))
+		Ok(LiveStatement::from_source_parts(expr, what, cond, fetch, before))
 	}
 
 	/// Parsers a OPTION statement.
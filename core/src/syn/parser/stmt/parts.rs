@@ -6,9 +6,10 @@ use crate::sql::Fetch;
 use crate::{
 	sql::{
 		changefeed::ChangeFeed,
-		index::{Distance, VectorType},
-		Base, Cond, Data, Duration, Fetchs, Field, Fields, Group, Groups, Ident, Idiom, Output,
-		Permission, Permissions, Tables, Timeout, Value, View,
+		index::{Distance, Quantization, VectorType},
+		Base, Cond, Data, Duration, Expire, Fetchs, Field, Fields, Group, Groups, Ident, Idiom,
+		Output, Permission, Permissions, Reference, ReferenceDeleteStrategy, Tables, Timeout,
+		Value, View,
 	},
 	syn::{
 		parser::{
@@ -390,6 +391,47 @@ impl Parser<'_> {
 		})
 	}
 
+	/// Parses an expire production
+	///
+	/// # Parser State
+	/// Expects the parser to have already eaten the `EXPIRE` keyword
+	pub async fn parse_expire(&mut self, ctx: &mut Stk) -> ParseResult<Expire> {
+		let duration = self.next_token_value()?;
+		let field = if self.eat(t!("ON")) {
+			Some(ctx.run(|ctx| self.parse_local_idiom(ctx)).await?)
+		} else {
+			None
+		};
+
+		Ok(Expire {
+			duration,
+			field,
+		})
+	}
+
+	/// Parses a reference production
+	///
+	/// # Parser State
+	/// Expects the parser to have already eaten the `REFERENCE` keyword
+	pub fn parse_reference(&mut self) -> ParseResult<Reference> {
+		let on_delete = if self.eat(t!("ON")) {
+			expected!(self, t!("DELETE"));
+			match self.next().kind {
+				t!("REJECT") => ReferenceDeleteStrategy::Reject,
+				t!("IGNORE") => ReferenceDeleteStrategy::Ignore,
+				t!("CASCADE") => ReferenceDeleteStrategy::Cascade,
+				t!("UNSET") => ReferenceDeleteStrategy::Unset,
+				x => unexpected!(self, x, "'REJECT', 'IGNORE', 'CASCADE', or 'UNSET'"),
+			}
+		} else {
+			ReferenceDeleteStrategy::default()
+		};
+
+		Ok(Reference {
+			on_delete,
+		})
+	}
+
 	/// Parses a view production
 	///
 	/// # Parse State
@@ -455,6 +497,22 @@ impl Parser<'_> {
 		}
 	}
 
+	/// Parses the quantization of a vector index, with the optional
+	/// number of candidates per result which are re-ranked.
+	pub fn parse_quantization(&mut self) -> ParseResult<(Quantization, Option<u16>)> {
+		let quantization = match self.next().kind {
+			t!("INT8") => Quantization::Int8,
+			t!("BINARY") => Quantization::Binary,
+			x => unexpected!(self, x, "a quantization mode"),
+		};
+		let rerank = if self.eat(t!("RERANK")) {
+			Some(self.next_token_value()?)
+		} else {
+			None
+		};
+		Ok((quantization, rerank))
+	}
+
 	pub fn parse_custom_function_name(&mut self) -> ParseResult<Ident> {
 		expected!(self, t!("fn"));
 		expected!(self, t!("::"));
@@ -5,8 +5,8 @@ use crate::{
 		statements::{
 			remove::RemoveAnalyzerStatement, RemoveAccessStatement, RemoveDatabaseStatement,
 			RemoveEventStatement, RemoveFieldStatement, RemoveFunctionStatement,
-			RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement,
-			RemoveUserStatement,
+			RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement,
+			RemoveRoleStatement, RemoveSequenceStatement, RemoveStatement, RemoveUserStatement,
 		},
 		Param,
 	},
@@ -198,6 +198,37 @@ impl Parser<'_> {
 					if_exists,
 				})
 			}
+			t!("ROLE") => {
+				let if_exists = if self.eat(t!("IF")) {
+					expected!(self, t!("EXISTS"));
+					true
+				} else {
+					false
+				};
+				let name = self.next_token_value()?;
+				expected!(self, t!("ON"));
+				let base = self.parse_base(false)?;
+
+				RemoveStatement::Role(RemoveRoleStatement {
+					name,
+					base,
+					if_exists,
+				})
+			}
+			t!("SEQUENCE") => {
+				let if_exists = if self.eat(t!("IF")) {
+					expected!(self, t!("EXISTS"));
+					true
+				} else {
+					false
+				};
+				let name = self.next_token_value()?;
+
+				RemoveStatement::Sequence(RemoveSequenceStatement {
+					name,
+					if_exists,
+				})
+			}
 			x => unexpected!(self, x, "a remove statement keyword"),
 		};
 		Ok(res)
@@ -2,8 +2,8 @@ use reblessive::Stk;
 
 use crate::{
 	sql::{
-		statements::SelectStatement, Explain, Field, Fields, Ident, Idioms, Limit, Order, Orders,
-		Split, Splits, Start, Values, Version, With,
+		statements::SelectStatement, After, Explain, Field, Fields, Ident, Idioms, Limit, Order,
+		Orders, Split, Splits, Start, Values, Version, With,
 	},
 	syn::{
 		parser::{
@@ -45,6 +45,7 @@ impl Parser<'_> {
 		let split = self.try_parse_split(stk, &expr, fields_span).await?;
 		let group = self.try_parse_group(stk, &expr, fields_span).await?;
 		let order = self.try_parse_orders(stk, &expr, fields_span).await?;
+		let after = self.try_parse_after(stk).await?;
 		let (limit, start) = if let t!("START") = self.peek_kind() {
 			let start = self.try_parse_start(stk).await?;
 			let limit = self.try_parse_limit(stk).await?;
@@ -79,6 +80,7 @@ impl Parser<'_> {
 			parallel,
 			tempfiles,
 			explain,
+			after,
 		})
 	}
 
@@ -219,6 +221,14 @@ impl Parser<'_> {
 		Ok(Some(Limit(value)))
 	}
 
+	async fn try_parse_after(&mut self, ctx: &mut Stk) -> ParseResult<Option<After>> {
+		if !self.eat(t!("AFTER")) {
+			return Ok(None);
+		}
+		let value = ctx.run(|ctx| self.parse_value(ctx)).await?;
+		Ok(Some(After(value)))
+	}
+
 	async fn try_parse_start(&mut self, ctx: &mut Stk) -> ParseResult<Option<Start>> {
 		if !self.eat(t!("START")) {
 			return Ok(None);
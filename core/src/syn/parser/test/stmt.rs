@@ -1,4 +1,5 @@
 use crate::{
+	iam::{Action, Grant, ResourceKind},
 	sql::{
 		access::AccessDuration,
 		access_type::{
@@ -8,7 +9,7 @@ use crate::{
 		block::Entry,
 		changefeed::ChangeFeed,
 		filter::Filter,
-		index::{Distance, HnswParams, MTreeParams, SearchParams, VectorType},
+		index::{Distance, HnswParams, MTreeParams, Quantization, SearchParams, VectorType},
 		language::Language,
 		statements::{
 			access,
@@ -16,26 +17,30 @@ use crate::{
 			analyze::AnalyzeStatement,
 			show::{ShowSince, ShowStatement},
 			sleep::SleepStatement,
-			AccessStatement, BeginStatement, BreakStatement, CancelStatement, CommitStatement,
-			ContinueStatement, CreateStatement, DefineAccessStatement, DefineAnalyzerStatement,
-			DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
-			DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
-			DefineParamStatement, DefineStatement, DefineTableStatement, DeleteStatement,
-			ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
+			AccessStatement, AlterStatement, AlterTableStatement, BeginStatement, BreakStatement,
+			CancelStatement, CommitStatement, ContinueStatement, CreateStatement,
+			DefineAccessStatement, DefineAnalyzerStatement, DefineDatabaseStatement,
+			DefineEventStatement, DefineFieldStatement, DefineFunctionStatement,
+			DefineIndexStatement, DefineModelStatement, DefineNamespaceStatement,
+			DefineParamStatement, DefineRoleStatement, DefineSequenceStatement, DefineStatement,
+			DefineTableStatement, DeleteStatement, ForeachStatement, IfelseStatement,
+			InfoStatement, InsertStatement, KillStatement, ModelFormat, ModelTensor,
 			OptionStatement, OutputStatement, RelateStatement, RemoveAccessStatement,
 			RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
 			RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement,
-			RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-			RemoveUserStatement, SelectStatement, SetStatement, ThrowStatement, UpdateStatement,
-			UpsertStatement, UseStatement,
+			RemoveNamespaceStatement, RemoveParamStatement, RemoveRoleStatement,
+			RemoveSequenceStatement, RemoveStatement, RemoveTableStatement, RemoveUserStatement,
+			SelectStatement, SetStatement, ThrowStatement, UpdateStatement, UpsertStatement,
+			UseStatement,
 		},
 		tokenizer::Tokenizer,
 		user::UserDuration,
-		Algorithm, Array, Base, Block, Cond, Data, Datetime, Dir, Duration, Edges, Explain,
-		Expression, Fetch, Fetchs, Field, Fields, Future, Graph, Group, Groups, Id, Ident, Idiom,
-		Idioms, Index, Kind, Limit, Number, Object, Operator, Order, Orders, Output, Param, Part,
-		Permission, Permissions, Scoring, Split, Splits, Start, Statement, Strand, Subquery, Table,
-		TableType, Tables, Thing, Timeout, Uuid, Value, Values, Version, With,
+		After, Algorithm, Array, Base, Block, Cond, Data, Datetime, Dir, Duration, Edges, Expire,
+		Explain, Expression, Fetch, Fetchs, Field, Fields, Future, Graph, Group, Groups, Id, Ident,
+		Idiom, Idioms, Index, Kind, Limit, Number, Object, Operator, Order, Orders, Output, Param,
+		Part, Permission, Permissions, Reference, ReferenceDeleteStrategy, Scoring, Split, Splits,
+		Start, Statement, Strand, Subquery, Table, TableType, Tables, Thing, Timeout, Uuid, Value,
+		Values, Version, With,
 	},
 	syn::parser::mac::test_parse,
 };
@@ -355,6 +360,69 @@ fn parse_define_user() {
 	}
 }
 
+#[test]
+fn parse_define_role() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE ROLE writer ON NAMESPACE GRANT read, create, update ON record, table GRANT kill COMMENT "test""#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Role(DefineRoleStatement {
+			name: Ident("writer".to_string()),
+			base: Base::Ns,
+			grants: vec![
+				Grant::new(
+					vec![Action::Read, Action::Create, Action::Update],
+					vec![ResourceKind::Record, ResourceKind::Table],
+				),
+				Grant::new(vec![Action::Kill], vec![]),
+			],
+			comment: Some(Strand("test".to_string())),
+			if_not_exists: false,
+			overwrite: false,
+		}))
+	);
+}
+
+#[test]
+fn parse_define_sequence() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE SEQUENCE IF NOT EXISTS invoice BATCH 100 START -10 COMMENT "test" PERMISSIONS FULL"#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Sequence(DefineSequenceStatement {
+			name: Ident("invoice".to_string()),
+			batch: 100,
+			start: -10,
+			comment: Some(Strand("test".to_string())),
+			permissions: Permission::Full,
+			if_not_exists: true,
+			overwrite: false,
+			generation: Default::default(),
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"DEFINE SEQUENCE invoice"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Sequence(DefineSequenceStatement {
+			name: Ident("invoice".to_string()),
+			batch: 1000,
+			start: 0,
+			comment: None,
+			permissions: Permission::None,
+			if_not_exists: false,
+			overwrite: false,
+			generation: Default::default(),
+		}))
+	);
+}
+
 // TODO(gguillemas): This test is kept in 2.0.0 for backward compatibility. Drop in 3.0.0.
 #[test]
 fn parse_define_token() {
@@ -1169,6 +1237,7 @@ fn parse_define_access_record() {
 							key: "foo".to_string(),
 						}),
 					},
+					refresh: false,
 				}),
 				authenticate: None,
 				duration: AccessDuration {
@@ -1207,6 +1276,7 @@ fn parse_define_access_record() {
 							key: "bar".to_string(),
 						}),
 					},
+					refresh: false,
 				}),
 				authenticate: None,
 				duration: AccessDuration {
@@ -1220,6 +1290,45 @@ fn parse_define_access_record() {
 			})),
 		);
 	}
+	// Verification and issuing with JWT are explicitly defined with two different keys. Refresh tokens are enabled.
+	{
+		let res = test_parse!(
+			parse_stmt,
+			r#"DEFINE ACCESS a ON DB TYPE RECORD WITH JWT ALGORITHM PS512 KEY "foo" WITH ISSUER KEY "bar" WITH REFRESH DURATION FOR GRANT 15d, FOR TOKEN 10s, FOR SESSION 15m"#
+		)
+		.unwrap();
+		assert_eq!(
+			res,
+			Statement::Define(DefineStatement::Access(DefineAccessStatement {
+				name: Ident("a".to_string()),
+				base: Base::Db,
+				kind: AccessType::Record(RecordAccess {
+					signup: None,
+					signin: None,
+					jwt: JwtAccess {
+						verify: JwtAccessVerify::Key(JwtAccessVerifyKey {
+							alg: Algorithm::Ps512,
+							key: "foo".to_string(),
+						}),
+						issue: Some(JwtAccessIssue {
+							alg: Algorithm::Ps512,
+							key: "bar".to_string(),
+						}),
+					},
+					refresh: true,
+				}),
+				authenticate: None,
+				duration: AccessDuration {
+					grant: Some(Duration::from_days(15)),
+					token: Some(Duration::from_secs(10)),
+					session: Some(Duration::from_mins(15)),
+				},
+				comment: None,
+				if_not_exists: false,
+				overwrite: false,
+			})),
+		);
+	}
 	// Verification and issuing with JWT are explicitly defined with two different keys. Token duration is explicitly defined.
 	{
 		let res = test_parse!(
@@ -1245,6 +1354,7 @@ fn parse_define_access_record() {
 							key: "bar".to_string(),
 						}),
 					},
+					refresh: false,
 				}),
 				authenticate: None,
 				duration: AccessDuration {
@@ -1314,6 +1424,7 @@ fn parse_define_access_record_with_jwt() {
 					}),
 					issue: None,
 				},
+				refresh: false,
 			}),
 			authenticate: None,
 			// Default durations.
@@ -1355,6 +1466,44 @@ fn parse_define_param() {
 	);
 }
 
+#[test]
+fn parse_define_model() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE MODEL ml::house_price<1.0.0> ONNX CONTENT $file INPUT size [1, 2], rooms [1] OUTPUT price [1] COMMENT "test" PERMISSIONS NONE"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Model(DefineModelStatement {
+			hash: String::new(),
+			name: Ident("house_price".to_string()),
+			version: "1.0.0".to_string(),
+			comment: Some(Strand("test".to_string())),
+			permissions: Permission::None,
+			if_not_exists: false,
+			overwrite: false,
+			format: ModelFormat::Onnx,
+			inputs: vec![
+				ModelTensor {
+					name: Ident("size".to_string()),
+					shape: vec![1, 2],
+				},
+				ModelTensor {
+					name: Ident("rooms".to_string()),
+					shape: vec![1],
+				},
+			],
+			outputs: vec![ModelTensor {
+				name: Ident("price".to_string()),
+				shape: vec![1],
+			}],
+			content: Some(Value::Param(Param(Ident("file".to_string())))),
+		}))
+	);
+}
+
 #[test]
 fn parse_define_table() {
 	let res =
@@ -1400,6 +1549,34 @@ fn parse_define_table() {
 			if_not_exists: false,
 			overwrite: false,
 			kind: TableType::Normal,
+			expire: None,
+		}))
+	);
+}
+
+#[test]
+fn parse_define_table_expire() {
+	let res = test_parse!(parse_stmt, r#"DEFINE TABLE name EXPIRE 1h ON created_at"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Table(DefineTableStatement {
+			name: Ident("name".to_string()),
+			permissions: Permissions::none(),
+			expire: Some(Expire {
+				duration: Duration(std::time::Duration::from_secs(3600)),
+				field: Some(Idiom(vec![Part::Field(Ident("created_at".to_owned()))])),
+			}),
+			..Default::default()
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"ALTER TABLE name EXPIRE NONE"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Alter(AlterStatement::Table(AlterTableStatement {
+			name: Ident("name".to_string()),
+			expire: Some(None),
+			..Default::default()
 		}))
 	);
 }
@@ -1459,10 +1636,45 @@ fn parse_define_field() {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
+			reference: None,
 		}))
 	)
 }
 
+#[test]
+fn parse_define_field_reference() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE FIELD author ON TABLE post TYPE record<user> REFERENCE ON DELETE CASCADE"#
+	)
+	.unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Field(DefineFieldStatement {
+			name: Idiom(vec![Part::Field(Ident("author".to_owned()))]),
+			what: Ident("post".to_owned()),
+			kind: Some(Kind::Record(vec![Table("user".to_owned())])),
+			reference: Some(Reference {
+				on_delete: ReferenceDeleteStrategy::Cascade,
+			}),
+			..Default::default()
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"DEFINE FIELD tags ON post REFERENCE"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Field(DefineFieldStatement {
+			name: Idiom(vec![Part::Field(Ident("tags".to_owned()))]),
+			what: Ident("post".to_owned()),
+			reference: Some(Reference {
+				on_delete: ReferenceDeleteStrategy::Ignore,
+			}),
+			..Default::default()
+		}))
+	);
+}
+
 #[test]
 fn parse_define_index() {
 	let res = test_parse!(
@@ -1546,6 +1758,8 @@ fn parse_define_index() {
 				doc_ids_cache: 8,
 				mtree_cache: 9,
 				vector_type: VectorType::I16,
+				quantization: None,
+				rerank: None,
 			}),
 			comment: None,
 			if_not_exists: false,
@@ -1573,6 +1787,70 @@ fn parse_define_index() {
 				extend_candidates: true,
 				keep_pruned_connections: true,
 				ml: 0.5.into(),
+				quantization: None,
+				rerank: None,
+			}),
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a HNSW DIMENSION 1536 QUANTIZE BINARY RERANK 4 M 8"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::Hnsw(HnswParams {
+				dimension: 1536,
+				distance: Distance::Euclidean,
+				vector_type: VectorType::F64,
+				m: 8,
+				m0: 16,
+				ef_construction: 150,
+				extend_candidates: false,
+				keep_pruned_connections: false,
+				ml: (1.0 / 8f64.ln()).into(),
+				quantization: Some(Quantization::Binary),
+				rerank: Some(4),
+			}),
+			comment: None,
+			if_not_exists: false,
+			overwrite: false,
+			concurrently: false
+		}))
+	);
+
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE INDEX index ON TABLE table FIELDS a MTREE DIMENSION 4 QUANTIZE INT8"#
+	)
+	.unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Index(DefineIndexStatement {
+			name: Ident("index".to_owned()),
+			what: Ident("table".to_owned()),
+			cols: Idioms(vec![Idiom(vec![Part::Field(Ident("a".to_owned()))]),]),
+			index: Index::MTree(MTreeParams {
+				dimension: 4,
+				distance: Distance::Euclidean,
+				capacity: 40,
+				doc_ids_order: 100,
+				doc_ids_cache: 100,
+				mtree_cache: 100,
+				vector_type: VectorType::F64,
+				quantization: Some(Quantization::Int8),
+				rerank: None,
 			}),
 			comment: None,
 			if_not_exists: false,
@@ -1615,6 +1893,35 @@ fn parse_define_analyzer() {
 	)
 }
 
+#[test]
+fn parse_define_analyzer_stopwords_synonyms() {
+	let res = test_parse!(
+		parse_stmt,
+		r#"DEFINE ANALYZER ana TOKENIZERS UNICODE, CJK FILTERS LOWERCASE, STOPWORDS(ENGLISH), STOPWORDS('foo', "bar"), SYNONYMS(['tv', 'television'], ['car'])"#
+	).unwrap();
+
+	assert_eq!(
+		res,
+		Statement::Define(DefineStatement::Analyzer(DefineAnalyzerStatement {
+			name: Ident("ana".to_owned()),
+			tokenizers: Some(vec![Tokenizer::Unicode, Tokenizer::Cjk]),
+			filters: Some(vec![
+				Filter::Lowercase,
+				Filter::Stopwords(Language::English),
+				Filter::StopwordList(vec!["foo".to_owned(), "bar".to_owned()]),
+				Filter::Synonyms(vec![
+					vec!["tv".to_owned(), "television".to_owned()],
+					vec!["car".to_owned()],
+				]),
+			]),
+			comment: None,
+			function: None,
+			if_not_exists: false,
+			overwrite: false,
+		})),
+	)
+}
+
 #[test]
 fn parse_delete() {
 	let res = test_parse!(
@@ -1867,11 +2174,27 @@ SELECT bar as foo,[1,2],bar OMIT bar FROM ONLY a,1
 			timeout: None,
 			parallel: false,
 			tempfiles: false,
+			after: None,
 			explain: Some(Explain(true)),
 		}),
 	);
 }
 
+#[test]
+fn parse_select_after() {
+	let res = test_parse!(parse_stmt, r#"SELECT * FROM a AFTER $cursor LIMIT 10"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Select(SelectStatement {
+			expr: Fields::all(),
+			what: Values(vec![Value::Table(Table("a".to_owned()))]),
+			after: Some(After(Value::Param(Param(Ident("cursor".to_owned()))))),
+			limit: Some(Limit(Value::Number(Number::Int(10)))),
+			..Default::default()
+		}),
+	);
+}
+
 #[test]
 fn parse_let() {
 	let res = test_parse!(parse_stmt, r#"LET $param = 1"#).unwrap();
@@ -2133,7 +2456,17 @@ fn parse_live() {
 			]))),
 			Fetch(Value::Idiom(Idiom(vec![Part::Field(Ident("b".to_owned()))]))),
 		])),
-	)
+	);
+	assert!(!stmt.before);
+
+	let res =
+		test_parse!(parse_stmt, r#"LIVE SELECT * FROM table WITH BEFORE WHERE true"#).unwrap();
+	let Statement::Live(stmt) = res else {
+		panic!()
+	};
+	assert_eq!(stmt.what, Value::Table(Table("table".to_owned())));
+	assert_eq!(stmt.cond, Some(Cond(Value::Bool(true))));
+	assert!(stmt.before);
 }
 
 #[test]
@@ -2320,6 +2653,25 @@ fn parse_remove() {
 			if_exists: false,
 		}))
 	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE ROLE IF EXISTS foo ON ROOT"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Role(RemoveRoleStatement {
+			name: Ident("foo".to_owned()),
+			base: Base::Root,
+			if_exists: true,
+		}))
+	);
+
+	let res = test_parse!(parse_stmt, r#"REMOVE SEQUENCE IF EXISTS invoice"#).unwrap();
+	assert_eq!(
+		res,
+		Statement::Remove(RemoveStatement::Sequence(RemoveSequenceStatement {
+			name: Ident("invoice".to_owned()),
+			if_exists: true,
+		}))
+	);
 }
 
 #[test]
@@ -214,6 +214,7 @@ fn statements() -> Vec<Statement> {
 					}),
 					issue: None,
 				},
+				refresh: false,
 			}),
 			authenticate: None,
 			// Default durations.
@@ -278,6 +279,7 @@ fn statements() -> Vec<Statement> {
 			if_not_exists: false,
 			overwrite: false,
 			kind: TableType::Normal,
+			expire: None,
 		})),
 		Statement::Define(DefineStatement::Event(DefineEventStatement {
 			name: Ident("event".to_owned()),
@@ -314,6 +316,7 @@ fn statements() -> Vec<Statement> {
 			comment: None,
 			if_not_exists: false,
 			overwrite: false,
+			reference: None,
 		})),
 		Statement::Define(DefineStatement::Index(DefineIndexStatement {
 			name: Ident("index".to_owned()),
@@ -365,6 +368,8 @@ fn statements() -> Vec<Statement> {
 				doc_ids_cache: 8,
 				mtree_cache: 9,
 				vector_type: VectorType::F64,
+				quantization: None,
+				rerank: None,
 			}),
 			comment: None,
 			if_not_exists: false,
@@ -528,6 +533,7 @@ fn statements() -> Vec<Statement> {
 			timeout: None,
 			parallel: false,
 			tempfiles: false,
+			after: None,
 			explain: Some(Explain(true)),
 		}),
 		Statement::Set(SetStatement {
@@ -38,8 +38,10 @@ keyword! {
 	At => "AT",
 	Authenticate => "AUTHENTICATE",
 	Bearer => "BEARER",
+	Batch => "BATCH",
 	Before => "BEFORE",
 	Begin => "BEGIN",
+	Binary => "BINARY",
 	Blank => "BLANK",
 	Bm25 => "BM25",
 	Break => "BREAK",
@@ -49,6 +51,8 @@ keyword! {
 	ChangeFeed => "CHANGEFEED",
 	Changes => "CHANGES",
 	Capacity => "CAPACITY",
+	Cascade => "CASCADE",
+	Cjk => "CJK",
 	Class => "CLASS",
 	Comment => "COMMENT",
 	Commit => "COMMIT",
@@ -77,6 +81,7 @@ keyword! {
 	End => "END",
 	Enforced => "ENFORCED",
 	Exists => "EXISTS",
+	Expire => "EXPIRE",
 	Explain => "EXPLAIN",
 	ExtendCandidates => "EXTEND_CANDIDATES",
 	False => "false",
@@ -97,7 +102,9 @@ keyword! {
 	Include => "INCLUDE",
 	Index => "INDEX",
 	Info => "INFO",
+	Input => "INPUT",
 	Insert => "INSERT",
+	Int8 => "INT8",
 	Into => "INTO",
 	If => "IF",
 	Is => "IS",
@@ -129,9 +136,11 @@ keyword! {
 	Omit => "OMIT",
 	On => "ON",
 	Only => "ONLY",
+	Onnx => "ONNX",
 	Option => "OPTION",
 	Order => "ORDER",
 	Original => "ORIGINAL",
+	Output => "OUTPUT",
 	Overwrite => "OVERWRITE",
 	Parallel => "PARALLEL",
 	Param => "PARAM",
@@ -143,15 +152,21 @@ keyword! {
 	PostingsOrder => "POSTINGS_ORDER",
 	Prune => "PRUNE",
 	Punct => "PUNCT",
+	Quantize => "QUANTIZE",
 	Range => "RANGE",
 	Readonly => "READONLY",
 	Rebuild => "REBUILD",
+	Refresh => "REFRESH",
+	Reference => "REFERENCE",
+	Reject => "REJECT",
 	Relate => "RELATE",
 	Relation => "RELATION",
 	Remove => "REMOVE",
 	Replace => "REPLACE",
+	Rerank => "RERANK",
 	Return => "RETURN",
 	Revoke => "REVOKE",
+	Role => "ROLE",
 	Roles => "ROLES",
 	Root => "ROOT",
 	Schemafull => "SCHEMAFULL",
@@ -159,6 +174,7 @@ keyword! {
 	Scope => "SCOPE",
 	Search => "SEARCH",
 	Select => "SELECT",
+	Sequence => "SEQUENCE",
 	Session => "SESSION",
 	Set => "SET",
 	Show => "SHOW",
@@ -169,7 +185,9 @@ keyword! {
 	Snowball => "SNOWBALL",
 	Split => "SPLIT",
 	Start => "START",
+	Stopwords => "STOPWORDS",
 	Structure => "STRUCTURE",
+	Synonyms => "SYNONYMS",
 	Table => "TABLE",
 	TempFiles => "TEMPFILES",
 	TermsCache => "TERMS_CACHE",
@@ -183,6 +201,7 @@ keyword! {
 	Transaction => "TRANSACTION",
 	True => "true",
 	Type => "TYPE",
+	Unicode => "UNICODE",
 	Unique => "UNIQUE",
 	Unset => "UNSET",
 	Update => "UPDATE",
@@ -1,11 +1,17 @@
 use super::MlExportConfig;
-use crate::{opt::Resource, value::Notification, Result};
+use crate::{
+	opt::{ConnectionEvent, Resource},
+	value::Notification,
+	Result,
+};
 use bincode::Options;
 use channel::Sender;
 use revision::Revisioned;
 use serde::{ser::SerializeMap as _, Serialize};
 use std::io::Read;
 use std::path::PathBuf;
+use surrealdb_core::kvs::export::Config as DbExportConfig;
+use surrealdb_core::sql::statements::LiveStatement;
 use surrealdb_core::sql::{Array as CoreArray, Object as CoreObject, Query, Value as CoreValue};
 use uuid::Uuid;
 
@@ -25,6 +31,9 @@ pub(crate) enum Command {
 	Signin {
 		credentials: CoreObject,
 	},
+	Refresh {
+		credentials: CoreObject,
+	},
 	Authenticate {
 		token: String,
 	},
@@ -60,12 +69,20 @@ pub(crate) enum Command {
 	Delete {
 		what: Resource,
 	},
+	Relate {
+		from: Resource,
+		with: Resource,
+		to: Resource,
+		data: Option<CoreValue>,
+	},
+	Info,
 	Query {
 		query: Query,
 		variables: CoreObject,
 	},
 	ExportFile {
 		path: PathBuf,
+		config: DbExportConfig,
 	},
 	ExportMl {
 		path: PathBuf,
@@ -73,6 +90,7 @@ pub(crate) enum Command {
 	},
 	ExportBytes {
 		bytes: Sender<Result<Vec<u8>>>,
+		config: DbExportConfig,
 	},
 	ExportBytesMl {
 		bytes: Sender<Result<Vec<u8>>>,
@@ -96,6 +114,12 @@ pub(crate) enum Command {
 	SubscribeLive {
 		uuid: Uuid,
 		notification_sender: Sender<Notification<CoreValue>>,
+		// The statement which started the live query, to restart it on a reconnect
+		statement: LiveStatement,
+		variables: CoreObject,
+	},
+	SubscribeEvents {
+		sender: Sender<ConnectionEvent>,
 	},
 	Kill {
 		uuid: Uuid,
@@ -105,17 +129,33 @@ pub(crate) enum Command {
 		version: Option<String>,
 		args: CoreArray,
 	},
+	Begin,
+	Commit {
+		uuid: Uuid,
+	},
+	Cancel {
+		uuid: Uuid,
+	},
+	GraphQL {
+		query: String,
+		variables: CoreObject,
+	},
 }
 
 impl Command {
 	#[cfg(any(feature = "protocol-ws", feature = "protocol-http"))]
-	pub(crate) fn into_router_request(self, id: Option<i64>) -> Option<RouterRequest> {
+	pub(crate) fn into_router_request(
+		self,
+		id: Option<i64>,
+		txn: Option<Uuid>,
+	) -> Option<RouterRequest> {
 		let res = match self {
 			Command::Use {
 				namespace,
 				database,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "use",
 				params: Some(vec![CoreValue::from(namespace), CoreValue::from(database)].into()),
 			},
@@ -123,6 +163,7 @@ impl Command {
 				credentials,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "signup",
 				params: Some(vec![CoreValue::from(credentials)].into()),
 			},
@@ -130,18 +171,29 @@ impl Command {
 				credentials,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "signin",
 				params: Some(vec![CoreValue::from(credentials)].into()),
 			},
+			Command::Refresh {
+				credentials,
+			} => RouterRequest {
+				id,
+				txn,
+				method: "refresh",
+				params: Some(vec![CoreValue::from(credentials)].into()),
+			},
 			Command::Authenticate {
 				token,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "authenticate",
 				params: Some(vec![CoreValue::from(token)].into()),
 			},
 			Command::Invalidate => RouterRequest {
 				id,
+				txn,
 				method: "invalidate",
 				params: None,
 			},
@@ -156,6 +208,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "create",
 					params: Some(params.into()),
 				}
@@ -172,6 +225,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "upsert",
 					params: Some(params.into()),
 				}
@@ -189,6 +243,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "update",
 					params: Some(params.into()),
 				}
@@ -210,6 +265,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "insert",
 					params: Some(params.into()),
 				}
@@ -227,6 +283,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "patch",
 					params: Some(params.into()),
 				}
@@ -243,6 +300,7 @@ impl Command {
 
 				RouterRequest {
 					id,
+					txn,
 					method: "merge",
 					params: Some(params.into()),
 				}
@@ -252,6 +310,7 @@ impl Command {
 				..
 			} => RouterRequest {
 				id,
+				txn,
 				method: "select",
 				params: Some(CoreValue::Array(vec![what.into_core_value()].into())),
 			},
@@ -260,9 +319,35 @@ impl Command {
 				..
 			} => RouterRequest {
 				id,
+				txn,
 				method: "delete",
 				params: Some(CoreValue::Array(vec![what.into_core_value()].into())),
 			},
+			Command::Relate {
+				from,
+				with,
+				to,
+				data,
+			} => {
+				let mut params =
+					vec![from.into_core_value(), with.into_core_value(), to.into_core_value()];
+				if let Some(data) = data {
+					params.push(data);
+				}
+
+				RouterRequest {
+					id,
+					txn,
+					method: "relate",
+					params: Some(params.into()),
+				}
+			}
+			Command::Info => RouterRequest {
+				id,
+				txn,
+				method: "info",
+				params: None,
+			},
 			Command::Query {
 				query,
 				variables,
@@ -270,6 +355,7 @@ impl Command {
 				let params: Vec<CoreValue> = vec![query.into(), variables.into()];
 				RouterRequest {
 					id,
+					txn,
 					method: "query",
 					params: Some(params.into()),
 				}
@@ -294,11 +380,13 @@ impl Command {
 			} => return None,
 			Command::Health => RouterRequest {
 				id,
+				txn,
 				method: "ping",
 				params: None,
 			},
 			Command::Version => RouterRequest {
 				id,
+				txn,
 				method: "version",
 				params: None,
 			},
@@ -307,6 +395,7 @@ impl Command {
 				value,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "let",
 				params: Some(CoreValue::from(vec![CoreValue::from(key), value])),
 			},
@@ -314,16 +403,21 @@ impl Command {
 				key,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "unset",
 				params: Some(CoreValue::from(vec![CoreValue::from(key)])),
 			},
 			Command::SubscribeLive {
 				..
+			}
+			| Command::SubscribeEvents {
+				..
 			} => return None,
 			Command::Kill {
 				uuid,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "kill",
 				params: Some(CoreValue::from(vec![CoreValue::from(uuid)])),
 			},
@@ -333,12 +427,49 @@ impl Command {
 				args,
 			} => RouterRequest {
 				id,
+				txn,
 				method: "run",
 				params: Some(
 					vec![CoreValue::from(name), CoreValue::from(version), CoreValue::Array(args)]
 						.into(),
 				),
 			},
+			Command::Begin => RouterRequest {
+				id,
+				txn,
+				method: "begin",
+				params: None,
+			},
+			Command::Commit {
+				uuid,
+			} => RouterRequest {
+				id,
+				txn,
+				method: "commit",
+				params: Some(CoreValue::from(vec![CoreValue::from(uuid)])),
+			},
+			Command::Cancel {
+				uuid,
+			} => RouterRequest {
+				id,
+				txn,
+				method: "cancel",
+				params: Some(CoreValue::from(vec![CoreValue::from(uuid)])),
+			},
+			Command::GraphQL {
+				query,
+				variables,
+			} => {
+				let mut request = CoreObject::default();
+				request.insert("query".to_owned(), CoreValue::from(query));
+				request.insert("variables".to_owned(), CoreValue::from(variables));
+				RouterRequest {
+					id,
+					txn,
+					method: "graphql",
+					params: Some(CoreValue::from(vec![CoreValue::from(request)])),
+				}
+			}
 		};
 		Some(res)
 	}
@@ -367,6 +498,10 @@ impl Command {
 			}
 			| Command::Delete {
 				what,
+			}
+			| Command::Relate {
+				with: what,
+				..
 			} => matches!(what, Resource::RecordId(_)),
 			Command::Insert {
 				data,
@@ -383,6 +518,7 @@ impl Command {
 #[derive(Debug)]
 pub(crate) struct RouterRequest {
 	id: Option<i64>,
+	txn: Option<Uuid>,
 	method: &'static str,
 	params: Option<CoreValue>,
 }
@@ -440,7 +576,10 @@ impl Serialize for RouterRequest {
 			where
 				S: serde::Serializer,
 			{
-				let size = 1 + self.0.id.is_some() as usize + self.0.params.is_some() as usize;
+				let size = 1
+					+ self.0.id.is_some() as usize
+					+ self.0.txn.is_some() as usize
+					+ self.0.params.is_some() as usize;
 				let mut map = serializer.serialize_map(Some(size))?;
 				if let Some(id) = self.0.id.as_ref() {
 					map.serialize_entry("id", &InnerNumberVariant(*id))?;
@@ -449,6 +588,9 @@ impl Serialize for RouterRequest {
 				if let Some(params) = self.0.params.as_ref() {
 					map.serialize_entry("params", params)?;
 				}
+				if let Some(txn) = self.0.txn {
+					map.serialize_entry("txn", &CoreValue::from(txn))?;
+				}
 				map.end()
 			}
 		}
@@ -487,7 +629,10 @@ impl Revisioned for RouterRequest {
 		// object wrapper version
 		Revisioned::serialize_revisioned(&1u32, w)?;
 
-		let size = 1 + self.id.is_some() as usize + self.params.is_some() as usize;
+		let size = 1
+			+ self.id.is_some() as usize
+			+ self.txn.is_some() as usize
+			+ self.params.is_some() as usize;
 		size.serialize_revisioned(w)?;
 
 		let serializer = bincode::options()
@@ -540,6 +685,13 @@ impl Revisioned for RouterRequest {
 			x.serialize_revisioned(w)?;
 		}
 
+		if let Some(x) = self.txn {
+			serializer
+				.serialize_into(&mut *w, "txn")
+				.map_err(|err| revision::Error::Serialize(err.to_string()))?;
+			CoreValue::from(x).serialize_revisioned(w)?;
+		}
+
 		Ok(())
 	}
 
@@ -557,6 +709,7 @@ mod test {
 
 	use revision::Revisioned;
 	use surrealdb_core::sql::{Number, Value};
+	use uuid::Uuid;
 
 	use super::RouterRequest;
 
@@ -584,12 +737,15 @@ mod test {
 		assert_eq!(x.0, req.method);
 
 		assert_eq!(obj.get("params").cloned(), req.params);
+
+		assert_eq!(obj.get("txn").cloned(), req.txn.map(Value::from));
 	}
 
 	#[test]
 	fn router_request_value_conversion() {
 		let request = RouterRequest {
 			id: Some(1234),
+			txn: Some(Uuid::from_u128(0x1234)),
 			method: "request",
 			params: Some(vec![Value::from(1234i64), Value::from("request")].into()),
 		};
@@ -13,7 +13,9 @@ use serde::de::DeserializeOwned;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use surrealdb_core::sql::{from_value as from_core_value, Value as CoreValue};
+use uuid::Uuid;
 
 mod cmd;
 pub(crate) use cmd::Command;
@@ -25,6 +27,8 @@ pub(crate) use cmd::RouterRequest;
 pub struct RequestData {
 	pub(crate) id: i64,
 	pub(crate) command: Command,
+	/// The interactive transaction the command runs within, if any
+	pub(crate) transaction: Option<Uuid>,
 }
 
 #[derive(Debug)]
@@ -38,11 +42,23 @@ pub(crate) struct Route {
 #[derive(Debug)]
 pub struct Router {
 	pub(crate) sender: Sender<Route>,
-	pub(crate) last_id: AtomicI64,
+	pub(crate) last_id: Arc<AtomicI64>,
 	pub(crate) features: HashSet<ExtraFeatures>,
+	/// The interactive transaction all commands run within, if any
+	pub(crate) transaction: Option<Uuid>,
 }
 
 impl Router {
+	/// Creates a router which sends all commands within an interactive transaction
+	pub(crate) fn with_transaction(&self, transaction: Uuid) -> Self {
+		Self {
+			sender: self.sender.clone(),
+			last_id: self.last_id.clone(),
+			features: self.features.clone(),
+			transaction: Some(transaction),
+		}
+	}
+
 	pub(crate) fn next_id(&self) -> i64 {
 		self.last_id.fetch_add(1, Ordering::SeqCst)
 	}
@@ -58,6 +74,7 @@ impl Router {
 				request: RequestData {
 					id,
 					command,
+					transaction: self.transaction,
 				},
 				response: sender,
 			};
@@ -42,12 +42,13 @@ use surrealdb_core::{
 	dbs::{Response, Session},
 	iam,
 	kvs::Datastore,
+	rpc::Transactions,
 	sql::{
 		statements::{
-			CreateStatement, DeleteStatement, InsertStatement, KillStatement, SelectStatement,
-			UpdateStatement, UpsertStatement,
+			CreateStatement, DeleteStatement, InsertStatement, KillStatement, RelateStatement,
+			SelectStatement, UpdateStatement, UpsertStatement,
 		},
-		Data, Field, Output, Query, Statement, Value as CoreValue,
+		Data, Field, Output, Param, Query, Statement, Value as CoreValue,
 	},
 };
 use uuid::Uuid;
@@ -55,6 +56,8 @@ use uuid::Uuid;
 use crate::api::err::Error;
 #[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
+#[cfg(not(target_arch = "wasm32"))]
+use surrealdb_core::kvs::export::{Config as DbExportConfig, Format as DbExportFormat};
 use surrealdb_core::sql::Function;
 #[cfg(feature = "ml")]
 use surrealdb_core::sql::Model;
@@ -64,6 +67,9 @@ use tokio::{
 	io::{self, AsyncReadExt, AsyncWriteExt},
 };
 
+#[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))]
+use surrealdb_core::gql::{Pessimistic, SchemaCache};
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "ml"))]
 use crate::api::conn::MlExportConfig;
 #[cfg(all(not(target_arch = "wasm32"), feature = "ml"))]
@@ -85,6 +91,9 @@ pub(crate) mod wasm;
 
 const DEFAULT_TICK_INTERVAL: Duration = Duration::from_secs(10);
 
+/// How long an interactive transaction can stay idle before it is cancelled
+const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// In-memory database
 ///
 /// # Examples
@@ -419,8 +428,13 @@ async fn take(one: bool, responses: Vec<Response>) -> Result<CoreValue> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-async fn export_file(kvs: &Datastore, sess: &Session, chn: channel::Sender<Vec<u8>>) -> Result<()> {
-	if let Err(error) = kvs.export(sess, chn).await?.await {
+async fn export_file(
+	kvs: &Datastore,
+	sess: &Session,
+	chn: channel::Sender<Vec<u8>>,
+	config: DbExportConfig,
+) -> Result<()> {
+	if let Err(error) = kvs.export_with_config(sess, chn, config).await?.await {
 		if let crate::error::Db::Channel(message) = error {
 			// This is not really an error. Just logging it for improved visibility.
 			trace!("{message}");
@@ -492,15 +506,36 @@ async fn kill_live_query(
 	take(true, response).await
 }
 
+/// Processes a query, within the given interactive transaction if any
+async fn process_in(
+	kvs: &Datastore,
+	transactions: &Transactions,
+	transaction: Option<Uuid>,
+	query: Query,
+	session: &Session,
+	vars: BTreeMap<String, CoreValue>,
+) -> Result<Vec<Response>> {
+	let response = match transaction {
+		Some(id) => transactions.process(kvs, id, query, session, Some(vars)).await?,
+		None => kvs.process(query, session, Some(vars)).await?,
+	};
+	Ok(response)
+}
+
 async fn router(
 	RequestData {
 		command,
+		transaction,
 		..
 	}: RequestData,
 	kvs: &Arc<Datastore>,
 	session: &mut Session,
 	vars: &mut BTreeMap<String, CoreValue>,
 	live_queries: &mut HashMap<Uuid, Sender<Notification<CoreValue>>>,
+	transactions: &Transactions,
+	#[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))] gql_schema: &SchemaCache<
+		Pessimistic,
+	>,
 ) -> Result<DbResponse> {
 	match command {
 		Command::Use {
@@ -519,7 +554,7 @@ async fn router(
 			credentials,
 		} => {
 			let response = iam::signup::signup(kvs, session, credentials).await?;
-			Ok(DbResponse::Other(response.into()))
+			Ok(DbResponse::Other(response.map(CoreValue::from).into()))
 		}
 		Command::Signin {
 			credentials,
@@ -527,6 +562,12 @@ async fn router(
 			let response = iam::signin::signin(kvs, session, credentials).await?;
 			Ok(DbResponse::Other(response.into()))
 		}
+		Command::Refresh {
+			credentials,
+		} => {
+			let response = iam::refresh::refresh(kvs, session, credentials).await?;
+			Ok(DbResponse::Other(response.into()))
+		}
 		Command::Authenticate {
 			token,
 		} => {
@@ -550,7 +591,8 @@ async fn router(
 				stmt
 			};
 			query.0 .0 = vec![Statement::Create(statement)];
-			let response = kvs.process(query, &*session, Some(vars.clone())).await?;
+			let response =
+				process_in(kvs, transactions, transaction, query, session, vars.clone()).await?;
 			let value = take(true, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -569,7 +611,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Upsert(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -588,7 +630,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Update(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -607,7 +649,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Insert(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -626,7 +668,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Update(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -645,7 +687,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Update(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -662,7 +704,7 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Select(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
@@ -679,17 +721,54 @@ async fn router(
 			};
 			query.0 .0 = vec![Statement::Delete(statement)];
 			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let value = take(one, response).await?;
 			Ok(DbResponse::Other(value))
 		}
+		Command::Relate {
+			from,
+			with,
+			to,
+			data,
+		} => {
+			let mut query = Query::default();
+			let one = matches!(with, ApiResource::RecordId(_));
+			let statement = {
+				let mut stmt = RelateStatement::default();
+				stmt.from = from.into_core_value();
+				stmt.kind = with.into_core_value();
+				stmt.with = to.into_core_value();
+				stmt.data = data.map(Data::ContentExpression);
+				stmt.output = Some(Output::After);
+				stmt
+			};
+			query.0 .0 = vec![Statement::Relate(statement)];
+			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
+			let value = take(one, response).await?;
+			Ok(DbResponse::Other(value))
+		}
+		Command::Info => {
+			let mut query = Query::default();
+			let statement = {
+				let mut stmt = SelectStatement::default();
+				stmt.what.0 = vec![CoreValue::Param(Param::from("auth"))];
+				stmt.expr.0 = vec![Field::All];
+				stmt
+			};
+			query.0 .0 = vec![Statement::Select(statement)];
+			let vars = vars.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
+			let value = take(true, response).await?;
+			Ok(DbResponse::Other(value))
+		}
 		Command::Query {
 			query,
 			mut variables,
 		} => {
 			let mut vars = vars.clone();
 			vars.append(&mut variables.0);
-			let response = kvs.process(query, &*session, Some(vars)).await?;
+			let response = process_in(kvs, transactions, transaction, query, session, vars).await?;
 			let response = process(response);
 			Ok(DbResponse::Query(response))
 		}
@@ -719,12 +798,13 @@ async fn router(
 		#[cfg(not(target_arch = "wasm32"))]
 		Command::ExportFile {
 			path: file,
+			config,
 		} => {
 			let (tx, rx) = crate::channel::bounded(1);
 			let (mut writer, mut reader) = io::duplex(10_240);
 
 			// Write to channel.
-			let export = export_file(kvs, session, tx);
+			let export = export_file(kvs, session, tx, config);
 
 			// Read from channel and write to pipe.
 			let bridge = async move {
@@ -812,6 +892,7 @@ async fn router(
 		#[cfg(not(target_arch = "wasm32"))]
 		Command::ExportBytes {
 			bytes,
+			config,
 		} => {
 			let (tx, rx) = crate::channel::bounded(1);
 
@@ -819,7 +900,7 @@ async fn router(
 			let session = session.clone();
 			tokio::spawn(async move {
 				let export = async {
-					if let Err(error) = export_file(&kvs, &session, tx).await {
+					if let Err(error) = export_file(&kvs, &session, tx, config).await {
 						let _ = bytes.send(Err(error)).await;
 					}
 				};
@@ -880,8 +961,8 @@ async fn router(
 					.into());
 				}
 			};
-			let mut statements = String::new();
-			if let Err(error) = file.read_to_string(&mut statements).await {
+			let mut buffer = Vec::new();
+			if let Err(error) = file.read_to_end(&mut buffer).await {
 				return Err(Error::FileRead {
 					path,
 					error,
@@ -889,7 +970,17 @@ async fn router(
 				.into());
 			}
 
-			let responses = kvs.execute(&statements, &*session, Some(vars.clone())).await?;
+			let responses = match DbExportFormat::detect(&buffer) {
+				DbExportFormat::Sql => {
+					let statements =
+						String::from_utf8(buffer).map_err(|error| Error::FileRead {
+							path,
+							error: std::io::Error::new(std::io::ErrorKind::InvalidData, error),
+						})?;
+					kvs.execute(&statements, &*session, Some(vars.clone())).await?
+				}
+				_ => kvs.import_bytes(&buffer, session).await?,
+			};
 
 			for response in responses {
 				response.result?;
@@ -990,10 +1081,14 @@ async fn router(
 		Command::SubscribeLive {
 			uuid,
 			notification_sender,
+			..
 		} => {
 			live_queries.insert(uuid, notification_sender);
 			Ok(DbResponse::Other(CoreValue::None))
 		}
+		Command::SubscribeEvents {
+			..
+		} => Err(Error::ConnectionEventsNotSupported.into()),
 		Command::Kill {
 			uuid,
 		} => {
@@ -1029,10 +1124,44 @@ async fn router(
 
 			let stmt = Statement::Value(func);
 
-			let response = kvs.process(stmt.into(), &*session, Some(vars.clone())).await?;
+			let response =
+				process_in(kvs, transactions, transaction, stmt.into(), session, vars.clone())
+					.await?;
 			let value = take(true, response).await?;
 
 			Ok(DbResponse::Other(value))
 		}
+		Command::Begin => {
+			let id = transactions.begin(kvs).await?;
+			Ok(DbResponse::Other(CoreValue::Uuid(id.into())))
+		}
+		Command::Commit {
+			uuid,
+		} => {
+			transactions.commit(kvs, uuid).await?;
+			Ok(DbResponse::Other(CoreValue::None))
+		}
+		Command::Cancel {
+			uuid,
+		} => {
+			transactions.cancel(uuid).await?;
+			Ok(DbResponse::Other(CoreValue::None))
+		}
+		#[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))]
+		Command::GraphQL {
+			query,
+			variables,
+		} => {
+			iam::check::check_ns_db(session)?;
+			let response = gql_schema
+				.execute(session, query, variables)
+				.await
+				.map_err(|e| Error::Query(e.to_string()))?;
+			Ok(DbResponse::Other(CoreValue::from(response)))
+		}
+		#[cfg(not(all(not(target_arch = "wasm32"), surrealdb_unstable)))]
+		Command::GraphQL {
+			..
+		} => Err(Error::GraphQLNotSupported.into()),
 	}
 }
@@ -9,7 +9,6 @@ use crate::{
 	engine::tasks::start_tasks,
 	opt::{auth::Root, WaitFor},
 	value::Notification,
-	Action,
 };
 use channel::{Receiver, Sender};
 use futures::{stream::poll_fn, StreamExt};
@@ -18,9 +17,14 @@ use std::{
 	sync::{atomic::AtomicI64, Arc, OnceLock},
 	task::Poll,
 };
-use surrealdb_core::{dbs::Session, iam::Level, kvs::Datastore, options::EngineOptions};
+use surrealdb_core::{
+	dbs::Session, iam::Level, kvs::Datastore, options::EngineOptions, rpc::Transactions,
+};
 use tokio::sync::watch;
 
+#[cfg(surrealdb_unstable)]
+use surrealdb_core::gql::{Pessimistic, SchemaCache, Subscriptions};
+
 impl crate::api::Connection for Db {}
 
 impl Connection for Db {
@@ -45,7 +49,8 @@ impl Connection for Db {
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
-					last_id: AtomicI64::new(0),
+					last_id: Arc::new(AtomicI64::new(0)),
+					transaction: None,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -117,6 +122,9 @@ pub(crate) async fn run_router(
 	let mut vars = BTreeMap::default();
 	let mut live_queries = HashMap::new();
 	let mut session = Session::default().with_rt(true);
+	let transactions = Transactions::new(super::TRANSACTION_TIMEOUT);
+	#[cfg(surrealdb_unstable)]
+	let gql_schema = SchemaCache::<Pessimistic>::new(kvs.clone(), Subscriptions::default());
 
 	let opt = {
 		let mut engine_options = EngineOptions::default();
@@ -142,8 +150,19 @@ pub(crate) async fn run_router(
 				let Ok(route) = route else {
 					break
 				};
-				match super::router(route.request, &kvs, &mut session, &mut vars, &mut live_queries)
-					.await
+				// Cancel any idle interactive transactions
+				transactions.expire().await;
+				match super::router(
+					route.request,
+					&kvs,
+					&mut session,
+					&mut vars,
+					&mut live_queries,
+					&transactions,
+					#[cfg(surrealdb_unstable)]
+					&gql_schema,
+				)
+				.await
 				{
 					Ok(value) => {
 						let _ = route.response.send(Ok(value)).await;
@@ -160,11 +179,7 @@ pub(crate) async fn run_router(
 					continue
 				};
 
-				let notification = Notification{
-					query_id: *notification.id,
-					action: Action::from_core(notification.action),
-					data: notification.result
-				};
+				let notification = Notification::from_core_notification(notification);
 
 				let id = notification.query_id;
 				if let Some(sender) = live_queries.get(&id) {
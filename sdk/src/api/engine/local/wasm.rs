@@ -16,7 +16,8 @@ use crate::kvs::Datastore;
 use crate::opt::auth::Root;
 use crate::opt::WaitFor;
 use crate::options::EngineOptions;
-use crate::{Action, Notification};
+use crate::rpc::Transactions;
+use crate::Notification;
 use channel::{Receiver, Sender};
 use futures::stream::poll_fn;
 use futures::FutureExt;
@@ -54,7 +55,8 @@ impl Connection for Db {
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
-					last_id: AtomicI64::new(0),
+					last_id: Arc::new(AtomicI64::new(0)),
+					transaction: None,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -112,6 +114,7 @@ pub(crate) async fn run_router(
 	let mut vars = BTreeMap::new();
 	let mut live_queries = HashMap::new();
 	let mut session = Session::default().with_rt(true);
+	let transactions = Transactions::new(super::TRANSACTION_TIMEOUT);
 
 	let mut opt = EngineOptions::default();
 	opt.tick_interval = address.config.tick_interval.unwrap_or(DEFAULT_TICK_INTERVAL);
@@ -132,12 +135,15 @@ pub(crate) async fn run_router(
 					break
 				};
 
+				// Cancel any idle interactive transactions
+				transactions.expire().await;
 				match super::router(
 					route.request,
 					&kvs,
 					&mut session,
 					&mut vars,
 					&mut live_queries,
+					&transactions,
 				)
 				.await
 				{
@@ -159,11 +165,7 @@ pub(crate) async fn run_router(
 				let id = notification.id;
 				if let Some(sender) = live_queries.get(&id) {
 
-					let notification = Notification {
-						query_id: notification.id.0,
-						action: Action::from_core(notification.action),
-						data: notification.result,
-					};
+					let notification = Notification::from_core_notification(notification);
 
 					if sender.send(notification).await.is_err() {
 						live_queries.remove(&id);
@@ -25,6 +25,7 @@ use reqwest::RequestBuilder;
 use serde::Deserialize;
 use serde::Serialize;
 use std::marker::PhantomData;
+use surrealdb_core::kvs::export::Config as DbExportConfig;
 use surrealdb_core::sql::{
 	from_value as from_core_value, statements::OutputStatement, Object as CoreObject, Param, Query,
 	Statement, Value as CoreValue,
@@ -197,6 +198,25 @@ async fn export_file(request: RequestBuilder, path: PathBuf) -> Result<()> {
 	Ok(())
 }
 
+fn export_request(
+	client: &reqwest::Client,
+	base_url: &Url,
+	headers: &HeaderMap,
+	auth: &Option<Auth>,
+	config: &DbExportConfig,
+) -> Result<RequestBuilder> {
+	let req_path = base_url.join("export")?;
+	// The default export doesn't need a configuration
+	let request = match *config == DbExportConfig::default() {
+		true => client.get(req_path),
+		false => client
+			.post(req_path)
+			.header(CONTENT_TYPE, "application/json")
+			.body(serde_json::to_vec(config).map_err(|e| Error::SerializeValue(e.to_string()))?),
+	};
+	Ok(request.headers(headers.clone()).auth(auth).header(ACCEPT, "application/octet-stream"))
+}
+
 async fn export_bytes(request: RequestBuilder, bytes: BackupSender) -> Result<()> {
 	let response = request.send().await?.error_for_status()?;
 
@@ -274,6 +294,17 @@ async fn send_request(
 	DbResponse::from_server_result(response.result)
 }
 
+/// Returns the access token from a signin or refresh result, which
+/// also includes a refresh token when the access method issues one
+fn access_token(value: &CoreValue) -> String {
+	match value {
+		CoreValue::Object(tokens) => {
+			tokens.get("token").map(CoreValue::to_raw_string).unwrap_or_default()
+		}
+		value => value.to_raw_string(),
+	}
+}
+
 fn flatten_dbresponse_array(res: DbResponse) -> DbResponse {
 	match res {
 		DbResponse::Other(CoreValue::Array(array)) if array.len() == 1 => {
@@ -302,7 +333,7 @@ async fn router(
 				query,
 				variables,
 			}
-			.into_router_request(None)
+			.into_router_request(None, None)
 			.expect("query should be valid request");
 			send_request(req, base_url, client, headers, auth).await
 		}
@@ -314,7 +345,7 @@ async fn router(
 				namespace: namespace.clone(),
 				database: database.clone(),
 			}
-			.into_router_request(None)
+			.into_router_request(None, None)
 			.unwrap();
 			// process request to check permissions
 			let out = send_request(req, base_url, client, headers, auth).await?;
@@ -337,7 +368,7 @@ async fn router(
 			let req = Command::Signin {
 				credentials: credentials.clone(),
 			}
-			.into_router_request(None)
+			.into_router_request(None, None)
 			.expect("signin should be a valid router request");
 
 			let DbResponse::Other(value) =
@@ -364,19 +395,43 @@ async fn router(
 				});
 			} else {
 				*auth = Some(Auth::Bearer {
-					token: value.to_raw_string(),
+					token: access_token(&value),
 				});
 			}
 
 			Ok(DbResponse::Other(value))
 		}
+		Command::Refresh {
+			credentials,
+		} => {
+			let req = Command::Refresh {
+				credentials,
+			}
+			.into_router_request(None, None)
+			.expect("refresh should be a valid router request");
+
+			let DbResponse::Other(value) =
+				send_request(req, base_url, client, headers, auth).await?
+			else {
+				return Err(Error::InternalError(
+					"recieved invalid result from server".to_string(),
+				)
+				.into());
+			};
+
+			*auth = Some(Auth::Bearer {
+				token: access_token(&value),
+			});
+
+			Ok(DbResponse::Other(value))
+		}
 		Command::Authenticate {
 			token,
 		} => {
 			let req = Command::Authenticate {
 				token: token.clone(),
 			}
-			.into_router_request(None)
+			.into_router_request(None, None)
 			.expect("authenticate should be a valid router request");
 			send_request(req, base_url, client, headers, auth).await?;
 
@@ -402,7 +457,7 @@ async fn router(
 				query,
 				variables,
 			}
-			.into_router_request(None)
+			.into_router_request(None, None)
 			.expect("query is valid request");
 			let DbResponse::Query(mut res) =
 				send_request(req, base_url, client, headers, auth).await?
@@ -443,25 +498,17 @@ async fn router(
 		#[cfg(not(target_arch = "wasm32"))]
 		Command::ExportFile {
 			path,
+			config,
 		} => {
-			let req_path = base_url.join("export")?;
-			let request = client
-				.get(req_path)
-				.headers(headers.clone())
-				.auth(auth)
-				.header(ACCEPT, "application/octet-stream");
+			let request = export_request(client, base_url, headers, auth, &config)?;
 			export_file(request, path).await?;
 			Ok(DbResponse::Other(CoreValue::None))
 		}
 		Command::ExportBytes {
 			bytes,
+			config,
 		} => {
-			let req_path = base_url.join("export")?;
-			let request = client
-				.get(req_path)
-				.headers(headers.clone())
-				.auth(auth)
-				.header(ACCEPT, "application/octet-stream");
+			let request = export_request(client, base_url, headers, auth, &config)?;
 			export_bytes(request, bytes).await?;
 			Ok(DbResponse::Other(CoreValue::None))
 		}
@@ -523,9 +570,19 @@ async fn router(
 		Command::SubscribeLive {
 			..
 		} => Err(Error::LiveQueriesNotSupported.into()),
+		Command::SubscribeEvents {
+			..
+		} => Err(Error::ConnectionEventsNotSupported.into()),
+		Command::Begin
+		| Command::Commit {
+			..
+		}
+		| Command::Cancel {
+			..
+		} => Err(Error::TransactionsNotSupported.into()),
 		cmd => {
 			let needs_flatten = cmd.needs_flatten();
-			let req = cmd.into_router_request(None).unwrap();
+			let req = cmd.into_router_request(None, None).unwrap();
 			let mut res = send_request(req, base_url, client, headers, auth).await?;
 			if needs_flatten {
 				res = flatten_dbresponse_array(res);
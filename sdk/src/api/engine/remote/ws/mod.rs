@@ -7,9 +7,11 @@ pub(crate) mod wasm;
 
 use crate::api::conn::Command;
 use crate::api::conn::DbResponse;
+use crate::api::opt::Config;
 use crate::api::Connect;
 use crate::api::Result;
 use crate::api::Surreal;
+use crate::opt::ConnectionEvent;
 use crate::opt::IntoEndpoint;
 use crate::value::Notification;
 use channel::Sender;
@@ -17,13 +19,16 @@ use indexmap::IndexMap;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::time::Duration;
-use surrealdb_core::sql::Value as CoreValue;
+use surrealdb_core::sql::statements::LiveStatement;
+use surrealdb_core::sql::{self, Object as CoreObject, Statement, Value as CoreValue};
 use trice::Instant;
 use uuid::Uuid;
 
 pub(crate) const PATH: &str = "rpc";
 const PING_INTERVAL: Duration = Duration::from_secs(5);
 const REVISION_HEADER: &str = "revision";
+const DEFAULT_RECONNECT_DELAY: (Duration, Duration) =
+	(Duration::from_secs(1), Duration::from_secs(30));
 
 enum RequestEffect {
 	/// Completing this request sets a variable to a give value.
@@ -37,6 +42,10 @@ enum RequestEffect {
 	},
 	/// Insert requests repsonses need to be flattened in an array.
 	Insert,
+	/// Completing this request restarts a live query after a reconnect.
+	Resume {
+		id: Uuid,
+	},
 	/// No effect
 	None,
 }
@@ -57,13 +66,32 @@ struct PendingRequest {
 	response_channel: Sender<Result<DbResponse>>,
 }
 
+/// A live query which is restarted on a reconnect
+struct LiveQuery {
+	/// The channel into which notifications are sent
+	sender: Sender<Notification<CoreValue>>,
+	/// The statement which started the live query
+	statement: LiveStatement,
+	/// The variables of the statement
+	variables: CoreObject,
+	/// The id of the live query on the server, which changes on a reconnect
+	server_id: Uuid,
+}
+
 struct RouterState<Sink, Stream> {
 	/// Vars currently set by the set method,
 	vars: IndexMap<String, CoreValue>,
 	/// Messages which aught to be replayed on a reconnect.
 	replay: IndexMap<ReplayMethod, Command>,
-	/// Pending live queries
-	live_queries: HashMap<Uuid, channel::Sender<Notification<CoreValue>>>,
+	/// Pending live queries, by the id known to the caller
+	live_queries: HashMap<Uuid, LiveQuery>,
+	/// The ids known to the caller, by the id of the live query on the server
+	live_query_ids: HashMap<Uuid, Uuid>,
+	/// The channels into which connection events are sent
+	event_senders: Vec<Sender<ConnectionEvent>>,
+	/// The id of the next request sent by the router itself, counting down to avoid
+	/// colliding with the ids of the requests sent by the client.
+	internal_id: i64,
 	/// Send requests which are still awaiting an awnser.
 	pending_requests: HashMap<i64, PendingRequest>,
 	/// The last time a message was recieved from the server.
@@ -80,12 +108,73 @@ impl<Sink, Stream> RouterState<Sink, Stream> {
 			vars: IndexMap::new(),
 			replay: IndexMap::new(),
 			live_queries: HashMap::new(),
+			live_query_ids: HashMap::new(),
+			event_senders: Vec::new(),
+			internal_id: 0,
 			pending_requests: HashMap::new(),
 			last_activity: Instant::now(),
 			sink,
 			stream,
 		}
 	}
+
+	/// Returns the id of the next request sent by the router itself
+	fn next_internal_id(&mut self) -> i64 {
+		self.internal_id -= 1;
+		self.internal_id
+	}
+
+	/// Sends a connection event to every subscriber, forgetting the dropped ones
+	async fn send_event(&mut self, event: ConnectionEvent) {
+		let mut senders = Vec::with_capacity(self.event_senders.len());
+		for sender in self.event_senders.drain(..) {
+			if sender.send(event).await.is_ok() {
+				senders.push(sender);
+			}
+		}
+		self.event_senders = senders;
+	}
+
+	/// Registers a live query started by the client
+	fn insert_live_query(&mut self, id: Uuid, query: LiveQuery) {
+		let server_id = query.server_id;
+		if let Some(old) = self.live_queries.insert(id, query) {
+			self.live_query_ids.remove(&old.server_id);
+		}
+		self.live_query_ids.insert(server_id, id);
+	}
+
+	/// Removes a live query, returning its id on the server
+	fn remove_live_query(&mut self, id: &Uuid) -> Option<Uuid> {
+		let query = self.live_queries.remove(id)?;
+		self.live_query_ids.remove(&query.server_id);
+		Some(query.server_id)
+	}
+
+	/// Points a live query to its new id on the server, after it was restarted
+	fn resume_live_query(&mut self, id: Uuid, server_id: Uuid) {
+		if let Some(query) = self.live_queries.get_mut(&id) {
+			self.live_query_ids.remove(&query.server_id);
+			self.live_query_ids.insert(server_id, id);
+			query.server_id = server_id;
+		}
+	}
+}
+
+/// Returns the delay before the given attempt to reconnect, starting at zero
+fn reconnect_delay(config: &Config, attempt: u32) -> Duration {
+	let (initial, max) = config.reconnect_delay.unwrap_or(DEFAULT_RECONNECT_DELAY);
+	initial.checked_mul(2u32.saturating_pow(attempt)).unwrap_or(max).min(max)
+}
+
+/// Returns the statement which restarts a live query
+fn resume_command(query: &LiveQuery) -> Command {
+	let mut statements = sql::Query::default();
+	statements.0 .0 = vec![Statement::Live(query.statement.clone())];
+	Command::Query {
+		query: statements,
+		variables: query.variables.clone(),
+	}
 }
 
 enum HandleResult {
@@ -140,3 +229,56 @@ impl Surreal<Client> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use surrealdb_core::sql::Fields;
+
+	#[test]
+	fn reconnect_delay_backs_off() {
+		let config =
+			Config::new().reconnect_delay(Duration::from_millis(100), Duration::from_secs(1));
+		assert_eq!(reconnect_delay(&config, 0), Duration::from_millis(100));
+		assert_eq!(reconnect_delay(&config, 1), Duration::from_millis(200));
+		assert_eq!(reconnect_delay(&config, 3), Duration::from_millis(800));
+		assert_eq!(reconnect_delay(&config, 4), Duration::from_secs(1));
+		assert_eq!(reconnect_delay(&config, u32::MAX), Duration::from_secs(1));
+		let config = Config::new();
+		assert_eq!(reconnect_delay(&config, 0), Duration::from_secs(1));
+		assert_eq!(reconnect_delay(&config, 10), Duration::from_secs(30));
+	}
+
+	#[test]
+	fn resumed_live_queries_keep_their_id() {
+		let mut state = RouterState::new((), ());
+		let (sender, _rx) = channel::unbounded();
+		let id = Uuid::new_v4();
+		state.insert_live_query(
+			id,
+			LiveQuery {
+				sender,
+				statement: LiveStatement::new(Fields::default()),
+				variables: CoreObject::default(),
+				server_id: id,
+			},
+		);
+		assert_eq!(state.live_query_ids.get(&id), Some(&id));
+		// The server gives the live query a new id after a reconnect
+		let server_id = Uuid::new_v4();
+		state.resume_live_query(id, server_id);
+		assert_eq!(state.live_query_ids.get(&id), None);
+		assert_eq!(state.live_query_ids.get(&server_id), Some(&id));
+		// Killing the live query uses the id known by the server
+		assert_eq!(state.remove_live_query(&id), Some(server_id));
+		assert!(state.live_query_ids.is_empty());
+		assert!(state.live_queries.is_empty());
+	}
+
+	#[test]
+	fn internal_ids_are_negative() {
+		let mut state = RouterState::new((), ());
+		assert_eq!(state.next_internal_id(), -1);
+		assert_eq!(state.next_internal_id(), -2);
+	}
+}
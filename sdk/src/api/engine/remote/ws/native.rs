@@ -1,4 +1,7 @@
-use super::{HandleResult, PendingRequest, ReplayMethod, RequestEffect, PATH};
+use super::{
+	reconnect_delay, resume_command, HandleResult, LiveQuery, PendingRequest, ReplayMethod,
+	RequestEffect, PATH,
+};
 use crate::api::conn::Route;
 use crate::api::conn::Router;
 use crate::api::conn::{Command, DbResponse};
@@ -18,8 +21,9 @@ use crate::api::Result;
 use crate::api::Surreal;
 use crate::engine::remote::Data;
 use crate::engine::IntervalStream;
+use crate::opt::ConnectionEvent;
 use crate::opt::WaitFor;
-use crate::{Action, Notification};
+use crate::Notification;
 use channel::Receiver;
 use futures::stream::{SplitSink, SplitStream};
 use futures::SinkExt;
@@ -134,7 +138,8 @@ impl Connection for Client {
 				Arc::new(OnceLock::with_value(Router {
 					features,
 					sender: route_tx,
-					last_id: AtomicI64::new(0),
+					last_id: Arc::new(AtomicI64::new(0)),
+					transaction: None,
 				})),
 				Arc::new(watch::channel(Some(WaitFor::Connection))),
 			))
@@ -152,9 +157,20 @@ async fn router_handle_route(
 ) -> HandleResult {
 	let RequestData {
 		id,
-		command,
+		mut command,
+		transaction,
 	} = request;
 
+	// The server knows live queries by the id they got when they were last started
+	if let Command::Kill {
+		uuid,
+	} = &mut command
+	{
+		if let Some(server_id) = state.remove_live_query(uuid) {
+			*uuid = server_id;
+		}
+	}
+
 	// We probably shouldn't be sending duplicate id requests.
 	let entry = state.pending_requests.entry(id);
 	let Entry::Vacant(entry) = entry else {
@@ -190,20 +206,35 @@ async fn router_handle_route(
 			effect = RequestEffect::Insert;
 		}
 		Command::SubscribeLive {
-			ref uuid,
-			ref notification_sender,
+			uuid,
+			notification_sender,
+			statement,
+			variables,
 		} => {
-			state.live_queries.insert(*uuid, notification_sender.clone());
-			if response.clone().send(Ok(DbResponse::Other(CoreValue::None))).await.is_err() {
+			state.insert_live_query(
+				uuid,
+				LiveQuery {
+					sender: notification_sender,
+					statement,
+					variables,
+					server_id: uuid,
+				},
+			);
+			if response.send(Ok(DbResponse::Other(CoreValue::None))).await.is_err() {
 				trace!("Receiver dropped");
 			}
 			// There is nothing to send to the server here
 			return HandleResult::Ok;
 		}
-		Command::Kill {
-			ref uuid,
+		Command::SubscribeEvents {
+			sender,
 		} => {
-			state.live_queries.remove(uuid);
+			state.event_senders.push(sender);
+			if response.send(Ok(DbResponse::Other(CoreValue::None))).await.is_err() {
+				trace!("Receiver dropped");
+			}
+			// There is nothing to send to the server here
+			return HandleResult::Ok;
 		}
 		Command::Use {
 			..
@@ -234,7 +265,7 @@ async fn router_handle_route(
 	}
 
 	let message = {
-		let Some(request) = command.into_router_request(Some(id)) else {
+		let Some(request) = command.into_router_request(Some(id), transaction) else {
 			let _ = response.send(Err(Error::BackupsNotSupported.into())).await;
 			return HandleResult::Ok;
 		};
@@ -280,6 +311,13 @@ async fn router_handle_response(
 								let resp = match DbResponse::from_server_result(response.result) {
 									Ok(x) => x,
 									Err(e) => {
+										if let RequestEffect::Resume {
+											id,
+										} = pending.effect
+										{
+											warn!("failed to restart live query '{id}'; {e}");
+											state.remove_live_query(&id);
+										}
 										let _ = pending.response_channel.send(Err(e)).await;
 										return HandleResult::Ok;
 									}
@@ -319,6 +357,31 @@ async fn router_handle_response(
 									} => {
 										state.vars.shift_remove(&key);
 									}
+									RequestEffect::Resume {
+										id,
+									} => {
+										// Point the live query to the id it got on the server,
+										// or end its stream if it couldn't be restarted
+										match &resp {
+											DbResponse::Query(response) => {
+												match response.results.get(&0) {
+													Some((_, Ok(CoreValue::Uuid(server_id)))) => {
+														state.resume_live_query(id, server_id.0);
+													}
+													_ => {
+														warn!(
+															"failed to restart live query '{id}'"
+														);
+														state.remove_live_query(&id);
+													}
+												}
+											}
+											DbResponse::Other(_) => {
+												warn!("failed to restart live query '{id}'");
+												state.remove_live_query(&id);
+											}
+										}
+									}
 								}
 								let _res = pending.response_channel.send(Ok(resp)).await;
 							} else {
@@ -332,21 +395,24 @@ async fn router_handle_response(
 							Ok(Data::Live(notification)) => {
 								let live_query_id = notification.id;
 								// Check if this live query is registered
-								if let Some(sender) = state.live_queries.get(&live_query_id) {
+								let Some(id) = state.live_query_ids.get(&live_query_id).copied()
+								else {
+									return HandleResult::Ok;
+								};
+								if let Some(query) = state.live_queries.get(&id) {
 									// Send the notification back to the caller or kill live query if the receiver is already dropped
 
-									let notification = Notification {
-										query_id: *notification.id,
-										action: Action::from_core(notification.action),
-										data: notification.result,
-									};
-									if sender.send(notification).await.is_err() {
-										state.live_queries.remove(&live_query_id);
+									let mut notification =
+										Notification::from_core_notification(notification);
+									// The caller knows the live query by its original id
+									notification.query_id = id;
+									if query.sender.send(notification).await.is_err() {
+										state.remove_live_query(&id);
 										let kill = {
 											let request = Command::Kill {
 												uuid: live_query_id.0,
 											}
-											.into_router_request(None)
+											.into_router_request(None, None)
 											.unwrap();
 											let value =
 												serialize(&request, endpoint.supports_revision)
@@ -398,13 +464,29 @@ async fn router_handle_response(
 	HandleResult::Ok
 }
 
+/// Reconnects to the server, returning `false` once the attempts to reconnect run out
 async fn router_reconnect(
 	maybe_connector: &Option<Connector>,
 	config: &WebSocketConfig,
 	state: &mut RouterState,
 	endpoint: &Endpoint,
-) {
-	loop {
+) -> bool {
+	state.send_event(ConnectionEvent::Disconnected).await;
+	let mut attempt = 0;
+	'reconnect: loop {
+		// The requests sent on a previous connection will never get a response
+		state.pending_requests.clear();
+		if let Some(attempts) = endpoint.config.reconnect_attempts {
+			if attempt >= attempts {
+				trace!("Giving up reconnecting after {attempt} attempts");
+				state.send_event(ConnectionEvent::ReconnectFailed).await;
+				return false;
+			}
+		}
+		if attempt > 0 {
+			time::sleep(reconnect_delay(&endpoint.config, attempt - 1)).await;
+		}
+		attempt += 1;
 		trace!("Reconnecting...");
 		match connect(endpoint, Some(*config), maybe_connector.clone()).await {
 			Ok(s) => {
@@ -414,15 +496,14 @@ async fn router_reconnect(
 				for commands in state.replay.values() {
 					let request = commands
 						.clone()
-						.into_router_request(None)
+						.into_router_request(None, None)
 						.expect("replay commands should always convert to route requests");
 
 					let message = serialize(&request, endpoint.supports_revision).unwrap();
 
 					if let Err(error) = state.sink.send(Message::Binary(message)).await {
 						trace!("{error}");
-						time::sleep(time::Duration::from_secs(1)).await;
-						continue;
+						continue 'reconnect;
 					}
 				}
 				for (key, value) in &state.vars {
@@ -430,22 +511,43 @@ async fn router_reconnect(
 						key: key.as_str().into(),
 						value: value.clone(),
 					}
-					.into_router_request(None)
+					.into_router_request(None, None)
 					.unwrap();
 					trace!("Request {:?}", request);
 					let payload = serialize(&request, endpoint.supports_revision).unwrap();
 					if let Err(error) = state.sink.send(Message::Binary(payload)).await {
 						trace!("{error}");
-						time::sleep(time::Duration::from_secs(1)).await;
-						continue;
+						continue 'reconnect;
 					}
 				}
+				// Restart the live queries, their new ids are set once the server responds
+				let ids: Vec<_> = state.live_queries.keys().copied().collect();
+				for id in ids {
+					let command = resume_command(&state.live_queries[&id]);
+					let request_id = state.next_internal_id();
+					let request = command.into_router_request(Some(request_id), None).unwrap();
+					trace!("Request {:?}", request);
+					let payload = serialize(&request, endpoint.supports_revision).unwrap();
+					if let Err(error) = state.sink.send(Message::Binary(payload)).await {
+						trace!("{error}");
+						continue 'reconnect;
+					}
+					state.pending_requests.insert(
+						request_id,
+						PendingRequest {
+							effect: RequestEffect::Resume {
+								id,
+							},
+							response_channel: channel::bounded(1).0,
+						},
+					);
+				}
 				trace!("Reconnected successfully");
-				break;
+				state.send_event(ConnectionEvent::Reconnected).await;
+				return true;
 			}
 			Err(error) => {
 				trace!("Failed to reconnect; {error}");
-				time::sleep(time::Duration::from_secs(1)).await;
 			}
 		}
 	}
@@ -460,7 +562,7 @@ pub(crate) async fn run_router(
 	route_rx: Receiver<Route>,
 ) {
 	let ping = {
-		let request = Command::Health.into_router_request(None).unwrap();
+		let request = Command::Health.into_router_request(None, None).unwrap();
 		let value = serialize(&request, endpoint.supports_revision).unwrap();
 		Message::Binary(value)
 	};
@@ -479,8 +581,6 @@ pub(crate) async fn run_router(
 		// recreated with each next.
 
 		state.last_activity = Instant::now();
-		state.live_queries.clear();
-		state.pending_requests.clear();
 
 		loop {
 			tokio::select! {
@@ -502,13 +602,16 @@ pub(crate) async fn run_router(
 					match router_handle_route(response, &mut state, &endpoint).await {
 						HandleResult::Ok => {},
 						HandleResult::Disconnected => {
-							router_reconnect(
+							if !router_reconnect(
 								&maybe_connector,
 								&config,
 								&mut state,
 								&endpoint,
 							)
-							.await;
+							.await
+							{
+								break 'router;
+							}
 							continue 'router;
 						}
 					}
@@ -518,13 +621,16 @@ pub(crate) async fn run_router(
 
 					let Some(result) = result else {
 						// stream returned none meaning the connection dropped, try to reconnect.
-						router_reconnect(
+						if !router_reconnect(
 							&maybe_connector,
 							&config,
 							&mut state,
 							&endpoint,
 						)
-						.await;
+						.await
+						{
+							break 'router;
+						}
 						continue 'router;
 					};
 
@@ -534,13 +640,16 @@ pub(crate) async fn run_router(
 							match router_handle_response(message, &mut state, &endpoint).await {
 								HandleResult::Ok => continue,
 								HandleResult::Disconnected => {
-									router_reconnect(
+									if !router_reconnect(
 										&maybe_connector,
 										&config,
 										&mut state,
 										&endpoint,
 									)
-									.await;
+									.await
+									{
+										break 'router;
+									}
 									continue 'router;
 								}
 							}
@@ -554,13 +663,16 @@ pub(crate) async fn run_router(
 									trace!("{error}");
 								}
 							}
-							router_reconnect(
+							if !router_reconnect(
 								&maybe_connector,
 								&config,
 								&mut state,
 								&endpoint,
 							)
-							.await;
+							.await
+							{
+								break 'router;
+							}
 							continue 'router;
 						}
 					}
@@ -571,13 +683,16 @@ pub(crate) async fn run_router(
 						trace!("Pinging the server");
 						if let Err(error) = state.sink.send(ping.clone()).await {
 							trace!("failed to ping the server; {error:?}");
-							router_reconnect(
+							if !router_reconnect(
 								&maybe_connector,
 								&config,
 								&mut state,
 								&endpoint,
 							)
-							.await;
+							.await
+							{
+								break 'router;
+							}
 							continue 'router;
 						}
 					}
@@ -173,6 +173,18 @@ pub enum Error {
 	#[error("The protocol or storage engine does not support live queries on this architecture")]
 	LiveQueriesNotSupported,
 
+	/// The protocol or storage engine being used does not support interactive transactions
+	#[error("The protocol or storage engine does not support interactive transactions")]
+	TransactionsNotSupported,
+
+	/// The protocol or storage engine being used does not support connection events
+	#[error("The protocol or storage engine does not support connection events")]
+	ConnectionEventsNotSupported,
+
+	/// The protocol or storage engine being used does not support GraphQL queries
+	#[error("The protocol or storage engine does not support GraphQL queries")]
+	GraphQLNotSupported,
+
 	/// Tried to use a range query on an object
 	#[error("Live queries on objects not supported")]
 	LiveOnObject,
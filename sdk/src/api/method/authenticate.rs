@@ -28,7 +28,7 @@ where
 			let router = self.client.router.extract()?;
 			router
 				.execute_unit(Command::Authenticate {
-					token: self.token.0,
+					token: self.token.token,
 				})
 				.await
 		})
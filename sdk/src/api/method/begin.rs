@@ -1,12 +1,18 @@
+use crate::api::conn::Command;
+use crate::api::err::Error;
 use crate::api::method::BoxFuture;
 use crate::api::method::Cancel;
 use crate::api::method::Commit;
 use crate::api::Connection;
+use crate::api::OnceLockExt;
 use crate::api::Result;
 use crate::api::Surreal;
 use std::future::IntoFuture;
 use std::ops::Deref;
-use surrealdb_core::sql::statements::BeginStatement;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use surrealdb_core::sql::Value as CoreValue;
+use uuid::Uuid;
 
 /// A beginning of a transaction
 #[derive(Debug)]
@@ -24,9 +30,24 @@ where
 
 	fn into_future(self) -> Self::IntoFuture {
 		Box::pin(async move {
-			self.client.query(BeginStatement::default()).await?;
+			let router = self.client.router.extract()?;
+			let CoreValue::Uuid(id) = router.execute_value(Command::Begin).await?.into_inner()
+			else {
+				return Err(Error::InternalError(
+					"successful begin didn't return a transaction id".to_string(),
+				)
+				.into());
+			};
+			// Every command sent through the transaction runs within it
+			let router = router.with_transaction(*id);
+			let txn = Surreal::new_from_router_waiter(
+				Arc::new(OnceLock::with_value(router)),
+				self.client.waiter.clone(),
+			);
 			Ok(Transaction {
+				id: *id,
 				client: self.client,
+				txn,
 			})
 		})
 	}
@@ -36,17 +57,25 @@ where
 #[derive(Debug)]
 #[must_use = "transactions must be committed or cancelled to complete them"]
 pub struct Transaction<C: Connection> {
+	id: Uuid,
 	client: Surreal<C>,
+	txn: Surreal<C>,
 }
 
 impl<C> Transaction<C>
 where
 	C: Connection,
 {
+	/// The id of the transaction on the server
+	pub fn id(&self) -> Uuid {
+		self.id
+	}
+
 	/// Creates a commit future
 	pub fn commit(self) -> Commit<C> {
 		Commit {
 			client: self.client,
+			id: self.id,
 		}
 	}
 
@@ -54,6 +83,7 @@ where
 	pub fn cancel(self) -> Cancel<C> {
 		Cancel {
 			client: self.client,
+			id: self.id,
 		}
 	}
 }
@@ -65,6 +95,6 @@ where
 	type Target = Surreal<C>;
 
 	fn deref(&self) -> &Self::Target {
-		&self.client
+		&self.txn
 	}
 }
@@ -0,0 +1,71 @@
+use crate::api::conn::Command;
+use crate::api::method::BoxFuture;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::opt::ConnectionEvent;
+use crate::Surreal;
+use channel::Receiver;
+use futures::StreamExt;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+/// A connection events future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ConnectionEvents<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+}
+
+impl<C> ConnectionEvents<'_, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> ConnectionEvents<'static, C> {
+		ConnectionEvents {
+			client: Cow::Owned(self.client.into_owned()),
+		}
+	}
+}
+
+impl<'r, Client> IntoFuture for ConnectionEvents<'r, Client>
+where
+	Client: Connection,
+{
+	type Output = Result<ConnectionEventStream>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move {
+			let router = self.client.router.extract()?;
+			let (sender, rx) = channel::unbounded();
+			router
+				.execute_unit(Command::SubscribeEvents {
+					sender,
+				})
+				.await?;
+			Ok(ConnectionEventStream {
+				rx,
+			})
+		})
+	}
+}
+
+/// A stream of the changes in the state of a connection
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct ConnectionEventStream {
+	rx: Receiver<ConnectionEvent>,
+}
+
+impl futures::Stream for ConnectionEventStream {
+	type Item = ConnectionEvent;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.rx.poll_next_unpin(cx)
+	}
+}
@@ -1,6 +1,7 @@
 use crate::api::conn::Command;
 use crate::api::conn::MlExportConfig;
 use crate::api::method::BoxFuture;
+use crate::api::opt::ExportConfig;
 use crate::api::Connection;
 use crate::api::Error;
 use crate::api::ExtraFeatures;
@@ -27,6 +28,7 @@ pub struct Export<'r, C: Connection, R, T = ()> {
 	pub(super) client: Cow<'r, Surreal<C>>,
 	pub(super) target: R,
 	pub(super) ml_config: Option<MlExportConfig>,
+	pub(super) config: ExportConfig,
 	pub(super) response: PhantomData<R>,
 	pub(super) export_type: PhantomData<T>,
 }
@@ -44,10 +46,19 @@ where
 				name: name.to_owned(),
 				version: version.to_string(),
 			}),
+			config: self.config,
 			response: self.response,
 			export_type: PhantomData,
 		}
 	}
+
+	/// Configures what to export and in which format
+	pub fn with_config(self, config: ExportConfig) -> Self {
+		Export {
+			config,
+			..self
+		}
+	}
 }
 
 impl<C, R, T> Export<'_, C, R, T>
@@ -89,6 +100,7 @@ where
 			router
 				.execute_unit(Command::ExportFile {
 					path: self.target,
+					config: self.config.0,
 				})
 				.await
 		})
@@ -125,6 +137,7 @@ where
 			router
 				.execute_unit(Command::ExportBytes {
 					bytes: tx,
+					config: self.config.0,
 				})
 				.await?;
 
@@ -0,0 +1,85 @@
+use crate::api::conn::Command;
+use crate::api::err::Error;
+use crate::api::method::BoxFuture;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::Surreal;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use surrealdb_core::sql::{to_value as to_core_value, Object as CoreObject, Value as CoreValue};
+
+/// A GraphQL query future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GraphQL<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) query: String,
+	pub(super) variables: Result<CoreObject>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> GraphQL<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> GraphQL<'static, C, R> {
+		GraphQL {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+impl<'r, Client, R> IntoFuture for GraphQL<'r, Client, R>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<R>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		let GraphQL {
+			client,
+			query,
+			variables,
+			..
+		} = self;
+		Box::pin(async move {
+			let router = client.router.extract()?;
+			let response = router
+				.execute_value(Command::GraphQL {
+					query,
+					variables: variables?,
+				})
+				.await?
+				.into_inner();
+			// The response is returned as a serialized JSON document
+			let response = response.as_raw_string();
+			serde_json::from_str(&response).map_err(|error| {
+				Error::FromJsonString {
+					string: response,
+					error: error.to_string(),
+				}
+				.into()
+			})
+		})
+	}
+}
+
+/// Converts the variables of a GraphQL query into an object
+pub(super) fn into_variables(variables: impl Serialize + 'static) -> Result<CoreObject> {
+	match to_core_value(variables)? {
+		CoreValue::None | CoreValue::Null => Ok(CoreObject::default()),
+		CoreValue::Object(variables) => Ok(variables),
+		variables => Err(Error::InvalidParams(format!(
+			"GraphQL variables must be an object, but found `{variables}`"
+		))
+		.into()),
+	}
+}
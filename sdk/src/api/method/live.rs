@@ -23,8 +23,8 @@ use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use surrealdb_core::sql::{
-	statements::LiveStatement, Cond, Expression, Field, Fields, Ident, Idiom, Operator, Part,
-	Statement, Table, Value as CoreValue,
+	statements::LiveStatement, Cond, Expression, Field, Fields, Ident, Idiom, Object as CoreObject,
+	Operator, Part, Statement, Table, Value as CoreValue,
 };
 use uuid::Uuid;
 
@@ -87,15 +87,19 @@ where
 			}
 			Resource::Unspecified => return Err(Error::LiveOnUnspecified.into()),
 		}
-		let query =
-			Query::new(client.clone(), vec![Statement::Live(stmt)], Default::default(), false);
+		let query = Query::new(
+			client.clone(),
+			vec![Statement::Live(stmt.clone())],
+			Default::default(),
+			false,
+		);
 		let CoreValue::Uuid(id) = query.await?.take::<Value>(0)?.into_inner() else {
 			return Err(Error::InternalError(
 				"successufull live query didn't return a uuid".to_string(),
 			)
 			.into());
 		};
-		let rx = register(router, *id).await?;
+		let rx = register(router, *id, stmt, Default::default()).await?;
 		Ok(Stream::new(
 			Surreal::new_from_router_waiter(client.router.clone(), client.waiter.clone()),
 			*id,
@@ -107,12 +111,16 @@ where
 pub(crate) async fn register(
 	router: &Router,
 	id: Uuid,
+	statement: LiveStatement,
+	variables: CoreObject,
 ) -> Result<Receiver<Notification<CoreValue>>> {
 	let (tx, rx) = channel::unbounded();
 	router
 		.execute_unit(Command::SubscribeLive {
 			uuid: id,
 			notification_sender: tx,
+			statement,
+			variables,
 		})
 		.await?;
 	Ok(rx)
@@ -206,7 +214,9 @@ impl futures::Stream for Stream<Value> {
 			let r = Notification{
 				query_id: notification.query_id,
 				action: notification.action,
+				record: notification.record,
 				data: Value::from_inner(notification.data),
+				before: notification.before.map(Value::from_inner),
 			};
 			Poll::Ready(Some(r))
 		}
@@ -31,13 +31,18 @@ mod commit;
 mod content;
 mod create;
 mod delete;
+mod events;
 mod export;
+mod graphql;
 mod health;
 mod import;
+mod info;
 mod insert;
 mod invalidate;
 mod merge;
 mod patch;
+mod refresh;
+mod relate;
 mod run;
 mod select;
 mod set;
@@ -65,10 +70,13 @@ pub use commit::Commit;
 pub use content::Content;
 pub use create::Create;
 pub use delete::Delete;
+pub use events::{ConnectionEventStream, ConnectionEvents};
 pub use export::{Backup, Export};
 use futures::Future;
+pub use graphql::GraphQL;
 pub use health::Health;
 pub use import::Import;
+pub use info::Info;
 pub use insert::Insert;
 pub use invalidate::Invalidate;
 pub use live::Stream;
@@ -76,6 +84,8 @@ pub use merge::Merge;
 pub use patch::Patch;
 pub use query::Query;
 pub use query::QueryStream;
+pub use refresh::Refresh;
+pub use relate::{Relate, RelateTo, RelateWith};
 pub use run::IntoFn;
 pub use run::Run;
 pub use select::Select;
@@ -248,7 +258,36 @@ where
 		}
 	}
 
-	#[doc(hidden)] // Not supported yet
+	/// Begins a transaction which spans several requests
+	///
+	/// Every method called on the transaction runs within it, until it is committed or cancelled.
+	/// The transaction is cancelled by the server if it stays idle for too long.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Deserialize;
+	///
+	/// #[derive(Debug, Deserialize)]
+	/// struct Account {
+	///     balance: f64,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let transaction = db.clone().transaction().await?;
+	/// let account: Option<Account> = transaction.select(("account", "one")).await?;
+	/// if let Some(account) = account {
+	///     transaction
+	///         .query("UPDATE account:one SET balance = $balance")
+	///         .bind(("balance", account.balance - 300.0))
+	///         .await?;
+	/// }
+	/// transaction.commit().await?;
+	/// # Ok(())
+	/// # }
+	/// ```
 	pub fn transaction(self) -> Begin<C> {
 		Begin {
 			client: self,
@@ -552,6 +591,69 @@ where
 		}
 	}
 
+	/// Exchanges a refresh token for a new pair of tokens
+	///
+	/// Refresh tokens are issued when signing in or up with a database record access
+	/// method defined `WITH REFRESH`. Each refresh token can only be exchanged once.
+	/// Other access methods, and system users, do not issue refresh tokens, so their
+	/// sessions are renewed by signing in again.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Serialize;
+	/// use surrealdb::opt::auth::Jwt;
+	/// use surrealdb::opt::auth::Record;
+	///
+	/// #[derive(Debug, Serialize)]
+	/// struct AuthParams {
+	///     email: String,
+	///     password: String,
+	/// }
+	///
+	/// #[derive(Debug, Serialize)]
+	/// struct RefreshParams<'a> {
+	///     refresh: &'a str,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Sign a user in
+	/// let jwt: Jwt = db.signin(Record {
+	///     namespace: "namespace",
+	///     database: "database",
+	///     access: "user_access",
+	///     params: AuthParams {
+	///         email: "john.doe@example.com".into(),
+	///         password: "password123".into(),
+	///     },
+	/// }).await?;
+	///
+	/// // Exchange the refresh token for new tokens
+	/// if let Some(refresh) = jwt.as_insecure_refresh_token() {
+	///     let jwt: Jwt = db.refresh(Record {
+	///         namespace: "namespace",
+	///         database: "database",
+	///         access: "user_access",
+	///         params: RefreshParams {
+	///             refresh,
+	///         },
+	///     }).await?;
+	/// }
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn refresh<R>(&self, credentials: impl Credentials<auth::Refresh, R>) -> Refresh<C, R> {
+		Refresh {
+			client: Cow::Borrowed(self),
+			credentials: Serializer::new().serialize(credentials),
+			response_type: PhantomData,
+		}
+	}
+
 	/// Invalidates the authentication for the current connection
 	///
 	/// # Examples
@@ -1226,6 +1328,119 @@ where
 		}
 	}
 
+	/// Relates records with edges, such as `RELATE $from->$with->$to`
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Serialize;
+	///
+	/// # #[derive(serde::Deserialize)]
+	/// # struct Wrote;
+	/// #
+	/// #[derive(Serialize)]
+	/// struct Details {
+	///     year: u16,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// // Relate two records with a new edge in a table
+	/// let edges: Vec<Wrote> = db
+	///     .relate(("person", "tobie"))
+	///     .with("wrote")
+	///     .to(("article", "surreal"))
+	///     .content(Details { year: 2024 })
+	///     .await?;
+	///
+	/// // Relate two records with a specific edge
+	/// let edge: Option<Wrote> = db
+	///     .relate(("person", "tobie"))
+	///     .with(("wrote", "first"))
+	///     .to(("article", "surreal"))
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn relate(&self, from: impl Into<opt::Resource>) -> Relate<C> {
+		Relate {
+			client: Cow::Borrowed(self),
+			from: from.into(),
+		}
+	}
+
+	/// Returns the record of the user authenticated on this connection
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde::Deserialize;
+	///
+	/// #[derive(Debug, Deserialize)]
+	/// struct User {
+	///     name: String,
+	/// }
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// let user: Option<User> = db.info().await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn info<R>(&self) -> Info<C, R> {
+		Info {
+			client: Cow::Borrowed(self),
+			response_type: PhantomData,
+		}
+	}
+
+	/// Runs a GraphQL query against the schema of the selected namespace and database
+	///
+	/// GraphQL is experimental, so the server and the embedded engines only support it in
+	/// builds with the `surrealdb_unstable` flag, and the server requires it to be enabled.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use serde_json::json;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("mem://").await?;
+	/// #
+	/// // Select the namespace/database to use
+	/// db.use_ns("namespace").use_db("database").await?;
+	///
+	/// let response: serde_json::Value = db
+	///     .graphql(
+	///         "query($name: String!) { person(filter: { name: { eq: $name } }) { id } }",
+	///         json!({ "name": "Tobie" }),
+	///     )
+	///     .await?;
+	/// #
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn graphql<R>(
+		&self,
+		query: impl Into<String>,
+		variables: impl Serialize + 'static,
+	) -> GraphQL<C, R> {
+		GraphQL {
+			client: Cow::Borrowed(self),
+			query: query.into(),
+			variables: graphql::into_variables(variables),
+			response_type: PhantomData,
+		}
+	}
+
 	/// Returns the version of the server
 	///
 	/// # Examples
@@ -1244,6 +1459,37 @@ where
 		}
 	}
 
+	/// Subscribes to the changes in the state of the connection
+	///
+	/// Only the WebSocket engine reconnects to the server, so other engines return an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use futures::StreamExt;
+	/// use surrealdb::opt::ConnectionEvent;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> surrealdb::Result<()> {
+	/// # let db = surrealdb::engine::any::connect("ws://localhost:8000").await?;
+	/// let mut events = db.connection_events().await?;
+	/// while let Some(event) = events.next().await {
+	///     match event {
+	///         ConnectionEvent::Disconnected => println!("Lost the connection"),
+	///         ConnectionEvent::Reconnected => println!("Reconnected"),
+	///         ConnectionEvent::ReconnectFailed => break,
+	///         _ => {}
+	///     }
+	/// }
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn connection_events(&self) -> ConnectionEvents<C> {
+		ConnectionEvents {
+			client: Cow::Borrowed(self),
+		}
+	}
+
 	/// Runs a function
 	///
 	/// # Examples
@@ -1346,6 +1592,7 @@ where
 			client: Cow::Borrowed(self),
 			target: target.into_export_destination(),
 			ml_config: None,
+			config: Default::default(),
 			response: PhantomData,
 			export_type: PhantomData,
 		}
@@ -136,8 +136,10 @@ where
 						)
 					})
 					.enumerate()
-					.filter(|(_, x)| matches!(x, Statement::Live(_)))
-					.map(|(i, _)| i)
+					.filter_map(|(i, x)| match x {
+						Statement::Live(stmt) => Some((i, stmt.clone())),
+						_ => None,
+					})
 					.collect()
 			} else {
 				Vec::new()
@@ -149,6 +151,12 @@ where
 				return Err(Error::LiveQueriesNotSupported.into());
 			}
 
+			// Keep the variables of the live queries, to restart them on a reconnect
+			let variables = match query_indicies.is_empty() {
+				true => CoreObject::default(),
+				false => bindings.clone(),
+			};
+
 			let mut query = sql::Query::default();
 			query.0 .0 = query_statements;
 
@@ -159,7 +167,7 @@ where
 				})
 				.await?;
 
-			for idx in query_indicies {
+			for (idx, statement) in query_indicies {
 				let Some((_, result)) = response.results.get(&idx) else {
 					continue;
 				};
@@ -174,16 +182,18 @@ where
 							)
 							.into());
 						};
-						live::register(router, uuid.0).await.map(|rx| {
-							Stream::new(
-								Surreal::new_from_router_waiter(
-									client.router.clone(),
-									client.waiter.clone(),
-								),
-								uuid.0,
-								Some(rx),
-							)
-						})
+						live::register(router, uuid.0, statement, variables.clone()).await.map(
+							|rx| {
+								Stream::new(
+									Surreal::new_from_router_waiter(
+										client.router.clone(),
+										client.waiter.clone(),
+									),
+									uuid.0,
+									Some(rx),
+								)
+							},
+						)
 					}
 					Err(_) => Err(crate::Error::from(Error::NotLiveQuery(idx))),
 				};
@@ -0,0 +1,189 @@
+use crate::api::conn::Command;
+use crate::api::method::BoxFuture;
+use crate::api::opt::Resource;
+use crate::api::Connection;
+use crate::api::Result;
+use crate::method::OnceLockExt;
+use crate::opt::IntoResource;
+use crate::Surreal;
+use crate::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Cow;
+use std::future::IntoFuture;
+use std::marker::PhantomData;
+use surrealdb_core::sql::{to_value as to_core_value, Value as CoreValue};
+
+use super::Content;
+
+/// The first part of a relate future, which specifies the records the edges start from
+#[derive(Debug)]
+#[must_use = "a relation needs an edge and a target, specified with `.with()` and `.to()`"]
+pub struct Relate<'r, C: Connection> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) from: Resource,
+}
+
+impl<'r, C> Relate<'r, C>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> Relate<'static, C> {
+		Relate {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Sets the edge table, or the edge record, of the relation
+	pub fn with<R>(self, edge: impl IntoResource<R>) -> RelateWith<'r, C, R> {
+		RelateWith {
+			client: self.client,
+			from: self.from,
+			with: edge.into_resource(),
+			response_type: PhantomData,
+		}
+	}
+}
+
+/// The second part of a relate future, which specifies the edge of the relation
+#[derive(Debug)]
+#[must_use = "a relation needs a target, specified with `.to()`"]
+pub struct RelateWith<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) from: Resource,
+	pub(super) with: Result<Resource>,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<'r, C, R> RelateWith<'r, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> RelateWith<'static, C, R> {
+		RelateWith {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+
+	/// Sets the records the edges point to
+	pub fn to(self, to: impl Into<Resource>) -> RelateTo<'r, C, R> {
+		RelateTo {
+			client: self.client,
+			from: self.from,
+			with: self.with,
+			to: to.into(),
+			response_type: PhantomData,
+		}
+	}
+}
+
+/// A relate future
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RelateTo<'r, C: Connection, R> {
+	pub(super) client: Cow<'r, Surreal<C>>,
+	pub(super) from: Resource,
+	pub(super) with: Result<Resource>,
+	pub(super) to: Resource,
+	pub(super) response_type: PhantomData<R>,
+}
+
+impl<C, R> RelateTo<'_, C, R>
+where
+	C: Connection,
+{
+	/// Converts to an owned type which can easily be moved to a different thread
+	pub fn into_owned(self) -> RelateTo<'static, C, R> {
+		RelateTo {
+			client: Cow::Owned(self.client.into_owned()),
+			..self
+		}
+	}
+}
+
+macro_rules! into_future {
+	($method:ident) => {
+		fn into_future(self) -> Self::IntoFuture {
+			let RelateTo {
+				client,
+				from,
+				with,
+				to,
+				..
+			} = self;
+			Box::pin(async move {
+				let router = client.router.extract()?;
+				let cmd = Command::Relate {
+					from,
+					with: with?,
+					to,
+					data: None,
+				};
+				router.$method(cmd).await
+			})
+		}
+	};
+}
+
+impl<'r, Client> IntoFuture for RelateTo<'r, Client, Value>
+where
+	Client: Connection,
+{
+	type Output = Result<Value>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	into_future! {execute_value}
+}
+
+impl<'r, Client, R> IntoFuture for RelateTo<'r, Client, Option<R>>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Option<R>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	into_future! {execute_opt}
+}
+
+impl<'r, Client, R> IntoFuture for RelateTo<'r, Client, Vec<R>>
+where
+	Client: Connection,
+	R: DeserializeOwned,
+{
+	type Output = Result<Vec<R>>;
+	type IntoFuture = BoxFuture<'r, Self::Output>;
+
+	into_future! {execute_vec}
+}
+
+impl<'r, C, R> RelateTo<'r, C, R>
+where
+	C: Connection,
+{
+	/// Sets content of the edges
+	pub fn content<D>(self, data: D) -> Content<'r, C, R>
+	where
+		D: Serialize + 'static,
+	{
+		Content::from_closure(self.client, || {
+			let content = to_core_value(data)?;
+
+			let data = match content {
+				CoreValue::None | CoreValue::Null => None,
+				content => Some(content),
+			};
+
+			Ok(Command::Relate {
+				from: self.from,
+				with: self.with?,
+				to: self.to,
+				data,
+			})
+		})
+	}
+}
@@ -6,6 +6,7 @@ mod server;
 mod types;
 
 use crate::api::method::tests::types::AuthParams;
+use crate::api::method::ConnectionEventStream;
 use crate::api::opt::auth::Database;
 use crate::api::opt::auth::Jwt;
 use crate::api::opt::auth::Namespace;
@@ -86,7 +87,7 @@ async fn api() {
 		.unwrap();
 
 	// authenticate
-	let _: () = DB.authenticate(Jwt(String::new())).await.unwrap();
+	let _: () = DB.authenticate(Jwt::from(String::new())).await.unwrap();
 
 	// query
 	let _: QueryResponse = DB.query("SELECT * FROM user").await.unwrap();
@@ -110,6 +111,15 @@ async fn api() {
 		.await
 		.unwrap();
 
+	// transaction
+	let transaction = DB.clone().transaction().await.unwrap();
+	let _: Option<User> = transaction.create((USER, "john")).await.unwrap();
+	let _: Option<User> = transaction.select((USER, "john")).await.unwrap();
+	let _: Surreal<Client> = transaction.commit().await.unwrap();
+	let transaction = DB.clone().transaction().await.unwrap();
+	let _: QueryResponse = transaction.query("SELECT * FROM user").await.unwrap();
+	let _: Surreal<Client> = transaction.cancel().await.unwrap();
+
 	// create
 	let _: Vec<User> = DB.create(USER).await.unwrap();
 	let _: Option<User> = DB.create((USER, "john")).await.unwrap();
@@ -159,6 +169,21 @@ async fn api() {
 	let _: Option<User> = DB.delete((USER, "john")).await.unwrap();
 	let _: Vec<User> = DB.delete(USER).range("jane".."john").await.unwrap();
 
+	// relate
+	let _: Vec<User> = DB.relate((USER, "john")).with("knows").to((USER, "jane")).await.unwrap();
+	let _: Option<User> =
+		DB.relate((USER, "john")).with(("knows", "jane")).to((USER, "jane")).await.unwrap();
+	let _: Vec<User> = DB
+		.relate((USER, "john"))
+		.with("knows")
+		.to((USER, "jane"))
+		.content(User::default())
+		.await
+		.unwrap();
+
+	// info
+	let _: Option<User> = DB.info().await.unwrap();
+
 	// export
 	let _: () = DB.export("backup.sql").await.unwrap();
 
@@ -168,8 +193,16 @@ async fn api() {
 	// version
 	let _: Version = DB.version().await.unwrap();
 
+	// connection events
+	let _: ConnectionEventStream = DB.connection_events().await.unwrap();
+
 	// run
 	let _: Option<User> = DB.run("foo").await.unwrap();
+
+	// graphql
+	let _: serde_json::Value = DB.graphql("{ user { id } }", ()).await.unwrap();
+	let _: serde_json::Value =
+		DB.graphql("query($id: ID!) { user(id: $id) { id } }", User::default()).await.unwrap();
 }
 
 fn assert_send_sync(_: impl Send + Sync) {}
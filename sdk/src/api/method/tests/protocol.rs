@@ -58,7 +58,8 @@ impl Connection for Client {
 			let router = Router {
 				features,
 				sender: route_tx,
-				last_id: AtomicI64::new(0),
+				last_id: Arc::new(AtomicI64::new(0)),
+				transaction: None,
 			};
 			server::mock(route_rx);
 			Ok(Surreal::new_from_router_waiter(
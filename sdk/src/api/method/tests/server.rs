@@ -4,6 +4,7 @@ use crate::api::Response as QueryResponse;
 use crate::opt::Resource;
 use channel::Receiver;
 use surrealdb_core::sql::{to_value as to_core_value, Value as CoreValue};
+use uuid::Uuid;
 
 pub(super) fn mock(route_rx: Receiver<Route>) {
 	tokio::spawn(async move {
@@ -19,6 +20,9 @@ pub(super) fn mock(route_rx: Receiver<Route>) {
 				Command::Authenticate {
 					..
 				}
+				| Command::SubscribeEvents {
+					..
+				}
 				| Command::Kill {
 					..
 				}
@@ -37,6 +41,9 @@ pub(super) fn mock(route_rx: Receiver<Route>) {
 				}
 				| Command::Signin {
 					..
+				}
+				| Command::Refresh {
+					..
 				} => Ok(DbResponse::Other("jwt".to_owned().into())),
 				Command::Set {
 					..
@@ -100,9 +107,29 @@ pub(super) fn mock(route_rx: Receiver<Route>) {
 					}
 					_ => Ok(DbResponse::Other(to_core_value(User::default()).unwrap())),
 				},
+				Command::Relate {
+					with,
+					..
+				} => match with {
+					Resource::RecordId(..) => {
+						Ok(DbResponse::Other(to_core_value(User::default()).unwrap()))
+					}
+					_ => Ok(DbResponse::Other(CoreValue::Array(Default::default()))),
+				},
+				Command::Info => Ok(DbResponse::Other(to_core_value(User::default()).unwrap())),
 				Command::Run {
 					..
 				} => Ok(DbResponse::Other(CoreValue::None)),
+				Command::GraphQL {
+					..
+				} => Ok(DbResponse::Other(r#"{"data":{}}"#.into())),
+				Command::Begin => Ok(DbResponse::Other(CoreValue::Uuid(Uuid::new_v4().into()))),
+				Command::Commit {
+					..
+				}
+				| Command::Cancel {
+					..
+				} => Ok(DbResponse::Other(CoreValue::None)),
 				Command::ExportMl {
 					..
 				}
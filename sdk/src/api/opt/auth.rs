@@ -12,6 +12,10 @@ pub struct Signup;
 #[derive(Debug)]
 pub struct Signin;
 
+/// A refresh action
+#[derive(Debug)]
+pub struct Refresh;
+
 /// Credentials for authenticating with the server
 pub trait Credentials<Action, Response>: Serialize {}
 
@@ -97,40 +101,59 @@ impl<T, P> Credentials<T, Jwt> for Record<'_, P> where P: Serialize {}
 /// * it can be stored in a secure cookie,
 /// * stored in a database with restricted access,
 /// * or encrypted in conjunction with other encryption mechanisms.
+///
+/// When signing in with a record access method defined `WITH REFRESH`, the server also
+/// issues a refresh token, which can be retrieved with
+/// [`as_insecure_refresh_token`](Jwt::as_insecure_refresh_token) and exchanged for a new
+/// pair of tokens with [`Surreal::refresh`](crate::Surreal::refresh).
 #[derive(Clone, Serialize, Deserialize)]
-pub struct Jwt(pub(crate) String);
+#[serde(from = "Tokens", into = "Tokens")]
+pub struct Jwt {
+	pub(crate) token: String,
+	pub(crate) refresh: Option<String>,
+}
 
 impl Jwt {
 	/// Returns the underlying token string.
 	///
 	/// ⚠️: It is important to note that the token should be handled securely and protected from unauthorized access.
 	pub fn as_insecure_token(&self) -> &str {
-		&self.0
+		&self.token
 	}
 
 	/// Returns the underlying token string.
 	///
 	/// ⚠️: It is important to note that the token should be handled securely and protected from unauthorized access.
 	pub fn into_insecure_token(self) -> String {
-		self.0
+		self.token
+	}
+
+	/// Returns the refresh token string, if one was issued.
+	///
+	/// ⚠️: It is important to note that the token should be handled securely and protected from unauthorized access.
+	pub fn as_insecure_refresh_token(&self) -> Option<&str> {
+		self.refresh.as_deref()
 	}
 }
 
 impl From<String> for Jwt {
 	fn from(jwt: String) -> Self {
-		Jwt(jwt)
+		Jwt {
+			token: jwt,
+			refresh: None,
+		}
 	}
 }
 
 impl<'a> From<&'a String> for Jwt {
 	fn from(jwt: &'a String) -> Self {
-		Jwt(jwt.to_owned())
+		jwt.to_owned().into()
 	}
 }
 
 impl<'a> From<&'a str> for Jwt {
 	fn from(jwt: &'a str) -> Self {
-		Jwt(jwt.to_owned())
+		jwt.to_owned().into()
 	}
 }
 
@@ -140,19 +163,80 @@ impl fmt::Debug for Jwt {
 	}
 }
 
+/// The tokens returned by the server, which only include
+/// a refresh token when the access method issues one
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Tokens {
+	Access(String),
+	WithRefresh {
+		token: String,
+		refresh: String,
+	},
+}
+
+impl From<Tokens> for Jwt {
+	fn from(tokens: Tokens) -> Self {
+		match tokens {
+			Tokens::Access(token) => Jwt {
+				token,
+				refresh: None,
+			},
+			Tokens::WithRefresh {
+				token,
+				refresh,
+			} => Jwt {
+				token,
+				refresh: Some(refresh),
+			},
+		}
+	}
+}
+
+impl From<Jwt> for Tokens {
+	fn from(jwt: Jwt) -> Self {
+		match jwt.refresh {
+			None => Tokens::Access(jwt.token),
+			Some(refresh) => Tokens::WithRefresh {
+				token: jwt.token,
+				refresh,
+			},
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use surrealdb_core::sql::from_value;
 
 	#[test]
 	fn as_insecure_token() {
-		let jwt = Jwt("super-long-jwt".to_owned());
+		let jwt = Jwt::from("super-long-jwt");
 		assert_eq!(jwt.as_insecure_token(), "super-long-jwt");
 	}
 
 	#[test]
 	fn into_insecure_token() {
-		let jwt = Jwt("super-long-jwt".to_owned());
+		let jwt = Jwt::from("super-long-jwt");
 		assert_eq!(jwt.into_insecure_token(), "super-long-jwt");
 	}
+
+	#[test]
+	fn from_token_value() {
+		let jwt: Jwt = from_value("super-long-jwt".into()).unwrap();
+		assert_eq!(jwt.as_insecure_token(), "super-long-jwt");
+		assert_eq!(jwt.as_insecure_refresh_token(), None);
+	}
+
+	#[test]
+	fn from_tokens_value() {
+		let value = surrealdb_core::sql::json(
+			r#"{ "token": "super-long-jwt", "refresh": "surreal-refresh-token" }"#,
+		)
+		.unwrap();
+		let jwt: Jwt = from_value(value).unwrap();
+		assert_eq!(jwt.as_insecure_token(), "super-long-jwt");
+		assert_eq!(jwt.as_insecure_refresh_token(), Some("surreal-refresh-token"));
+	}
 }
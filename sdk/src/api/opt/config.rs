@@ -25,6 +25,9 @@ pub struct Config {
 	pub(crate) username: String,
 	pub(crate) password: String,
 	pub(crate) tick_interval: Option<Duration>,
+	// Only used by the WebSocket engine
+	pub(crate) reconnect_delay: Option<(Duration, Duration)>,
+	pub(crate) reconnect_attempts: Option<u32>,
 	pub(crate) capabilities: CoreCapabilities,
 	#[cfg(any(
 		feature = "kv-mem",
@@ -116,6 +119,23 @@ impl Config {
 		self
 	}
 
+	/// Set the delay before the first attempt to reconnect to the server, and the maximum delay
+	///
+	/// The delay doubles after every failed attempt, until it reaches the maximum delay.
+	/// Defaults to one second, up to 30 seconds.
+	pub fn reconnect_delay(mut self, initial: Duration, max: Duration) -> Self {
+		self.reconnect_delay = Some((initial, max.max(initial)));
+		self
+	}
+
+	/// Set the number of attempts to reconnect to the server before giving up
+	///
+	/// Defaults to retrying forever.
+	pub fn reconnect_attempts(mut self, attempts: impl Into<Option<u32>>) -> Self {
+		self.reconnect_attempts = attempts.into();
+		self
+	}
+
 	/// Set the capabilities for the database
 	pub fn capabilities(mut self, capabilities: Capabilities) -> Self {
 		self.capabilities = capabilities.build();
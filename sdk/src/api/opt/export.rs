@@ -28,3 +28,51 @@ where
 impl IntoExportDestination<()> for () {
 	fn into_export_destination(self) {}
 }
+
+pub use surrealdb_core::kvs::export::Format as ExportFormat;
+
+/// The configuration of a database export
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))]
+pub struct ExportConfig(pub(crate) surrealdb_core::kvs::export::Config);
+
+impl ExportConfig {
+	/// Creates a configuration which exports the whole database as SurrealQL
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Exports only the specified tables, without the database level definitions
+	pub fn tables<I, S>(mut self, tables: I) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		self.0.tables = Some(tables.into_iter().map(Into::into).collect());
+		self
+	}
+
+	/// Sets whether to export the definitions
+	pub fn schema(mut self, schema: bool) -> Self {
+		self.0.schema = schema;
+		self
+	}
+
+	/// Sets whether to export the records
+	pub fn records(mut self, records: bool) -> Self {
+		self.0.records = records;
+		self
+	}
+
+	/// Sets whether to export every stored version of the records
+	pub fn versions(mut self, versions: bool) -> Self {
+		self.0.versions = versions;
+		self
+	}
+
+	/// Sets the format of the export
+	pub fn format(mut self, format: ExportFormat) -> Self {
+		self.0.format = format;
+		self
+	}
+}
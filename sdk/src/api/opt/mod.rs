@@ -143,3 +143,15 @@ pub enum WaitFor {
 	/// Waits for the desired database to be selected
 	Database,
 }
+
+/// A change in the state of a remote connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionEvent {
+	/// The connection to the server was lost, and the client is trying to reconnect
+	Disconnected,
+	/// The client reconnected to the server, and resumed its session and live queries
+	Reconnected,
+	/// The client gave up reconnecting to the server, after the configured number of attempts
+	ReconnectFailed,
+}
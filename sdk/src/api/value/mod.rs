@@ -8,7 +8,7 @@ use std::{
 	str::FromStr,
 };
 use surrealdb_core::{
-	dbs::Action as CoreAction,
+	dbs::{Action as CoreAction, Notification as CoreNotification},
 	sql::{
 		Array as CoreArray, Datetime as CoreDatetime, Id as CoreId, Number as CoreNumber,
 		Thing as CoreThing, Value as CoreValue,
@@ -180,7 +180,7 @@ transparent_wrapper!(
 	///
 	/// Record id's consist of a table name and a key.
 	/// For example the record id `user:tkwse1j5o0anqjxonvzx` has the table `user` and the key `tkwse1j5o0anqjxonvzx`.
-	#[derive( Clone, PartialEq, PartialOrd)]
+	#[derive( Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 	pub struct RecordId(CoreThing)
 );
 impl_serialize_wrapper!(RecordId);
@@ -348,24 +348,45 @@ impl Action {
 /// Live queries return a stream of notifications. The notification contains an `action` that triggered the change in the database record and `data` itself.
 /// For deletions the data is the record before it was deleted. For everything else, it's the newly created record or updated record depending on whether
 /// the action is create or update.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+///
+/// The notification also contains the `record` id of the changed record, and the record content as it was `before`
+/// the change when the live query was started using `LIVE SELECT ... WITH BEFORE`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[non_exhaustive]
 pub struct Notification<R> {
 	pub query_id: Uuid,
 	pub action: Action,
+	pub record: Option<RecordId>,
 	pub data: R,
+	pub before: Option<R>,
 }
 
 impl Notification<CoreValue> {
+	pub(crate) fn from_core_notification(notification: CoreNotification) -> Self {
+		Notification {
+			query_id: notification.id.0,
+			action: Action::from_core(notification.action),
+			record: match notification.record {
+				CoreValue::Thing(v) => Some(RecordId::from_inner(v)),
+				_ => None,
+			},
+			data: notification.result,
+			before: notification.before,
+		}
+	}
+
 	pub fn map_deserialize<R>(self) -> Result<Notification<R>, crate::error::Db>
 	where
 		R: DeserializeOwned,
 	{
 		let data = surrealdb_core::sql::from_value(self.data)?;
+		let before = self.before.map(surrealdb_core::sql::from_value).transpose()?;
 		Ok(Notification {
 			query_id: self.query_id,
 			action: self.action,
+			record: self.record,
 			data,
+			before,
 		})
 	}
 }
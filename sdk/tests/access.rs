@@ -627,3 +627,49 @@ async fn permissions_access_grant_root() {
 		}
 	}
 }
+
+#[tokio::test]
+async fn custom_role_grants() {
+	let ds = new_ds().await.unwrap().with_auth_enabled(true);
+	let owner = Session::owner().with_ns("test").with_db("test");
+	let sess = Session::for_level(("test", "test").into(), Role::Custom("updater".to_string()))
+		.with_ns("test")
+		.with_db("test");
+	let sql = "
+		DEFINE ROLE updater ON DATABASE GRANT read, update ON record;
+		DEFINE TABLE item;
+		CREATE item:one SET n = 1;
+	";
+	for res in ds.execute(sql, &owner, None).await.unwrap() {
+		res.result.unwrap();
+	}
+	// An UPSERT can update a record, but not create one, without the create action
+	let sql = "UPSERT item:one SET n = 2; UPSERT item:two SET n = 2;";
+	for res in ds.execute(sql, &sess, None).await.unwrap() {
+		res.result.unwrap();
+	}
+	// An INSERT can not update a record without the update action
+	let sql = "
+		DEFINE ROLE OVERWRITE updater ON DATABASE GRANT read, create ON record;
+		INSERT INTO item { id: 'three', n: 3 };
+	";
+	for res in ds.execute(sql, &owner, None).await.unwrap() {
+		res.result.unwrap();
+	}
+	let sql = "
+		INSERT INTO item { id: 'one', n: 4 } ON DUPLICATE KEY UPDATE n = 4;
+		INSERT INTO item { id: 'four', n: 4 };
+	";
+	for res in ds.execute(sql, &sess, None).await.unwrap() {
+		res.result.unwrap();
+	}
+	let res = &mut ds.execute("SELECT * FROM item", &owner, None).await.unwrap();
+	let val = res.remove(0).result.unwrap();
+	let exp = Value::parse("[{ id: item:four, n: 4 }, { id: item:one, n: 2 }, { id: item:three, n: 3 }]");
+	assert_eq!(val, exp);
+	// The existing sessions lose the grants of a removed role
+	ds.execute("REMOVE ROLE updater ON DATABASE", &owner, None).await.unwrap().remove(0).result.unwrap();
+	let res = &mut ds.execute("SELECT * FROM item", &sess, None).await.unwrap();
+	let val = res.remove(0).result.unwrap();
+	assert_eq!(val, Value::parse("[]"));
+}
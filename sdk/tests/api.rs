@@ -67,6 +67,11 @@ mod api_integration {
 		pass: &'a str,
 	}
 
+	#[derive(Debug, Serialize)]
+	struct RefreshParams<'a> {
+		refresh: &'a str,
+	}
+
 	#[cfg(feature = "protocol-ws")]
 	mod ws {
 		use super::*;
@@ -141,6 +146,7 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 	}
 
 	#[cfg(feature = "protocol-http")]
@@ -265,8 +271,188 @@ mod api_integration {
 			db.use_ns("test").use_db("test").await.unwrap();
 		}
 
+		#[test_log::test(tokio::test)]
+		#[cfg(surrealdb_unstable)]
+		async fn graphql() {
+			let (permit, db) = new_db().await;
+			db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+			drop(permit);
+			db.query(
+				"
+				DEFINE TABLE person SCHEMAFULL;
+				DEFINE FIELD name ON person TYPE string;
+				CREATE person:tobie SET name = 'Tobie';
+				CREATE person:jaime SET name = 'Jaime';
+			",
+			)
+			.await
+			.unwrap()
+			.check()
+			.unwrap();
+			let response: serde_json::Value = db
+				.graphql(
+					"query($name: String!) { person(filter: { name: { eq: $name } }) { id name } }",
+					json!({ "name": "Tobie" }),
+				)
+				.await
+				.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "person": [{ "id": "person:tobie", "name": "Tobie" }] } })
+			);
+			// The schema of the engine follows the changes to the tables
+			db.query(
+				"
+				DEFINE TABLE post SCHEMAFULL;
+				DEFINE FIELD title ON post TYPE string;
+				CREATE post:first SET title = 'First';
+			",
+			)
+			.await
+			.unwrap()
+			.check()
+			.unwrap();
+			let response: serde_json::Value =
+				db.graphql("{ post { id title } }", ()).await.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "post": [{ "id": "post:first", "title": "First" }] } })
+			);
+		}
+
+		#[test_log::test(tokio::test)]
+		#[cfg(surrealdb_unstable)]
+		async fn graphql_mutations() {
+			let (permit, db) = new_db().await;
+			let database = Ulid::new().to_string();
+			db.use_ns(NS).use_db(&database).await.unwrap();
+			drop(permit);
+			db.query(
+				"
+				DEFINE TABLE person SCHEMAFULL;
+				DEFINE FIELD name ON person TYPE string;
+				DEFINE TABLE knows TYPE RELATION IN person OUT person SCHEMAFULL;
+				DEFINE FIELD since ON knows TYPE int;
+				DEFINE TABLE user SCHEMAFULL;
+				DEFINE FIELD name ON user TYPE option<string>;
+				DEFINE TABLE secret SCHEMAFULL PERMISSIONS FOR select FULL, FOR create NONE;
+				DEFINE FIELD name ON secret TYPE string;
+				DEFINE ACCESS account ON DATABASE TYPE RECORD
+					SIGNUP ( CREATE user SET name = $name )
+					SIGNIN ( SELECT * FROM user WHERE name = $name );
+			",
+			)
+			.await
+			.unwrap()
+			.check()
+			.unwrap();
+			// Create a record
+			let response: serde_json::Value = db
+				.graphql(
+					r#"mutation { createPerson(id: "tobie", data: { name: "Tobie" }) { id name } }"#,
+					(),
+				)
+				.await
+				.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "createPerson": { "id": "person:tobie", "name": "Tobie" } } })
+			);
+			// Update a record
+			let response: serde_json::Value = db
+				.graphql(
+					r#"mutation { updatePerson(id: "person:tobie", data: { name: "Tobias" }) { id name } }"#,
+					(),
+				)
+				.await
+				.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "updatePerson": { "id": "person:tobie", "name": "Tobias" } } })
+			);
+			// Upsert a record
+			let response: serde_json::Value = db
+				.graphql(
+					r#"mutation { upsertPerson(id: "jaime", data: { name: "Jaime" }) { id name } }"#,
+					(),
+				)
+				.await
+				.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "upsertPerson": { "id": "person:jaime", "name": "Jaime" } } })
+			);
+			// Relate two records
+			let response: serde_json::Value = db
+				.graphql(
+					r#"mutation { relateKnows(in: "person:tobie", out: "person:jaime", data: { since: 2020 }) { since } }"#,
+					(),
+				)
+				.await
+				.unwrap();
+			assert_eq!(response, json!({ "data": { "relateKnows": { "since": 2020 } } }));
+			let mut response = db.query("SELECT VALUE ->knows->person FROM person:tobie").await.unwrap();
+			let out: Option<Vec<RecordId>> = response.take(0).unwrap();
+			assert_eq!(out, Some(vec![RecordId::from(("person", "jaime"))]));
+			// Delete a record
+			let response: serde_json::Value = db
+				.graphql(r#"mutation { deletePerson(id: "person:jaime") { id name } }"#, ())
+				.await
+				.unwrap();
+			assert_eq!(
+				response,
+				json!({ "data": { "deletePerson": { "id": "person:jaime", "name": "Jaime" } } })
+			);
+			let mut response = db.query("SELECT VALUE id FROM person").await.unwrap();
+			let ids: Vec<RecordId> = response.take(0).unwrap();
+			assert_eq!(ids, vec![RecordId::from(("person", "tobie"))]);
+			// A record of another table can not be written through a mutation
+			for mutation in [
+				r#"mutation { createPerson(id: "user:mallory", data: { name: "Mallory" }) { id } }"#,
+				r#"mutation { upsertPerson(id: "user:mallory", data: { name: "Mallory" }) { id } }"#,
+				r#"mutation { deletePerson(id: "knows:1") { id } }"#,
+			] {
+				let response: serde_json::Value = db.graphql(mutation, ()).await.unwrap();
+				assert!(response["errors"].is_array(), "{response}");
+			}
+			let mut response = db.query("SELECT * FROM user").await.unwrap();
+			let users: Vec<Value> = response.take(0).unwrap();
+			assert!(users.is_empty());
+			// Mutations are subject to the table permissions
+			#[derive(Serialize)]
+			struct Credentials<'a> {
+				name: &'a str,
+			}
+			db.signup(RecordAccess {
+				namespace: NS,
+				database: &database,
+				access: "account",
+				params: Credentials {
+					name: "Mallory",
+				},
+			})
+			.await
+			.unwrap();
+			let response: serde_json::Value = db
+				.graphql(r#"mutation { createSecret(data: { name: "Secret" }) { id } }"#, ())
+				.await
+				.unwrap();
+			assert!(response["errors"].is_array(), "{response}");
+			db.signin(Root {
+				username: ROOT_USER,
+				password: ROOT_PASS,
+			})
+			.await
+			.unwrap();
+			db.use_ns(NS).use_db(&database).await.unwrap();
+			let mut response = db.query("SELECT * FROM secret").await.unwrap();
+			let secrets: Vec<Value> = response.take(0).unwrap();
+			assert!(secrets.is_empty());
+		}
+
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
@@ -304,6 +490,7 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
@@ -339,6 +526,7 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
@@ -372,6 +560,7 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
@@ -402,6 +591,7 @@ mod api_integration {
 
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
@@ -581,8 +771,53 @@ mod api_integration {
 			assert!(response.is_none());
 		}
 
+		#[test_log::test(tokio::test)]
+		async fn import_with_version() {
+			let (permit, db) = new_db().await;
+			db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+			drop(permit);
+
+			// The field definitions are not applied to the imported versions.
+			let _ = db
+				.query("DEFINE FIELD name ON user VALUE string::uppercase($value)")
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+			let _ = db
+				.query(
+					"OPTION IMPORT;
+					INSERT [{ id: user:john, name: 'John v1' }] VERSION d'2024-08-19T08:00:00Z';
+					INSERT [{ id: user:john, name: 'John v2' }] VERSION d'2024-08-19T09:00:00Z';",
+				)
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+
+			// Without VERSION, SELECT should return the latest version.
+			let mut response = db.query("SELECT * FROM user:john").await.unwrap().check().unwrap();
+			let Some(name): Option<String> = response.take("name").unwrap() else {
+				panic!("query returned no record");
+			};
+			assert_eq!(name, "John v2");
+
+			// SELECT with the VERSION set between the versions should return the first one.
+			let mut response = db
+				.query("SELECT * FROM user:john VERSION d'2024-08-19T08:30:00Z'")
+				.await
+				.unwrap()
+				.check()
+				.unwrap();
+			let Some(name): Option<String> = response.take("name").unwrap() else {
+				panic!("query returned no record");
+			};
+			assert_eq!(name, "John v1");
+		}
+
 		include!("api/mod.rs");
 		include!("api/live.rs");
+		include!("api/transaction.rs");
 		include!("api/backup.rs");
 	}
 
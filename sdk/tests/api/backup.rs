@@ -32,6 +32,78 @@ async fn export_import() {
 	res.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn export_import_ndjson_tables() {
+	let (permit, db) = new_db().await;
+	let db_name = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&db_name).await.unwrap();
+	for i in 0..10 {
+		let _: Vec<ApiRecordId> = db
+			.create("user")
+			.content(Record {
+				name: format!("User {i}"),
+			})
+			.await
+			.unwrap();
+	}
+	let _: Vec<ApiRecordId> = db
+		.create("post")
+		.content(Record {
+			name: "Post".to_owned(),
+		})
+		.await
+		.unwrap();
+	drop(permit);
+	let file = format!("{db_name}.ndjson");
+
+	let res = async {
+		let config = surrealdb::opt::ExportConfig::new()
+			.tables(["user"])
+			.format(surrealdb::opt::ExportFormat::Ndjson);
+		db.export(&file).with_config(config).await?;
+		db.query("REMOVE TABLE user; REMOVE TABLE post").await?.check()?;
+		db.import(&file).await?;
+		let users: Vec<ApiRecordId> = db.select("user").await?;
+		assert_eq!(users.len(), 10);
+		let posts: Vec<ApiRecordId> = db.select("post").await?;
+		assert!(posts.is_empty());
+		Result::<(), Error>::Ok(())
+	}
+	.await;
+	remove_file(file).await.unwrap();
+	res.unwrap();
+}
+
+#[test_log::test(tokio::test)]
+async fn export_import_sequence() {
+	let (permit, db) = new_db().await;
+	let db_name = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&db_name).await.unwrap();
+	db.query("DEFINE SEQUENCE invoice BATCH 1 START 1").await.unwrap().check().unwrap();
+	let mut res = db
+		.query("RETURN [sequence::nextval('invoice'), sequence::nextval('invoice')]")
+		.await
+		.unwrap();
+	let taken: Vec<i64> = res.take(0).unwrap();
+	assert_eq!(taken, vec![1, 2]);
+	drop(permit);
+	let file = format!("{db_name}.sql");
+
+	let res = async {
+		db.export(&file).await?;
+		db.query("REMOVE SEQUENCE invoice").await?.check()?;
+		db.import(&file).await?;
+		// The imported sequence continues after the values already taken
+		let mut res = db.query("RETURN sequence::nextval('invoice')").await?;
+		let next: Option<i64> = res.take(0)?;
+		assert_eq!(next, Some(3));
+		Result::<(), Error>::Ok(())
+	}
+	.await;
+	remove_file(file).await.unwrap();
+	res.unwrap();
+}
+
 #[test_log::test(tokio::test)]
 #[cfg(feature = "ml")]
 async fn ml_export_import() {
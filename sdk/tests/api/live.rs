@@ -395,6 +395,44 @@ async fn live_select_query() {
 		assert_eq!(notification.action, Action::Delete, "{:?}", notification);
 	}
 
+	{
+		let table = format!("table_{}", Ulid::new());
+		db.query(format!("DEFINE TABLE {table}")).await.unwrap();
+
+		// Start listening
+		let mut users = db
+			.query(format!("LIVE SELECT * FROM {table} WITH BEFORE"))
+			.await
+			.unwrap()
+			.stream::<Notification<_>>(())
+			.unwrap();
+
+		// Create a record
+		let created: Vec<ApiRecordId> = db.create(table).await.unwrap();
+		// Pull the notification
+		let notification: Notification<ApiRecordId> =
+			tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+		// It should be newly created
+		assert_eq!(notification.action, Action::Create, "{:?}", notification);
+		// It should contain the id of the changed record
+		assert_eq!(notification.record, Some(created[0].id.clone()));
+		// A created record has no content before the change
+		assert_eq!(notification.before, None);
+
+		// Update the record
+		let _: Option<ApiRecordId> =
+			db.update(&created[0].id).content(json!({"foo": "bar"})).await.unwrap();
+		// Pull the notification
+		let notification: Notification<ApiRecordId> =
+			tokio::time::timeout(LQ_TIMEOUT, users.next()).await.unwrap().unwrap().unwrap();
+		// It should be updated
+		assert_eq!(notification.action, Action::Update, "{:?}", notification);
+		// It should contain the id of the changed record
+		assert_eq!(notification.record, Some(created[0].id.clone()));
+		// It should contain the record content before the change
+		assert_eq!(notification.before, Some(created[0].clone()));
+	}
+
 	{
 		let table = format!("table_{}", Ulid::new());
 		db.query(format!("DEFINE TABLE {table} CHANGEFEED 10m INCLUDE ORIGINAL")).await.unwrap();
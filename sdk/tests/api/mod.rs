@@ -122,6 +122,42 @@ async fn signin_db() {
 	.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn signin_db_custom_role() {
+	let (permit, db) = new_db().await;
+	let database = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&database).await.unwrap();
+	let user = Ulid::new().to_string();
+	let pass = "password123";
+	// Users can only hold custom roles which are defined
+	let sql = format!("DEFINE USER `{user}` ON DATABASE PASSWORD '{pass}' ROLES writer");
+	db.query(&sql).await.unwrap().check().unwrap_err();
+	let response = db
+		.query("DEFINE ROLE writer ON DATABASE GRANT read, create ON record, table")
+		.query("DEFINE TABLE item")
+		.query(sql)
+		.await
+		.unwrap();
+	drop(permit);
+	response.check().unwrap();
+	db.signin(Database {
+		namespace: NS,
+		database: &database,
+		username: &user,
+		password: pass,
+	})
+	.await
+	.unwrap();
+	// The role grants the actions on the records
+	db.query("CREATE item:one").await.unwrap().check().unwrap();
+	let mut response = db.query("SELECT VALUE id FROM item").await.unwrap();
+	let ids: Vec<RecordId> = response.take(0).unwrap();
+	assert_eq!(ids, vec!["item:one".parse::<RecordId>().unwrap()]);
+	// The role doesn't grant any other actions
+	db.query("DEFINE TABLE other").await.unwrap().check().unwrap_err();
+	db.query("REMOVE TABLE item").await.unwrap().check().unwrap_err();
+}
+
 #[test_log::test(tokio::test)]
 async fn signin_record() {
 	let (permit, db) = new_db().await;
@@ -165,6 +201,105 @@ async fn signin_record() {
 	.unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn refresh_record() {
+	let (permit, db) = new_db().await;
+	let database = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&database).await.unwrap();
+	let access = Ulid::new().to_string();
+	let email = format!("{access}@example.com");
+	let pass = "password123";
+	let sql = format!(
+		"
+        DEFINE ACCESS `{access}` ON DB TYPE RECORD
+        SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+        SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+		WITH REFRESH
+		DURATION FOR GRANT 1d FOR SESSION 1d FOR TOKEN 15s;
+        DEFINE TABLE user PERMISSIONS FOR select WHERE id = $auth.id;
+    "
+	);
+	let response = db.query(sql).await.unwrap();
+	drop(permit);
+	response.check().unwrap();
+	let jwt: Jwt = db
+		.signup(RecordAccess {
+			namespace: NS,
+			database: &database,
+			access: &access,
+			params: AuthParams {
+				pass,
+				email: &email,
+			},
+		})
+		.await
+		.unwrap();
+	let refresh = jwt.as_insecure_refresh_token().unwrap().to_owned();
+	// Exchange the refresh token for new tokens
+	let jwt: Jwt = db
+		.refresh(RecordAccess {
+			namespace: NS,
+			database: &database,
+			access: &access,
+			params: RefreshParams {
+				refresh: &refresh,
+			},
+		})
+		.await
+		.unwrap();
+	assert!(!jwt.as_insecure_token().is_empty());
+	assert_ne!(jwt.as_insecure_refresh_token(), Some(refresh.as_str()));
+	// The connection is authenticated as the record
+	let user: Option<ApiRecordId> = db.info().await.unwrap();
+	assert_eq!(user.unwrap().id.table(), "user");
+	// The exchanged refresh token can not be used again
+	db.refresh::<Jwt>(RecordAccess {
+		namespace: NS,
+		database: &database,
+		access: &access,
+		params: RefreshParams {
+			refresh: &refresh,
+		},
+	})
+	.await
+	.unwrap_err();
+}
+
+#[test_log::test(tokio::test)]
+async fn info() {
+	let (permit, db) = new_db().await;
+	let database = Ulid::new().to_string();
+	db.use_ns(NS).use_db(&database).await.unwrap();
+	let access = Ulid::new().to_string();
+	let email = format!("{access}@example.com");
+	let pass = "password123";
+	let sql = format!(
+		"
+        DEFINE ACCESS `{access}` ON DB TYPE RECORD
+        SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+        SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+		DURATION FOR SESSION 1d FOR TOKEN 15s;
+        DEFINE TABLE user PERMISSIONS FOR select WHERE id = $auth;
+    "
+	);
+	let response = db.query(sql).await.unwrap();
+	drop(permit);
+	response.check().unwrap();
+	db.signup(RecordAccess {
+		namespace: NS,
+		database: &database,
+		access: &access,
+		params: AuthParams {
+			pass,
+			email: &email,
+		},
+	})
+	.await
+	.unwrap();
+	let user: Option<ApiRecordId> = db.info().await.unwrap();
+	assert_eq!(user.unwrap().id.table(), "user");
+}
+
 #[test_log::test(tokio::test)]
 async fn record_access_throws_error() {
 	let (permit, db) = new_db().await;
@@ -1128,6 +1263,45 @@ async fn delete_record_range() {
 	);
 }
 
+#[test_log::test(tokio::test)]
+async fn relate_records() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let _: Option<ApiRecordId> = db.create(("person", "tobie")).await.unwrap();
+	let _: Option<ApiRecordId> = db.create(("article", "surreal")).await.unwrap();
+	// Relate the records with a new edge in a table
+	let edges: Vec<RecordBuf> = db
+		.relate(("person", "tobie"))
+		.with("wrote")
+		.to(("article", "surreal"))
+		.content(Record {
+			name: "first".to_owned(),
+		})
+		.await
+		.unwrap();
+	assert_eq!(edges.len(), 1);
+	assert_eq!(edges[0].id.table(), "wrote");
+	assert_eq!(edges[0].name, "first");
+	// Relate the records with a specific edge
+	let edge: Option<ApiRecordId> = db
+		.relate(("person", "tobie"))
+		.with(("wrote", "second"))
+		.to(("article", "surreal"))
+		.await
+		.unwrap();
+	assert_eq!(
+		edge,
+		Some(ApiRecordId {
+			id: "wrote:second".parse().unwrap(),
+		})
+	);
+	// Both edges point to the article
+	let mut response = db.query("SELECT VALUE out FROM wrote").await.unwrap();
+	let articles: Vec<RecordId> = response.take(0).unwrap();
+	assert_eq!(articles, vec!["article:surreal".parse::<RecordId>().unwrap(); 2]);
+}
+
 #[test_log::test(tokio::test)]
 async fn changefeed() {
 	let (permit, db) = new_db().await;
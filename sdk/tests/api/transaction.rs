@@ -0,0 +1,60 @@
+// Tests for interactive transactions
+// Supported by the storage engines and the WS protocol
+
+#[test_log::test(tokio::test)]
+async fn transaction_commit() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let transaction = db.clone().transaction().await.unwrap();
+	let _: Option<ApiRecordId> = transaction.create(("account", "one")).await.unwrap();
+	let _: Option<ApiRecordId> = transaction.create(("account", "two")).await.unwrap();
+	// The changes are visible within the transaction
+	let accounts: Vec<ApiRecordId> = transaction.select("account").await.unwrap();
+	assert_eq!(accounts.len(), 2);
+	// The changes are not visible outside of the transaction
+	let accounts: Vec<ApiRecordId> = db.select("account").await.unwrap();
+	assert!(accounts.is_empty());
+	transaction.commit().await.unwrap();
+	let accounts: Vec<ApiRecordId> = db.select("account").await.unwrap();
+	assert_eq!(accounts.len(), 2);
+}
+
+#[test_log::test(tokio::test)]
+async fn transaction_cancel() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let transaction = db.clone().transaction().await.unwrap();
+	let _: Option<ApiRecordId> = transaction.create(("account", "one")).await.unwrap();
+	let mut response = transaction.query("SELECT * FROM account").await.unwrap();
+	let accounts: Vec<ApiRecordId> = response.take(0).unwrap();
+	assert_eq!(accounts.len(), 1);
+	transaction.cancel().await.unwrap();
+	let account: Option<ApiRecordId> = db.select(("account", "one")).await.unwrap();
+	assert!(account.is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn transaction_failed_query() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let transaction = db.clone().transaction().await.unwrap();
+	let _: Option<ApiRecordId> = transaction.create(("account", "one")).await.unwrap();
+	// A failed query prevents the transaction from being committed
+	transaction.query("THROW 'failure'").await.unwrap().check().unwrap_err();
+	transaction.commit().await.unwrap_err();
+	let account: Option<ApiRecordId> = db.select(("account", "one")).await.unwrap();
+	assert!(account.is_none());
+}
+
+#[test_log::test(tokio::test)]
+async fn transaction_statements_are_rejected() {
+	let (permit, db) = new_db().await;
+	db.use_ns(NS).use_db(Ulid::new().to_string()).await.unwrap();
+	drop(permit);
+	let transaction = db.clone().transaction().await.unwrap();
+	transaction.query("COMMIT").await.unwrap().check().unwrap_err();
+	transaction.cancel().await.unwrap();
+}
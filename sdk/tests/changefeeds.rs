@@ -780,3 +780,61 @@ async fn changefeed_with_original() -> Result<(), Error> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn changes_since_garbage_collected() -> Result<(), Error> {
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	dbs.tick_at(0).await?;
+	let sql = "DEFINE DATABASE test CHANGEFEED 1h; CREATE person:one;";
+	for res in dbs.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	dbs.tick_at(1).await?;
+	// The changes are available within the retention period
+	let changes = dbs.changes_since(&ses, 0, 1000).await?;
+	assert_eq!(changes.len(), 1);
+	// The changes which have been garbage collected are reported
+	dbs.tick_at(3602).await?;
+	let res = dbs.changes_since(&ses, 0, 1000).await;
+	assert!(matches!(res, Err(Error::ChangefeedGap(0))), "{res:?}");
+	// A snapshot includes the changes which have been garbage collected
+	let (snd, _rcv) = surrealdb::channel::unbounded();
+	let (state, _) = dbs.export_snapshot(&ses, snd, Default::default()).await?;
+	assert!(state.versionstamp > 0);
+	let changes = dbs.changes_since(&ses, state.versionstamp, 1000).await?;
+	assert!(changes.is_empty());
+	Ok(())
+}
+
+#[tokio::test]
+async fn show_changes_garbage_collected() -> Result<(), Error> {
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	dbs.tick_at(0).await?;
+	let sql = "DEFINE DATABASE test CHANGEFEED 1h; CREATE person:one; CREATE person:two;";
+	for res in dbs.execute(sql, &ses, None).await? {
+		res.result?;
+	}
+	dbs.tick_at(1).await?;
+	// Read the versionstamp of the first change
+	let res = &mut dbs.execute("SHOW CHANGES FOR DATABASE SINCE 0", &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let Value::Number(vs) = tmp.pick(&[0.into(), "versionstamp".into()]) else {
+		panic!("Unexpected changes: {tmp}");
+	};
+	let since = (u128::try_from(vs).unwrap() >> 16) as u64;
+	// The changes after the versionstamp are available within the retention period
+	let sql = format!("SHOW CHANGES FOR DATABASE SINCE {since}");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert!(res.remove(0).result.is_ok());
+	// The changes which have been garbage collected are reported
+	dbs.tick_at(3602).await?;
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::ChangefeedGap(v)) if v == since as u128), "{tmp:?}");
+	// The changes are read from the start of the change feed
+	let res = &mut dbs.execute("SHOW CHANGES FOR DATABASE SINCE 0", &ses, None).await?;
+	assert_eq!(res.remove(0).result?, Value::parse("[]"));
+	Ok(())
+}
@@ -10,7 +10,7 @@ use surrealdb::dbs::Session;
 use surrealdb::err::Error;
 use surrealdb::iam::Role;
 use surrealdb::sql::Idiom;
-use surrealdb::sql::{Part, Value};
+use surrealdb::sql::{Part, Thing, Value};
 
 #[tokio::test]
 async fn define_statement_namespace() -> Result<(), Error> {
@@ -32,6 +32,7 @@ async fn define_statement_namespace() -> Result<(), Error> {
 			accesses: {},
 			namespaces: { test: 'DEFINE NAMESPACE test' },
 			nodes: {},
+			roles: {},
 			users: {},
 		}",
 	);
@@ -59,6 +60,7 @@ async fn define_statement_database() -> Result<(), Error> {
 		"{
 			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
+			roles: {},
 			users: {},
 		}",
 	);
@@ -91,6 +93,8 @@ async fn define_statement_function() -> Result<(), Error> {
 			functions: { test: 'DEFINE FUNCTION fn::test($first: string, $last: string) { RETURN $first + $last; } PERMISSIONS FULL' },
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: {},
 			users: {},
 		}",
@@ -122,6 +126,8 @@ async fn define_statement_table_drop() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { test: 'DEFINE TABLE test TYPE ANY DROP SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -153,6 +159,8 @@ async fn define_statement_table_schemaless() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -179,6 +187,8 @@ async fn define_statement_table_schemafull() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { test: 'DEFINE TABLE test TYPE NORMAL SCHEMAFULL PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -208,6 +218,8 @@ async fn define_statement_table_schemaful() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { test: 'DEFINE TABLE test TYPE NORMAL SCHEMAFULL PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -247,6 +259,8 @@ async fn define_statement_table_foreigntable() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: {
 				test: 'DEFINE TABLE test TYPE NORMAL SCHEMAFULL PERMISSIONS NONE',
 				view: 'DEFINE TABLE view TYPE ANY SCHEMALESS AS SELECT count() FROM test GROUP ALL PERMISSIONS NONE',
@@ -279,6 +293,8 @@ async fn define_statement_table_foreigntable() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: {
 				test: 'DEFINE TABLE test TYPE NORMAL SCHEMAFULL PERMISSIONS NONE',
 			},
@@ -1068,6 +1084,8 @@ async fn define_statement_analyzer() -> Result<(), Error> {
 			},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: {},
 			users: {},
 		}"#,
@@ -1139,6 +1157,95 @@ async fn define_statement_search_index() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_statement_sequence() -> Result<(), Error> {
+	let sql = "
+		DEFINE SEQUENCE invoice BATCH 2 START 100;
+		RETURN [sequence::nextval('invoice'), sequence::nextval('invoice'), sequence::nextval('invoice')];
+		INFO FOR DB;
+		DEFINE SEQUENCE OVERWRITE invoice START 10;
+		RETURN sequence::nextval('invoice');
+		REMOVE SEQUENCE invoice;
+		DEFINE SEQUENCE invoice START 10;
+		RETURN sequence::nextval('invoice');
+		RETURN sequence::nextval('unknown');
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(1)?;
+	t.expect_val("[100, 101, 102]")?;
+	t.expect_val(
+		"{
+			accesses: {},
+			analyzers: {},
+			functions: {},
+			models: {},
+			params: {},
+			roles: {},
+			sequences: { invoice: 'DEFINE SEQUENCE invoice BATCH 2 START 100 PERMISSIONS NONE' },
+			tables: {},
+			users: {},
+		}",
+	)?;
+	t.skip_ok(1)?;
+	t.expect_val("103")?;
+	t.skip_ok(2)?;
+	t.expect_val("10")?;
+	t.expect_error("The sequence 'unknown' does not exist")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_sequence_exhausted() -> Result<(), Error> {
+	let sql = "
+		DEFINE SEQUENCE big BATCH 2 START 9223372036854775805;
+		RETURN [sequence::nextval('big'), sequence::nextval('big')];
+		RETURN sequence::nextval('big');
+		RETURN sequence::nextval('big');
+		DEFINE SEQUENCE last START 9223372036854775807;
+		RETURN sequence::nextval('last');
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(1)?;
+	t.expect_val("[9223372036854775805, 9223372036854775806]")?;
+	// The largest value is never handed out, so that the sequence can't overflow
+	t.expect_error("The 'big' sequence has no values left")?;
+	t.expect_error("The 'big' sequence has no values left")?;
+	t.skip_ok(1)?;
+	t.expect_error("The 'last' sequence has no values left")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_statement_sequence_permissions() -> Result<(), Error> {
+	let dbs = new_ds().await?.with_auth_enabled(true);
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute("DEFINE SEQUENCE invoice", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	assert!(res.remove(0).result.is_ok());
+	// Record users can not take values from a sequence
+	let ses = Session::for_record("test", "test", "test", Thing::from(("user", "john")).into());
+	let res = &mut dbs.execute("RETURN sequence::nextval('invoice')", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result;
+	assert!(
+		matches!(tmp, Err(Error::SequencePermissions { ref name }) if name == "invoice"),
+		"{tmp:?}"
+	);
+	// The sequence permissions can allow record users to take values
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let sql = "DEFINE SEQUENCE OVERWRITE invoice PERMISSIONS WHERE $auth = user:john";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert!(res.remove(0).result.is_ok());
+	let ses = Session::for_record("test", "test", "test", Thing::from(("user", "john")).into());
+	let res = &mut dbs.execute("RETURN sequence::nextval('invoice')", &ses, None).await?;
+	assert_eq!(res.remove(0).result?, Value::from(0));
+	let ses = Session::for_record("test", "test", "test", Thing::from(("user", "jane")).into());
+	let res = &mut dbs.execute("RETURN sequence::nextval('invoice')", &ses, None).await?;
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::SequencePermissions { .. })), "{tmp:?}");
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_statement_user_root() -> Result<(), Error> {
 	let sql = "
@@ -1307,8 +1414,8 @@ async fn permissions_checks_define_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-		vec!["{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, namespaces: { NS: 'DEFINE NAMESPACE NS' }, nodes: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"],
 	];
 
 	let test_cases = [
@@ -1346,8 +1453,10 @@ async fn permissions_checks_define_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-		vec!["{ accesses: {  }, databases: { DB: 'DEFINE DATABASE DB' }, users: {  } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"],
+		vec![
+			"{ accesses: {  }, databases: { DB: 'DEFINE DATABASE DB' }, roles: {  }, users: {  } }",
+		],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"],
 	];
 
 	let test_cases = [
@@ -1388,8 +1497,8 @@ async fn permissions_checks_define_function() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: { greet: \"DEFINE FUNCTION fn::greet() { RETURN 'Hello'; } PERMISSIONS FULL\" }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, functions: { greet: \"DEFINE FUNCTION fn::greet() { RETURN 'Hello'; } PERMISSIONS FULL\" }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1420,6 +1529,48 @@ async fn permissions_checks_define_function() {
 	assert!(res.is_ok(), "{}", res.unwrap_err());
 }
 
+#[tokio::test]
+async fn permissions_checks_define_sequence() {
+	let scenario = HashMap::from([
+		("prepare", ""),
+		("test", "DEFINE SEQUENCE seq"),
+		("check", "INFO FOR DB"),
+	]);
+
+	// Define the expected results for the check statement when the test statement succeeded and when it failed
+	let check_results = [
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: { seq: 'DEFINE SEQUENCE seq BATCH 1000 START 0 PERMISSIONS NONE' }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+	];
+
+	let test_cases = [
+		// Root level
+		((().into(), Role::Owner), ("NS", "DB"), true),
+		((().into(), Role::Editor), ("NS", "DB"), true),
+		((().into(), Role::Viewer), ("NS", "DB"), false),
+		// Namespace level
+		((("NS",).into(), Role::Owner), ("NS", "DB"), true),
+		((("NS",).into(), Role::Owner), ("OTHER_NS", "DB"), false),
+		((("NS",).into(), Role::Editor), ("NS", "DB"), true),
+		((("NS",).into(), Role::Editor), ("OTHER_NS", "DB"), false),
+		((("NS",).into(), Role::Viewer), ("NS", "DB"), false),
+		((("NS",).into(), Role::Viewer), ("OTHER_NS", "DB"), false),
+		// Database level
+		((("NS", "DB").into(), Role::Owner), ("NS", "DB"), true),
+		((("NS", "DB").into(), Role::Owner), ("NS", "OTHER_DB"), false),
+		((("NS", "DB").into(), Role::Owner), ("OTHER_NS", "DB"), false),
+		((("NS", "DB").into(), Role::Editor), ("NS", "DB"), true),
+		((("NS", "DB").into(), Role::Editor), ("NS", "OTHER_DB"), false),
+		((("NS", "DB").into(), Role::Editor), ("OTHER_NS", "DB"), false),
+		((("NS", "DB").into(), Role::Viewer), ("NS", "DB"), false),
+		((("NS", "DB").into(), Role::Viewer), ("NS", "OTHER_DB"), false),
+		((("NS", "DB").into(), Role::Viewer), ("OTHER_NS", "DB"), false),
+	];
+
+	let res = iam_check_cases(test_cases.iter(), &scenario, check_results).await;
+	assert!(res.is_ok(), "{}", res.unwrap_err());
+}
+
 #[tokio::test]
 async fn permissions_checks_define_analyzer() {
 	let scenario = HashMap::from([
@@ -1430,8 +1581,8 @@ async fn permissions_checks_define_analyzer() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: { analyzer: 'DEFINE ANALYZER analyzer TOKENIZERS BLANK' }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: { analyzer: 'DEFINE ANALYZER analyzer TOKENIZERS BLANK' }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1472,8 +1623,8 @@ async fn permissions_checks_define_access_root() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { access: \"DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, namespaces: {  }, nodes: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: {  } }"]
+        vec!["{ accesses: { access: \"DEFINE ACCESS access ON ROOT TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1514,8 +1665,8 @@ async fn permissions_checks_define_access_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { access: \"DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, databases: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"]
+        vec!["{ accesses: { access: \"DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, databases: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1556,8 +1707,8 @@ async fn permissions_checks_define_access_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { access: \"DEFINE ACCESS access ON DATABASE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: { access: \"DEFINE ACCESS access ON DATABASE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE\" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1598,8 +1749,8 @@ async fn permissions_checks_define_user_root() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: { user: \"DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
-		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: { user: \"DEFINE USER user ON ROOT PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
+		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1640,8 +1791,8 @@ async fn permissions_checks_define_user_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, databases: {  }, users: { user: \"DEFINE USER user ON NAMESPACE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: { user: \"DEFINE USER user ON NAMESPACE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1682,8 +1833,8 @@ async fn permissions_checks_define_user_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: { user: \"DEFINE USER user ON DATABASE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: { user: \"DEFINE USER user ON DATABASE PASSHASH 'secret' ROLES VIEWER DURATION FOR TOKEN 15m, FOR SESSION 6h\" } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1724,8 +1875,8 @@ async fn permissions_checks_define_access_record() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: { account: \"DEFINE ACCESS account ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 15m, FOR SESSION 12h\" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: { account: \"DEFINE ACCESS account ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 15m, FOR SESSION 12h\" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1766,8 +1917,8 @@ async fn permissions_checks_define_param() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: { param: \"DEFINE PARAM $param VALUE 'foo' PERMISSIONS FULL\" }, tables: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: { param: \"DEFINE PARAM $param VALUE 'foo' PERMISSIONS FULL\" }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1805,8 +1956,8 @@ async fn permissions_checks_define_table() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' }, users: {  } }"],
-		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"]
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: { TB: 'DEFINE TABLE TB TYPE ANY SCHEMALESS PERMISSIONS NONE' }, users: {  } }"],
+		vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"]
     ];
 
 	let test_cases = [
@@ -1995,6 +2146,8 @@ async fn define_statement_table_permissions() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: {
 					default: 'DEFINE TABLE default TYPE ANY SCHEMALESS PERMISSIONS NONE',
 					full: 'DEFINE TABLE full TYPE ANY SCHEMALESS PERMISSIONS FULL',
@@ -2228,6 +2381,28 @@ async fn define_remove_tables() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn define_remove_sequence() -> Result<(), Error> {
+	let sql = "
+		DEFINE SEQUENCE example;
+		DEFINE SEQUENCE IF NOT EXISTS example;
+		DEFINE SEQUENCE OVERWRITE example;
+		DEFINE SEQUENCE example;
+		REMOVE SEQUENCE IF EXISTS example;
+		REMOVE SEQUENCE example;
+		REMOVE SEQUENCE IF EXISTS example;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(1)?;
+	t.expect_val("None")?;
+	t.skip_ok(1)?;
+	t.expect_error("The sequence 'example' already exists")?;
+	t.skip_ok(1)?;
+	t.expect_error("The sequence 'example' does not exist")?;
+	t.expect_val("None")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn define_remove_users() -> Result<(), Error> {
 	let sql = "
@@ -2399,6 +2574,8 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -2420,6 +2597,8 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -2441,6 +2620,8 @@ async fn define_table_relation_redefinition_info() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { likes: 'DEFINE TABLE likes TYPE RELATION IN person OUT person | thing | other SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
@@ -734,3 +734,109 @@ async fn select_aggregate_mean_update() -> Result<(), Error> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_aggregate_from_keys() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE empty;
+		DEFINE FIELD age ON person TYPE int;
+		DEFINE INDEX idx_age ON person FIELDS age;
+		DEFINE INDEX idx_score ON person FIELDS score;
+		CREATE person:1 SET age = 33, score = 2;
+		CREATE person:2 SET age = -5, score = 7.5;
+		CREATE person:3 SET age = 71, score = 4;
+		SELECT count(), math::min(age) AS youngest, math::max(age) AS oldest FROM person GROUP ALL EXPLAIN;
+		SELECT count(), math::min(age) AS youngest, math::max(age) AS oldest FROM person GROUP ALL;
+		SELECT math::max(score) AS best FROM person GROUP ALL EXPLAIN;
+		SELECT math::max(score) AS best FROM person GROUP ALL;
+		SELECT count() FROM empty GROUP ALL;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 12);
+	//
+	skip_ok(res, 7)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		r#"[
+				{
+					detail: {
+						aggregates: {
+							count: 'Count Keys',
+							oldest: {
+								index: 'idx_age',
+								operation: 'Last Entry'
+							},
+							youngest: {
+								index: 'idx_age',
+								operation: 'First Entry'
+							}
+						},
+						table: 'person'
+					},
+					operation: 'Iterate Aggregate'
+				},
+				{
+					detail: {
+						type: 'Memory'
+					},
+					operation: 'Collector'
+				}
+			]"#,
+	);
+	assert_eq!(format!("{tmp:#}"), format!("{val:#}"));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				count: 3,
+				oldest: 71,
+				youngest: -5
+			}
+		]",
+	);
+	assert_eq!(format!("{tmp:#}"), format!("{val:#}"));
+	// The values of an untyped field are not read from the index
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		r#"[
+				{
+					detail: {
+						table: 'person'
+					},
+					operation: 'Iterate Table'
+				},
+				{
+					detail: {
+						idioms: {
+							best: [
+								'math::max'
+							]
+						},
+						type: 'Group'
+					},
+					operation: 'Collector'
+				}
+			]"#,
+	);
+	assert_eq!(format!("{tmp:#}"), format!("{val:#}"));
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				best: 7.5
+			}
+		]",
+	);
+	assert_eq!(format!("{tmp:#}"), format!("{val:#}"));
+	// There is no group when there are no records
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(format!("{tmp:#}"), format!("{val:#}"));
+	//
+	Ok(())
+}
@@ -25,7 +25,7 @@ async fn info_for_root() {
 	assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 	let output_regex =
-		Regex::new(r"\{ accesses: \{ access: .* \}, namespaces: \{ NS: .* \}, nodes: \{ .* \}, users: \{ user: .* \} \}")
+		Regex::new(r"\{ accesses: \{ access: .* \}, namespaces: \{ NS: .* \}, nodes: \{ .* \}, roles: \{  \}, users: \{ user: .* \} \}")
 			.unwrap();
 	let out_str = out.unwrap().to_string();
 	assert!(
@@ -54,7 +54,7 @@ async fn info_for_ns() {
 	assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 	let output_regex = Regex::new(
-		r"\{ accesses: \{ access: .* \}, databases: \{ DB: .* \}, users: \{ user: .* \} \}",
+		r"\{ accesses: \{ access: .* \}, databases: \{ DB: .* \}, roles: \{  \}, users: \{ user: .* \} \}",
 	)
 	.unwrap();
 	let out_str = out.unwrap().to_string();
@@ -87,7 +87,7 @@ async fn info_for_db() {
 	let out = res.pop().unwrap().output();
 	assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
-	let output_regex = Regex::new(r"\{ accesses: \{ jwt: .*, record: .* \}, analyzers: \{ analyzer: .* \}, functions: \{ greet: .* \}, params: \{ param: .* \}, tables: \{ TB: .* \}, users: \{ user: .* \} \}").unwrap();
+	let output_regex = Regex::new(r"\{ accesses: \{ jwt: .*, record: .* \}, analyzers: \{ analyzer: .* \}, functions: \{ greet: .* \}, params: \{ param: .* \}, roles: \{  \}, sequences: \{  \}, tables: \{ TB: .* \}, users: \{ user: .* \} \}").unwrap();
 	let out_str = out.unwrap().to_string();
 	assert!(
 		output_regex.is_match(&out_str),
@@ -212,8 +212,8 @@ async fn permissions_checks_info_root() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, namespaces: {  }, nodes: {  }, roles: {  }, users: {  } }"],
 	];
 
 	let test_cases = [
@@ -251,8 +251,8 @@ async fn permissions_checks_info_ns() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"],
-		vec!["{ accesses: {  }, databases: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"],
+		vec!["{ accesses: {  }, databases: {  }, roles: {  }, users: {  } }"],
 	];
 
 	let test_cases = [
@@ -290,8 +290,8 @@ async fn permissions_checks_info_db() {
 
 	// Define the expected results for the check statement when the test statement succeeded and when it failed
 	let check_results = [
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
-        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"],
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
+        vec!["{ accesses: {  }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"],
     ];
 
 	let test_cases = [
@@ -508,7 +508,7 @@ async fn access_info_redacted() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: { access: "DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, databases: {  }, users: {  } }"#.to_string();
+			r#"{ accesses: { access: "DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, databases: {  }, roles: {  }, users: {  } }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -531,7 +531,7 @@ async fn access_info_redacted() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: { access: "DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM PS512 KEY 'public' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, databases: {  }, users: {  } }"#.to_string();
+			r#"{ accesses: { access: "DEFINE ACCESS access ON NAMESPACE TYPE JWT ALGORITHM PS512 KEY 'public' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, databases: {  }, roles: {  }, users: {  } }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -554,7 +554,30 @@ async fn access_info_redacted() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: { access: "DEFINE ACCESS access ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, tables: {  }, users: {  } }"#.to_string();
+			r#"{ accesses: { access: "DEFINE ACCESS access ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' DURATION FOR TOKEN 1h, FOR SESSION NONE" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"#.to_string();
+		let out_str = out.unwrap().to_string();
+		assert_eq!(
+			out_str, out_expected,
+			"Output '{out_str}' doesn't match expected output '{out_expected}'",
+		);
+	}
+	// Record with refresh
+	{
+		let sql = r#"
+			DEFINE ACCESS access ON DB TYPE RECORD WITH JWT ALGORITHM HS512 KEY 'secret' WITH ISSUER KEY 'secret' WITH REFRESH DURATION FOR GRANT 15d;
+			INFO FOR DB
+		"#;
+		let dbs = new_ds().await.unwrap();
+		let ses = Session::owner().with_ns("ns").with_db("test");
+
+		let mut res = dbs.execute(sql, &ses, None).await.unwrap();
+		assert_eq!(res.len(), 2);
+
+		let out = res.pop().unwrap().output();
+		assert!(out.is_ok(), "Unexpected error: {:?}", out);
+
+		let out_expected =
+			r#"{ accesses: { access: "DEFINE ACCESS access ON DATABASE TYPE RECORD WITH JWT ALGORITHM HS512 KEY '[REDACTED]' WITH ISSUER KEY '[REDACTED]' WITH REFRESH DURATION FOR GRANT 2w1d, FOR TOKEN 1h, FOR SESSION NONE" }, analyzers: {  }, functions: {  }, models: {  }, params: {  }, roles: {  }, sequences: {  }, tables: {  }, users: {  } }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -581,7 +604,7 @@ async fn access_info_redacted_structure() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: [{ base: 'NAMESPACE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'HS512', key: '[REDACTED]' }, verify: { alg: 'HS512', key: '[REDACTED]' } }, kind: 'JWT' }, name: 'access' }], databases: [], users: [] }"#.to_string();
+			r#"{ accesses: [{ base: 'NAMESPACE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'HS512', key: '[REDACTED]' }, verify: { alg: 'HS512', key: '[REDACTED]' } }, kind: 'JWT' }, name: 'access' }], databases: [], roles: [], users: [] }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -604,7 +627,7 @@ async fn access_info_redacted_structure() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: [{ base: 'NAMESPACE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'PS512', key: '[REDACTED]' }, verify: { alg: 'PS512', key: 'public' } }, kind: 'JWT' }, name: 'access' }], databases: [], users: [] }"#.to_string();
+			r#"{ accesses: [{ base: 'NAMESPACE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'PS512', key: '[REDACTED]' }, verify: { alg: 'PS512', key: 'public' } }, kind: 'JWT' }, name: 'access' }], databases: [], roles: [], users: [] }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -627,7 +650,7 @@ async fn access_info_redacted_structure() {
 		assert!(out.is_ok(), "Unexpected error: {:?}", out);
 
 		let out_expected =
-			r#"{ accesses: [{ base: 'DATABASE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'HS512', key: '[REDACTED]' }, verify: { alg: 'HS512', key: '[REDACTED]' } }, kind: 'RECORD' }, name: 'access' }], analyzers: [], functions: [], models: [], params: [], tables: [], users: [] }"#.to_string();
+			r#"{ accesses: [{ base: 'DATABASE', duration: { session: 6h, token: 15m }, kind: { jwt: { issuer: { alg: 'HS512', key: '[REDACTED]' }, verify: { alg: 'HS512', key: '[REDACTED]' } }, kind: 'RECORD' }, name: 'access' }], analyzers: [], functions: [], models: [], params: [], roles: [], sequences: [], tables: [], users: [] }"#.to_string();
 		let out_str = out.unwrap().to_string();
 		assert_eq!(
 			out_str, out_expected,
@@ -635,3 +658,103 @@ async fn access_info_redacted_structure() {
 		);
 	}
 }
+
+#[tokio::test]
+async fn info_for_db_with_onnx_model() {
+	let sql = r#"
+		DEFINE MODEL ml::house<1.0.0> ONNX INPUT size [1, 2], rooms [1] OUTPUT price [1];
+		DEFINE MODEL ml::house<1.0.3> ONNX INPUT size [0] OUTPUT price [1];
+		DEFINE MODEL ml::house<1.0.4> ONNX INPUT size [1] OUTPUT price [4294967296, 4294967296, 4294967296];
+		INFO FOR DB;
+		INFO FOR DB STRUCTURE;
+	"#;
+	let dbs = new_ds().await.unwrap();
+	let ses = Session::owner().with_ns("ns").with_db("db");
+	let res = &mut dbs.execute(sql, &ses, None).await.unwrap();
+	assert_eq!(res.len(), 5);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok(), "Unexpected error: {:?}", tmp);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert_eq!(
+		tmp.to_string(),
+		"Problem with machine learning computation. The model tensor 'size' has an invalid shape."
+	);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert_eq!(
+		tmp.to_string(),
+		"Problem with machine learning computation. The model tensor 'price' has an invalid shape."
+	);
+	//
+	let out = res.remove(0).result.unwrap().to_string();
+	assert!(
+		out.contains("models: { house: 'DEFINE MODEL ml::house<1.0.0> ONNX INPUT size [1, 2], rooms [1] OUTPUT price [1] PERMISSIONS FULL' }"),
+		"Unexpected output: {out}"
+	);
+	//
+	let out = res.remove(0).result.unwrap().to_string();
+	assert!(
+		out.contains("models: [{ format: 'onnx', inputs: [{ name: 'size', shape: [1, 2] }, { name: 'rooms', shape: [1] }], name: 'house', outputs: [{ name: 'price', shape: [1] }], permissions: true, version: '1.0.0' }]"),
+		"Unexpected output: {out}"
+	);
+}
+
+#[cfg(feature = "ml")]
+#[tokio::test]
+async fn define_onnx_model_content() {
+	let sql = r#"
+		DEFINE MODEL ml::house<1.0.1> CONTENT "not bytes";
+		DEFINE MODEL ml::house<1.0.2> ONNX CONTENT "not bytes" INPUT size [1] OUTPUT price [1];
+	"#;
+	let dbs = new_ds().await.unwrap();
+	let ses = Session::owner().with_ns("ns").with_db("db");
+	let res = &mut dbs.execute(sql, &ses, None).await.unwrap();
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert_eq!(
+		tmp.to_string(),
+		"Problem with machine learning computation. Only ONNX models can be defined with a file content."
+	);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert_eq!(
+		tmp.to_string(),
+		"Problem with machine learning computation. Expected the ONNX model content as bytes, but found 'not bytes'."
+	);
+}
+
+#[cfg(feature = "ml")]
+#[tokio::test]
+async fn compute_onnx_model_element_types() {
+	// Doubles a float and an int64 tensor, squares a double tensor, and negates a bool tensor
+	let sql = r#"
+		DEFINE MODEL ml::types<1.0.0> ONNX
+			CONTENT encoding::base64::decode("CAgSCXN1cnJlYWxkYjqEAgoVCgF4CgF4EgF5GgVhZGRfeCIDQWRkChcKAWEKAWESA3N1bRoFYWRkX2EiA0FkZAobCgFiCgFiEgdwcm9kdWN0GgVtdWxfYiIDTXVsChgKAWMSB25lZ2F0ZWQaBW5vdF9jIgNOb3QSBXR5cGVzWg8KAXgSCgoICAESBAoCCAJaDwoBYRIKCggIBxIECgIIAloPCgFiEgoKCAgLEgQKAggCWg8KAWMSCgoICAkSBAoCCAJiDwoBeRIKCggIARIECgIIAmIRCgNzdW0SCgoICAcSBAoCCAJiFQoHcHJvZHVjdBIKCggICxIECgIIAmIVCgduZWdhdGVkEgoKCAgJEgQKAggCQgQKABAN")
+			INPUT x [2], a [2], b [2], c [2]
+			OUTPUT y [2], sum [2], product [2], negated [2];
+		ml::types<1.0.0>({ x: [1.5, 2], a: [1, 2], b: [0.5, 3], c: [true, false] });
+		ml::types<1.0.0>({ x: [1.5, 2], a: [true, 2], b: [0.5, 3], c: [true, false] });
+	"#;
+	let dbs = new_ds().await.unwrap();
+	let ses = Session::owner().with_ns("ns").with_db("db");
+	let res = &mut dbs.execute(sql, &ses, None).await.unwrap();
+	assert_eq!(res.len(), 3);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok(), "{tmp:?}");
+	//
+	let tmp = res.remove(0).result.unwrap();
+	assert_eq!(
+		tmp.to_string(),
+		"{ negated: [false, true], product: [0.25f, 9f], sum: [2, 4], y: [3f, 4f] }"
+	);
+	//
+	let tmp = res.remove(0).result.unwrap_err();
+	assert_eq!(
+		tmp.to_string(),
+		"Incorrect arguments for function ml::types<1.0.0>(). The model input 'a' expects 2 values of type Int64 with the shape [2]."
+	);
+}
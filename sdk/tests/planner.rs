@@ -2779,3 +2779,190 @@ async fn select_from_unique_index_ascending() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+async fn select_composite_index_range(unique: bool) -> Result<(), Error> {
+	//
+	let sql = format!(
+		"
+		DEFINE INDEX tenant_created ON TABLE event COLUMNS tenant, created_at {};
+		CREATE event:1 SET tenant = 'a', created_at = 1;
+		CREATE event:2 SET tenant = 'a', created_at = 3;
+		CREATE event:3 SET tenant = 'b', created_at = 2;
+		CREATE event:4 SET tenant = 'a', created_at = 5;
+		CREATE event:5 SET tenant = 'a', created_at = 2;
+		SELECT id, created_at FROM event WHERE tenant = 'a' AND created_at > 1 ORDER BY created_at LIMIT 2 EXPLAIN;
+		SELECT id, created_at FROM event WHERE tenant = 'a' AND created_at > 1 ORDER BY created_at LIMIT 2;
+		SELECT id FROM event WHERE tenant = 'a' EXPLAIN;
+		SELECT id FROM event WHERE tenant = 'a';
+	",
+		if unique {
+			"UNIQUE"
+		} else {
+			""
+		}
+	);
+	let mut t = Test::new(&sql).await?;
+	t.skip_ok(6)?;
+	//
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						from: {
+							inclusive: false,
+							value: 1
+						},
+						index: 'tenant_created',
+						operator: 'compound',
+						prefix: ['a'],
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'event'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				created_at: 2,
+				id: event:5
+			},
+			{
+				created_at: 3,
+				id: event:2
+			}
+		]",
+		"[
+			{
+				detail: {
+					plan: {
+						from: {
+							inclusive: false,
+							value: NONE
+						},
+						index: 'tenant_created',
+						operator: 'compound',
+						prefix: ['a'],
+						to: {
+							inclusive: false,
+							value: NONE
+						}
+					},
+					table: 'event'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: event:1
+			},
+			{
+				id: event:5
+			},
+			{
+				id: event:2
+			},
+			{
+				id: event:4
+			}
+		]",
+	])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_composite_standard_index_range() -> Result<(), Error> {
+	select_composite_index_range(false).await
+}
+
+#[tokio::test]
+async fn select_composite_unique_index_range() -> Result<(), Error> {
+	select_composite_index_range(true).await
+}
+
+async fn select_index_starts_with(unique: bool) -> Result<(), Error> {
+	//
+	let sql = format!(
+		"
+		DEFINE INDEX name ON TABLE person COLUMNS name {};
+		CREATE person:1 SET name = 'alice';
+		CREATE person:2 SET name = 'albert';
+		CREATE person:3 SET name = 'bob';
+		CREATE person:4 SET name = 'al';
+		CREATE person:5 SET name = 'Alan';
+		SELECT id FROM person WHERE string::startsWith(name, 'al') EXPLAIN;
+		SELECT id FROM person WHERE string::startsWith(name, 'al');
+	",
+		if unique {
+			"UNIQUE"
+		} else {
+			""
+		}
+	);
+	let mut t = Test::new(&sql).await?;
+	t.skip_ok(6)?;
+	//
+	t.expect_vals(&[
+		"[
+			{
+				detail: {
+					plan: {
+						index: 'name',
+						operator: 'string::startsWith',
+						prefix: [],
+						value: 'al'
+					},
+					table: 'person'
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+		"[
+			{
+				id: person:4
+			},
+			{
+				id: person:2
+			},
+			{
+				id: person:1
+			}
+		]",
+	])?;
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_standard_index_starts_with() -> Result<(), Error> {
+	select_index_starts_with(false).await
+}
+
+#[tokio::test]
+async fn select_unique_index_starts_with() -> Result<(), Error> {
+	select_index_starts_with(true).await
+}
@@ -0,0 +1,202 @@
+mod parse;
+use parse::Parse;
+
+mod helpers;
+use helpers::*;
+
+use surrealdb::dbs::Session;
+use surrealdb::err::Error;
+use surrealdb::sql::{Thing, Value};
+
+#[tokio::test]
+async fn reference_on_delete_reject() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE ON DELETE REJECT;
+		CREATE user:one;
+		CREATE post:one SET author = user:one;
+		DELETE user:one;
+		SELECT VALUE id FROM user;
+		DELETE post:one;
+		DELETE user:one;
+		SELECT VALUE id FROM user;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(3)?;
+	t.expect_error(
+		"Cannot delete `user:one`, as it is referenced by `post:one` with an ON DELETE REJECT clause",
+	)?;
+	t.expect_val("[user:one]")?;
+	t.skip_ok(2)?;
+	t.expect_val("[]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_on_delete_cascade() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE ON DELETE CASCADE;
+		DEFINE FIELD post ON comment TYPE record<post> REFERENCE ON DELETE CASCADE;
+		CREATE user:one, user:two;
+		CREATE post:one SET author = user:one;
+		CREATE post:two SET author = user:two;
+		CREATE comment:one SET post = post:one;
+		DELETE user:one;
+		SELECT VALUE id FROM post;
+		SELECT VALUE id FROM comment;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(7)?;
+	t.expect_val("[post:two]")?;
+	t.expect_val("[]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_on_delete_unset() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE option<record<user>> REFERENCE ON DELETE UNSET;
+		DEFINE FIELD tags ON post TYPE array<record<tag>> REFERENCE ON DELETE UNSET;
+		CREATE user:one, tag:one, tag:two;
+		CREATE post:one SET author = user:one, tags = [tag:one, tag:two];
+		DELETE user:one, tag:one;
+		SELECT * FROM post;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(5)?;
+	t.expect_val("[{ id: post:one, tags: [tag:two] }]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_on_delete_ignore() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE;
+		CREATE user:one;
+		CREATE post:one SET author = user:one;
+		DELETE user:one;
+		SELECT * FROM post;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(4)?;
+	t.expect_val("[{ id: post:one, author: user:one }]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_lookup() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE;
+		DEFINE FIELD editors ON post TYPE array<record<user>> REFERENCE;
+		DEFINE FIELD author ON comment TYPE record<user> REFERENCE;
+		CREATE user:one, user:two;
+		CREATE post:one SET author = user:one, editors = [user:one];
+		CREATE post:two SET author = user:two, editors = [user:one];
+		CREATE comment:one SET author = user:one;
+		RETURN record::refs(user:one);
+		RETURN record::refs(user:one, 'post');
+		RETURN record::refs(user:one, 'post', 'author');
+		UPDATE post:two SET editors = [];
+		RETURN record::refs(user:one, 'post');
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(7)?;
+	t.expect_val("[comment:one, post:one, post:two]")?;
+	t.expect_val("[post:one, post:two]")?;
+	t.expect_val("[post:one]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[post:one]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_lookup_permissions() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE post PERMISSIONS FOR select WHERE public = true;
+		DEFINE TABLE comment PERMISSIONS NONE;
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE;
+		DEFINE FIELD author ON comment TYPE record<user> REFERENCE;
+		CREATE user:one;
+		CREATE post:one SET author = user:one, public = true;
+		CREATE post:two SET author = user:one, public = false;
+		CREATE comment:one SET author = user:one;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(8)?;
+	// A record user only sees the records which it can select
+	let ses = Session::for_record("test", "test", "test", Thing::from(("user", "one")).into());
+	let res = &mut t.ds.execute("RETURN record::refs(user:one);", &ses, None).await?;
+	assert_eq!(res.remove(0).result?, Value::parse("[post:one]"));
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_backfill() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user>;
+		CREATE user:one;
+		CREATE post:one, post:two SET author = user:one;
+		RETURN record::refs(user:one);
+		DEFINE FIELD OVERWRITE author ON post TYPE record<user> REFERENCE;
+		RETURN record::refs(user:one);
+		DEFINE FIELD OVERWRITE author ON post TYPE record<user>;
+		RETURN record::refs(user:one);
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(3)?;
+	t.expect_val("[]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[post:one, post:two]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_remove_cleanup() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD author ON post TYPE record<user> REFERENCE;
+		DEFINE FIELD author ON comment TYPE record<user> REFERENCE;
+		CREATE user:one;
+		CREATE post:one SET author = user:one;
+		CREATE comment:one SET author = user:one;
+		RETURN record::refs(user:one);
+		REMOVE FIELD author ON post;
+		RETURN record::refs(user:one);
+		REMOVE TABLE comment;
+		RETURN record::refs(user:one);
+		DEFINE FIELD author ON comment TYPE record<user> REFERENCE;
+		RETURN record::refs(user:one);
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(5)?;
+	t.expect_val("[comment:one, post:one]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[comment:one]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[]")?;
+	t.skip_ok(1)?;
+	t.expect_val("[]")?;
+	Ok(())
+}
+
+#[tokio::test]
+async fn reference_requires_record_type() -> Result<(), Error> {
+	let sql = "
+		DEFINE FIELD name ON user TYPE string REFERENCE;
+		DEFINE FIELD author ON post TYPE option<record<user>> REFERENCE ON DELETE CASCADE;
+		INFO FOR TABLE post;
+	";
+	let mut t = Test::new(sql).await?;
+	t.expect_error("The field 'name' can not be a reference, as its type is not a record")?;
+	t.skip_ok(1)?;
+	t.expect_val(
+		"{
+			events: {},
+			fields: { author: 'DEFINE FIELD author ON post TYPE option<record<user>> REFERENCE ON DELETE CASCADE PERMISSIONS FULL' },
+			indexes: {},
+			lives: {},
+			tables: {},
+		}",
+	)?;
+	Ok(())
+}
@@ -1298,3 +1298,106 @@ async fn select_field_from_graph_no_flattening() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_after_cursor_table_scan() -> Result<(), Error> {
+	let sql = "
+		INSERT INTO person [{ id: 1, age: 10 }, { id: 2, age: 20 }, { id: 3, age: 30 }, { id: 4, age: 40 }, { id: 5, age: 50 }];
+		LET $first = SELECT id FROM person AFTER NONE LIMIT 2;
+		LET $second = SELECT id FROM person AFTER $first.cursor LIMIT 2;
+		LET $third = SELECT id FROM person AFTER $second.cursor LIMIT 2;
+		RETURN [$first.result, $second.result, $third.result, $third.cursor];
+		SELECT id FROM person WHERE age > 15 AFTER $first.cursor LIMIT 2;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	for _ in 0..4 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			[{ id: person:1 }, { id: person:2 }],
+			[{ id: person:3 }, { id: person:4 }],
+			[{ id: person:5 }],
+			NONE
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp.pick(&["result".into()]), Value::parse("[{ id: person:3 }, { id: person:4 }]"));
+	assert!(tmp.pick(&["cursor".into()]).is_strand());
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_after_cursor_index_scan() -> Result<(), Error> {
+	let sql = "
+		DEFINE INDEX age ON person FIELDS age;
+		INSERT INTO person [{ id: 1, age: 50 }, { id: 2, age: 40 }, { id: 3, age: 30 }, { id: 4, age: 30 }, { id: 5, age: 10 }];
+		LET $first = SELECT id FROM person WHERE age > 15 AFTER NONE LIMIT 2;
+		LET $second = SELECT id FROM person WHERE age > 15 AFTER $first.cursor LIMIT 2;
+		LET $third = SELECT id FROM person WHERE age > 15 AFTER $second.cursor LIMIT 2;
+		RETURN [$first.result, $second.result, $third.result, $third.cursor];
+		LET $equal = SELECT id FROM person WHERE age = 30 AFTER NONE LIMIT 1;
+		SELECT id FROM person WHERE age = 30 AFTER $equal.cursor LIMIT 1;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 8);
+	//
+	for _ in 0..5 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			[{ id: person:3 }, { id: person:4 }],
+			[{ id: person:2 }, { id: person:1 }],
+			[],
+			NONE
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	//
+	let tmp = res.remove(0).result?;
+	assert_eq!(tmp.pick(&["result".into()]), Value::parse("[{ id: person:4 }]"));
+	//
+	Ok(())
+}
+
+#[tokio::test]
+async fn select_after_cursor_errors() -> Result<(), Error> {
+	let sql = "
+		SELECT * FROM person ORDER BY age AFTER NONE LIMIT 2;
+		SELECT * FROM person AFTER 'invalid' LIMIT 2;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 2);
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(
+		tmp,
+		Err(Error::InvalidAfter { clause }) if clause == "ORDER"
+	));
+	//
+	let tmp = res.remove(0).result;
+	assert!(matches!(tmp, Err(Error::InvalidCursor { .. })));
+	//
+	Ok(())
+}
@@ -236,6 +236,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 			accesses: {},
 			namespaces: { test: 'DEFINE NAMESPACE test' },
 			nodes: {},
+			roles: {},
 			users: {},
 		}",
 	);
@@ -246,6 +247,7 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 		"{
 			accesses: {},
 			databases: { test: 'DEFINE DATABASE test' },
+			roles: {},
 			users: {},
 		}",
 	);
@@ -259,6 +261,8 @@ async fn loose_mode_all_ok() -> Result<(), Error> {
 			functions: {},
 			models: {},
 			params: {},
+			roles: {},
+			sequences: {},
 			tables: { test: 'DEFINE TABLE test TYPE ANY SCHEMALESS PERMISSIONS NONE' },
 			users: {},
 		}",
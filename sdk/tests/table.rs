@@ -283,3 +283,118 @@ async fn define_foreign_table_with_no_cond_and_group_max() -> Result<(), Error>
 async fn define_foreign_table_with_no_cond_and_group_sum() -> Result<(), Error> {
 	define_foreign_table_group(false, "math::sum(value)").await
 }
+
+#[tokio::test]
+async fn define_table_expire() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE session EXPIRE 1h;
+		DEFINE TABLE token EXPIRE 1h ON issued;
+		DEFINE EVENT expired ON session WHEN $event = 'DELETE' THEN (CREATE log SET session = $before.id);
+		CREATE session:one;
+		CREATE token:one SET issued = d'2020-01-01T00:00:00Z';
+		CREATE token:two SET issued = time::now() + 1d;
+		CREATE token:three;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 7);
+	skip_ok(res, 7)?;
+	// Only the records with an elapsed expiry are deleted
+	let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+	dbs.tick_at(now.as_secs()).await?;
+	let sql = "SELECT VALUE id FROM session, token; SELECT VALUE session FROM log;";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[session:one, token:three, token:two]");
+	assert_eq!(tmp, val);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[]");
+	assert_eq!(tmp, val);
+	// Deleting expired records runs the table events
+	dbs.tick_at(now.as_secs() + 7200).await?;
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[token:three, token:two]");
+	assert_eq!(tmp, val);
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[session:one]");
+	assert_eq!(tmp, val);
+	// Removing the expiry keeps the remaining records
+	let sql = "ALTER TABLE token EXPIRE NONE";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	skip_ok(res, 1)?;
+	dbs.tick_at(now.as_secs() + 200_000).await?;
+	let sql = "SELECT VALUE id FROM token";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[token:three, token:two]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn define_table_expire_rejected_delete() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE session EXPIRE 1h ON touched;
+		DEFINE FIELD owner ON note TYPE record<session> REFERENCE ON DELETE REJECT;
+		CREATE session:one SET touched = d'2020-01-01T00:00:00Z';
+		CREATE session:two SET touched = d'2020-01-01T00:00:00Z';
+		CREATE session:three SET touched = d'2020-01-01T00:00:00Z';
+		CREATE note:one SET owner = session:two;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	skip_ok(res, 6)?;
+	// A record which can not be deleted doesn't stop the others from expiring
+	let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+	dbs.tick_at(now.as_secs()).await?;
+	let sql = "SELECT VALUE id FROM session";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[session:two]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
+
+#[tokio::test]
+async fn alter_table_expire_existing_records() -> Result<(), Error> {
+	let sql = "
+		CREATE session:one;
+		CREATE token:one SET issued = d'2020-01-01T00:00:00Z';
+		CREATE token:two SET issued = time::now() + 1d;
+		ALTER TABLE token EXPIRE 1h ON issued;
+		DEFINE TABLE OVERWRITE session EXPIRE 1h;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 5);
+	skip_ok(res, 5)?;
+	// The existing records expire once the table has an expiry
+	let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+	dbs.tick_at(now.as_secs()).await?;
+	let sql = "SELECT VALUE id FROM session, token";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[session:one, token:two]");
+	assert_eq!(tmp, val);
+	// Changing the expiry recalculates the expiry of the existing records
+	let sql = "ALTER TABLE session EXPIRE 3h";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	skip_ok(res, 1)?;
+	dbs.tick_at(now.as_secs() + 7200).await?;
+	let sql = "SELECT VALUE id FROM session, token";
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[session:one, token:two]");
+	assert_eq!(tmp, val);
+	dbs.tick_at(now.as_secs() + 14400).await?;
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[token:two]");
+	assert_eq!(tmp, val);
+	Ok(())
+}
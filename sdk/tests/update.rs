@@ -3,10 +3,10 @@ use parse::Parse;
 mod helpers;
 use crate::helpers::Test;
 use helpers::new_ds;
-use surrealdb::dbs::Session;
+use surrealdb::dbs::{Action, Notification, Session};
 use surrealdb::err::Error;
 use surrealdb::iam::Role;
-use surrealdb::sql::Value;
+use surrealdb::sql::{Thing, Value};
 
 #[tokio::test]
 async fn update_merge_and_content() -> Result<(), Error> {
@@ -623,3 +623,51 @@ async fn check_permissions_auth_disabled() {
 		);
 	}
 }
+
+#[tokio::test]
+async fn update_live_notification_with_before() -> Result<(), Error> {
+	let sql = "
+		DEFINE TABLE person PERMISSIONS FULL;
+		DEFINE FIELD secret ON person PERMISSIONS FOR select NONE;
+		CREATE person:test SET name = 'Tobie', secret = 'hidden';
+	";
+	let dbs = new_ds().await?.with_auth_enabled(true).with_notifications();
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 3);
+	//
+	for _ in 0..3 {
+		let tmp = res.remove(0).result;
+		assert!(tmp.is_ok());
+	}
+	// Start the live query as a record user
+	let ses = Session::for_record("test", "test", "test", Thing::from(("user", "tobie")).into())
+		.with_rt(true);
+	let res =
+		&mut dbs.execute("LIVE SELECT name, secret FROM person WITH BEFORE", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	let live_id = match res.remove(0).result? {
+		Value::Uuid(id) => id,
+		_ => panic!("expected uuid"),
+	};
+	// Update the record
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute("UPDATE person:test SET name = 'Jaime'", &ses, None).await?;
+	assert_eq!(res.len(), 1);
+	let tmp = res.remove(0).result;
+	assert!(tmp.is_ok());
+	// The before image should be projected and filtered like the result
+	let notifications = dbs.notifications().expect("expected notifications");
+	let notification = notifications.recv().await.unwrap();
+	assert_eq!(
+		notification,
+		Notification::new(
+			live_id,
+			Action::Update,
+			Value::parse("person:test"),
+			Value::parse("{ name: 'Jaime' }"),
+		)
+		.with_before(Value::parse("{ name: 'Tobie' }"))
+	);
+	Ok(())
+}
@@ -293,6 +293,51 @@ async fn select_where_hnsw_knn() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn select_where_quantized_knn() -> Result<(), Error> {
+	let sql = r"
+		CREATE pts:1 SET point = [1,2,3,4];
+		CREATE pts:2 SET point = [4,5,6,7];
+		CREATE pts:3 SET point = [8,9,10,11];
+		DEFINE INDEX hnsw_pts ON pts FIELDS point HNSW DIMENSION 4 QUANTIZE INT8 RERANK 2;
+		DEFINE INDEX mt_pts ON pts FIELDS point MTREE DIMENSION 4 QUANTIZE BINARY RERANK 3;
+		LET $pt = [2,3,4,5];
+		SELECT id, vector::distance::knn() AS dist FROM pts WHERE point <|2,40|> $pt;
+		SELECT id, vector::distance::knn() AS dist FROM pts WHERE point <|2|> $pt;
+		INFO FOR TABLE pts;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(6)?;
+	// The candidates are re-ranked with the full-precision vectors
+	for _ in 0..2 {
+		t.expect_val(
+			"[
+				{
+					id: pts:1,
+					dist: 2f
+				},
+				{
+					id: pts:2,
+					dist: 4f
+				}
+			]",
+		)?;
+	}
+	t.expect_val(
+		"{
+			events: {},
+			fields: {},
+			indexes: {
+				hnsw_pts: 'DEFINE INDEX hnsw_pts ON pts FIELDS point HNSW DIMENSION 4 DIST EUCLIDEAN TYPE F64 EFC 150 M 12 M0 24 LM 0.40242960438184466f QUANTIZE INT8 RERANK 2',
+				mt_pts: 'DEFINE INDEX mt_pts ON pts FIELDS point MTREE DIMENSION 4 DIST EUCLIDEAN TYPE F64 CAPACITY 40 DOC_IDS_ORDER 100 DOC_IDS_CACHE 100 MTREE_CACHE 100 QUANTIZE BINARY RERANK 3'
+			},
+			lives: {},
+			tables: {}
+		}",
+	)?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn select_mtree_knn_with_condition() -> Result<(), Error> {
 	let sql = r"
@@ -437,6 +482,52 @@ async fn select_hnsw_knn_with_condition() -> Result<(), Error> {
 	Ok(())
 }
 
+#[test_log::test(tokio::test)]
+async fn select_hnsw_knn_with_selective_condition() -> Result<(), Error> {
+	// Only the three points which are the furthest from the query belong to the tenant
+	let mut sql = String::from("DEFINE INDEX hn_pt ON pts FIELDS point HNSW DIMENSION 1;");
+	for i in 1..=400 {
+		let tenant = if i <= 3 {
+			"a"
+		} else {
+			"b"
+		};
+		sql.push_str(&format!("CREATE pts:{i} SET point = [{i}f], tenant = '{tenant}';"));
+	}
+	sql.push_str(
+		"SELECT id, vector::distance::knn() AS distance FROM pts
+			WHERE tenant = 'a' AND point <|3,10|> [400f]
+			ORDER BY distance;",
+	);
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(&sql, &ses, None).await?;
+	assert_eq!(res.len(), 402);
+	//
+	skip_ok(res, 401)?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				distance: 397f,
+				id: pts:3
+			},
+			{
+				distance: 398f,
+				id: pts:2
+			},
+			{
+				distance: 399f,
+				id: pts:1
+			}
+		]",
+	);
+	assert_eq!(format!("{:#}", tmp), format!("{:#}", val));
+	//
+	Ok(())
+}
+
 #[test_log::test(tokio::test)]
 async fn select_bruteforce_knn_with_condition() -> Result<(), Error> {
 	let sql = r"
@@ -565,3 +656,86 @@ async fn check_hnsw_persistence() -> Result<(), Error> {
 	)?;
 	Ok(())
 }
+
+#[tokio::test]
+async fn select_hybrid_search() -> Result<(), Error> {
+	let sql = r"
+		DEFINE ANALYZER simple TOKENIZERS blank,class FILTERS lowercase;
+		DEFINE INDEX ft_content ON doc FIELDS content SEARCH ANALYZER simple BM25;
+		DEFINE INDEX hn_embedding ON doc FIELDS embedding HNSW DIMENSION 2;
+		INSERT INTO doc [
+			{ id: doc:1, content: 'hello world', embedding: [0f, 0f] },
+			{ id: doc:2, content: 'hello', embedding: [5f, 5f] },
+			{ id: doc:3, content: 'goodbye', embedding: [1f, 0f] },
+			{ id: doc:4, content: 'nothing', embedding: [9f, 9f] },
+			{ id: doc:5, content: 'something', embedding: [8f, 9f] },
+			{ id: doc:6, content: 'anything', embedding: [9f, 8f] }
+		];
+		LET $pt = [0f, 0f];
+		SELECT id FROM doc WHERE content @1@ 'hello' OR embedding <|2,40|> $pt EXPLAIN;
+		SELECT VALUE id FROM (
+			SELECT id, search::rrf() AS rrf FROM doc
+				WHERE content @1@ 'hello' OR embedding <|2,40|> $pt
+				ORDER BY rrf DESC
+		);
+		SELECT id, search::linear(1, 0) AS text, search::linear(0, 1) AS vector FROM doc
+			WHERE content @1@ 'hello' OR embedding <|2,40|> $pt
+			ORDER BY id;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(5)?;
+	// Explains the hybrid iterator
+	t.expect_val(
+		"[
+			{
+				detail: {
+					plan: {
+						hybrid: [
+							{
+								index: 'ft_content',
+								operator: '@1@',
+								value: 'hello'
+							},
+							{
+								index: 'hn_embedding',
+								operator: '<|2,40|>',
+								value: [0f, 0f]
+							}
+						]
+					},
+					table: 'doc',
+				},
+				operation: 'Iterate Index'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	// The record found by both searches is ranked first
+	t.expect_val("[doc:1, doc:2, doc:3]")?;
+	// The weighted linear fusion of the normalised scores
+	t.expect_val(
+		"[
+			{
+				id: doc:1,
+				text: 0f,
+				vector: 1f
+			},
+			{
+				id: doc:2,
+				text: 1f,
+				vector: 0f
+			},
+			{
+				id: doc:3,
+				text: 0f,
+				vector: 0f
+			}
+		]",
+	)?;
+	Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::err::Error;
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::{Table, Value};
+
+/// The changes of a single versionstamp, as found in a change feed segment,
+/// or in the changes which are streamed by the /sync endpoint
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChangeSet {
+	pub versionstamp: u128,
+	/// The changes, as a SurrealQL array
+	pub changes: String,
+}
+
+/// Converts the changes of a versionstamp into the statements which apply them
+pub fn replay(change: &ChangeSet) -> Result<String, Error> {
+	let invalid =
+		|| Error::Other(format!("Invalid changes at versionstamp {}", change.versionstamp));
+	let Value::Array(changes) = surrealdb::sql::value(&change.changes).map_err(|_| invalid())?
+	else {
+		return Err(invalid());
+	};
+	let mut sql = String::from("OPTION IMPORT;\nBEGIN TRANSACTION;\n");
+	for change in changes {
+		let Value::Object(change) = change else {
+			return Err(invalid());
+		};
+		// Record changes contain the current value of the record
+		if let Some(v) = change.get("current").or(change.get("create")).or(change.get("update")) {
+			sql.push_str(&format!("UPSERT {} CONTENT {v};\n", v.rid()));
+		} else if let Some(v) = change.get("delete") {
+			sql.push_str(&format!("DELETE {};\n", v.rid()));
+		} else if let Some(v) = change.get("define_table") {
+			let Value::Object(v) = v else {
+				return Err(invalid());
+			};
+			let Some(Value::Strand(name)) = v.get("name") else {
+				return Err(invalid());
+			};
+			sql.push_str(&format!("DEFINE TABLE IF NOT EXISTS {};\n", Table::from(name.as_str())));
+		} else {
+			return Err(invalid());
+		}
+	}
+	sql.push_str("COMMIT TRANSACTION;\n");
+	Ok(sql)
+}
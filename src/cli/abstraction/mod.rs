@@ -1,7 +1,11 @@
 pub(crate) mod auth;
 
-use auth::CredentialsLevel;
+use crate::err::Error;
+use auth::{CredentialsBuilder, CredentialsLevel};
 use clap::Args;
+use surrealdb::engine::any::{connect, Any, IntoEndpoint};
+use surrealdb::opt::Config;
+use surrealdb::Surreal;
 
 #[derive(Args, Debug)]
 pub(crate) struct AuthArguments {
@@ -79,3 +83,58 @@ pub struct OptionalDatabaseConnectionArguments {
 	#[arg(value_parser = super::validator::endpoint_valid)]
 	pub(crate) endpoint: Option<String>,
 }
+
+/// Connects to the database engine at the endpoint, and selects the namespace and database.
+///
+/// If username and password are specified, and we are connecting to a remote SurrealDB server, then we need to authenticate.
+/// If we are connecting directly to a datastore (i.e. surrealkv://local.skv or tikv://...), then we don't need to authenticate because we use an embedded (local) SurrealDB instance with auth disabled.
+/// The config is only used for local engines.
+pub(crate) async fn connect_database(
+	endpoint: String,
+	config: Config,
+	AuthArguments {
+		username,
+		password,
+		token,
+		auth_level,
+	}: AuthArguments,
+	DatabaseSelectionArguments {
+		namespace,
+		database,
+	}: DatabaseSelectionArguments,
+) -> Result<Surreal<Any>, Error> {
+	let client = if username.is_some()
+		&& password.is_some()
+		&& !endpoint.clone().into_endpoint()?.parse_kind()?.is_local()
+	{
+		debug!("Connecting to the database engine with authentication");
+		let creds = CredentialsBuilder::default()
+			.with_username(username.as_deref())
+			.with_password(password.as_deref())
+			.with_namespace(namespace.as_str())
+			.with_database(database.as_str());
+
+		let client = connect(endpoint).await?;
+
+		debug!("Signing in to the database engine at '{:?}' level", auth_level);
+		match auth_level {
+			CredentialsLevel::Root => client.signin(creds.root()?).await?,
+			CredentialsLevel::Namespace => client.signin(creds.namespace()?).await?,
+			CredentialsLevel::Database => client.signin(creds.database()?).await?,
+		};
+
+		client
+	} else if token.is_some() && !endpoint.clone().into_endpoint()?.parse_kind()?.is_local() {
+		let client = connect(endpoint).await?;
+		client.authenticate(token.unwrap()).await?;
+
+		client
+	} else {
+		debug!("Connecting to the database engine without authentication");
+		connect((endpoint, config)).await?
+	};
+
+	// Use the specified namespace / database
+	client.use_ns(namespace).use_db(database).await?;
+	Ok(client)
+}
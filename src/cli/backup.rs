@@ -0,0 +1,453 @@
+use crate::changes::ChangeSet;
+use crate::cli::abstraction::{
+	connect_database, AuthArguments, DatabaseConnectionArguments, DatabaseSelectionArguments,
+};
+use crate::err::Error;
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::Args;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use surrealdb::engine::any::Any;
+use surrealdb::opt::{Config, ExportConfig};
+use surrealdb::sql::Value;
+use surrealdb::Surreal;
+use tokio::fs;
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+/// The file containing the base snapshot of a backup
+pub(super) const BASE_FILE: &str = "base.surql";
+/// The file containing the metadata of the base snapshot
+pub(super) const BASE_META_FILE: &str = "base.json";
+/// The file containing the definitions of the database when it was last backed up
+const SCHEMA_FILE: &str = "schema.surql";
+/// The extension of the records which are exported alongside a segment
+pub(super) const SNAPSHOT_EXTENSION: &str = "surql";
+/// The extension of the changes of a segment while they are being read
+const CHANGES_EXTENSION: &str = "changes";
+/// The number of change feed entries to fetch at once
+const CHANGES_BATCH_SIZE: u32 = 1000;
+
+/// The metadata of the base snapshot of a backup
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct Base {
+	/// The last versionstamp which is included in the snapshot
+	pub versionstamp: u128,
+	/// When the snapshot was taken
+	pub taken_at: String,
+}
+
+/// The first line of a change feed segment
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct Segment {
+	/// The last versionstamp of the previous segment, or of the base snapshot
+	pub since: u128,
+	/// The last versionstamp of this segment
+	pub until: u128,
+	/// When the segment was taken
+	pub taken_at: String,
+	/// Whether the segment is a full snapshot of the database, which is
+	/// taken instead of the changes when the schema has changed
+	#[serde(default)]
+	pub snapshot: bool,
+	/// The tables without a change feed, whose records are exported
+	/// alongside the segment
+	#[serde(default)]
+	pub tables: Vec<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct BackupCommandArguments {
+	#[arg(help = "Path to the directory containing the backup")]
+	#[arg(index = 1)]
+	dir: String,
+
+	#[command(flatten)]
+	conn: DatabaseConnectionArguments,
+	#[command(flatten)]
+	auth: AuthArguments,
+	#[command(flatten)]
+	sel: DatabaseSelectionArguments,
+}
+
+pub async fn init(
+	BackupCommandArguments {
+		dir,
+		conn: DatabaseConnectionArguments {
+			endpoint,
+		},
+		auth,
+		sel,
+	}: BackupCommandArguments,
+) -> Result<(), Error> {
+	// Initialize opentelemetry and logging
+	crate::telemetry::builder().with_log_level("info").init();
+	// Connect to the database engine
+	let database = sel.database.clone();
+	let client = connect_database(endpoint, Config::new(), auth, sel).await?;
+	// Take a base snapshot, or append the latest changes
+	let dir = PathBuf::from(dir);
+	fs::create_dir_all(&dir).await?;
+	match read_base(&dir).await? {
+		None => backup_base(&client, &dir).await?,
+		Some(base) => backup_segment(&client, &dir, &base, &database).await?,
+	}
+	// Everything OK
+	Ok(())
+}
+
+/// Takes a base snapshot of the database
+async fn backup_base(client: &Surreal<Any>, dir: &Path) -> Result<(), Error> {
+	let taken_at = Utc::now().to_rfc3339();
+	// The changes which are committed before the export starts are included
+	// in the snapshot. Later changes may be included too, which is fine, as
+	// replaying the changes of a record sets it to its latest value.
+	let versionstamp = latest_versionstamp(client).await?;
+	debug!("Exporting the base snapshot of the database");
+	client.export(dir.join(BASE_FILE)).await?;
+	fs::write(dir.join(SCHEMA_FILE), export_schema(client).await?).await?;
+	let base = Base {
+		versionstamp,
+		taken_at,
+	};
+	let json = serde_json::to_vec(&base).map_err(|e| Error::Json(e.to_string()))?;
+	fs::write(dir.join(BASE_META_FILE), json).await?;
+	info!("The base snapshot was backed up successfully at versionstamp {versionstamp}");
+	Ok(())
+}
+
+/// Appends the changes since the last segment to the backup
+async fn backup_segment(
+	client: &Surreal<Any>,
+	dir: &Path,
+	base: &Base,
+	database: &str,
+) -> Result<(), Error> {
+	let taken_at = Utc::now().to_rfc3339();
+	// Continue from the end of the last segment
+	let segments = list_segments(dir).await?;
+	let since = match segments.last() {
+		Some(path) => read_segment(path).await?.0.until,
+		None => base.versionstamp,
+	};
+	// The changes which have been removed from the change feed can not be
+	// backed up, so a new base snapshot needs to be taken instead
+	let unreadable = |e: Error| {
+		Error::Other(format!(
+			"Unable to read the changes since versionstamp {since}, take a new base snapshot into an empty directory: {e}"
+		))
+	};
+	// The changes are streamed to a file, as the segment starts with the
+	// last versionstamp which is only known once all the changes are read
+	let path = dir.join(segment_name(segments.len() + 1));
+	let changes = path.with_extension(CHANGES_EXTENSION);
+	let mut file = BufWriter::new(fs::File::create(&changes).await?);
+	let mut reader = ChangeReader::new(client, since);
+	let mut count = 0;
+	let mut until = since;
+	while let Some(change) = reader.next().await.map_err(unreadable)? {
+		if change.versionstamp <= since {
+			continue;
+		}
+		let mut line = serde_json::to_vec(&change).map_err(|e| Error::Json(e.to_string()))?;
+		line.push(b'\n');
+		file.write_all(&line).await?;
+		until = change.versionstamp;
+		count += 1;
+	}
+	file.flush().await?;
+	drop(file);
+	// Schema changes are not recorded in the change feed, so when the schema
+	// has changed, the segment is a full snapshot of the database instead
+	let schema = export_schema(client).await?;
+	let previous = match fs::try_exists(dir.join(SCHEMA_FILE)).await? {
+		true => Some(fs::read_to_string(dir.join(SCHEMA_FILE)).await?),
+		false => None,
+	};
+	if previous.is_some_and(|v| v != schema) {
+		debug!("Exporting a snapshot of the database, as its schema has changed");
+		client.export(path.with_extension(SNAPSHOT_EXTENSION)).await?;
+		let segment = Segment {
+			since,
+			until,
+			taken_at,
+			snapshot: true,
+			tables: Vec::new(),
+		};
+		fs::remove_file(&changes).await?;
+		write_segment(&path, &segment, None).await?;
+		fs::write(dir.join(SCHEMA_FILE), schema).await?;
+		info!(
+			"The schema has changed, a snapshot up to versionstamp {until} was backed up to {}",
+			path.display()
+		);
+		return Ok(());
+	}
+	fs::write(dir.join(SCHEMA_FILE), schema).await?;
+	// The records of the tables without a change feed are backed up in full
+	let tables = tables_without_changefeed(client, database).await?;
+	if count == 0 && tables.is_empty() {
+		fs::remove_file(&changes).await?;
+		info!("There are no changes since versionstamp {since}");
+		return Ok(());
+	}
+	if !tables.is_empty() {
+		debug!("Exporting the records of the tables without a change feed: {}", tables.join(", "));
+		let config = ExportConfig::new().schema(false).tables(tables.clone());
+		client.export(path.with_extension(SNAPSHOT_EXTENSION)).with_config(config).await?;
+	}
+	// Write the segment
+	let segment = Segment {
+		since,
+		until,
+		taken_at,
+		snapshot: false,
+		tables,
+	};
+	write_segment(&path, &segment, Some(&changes)).await?;
+	fs::remove_file(&changes).await?;
+	info!("{count} change sets up to versionstamp {until} were backed up to {}", path.display());
+	Ok(())
+}
+
+/// Writes a change feed segment, followed by the changes which were read into a file
+async fn write_segment(
+	path: &Path,
+	segment: &Segment,
+	changes: Option<&Path>,
+) -> Result<(), Error> {
+	let mut data = serde_json::to_vec(segment).map_err(|e| Error::Json(e.to_string()))?;
+	data.push(b'\n');
+	let mut file = fs::File::create(path).await?;
+	file.write_all(&data).await?;
+	if let Some(changes) = changes {
+		tokio::io::copy(&mut fs::File::open(changes).await?, &mut file).await?;
+	}
+	file.sync_all().await?;
+	Ok(())
+}
+
+/// Exports the definitions of the database, without any records
+async fn export_schema(client: &Surreal<Any>) -> Result<String, Error> {
+	let config = ExportConfig::new().records(false);
+	let mut export = client.export(()).with_config(config).await?;
+	let mut data = Vec::new();
+	while let Some(bytes) = export.next().await {
+		data.extend(bytes?);
+	}
+	String::from_utf8(data).map_err(|e| Error::Other(e.to_string()))
+}
+
+/// Lists the tables whose changes are not recorded in the change feed
+async fn tables_without_changefeed(
+	client: &Surreal<Any>,
+	database: &str,
+) -> Result<Vec<String>, Error> {
+	let field = |v: &Value, name: &str| v.pick(&[name.into()]);
+	// A database change feed records the changes of every table. Users
+	// without access to the namespace definitions back up every table
+	// without a change feed of its own.
+	if let Ok(info) = client.query("INFO FOR NS STRUCTURE").await?.take::<surrealdb::Value>(0) {
+		if let Value::Array(databases) = field(&info.into_inner(), "databases") {
+			if databases.iter().any(|v| {
+				field(v, "name") == Value::from(database) && !field(v, "changefeed").is_none()
+			}) {
+				return Ok(Vec::new());
+			}
+		}
+	}
+	let info: surrealdb::Value = client.query("INFO FOR DB STRUCTURE").await?.take(0)?;
+	let Value::Array(tables) = field(&info.into_inner(), "tables") else {
+		return Err(Error::Other("Unable to list the tables of the database".to_string()));
+	};
+	// The records of views are computed from the records of other tables
+	Ok(tables
+		.iter()
+		.filter(|v| field(v, "changefeed").is_none() && field(v, "view").is_none())
+		.filter_map(|v| match field(v, "name") {
+			Value::Strand(v) => Some(v.0),
+			_ => None,
+		})
+		.collect())
+}
+
+/// Finds the versionstamp of the latest change in the change feed
+///
+/// The change feed is read from the versionstamps which were recorded at
+/// increasingly older times, so that it is only read from the start when
+/// there have been no recent changes.
+async fn latest_versionstamp(client: &Surreal<Any>) -> Result<u128, Error> {
+	let now = Utc::now();
+	for ago in [TimeDelta::minutes(1), TimeDelta::hours(1), TimeDelta::days(1)] {
+		let since = surrealdb::sql::Datetime::from(now - ago);
+		let sql = format!("SHOW CHANGES FOR DATABASE SINCE {since} LIMIT 1");
+		// No versionstamp has been recorded yet at this time
+		let Ok(mut response) = client.query(sql).await?.check() else {
+			break;
+		};
+		let value: surrealdb::Value = response.take(0)?;
+		let Value::Array(batch) = value.into_inner() else {
+			break;
+		};
+		if let Some(change) = batch.first() {
+			return last_versionstamp(client, versionstamp(change)?).await;
+		}
+	}
+	last_versionstamp(client, 0).await
+}
+
+/// Reads the change feed from the specified versionstamp up to its last versionstamp
+async fn last_versionstamp(client: &Surreal<Any>, since: u128) -> Result<u128, Error> {
+	let mut reader = ChangeReader::new(client, since);
+	let mut last = since;
+	while let Some(change) = reader.next().await? {
+		last = change.versionstamp;
+	}
+	Ok(last)
+}
+
+/// Reads the change feed of the database in batches, from the specified versionstamp
+struct ChangeReader<'a> {
+	client: &'a Surreal<Any>,
+	/// The versionstamp which the change feed is read from
+	since: u128,
+	/// The change sets which have been fetched, but not yet read
+	batch: VecDeque<ChangeSet>,
+	/// Whether the end of the change feed has been fetched
+	done: bool,
+}
+
+impl<'a> ChangeReader<'a> {
+	fn new(client: &'a Surreal<Any>, since: u128) -> Self {
+		Self {
+			client,
+			since,
+			batch: VecDeque::new(),
+			done: false,
+		}
+	}
+
+	/// Returns the next change set, in versionstamp order
+	async fn next(&mut self) -> Result<Option<ChangeSet>, Error> {
+		loop {
+			// The last change set of a batch could be incomplete, so it is
+			// only read once the next batch has been fetched
+			if self.batch.len() > 1 || self.done {
+				return Ok(self.batch.pop_front());
+			}
+			self.fetch().await?;
+		}
+	}
+
+	/// Fetches the next batch of change sets
+	async fn fetch(&mut self) -> Result<(), Error> {
+		// The versionstamp of the last change set is fetched again, as a
+		// limited batch could have stopped in the middle of its changes.
+		let since = self.batch.back().map_or(self.since, |c| c.versionstamp);
+		let sql = format!(
+			"SHOW CHANGES FOR DATABASE SINCE {} LIMIT {CHANGES_BATCH_SIZE}",
+			(since >> 16) as u64
+		);
+		let mut response = self.client.query(sql).await?.check()?;
+		let value: surrealdb::Value = response.take(0)?;
+		let Value::Array(batch) = value.into_inner() else {
+			self.done = true;
+			return Ok(());
+		};
+		let mut progressed = false;
+		for change in batch {
+			let versionstamp = versionstamp(&change)?;
+			let changes = change.pick(&["changes".into()]).to_string();
+			match self.batch.back_mut() {
+				// This is a change set which was already fetched
+				Some(last) if last.versionstamp > versionstamp => continue,
+				// This change set could have been incomplete
+				Some(last) if last.versionstamp == versionstamp => last.changes = changes,
+				_ => {
+					self.batch.push_back(ChangeSet {
+						versionstamp,
+						changes,
+					});
+					progressed = true;
+				}
+			}
+		}
+		self.done = !progressed;
+		Ok(())
+	}
+}
+
+/// Reads the versionstamp of a change feed entry
+fn versionstamp(change: &Value) -> Result<u128, Error> {
+	match change {
+		Value::Object(v) => match v.get("versionstamp") {
+			Some(Value::Number(v)) => {
+				u128::try_from(v.clone()).map_err(|e| Error::Other(e.to_string()))
+			}
+			_ => Err(Error::Other("Missing change feed versionstamp".to_string())),
+		},
+		v => Err(Error::Other(format!("Unexpected change feed entry {v}"))),
+	}
+}
+
+/// Reads the metadata of the base snapshot, if there is one
+pub(super) async fn read_base(dir: &Path) -> Result<Option<Base>, Error> {
+	let path = dir.join(BASE_META_FILE);
+	if !fs::try_exists(&path).await? {
+		return Ok(None);
+	}
+	let data = fs::read(&path).await?;
+	let base = serde_json::from_slice(&data).map_err(|e| Error::Json(e.to_string()))?;
+	Ok(Some(base))
+}
+
+/// Lists the change feed segments of a backup, in order
+pub(super) async fn list_segments(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+	let mut res = Vec::new();
+	let mut entries = fs::read_dir(dir).await?;
+	while let Some(entry) = entries.next_entry().await? {
+		let name = entry.file_name();
+		let name = name.to_string_lossy();
+		if name.starts_with("segment-") && name.ends_with(".ndjson") {
+			res.push(entry.path());
+		}
+	}
+	// Segment names are zero padded, so they sort in order
+	res.sort();
+	// Check that no segment is missing
+	for (i, path) in res.iter().enumerate() {
+		if !path.ends_with(segment_name(i + 1)) {
+			return Err(Error::Other(format!(
+				"The backup is not continuous, {} is missing",
+				segment_name(i + 1)
+			)));
+		}
+	}
+	Ok(res)
+}
+
+/// Reads a change feed segment
+pub(super) async fn read_segment(path: &Path) -> Result<(Segment, Vec<ChangeSet>), Error> {
+	let data = fs::read(path).await?;
+	let mut lines = data.split(|b| *b == b'\n').filter(|l| !l.is_empty());
+	let invalid = |e: serde_json::Error| Error::Json(format!("{}: {e}", path.display()));
+	let segment: Segment = match lines.next() {
+		Some(line) => serde_json::from_slice(line).map_err(invalid)?,
+		None => return Err(Error::Other(format!("The segment {} is empty", path.display()))),
+	};
+	let changes =
+		lines.map(|l| serde_json::from_slice(l).map_err(invalid)).collect::<Result<Vec<_>, _>>()?;
+	Ok((segment, changes))
+}
+
+fn segment_name(number: usize) -> String {
+	format!("segment-{number:06}.ndjson")
+}
+
+/// Parses the time at which a snapshot or a segment was taken
+pub(super) fn taken_at(value: &str) -> Result<DateTime<Utc>, Error> {
+	DateTime::parse_from_rfc3339(value)
+		.map(|v| v.with_timezone(&Utc))
+		.map_err(|e| Error::Other(format!("Invalid backup timestamp {value}: {e}")))
+}
@@ -1,4 +1,6 @@
 use crate::net::client_ip::ClientIp;
+use crate::net::limit::RateLimit;
+use crate::net::sync::Replica;
 use std::sync::OnceLock;
 use std::{net::SocketAddr, path::PathBuf};
 
@@ -15,6 +17,14 @@ pub struct Config {
 	pub pass: Option<String>,
 	pub crt: Option<PathBuf>,
 	pub key: Option<PathBuf>,
+	pub encryption_key: Option<String>,
+	pub encrypt_keys: bool,
 	pub engine: Option<EngineOptions>,
 	pub no_identification_headers: bool,
+	pub prometheus_metrics: bool,
+	pub rate_limit: Option<RateLimit>,
+	pub user_rate_limit: Option<RateLimit>,
+	pub auth_rate_limit: Option<RateLimit>,
+	pub auth_access_rate_limit: Option<RateLimit>,
+	pub replica: Option<Replica>,
 }
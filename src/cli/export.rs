@@ -1,19 +1,33 @@
-use crate::cli::abstraction::auth::{CredentialsBuilder, CredentialsLevel};
 use crate::cli::abstraction::{
-	AuthArguments, DatabaseConnectionArguments, DatabaseSelectionArguments,
+	connect_database, AuthArguments, DatabaseConnectionArguments, DatabaseSelectionArguments,
 };
 use crate::err::Error;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use futures_util::StreamExt;
-use surrealdb::engine::any::{connect, IntoEndpoint};
+use surrealdb::opt::{Config, ExportConfig, ExportFormat};
 use tokio::io::{self, AsyncWriteExt};
 
 #[derive(Args, Debug)]
 pub struct ExportCommandArguments {
-	#[arg(help = "Path to the SurrealQL file to export. Use dash - to write into stdout.")]
+	#[arg(help = "Path to the file to export. Use dash - to write into stdout.")]
 	#[arg(default_value = "-")]
 	#[arg(index = 1)]
 	file: String,
+	#[arg(help = "Comma-separated list of tables to export. Exports all tables by default.")]
+	#[arg(long, value_delimiter = ',')]
+	tables: Option<Vec<String>>,
+	#[arg(help = "Whether to only export the definitions, without any records")]
+	#[arg(long, conflicts_with = "records_only")]
+	schema_only: bool,
+	#[arg(help = "Whether to only export the records, without any definitions")]
+	#[arg(long)]
+	records_only: bool,
+	#[arg(help = "Whether to export every stored version of the records")]
+	#[arg(long)]
+	versions: bool,
+	#[arg(help = "The format of the export")]
+	#[arg(long, default_value = "sql", value_enum)]
+	format: Format,
 
 	#[command(flatten)]
 	conn: DatabaseConnectionArguments,
@@ -23,67 +37,60 @@ pub struct ExportCommandArguments {
 	sel: DatabaseSelectionArguments,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Format {
+	/// SurrealQL statements
+	Sql,
+	/// Newline delimited JSON, with a SurrealQL value for each record
+	Ndjson,
+	/// Concatenated CBOR, which imports faster than SurrealQL
+	Cbor,
+}
+
+impl From<Format> for ExportFormat {
+	fn from(format: Format) -> Self {
+		match format {
+			Format::Sql => ExportFormat::Sql,
+			Format::Ndjson => ExportFormat::Ndjson,
+			Format::Cbor => ExportFormat::Cbor,
+		}
+	}
+}
+
 pub async fn init(
 	ExportCommandArguments {
 		file,
+		tables,
+		schema_only,
+		records_only,
+		versions,
+		format,
 		conn: DatabaseConnectionArguments {
 			endpoint,
 		},
-		auth: AuthArguments {
-			username,
-			password,
-			token,
-			auth_level,
-		},
-		sel: DatabaseSelectionArguments {
-			namespace,
-			database,
-		},
+		auth,
+		sel,
 	}: ExportCommandArguments,
 ) -> Result<(), Error> {
 	// Initialize opentelemetry and logging
 	crate::telemetry::builder().with_log_level("error").init();
 
-	// If username and password are specified, and we are connecting to a remote SurrealDB server, then we need to authenticate.
-	// If we are connecting directly to a datastore (i.e. surrealkv://local.skv or tikv://...), then we don't need to authenticate because we use an embedded (local) SurrealDB instance with auth disabled.
-	let client = if username.is_some()
-		&& password.is_some()
-		&& !endpoint.clone().into_endpoint()?.parse_kind()?.is_local()
-	{
-		debug!("Connecting to the database engine with authentication");
-		let creds = CredentialsBuilder::default()
-			.with_username(username.as_deref())
-			.with_password(password.as_deref())
-			.with_namespace(namespace.as_str())
-			.with_database(database.as_str());
-
-		let client = connect(endpoint).await?;
-
-		debug!("Signing in to the database engine at '{:?}' level", auth_level);
-		match auth_level {
-			CredentialsLevel::Root => client.signin(creds.root()?).await?,
-			CredentialsLevel::Namespace => client.signin(creds.namespace()?).await?,
-			CredentialsLevel::Database => client.signin(creds.database()?).await?,
-		};
-
-		client
-	} else if token.is_some() && !endpoint.clone().into_endpoint()?.parse_kind()?.is_local() {
-		let client = connect(endpoint).await?;
-		client.authenticate(token.unwrap()).await?;
-
-		client
-	} else {
-		debug!("Connecting to the database engine without authentication");
-		connect(endpoint).await?
-	};
-
-	// Use the specified namespace / database
-	client.use_ns(namespace).use_db(database).await?;
+	// Connect to the database engine
+	let client = connect_database(endpoint, Config::new(), auth, sel).await?;
+	// Configure the export
+	let mut config = ExportConfig::new()
+		.schema(!records_only)
+		.records(!schema_only)
+		.versions(versions)
+		.format(format.into());
+	if let Some(tables) = tables {
+		config = config.tables(tables);
+	}
 	// Export the data from the database
 	debug!("Exporting data from the database");
 	if file == "-" {
 		// Prepare the backup
-		let mut backup = client.export(()).await?;
+		let mut backup = client.export(()).with_config(config).await?;
 		// Get a handle to standard output
 		let mut stdout = io::stdout();
 		// Write the backup to standard output
@@ -91,9 +98,9 @@ pub async fn init(
 			stdout.write_all(&bytes?).await?;
 		}
 	} else {
-		client.export(file).await?;
+		client.export(file).with_config(config).await?;
 	}
-	info!("The SurrealQL file was exported successfully");
+	info!("The database was exported successfully");
 	// Everything OK
 	Ok(())
 }
@@ -1,9 +1,12 @@
 pub(crate) mod abstraction;
+pub(crate) mod backup;
 mod config;
 mod export;
 mod import;
 mod isready;
 mod ml;
+mod rekey;
+pub(crate) mod restore;
 mod sql;
 mod start;
 #[cfg(test)]
@@ -19,12 +22,15 @@ use crate::cli::version_client::VersionClient;
 use crate::cnf::DEBUG_BUILD_WARNING;
 use crate::cnf::{LOGO, PKG_VERSION};
 use crate::env::RELEASE;
+use backup::BackupCommandArguments;
 use clap::{Parser, Subcommand};
 pub use config::CF;
 use export::ExportCommandArguments;
 use import::ImportCommandArguments;
 use isready::IsReadyCommandArguments;
 use ml::MlCommand;
+use rekey::RekeyCommandArguments;
+use restore::RestoreCommandArguments;
 use semver::Version;
 use sql::SqlCommandArguments;
 use start::StartCommandArguments;
@@ -66,10 +72,10 @@ struct Cli {
 enum Commands {
 	#[command(about = "Start the database server")]
 	Start(StartCommandArguments),
-	/* Not implemented yet
-	#[command(about = "Backup data to or from an existing database")]
+	#[command(about = "Back up an existing database incrementally, using its change feed")]
 	Backup(BackupCommandArguments),
-	*/
+	#[command(about = "Restore an incremental backup into an existing database")]
+	Restore(RestoreCommandArguments),
 	#[command(about = "Import a SurrealQL script into an existing database")]
 	Import(ImportCommandArguments),
 	#[command(about = "Export an existing database as a SurrealQL script")]
@@ -89,6 +95,8 @@ enum Commands {
 	IsReady(IsReadyCommandArguments),
 	#[command(about = "Validate SurrealQL query files")]
 	Validate(ValidateCommandArguments),
+	#[command(about = "Re-encrypt the data of a stopped database server with a new key")]
+	Rekey(RekeyCommandArguments),
 }
 
 pub async fn init() -> ExitCode {
@@ -126,12 +134,15 @@ pub async fn init() -> ExitCode {
 		Commands::Start(args) => start::init(args).await,
 		Commands::Import(args) => import::init(args).await,
 		Commands::Export(args) => export::init(args).await,
+		Commands::Backup(args) => backup::init(args).await,
+		Commands::Restore(args) => restore::init(args).await,
 		Commands::Version(args) => version::init(args).await,
 		Commands::Upgrade(args) => upgrade::init(args).await,
 		Commands::Sql(args) => sql::init(args).await,
 		Commands::Ml(args) => ml::init(args).await,
 		Commands::IsReady(args) => isready::init(args).await,
 		Commands::Validate(args) => validate::init(args).await,
+		Commands::Rekey(args) => rekey::init(args).await,
 	};
 	// Save the flamegraph and profile
 	#[cfg(feature = "performance-profiler")]
@@ -0,0 +1,53 @@
+use crate::err::Error;
+use clap::Args;
+use surrealdb::engine::any::IntoEndpoint;
+use surrealdb::kvs::Datastore;
+
+#[derive(Args, Debug)]
+pub struct RekeyCommandArguments {
+	#[arg(help = "Database path used for storing data")]
+	#[arg(env = "SURREAL_PATH", index = 1)]
+	#[arg(value_parser = super::validator::path_valid)]
+	path: String,
+	#[arg(help = "The current encryption key, if the data is encrypted")]
+	#[arg(env = "SURREAL_KEY", short = 'k', long = "key")]
+	#[arg(value_parser = super::validator::key_valid)]
+	key: Option<String>,
+	#[arg(help = "Whether the keys are currently encrypted")]
+	#[arg(env = "SURREAL_ENCRYPT_KEYS", long = "encrypt-keys", requires = "key")]
+	#[arg(default_value_t = false)]
+	encrypt_keys: bool,
+	#[arg(help = "The new encryption key, or none to decrypt the data")]
+	#[arg(env = "SURREAL_NEW_KEY", long = "new-key")]
+	#[arg(value_parser = super::validator::key_valid)]
+	new_key: Option<String>,
+	#[arg(help = "Whether to also encrypt the keys with the new encryption key")]
+	#[arg(env = "SURREAL_NEW_ENCRYPT_KEYS", long = "new-encrypt-keys", requires = "new_key")]
+	#[arg(default_value_t = false)]
+	new_encrypt_keys: bool,
+}
+
+pub async fn init(
+	RekeyCommandArguments {
+		path,
+		key,
+		encrypt_keys,
+		new_key,
+		new_encrypt_keys,
+	}: RekeyCommandArguments,
+) -> Result<(), Error> {
+	// Initialize opentelemetry and logging
+	crate::telemetry::builder().with_log_level("info").init();
+	// Clean the path
+	let endpoint = path.into_endpoint()?;
+	let path = if endpoint.path.is_empty() {
+		endpoint.url.to_string()
+	} else {
+		endpoint.path
+	};
+	// Re-encrypt the datastore
+	Datastore::rekey(&path, key.as_deref(), encrypt_keys, new_key.as_deref(), new_encrypt_keys)
+		.await?;
+	info!("The data has been re-encrypted");
+	Ok(())
+}
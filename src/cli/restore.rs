@@ -0,0 +1,199 @@
+use super::backup::{
+	list_segments, read_base, read_segment, taken_at, Segment, BASE_FILE, SNAPSHOT_EXTENSION,
+};
+use crate::changes::{replay, ChangeSet};
+use crate::cli::abstraction::{
+	connect_database, AuthArguments, DatabaseConnectionArguments, DatabaseSelectionArguments,
+};
+use crate::err::Error;
+use chrono::{DateTime, Utc};
+use clap::Args;
+use std::path::PathBuf;
+use surrealdb::opt::{capabilities::Capabilities, Config};
+use surrealdb::sql::Table;
+use tokio::fs;
+
+/// The point in time up to which a backup is restored
+#[derive(Clone, Debug)]
+enum Until {
+	/// Restores the changes up to and including this versionstamp
+	Versionstamp(u128),
+	/// Restores the segments which were taken at or before this time
+	Timestamp(DateTime<Utc>),
+}
+
+fn until_valid(v: &str) -> Result<Until, String> {
+	if let Ok(vs) = v.parse::<u128>() {
+		return Ok(Until::Versionstamp(vs));
+	}
+	match DateTime::parse_from_rfc3339(v) {
+		Ok(ts) => Ok(Until::Timestamp(ts.with_timezone(&Utc))),
+		Err(_) => Err("Expected a versionstamp or an RFC 3339 timestamp".to_string()),
+	}
+}
+
+#[derive(Args, Debug)]
+pub struct RestoreCommandArguments {
+	#[arg(help = "Path to the directory containing the backup")]
+	#[arg(index = 1)]
+	dir: String,
+	#[arg(help = "The versionstamp or RFC 3339 timestamp up to which the backup is restored. \
+		Timestamps restore the segments which were backed up at or before them. \
+		Restores all the segments by default.")]
+	#[arg(long, value_parser = until_valid)]
+	until: Option<Until>,
+
+	#[command(flatten)]
+	conn: DatabaseConnectionArguments,
+	#[command(flatten)]
+	auth: AuthArguments,
+	#[command(flatten)]
+	sel: DatabaseSelectionArguments,
+}
+
+pub async fn init(
+	RestoreCommandArguments {
+		dir,
+		until,
+		conn: DatabaseConnectionArguments {
+			endpoint,
+		},
+		auth,
+		sel,
+	}: RestoreCommandArguments,
+) -> Result<(), Error> {
+	// Initialize opentelemetry and logging
+	crate::telemetry::builder().with_log_level("info").init();
+	// Default datastore configuration for local engines
+	let config = Config::new().capabilities(Capabilities::all());
+
+	// Read and validate the whole backup before restoring anything
+	let dir = PathBuf::from(dir);
+	let Some(base) = read_base(&dir).await? else {
+		return Err(Error::Other(format!("There is no backup in {}", dir.display())));
+	};
+	match &until {
+		Some(Until::Versionstamp(vs)) if *vs < base.versionstamp => {
+			return Err(Error::Other(format!(
+				"The base snapshot is more recent than versionstamp {vs}"
+			)));
+		}
+		Some(Until::Timestamp(ts)) if taken_at(&base.taken_at)? > *ts => {
+			return Err(Error::Other(format!("The base snapshot is more recent than {ts}")));
+		}
+		_ => {}
+	}
+	let mut segments: Vec<(PathBuf, Segment, Vec<ChangeSet>)> = Vec::new();
+	let mut last = base.versionstamp;
+	for path in list_segments(&dir).await? {
+		let (segment, changes) = read_segment(&path).await?;
+		// Check that the segment continues from the previous one
+		if segment.since != last {
+			return Err(Error::Other(format!(
+				"The backup is not continuous, {} starts after versionstamp {} instead of {last}",
+				path.display(),
+				segment.since
+			)));
+		}
+		// Check that the changes are ordered and within the segment
+		for change in changes.iter() {
+			if change.versionstamp <= last || change.versionstamp > segment.until {
+				return Err(Error::Other(format!(
+					"The segment {} contains an unexpected versionstamp {}",
+					path.display(),
+					change.versionstamp
+				)));
+			}
+			last = change.versionstamp;
+		}
+		// Snapshots contain the changes up to the end of the segment
+		if segment.snapshot && segment.until >= last {
+			last = segment.until;
+		}
+		if last != segment.until {
+			return Err(Error::Other(format!(
+				"The segment {} is incomplete, it ends at versionstamp {last} instead of {}",
+				path.display(),
+				segment.until
+			)));
+		}
+		// Check that the exported records are there
+		if (segment.snapshot || !segment.tables.is_empty())
+			&& !fs::try_exists(path.with_extension(SNAPSHOT_EXTENSION)).await?
+		{
+			return Err(Error::Other(format!(
+				"The records of the segment {} are missing",
+				path.display()
+			)));
+		}
+		// Select the segments to restore
+		let included = match &until {
+			Some(Until::Versionstamp(vs)) => segment.since < *vs,
+			Some(Until::Timestamp(ts)) => taken_at(&segment.taken_at)? <= *ts,
+			None => true,
+		};
+		if included {
+			segments.push((path, segment, changes));
+		}
+	}
+	// A segment is restored in full unless the restore stops within its changes
+	let complete = |segment: &Segment| match &until {
+		Some(Until::Versionstamp(vs)) => segment.until <= *vs,
+		_ => true,
+	};
+	// A snapshot segment has no changes, so the restore can't stop within it
+	let partial = segments.iter().find(|(_, s, _)| s.snapshot && !complete(s));
+	if let Some((path, segment, _)) = partial {
+		return Err(Error::Other(format!(
+			"The segment {} is a snapshot, taken as the schema changed, \
+			so it can only be restored up to versionstamp {} or {}",
+			path.display(),
+			segment.since,
+			segment.until
+		)));
+	}
+	// Restore from the latest snapshot, rather than from the base snapshot
+	let start = segments.iter().rposition(|(_, s, _)| s.snapshot && complete(s));
+
+	// Connect to the database engine
+	let client = connect_database(endpoint, config, auth, sel).await?;
+	// Import the base snapshot, or the latest snapshot segment
+	match start {
+		Some(i) => {
+			debug!("Importing the snapshot of {}", segments[i].0.display());
+			client.import(segments[i].0.with_extension(SNAPSHOT_EXTENSION)).await?;
+		}
+		None => {
+			debug!("Importing the base snapshot");
+			client.import(dir.join(BASE_FILE)).await?;
+		}
+	}
+	let mut count = 0;
+	for (path, segment, changes) in segments.into_iter().skip(start.map_or(0, |i| i + 1)) {
+		// Replay the changes, one transaction per versionstamp
+		for change in changes {
+			if let Some(Until::Versionstamp(vs)) = &until {
+				if change.versionstamp > *vs {
+					break;
+				}
+			}
+			debug!("Replaying the changes at versionstamp {}", change.versionstamp);
+			let sql = replay(&change)?;
+			client.query(sql).await?.check()?;
+			count += 1;
+		}
+		// Replace the records of the tables without a change feed
+		if !segment.tables.is_empty() && complete(&segment) {
+			debug!("Importing the tables without a change feed from {}", path.display());
+			let mut sql = String::from("OPTION IMPORT;\n");
+			for tb in segment.tables.iter() {
+				sql.push_str(&format!("DELETE {};\n", Table::from(tb.as_str())));
+			}
+			client.query(sql).await?.check()?;
+			client.import(path.with_extension(SNAPSHOT_EXTENSION)).await?;
+		}
+	}
+	info!("The backup was restored successfully, with {count} change sets");
+	// Everything OK
+	Ok(())
+}
@@ -7,7 +7,7 @@ use crate::dbs;
 use crate::dbs::StartCommandDbsOptions;
 use crate::env;
 use crate::err::Error;
-use crate::net::{self, client_ip::ClientIp};
+use crate::net::{self, client_ip::ClientIp, limit::RateLimit, sync::Replica};
 use clap::Args;
 use std::net::SocketAddr;
 use std::path::PathBuf;
@@ -34,11 +34,18 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_NO_BANNER", long)]
 	#[arg(default_value_t = false)]
 	no_banner: bool,
-	#[arg(help = "Encryption key to use for on-disk encryption")]
+	#[arg(
+		help = "Encryption key to use for on-disk encryption of values, and of keys with --encrypt-keys"
+	)]
 	#[arg(env = "SURREAL_KEY", short = 'k', long = "key")]
 	#[arg(value_parser = super::validator::key_valid)]
-	#[arg(hide = true)] // Not currently in use
 	key: Option<String>,
+	#[arg(
+		help = "Whether to also encrypt keys on disk. Encrypted keys are not stored in order, so every range scan reads all the keys with the prefix of the range, which makes scans of large tables much slower"
+	)]
+	#[arg(env = "SURREAL_ENCRYPT_KEYS", long = "encrypt-keys", requires = "key")]
+	#[arg(default_value_t = false)]
+	encrypt_keys: bool,
 
 	#[arg(
 		help = "The interval at which to run node agent tick (including garbage collection)",
@@ -101,6 +108,67 @@ pub struct StartCommandArguments {
 	#[arg(env = "SURREAL_NO_IDENTIFICATION_HEADERS", long)]
 	#[arg(default_value_t = false)]
 	no_identification_headers: bool,
+	#[arg(help = "Whether to serve the metrics in the Prometheus format to root users")]
+	#[arg(env = "SURREAL_PROMETHEUS_METRICS", long)]
+	#[arg(default_value_t = false)]
+	prometheus_metrics: bool,
+	#[arg(help = "The maximum rate of requests from each client IP address, such as 100/1s")]
+	#[arg(env = "SURREAL_RATE_LIMIT", long, value_parser = super::validator::rate_limit)]
+	rate_limit: Option<RateLimit>,
+	#[arg(help = "The maximum rate of requests from each authenticated user, such as 100/1s")]
+	#[arg(env = "SURREAL_USER_RATE_LIMIT", long, value_parser = super::validator::rate_limit)]
+	user_rate_limit: Option<RateLimit>,
+	#[arg(
+		help = "The maximum rate of signin and signup attempts from each client IP address, such as 10/1m"
+	)]
+	#[arg(env = "SURREAL_AUTH_RATE_LIMIT", long, value_parser = super::validator::rate_limit)]
+	auth_rate_limit: Option<RateLimit>,
+	#[arg(
+		help = "The maximum rate of signin and signup attempts for each access method or system user, from all client IP addresses, such as 1000/1m. This is shared by every user of a record access method, so it should be much higher than the limit of each client IP address"
+	)]
+	#[arg(
+		env = "SURREAL_AUTH_ACCESS_RATE_LIMIT",
+		long,
+		value_parser = super::validator::rate_limit
+	)]
+	auth_access_rate_limit: Option<RateLimit>,
+
+	//
+	// Replication
+	//
+	#[arg(
+		help = "The address of a primary server to follow as a read only replica. The replicated database needs a change feed on the primary server",
+		help_heading = "Replication"
+	)]
+	#[arg(env = "SURREAL_REPLICA_OF", long = "replica-of")]
+	#[arg(requires = "replica_ns", requires = "replica_db")]
+	replica_of: Option<String>,
+	#[arg(help = "The namespace of the replicated database", help_heading = "Replication")]
+	#[arg(env = "SURREAL_REPLICA_NS", long = "replica-ns", requires = "replica_of")]
+	replica_ns: Option<String>,
+	#[arg(help = "The name of the replicated database", help_heading = "Replication")]
+	#[arg(env = "SURREAL_REPLICA_DB", long = "replica-db", requires = "replica_of")]
+	replica_db: Option<String>,
+	#[arg(
+		help = "The username of a root user of the primary server",
+		help_heading = "Replication"
+	)]
+	#[arg(env = "SURREAL_REPLICA_USER", long = "replica-user", requires = "replica_pass")]
+	replica_user: Option<String>,
+	#[arg(
+		help = "The password of a root user of the primary server",
+		help_heading = "Replication"
+	)]
+	#[arg(env = "SURREAL_REPLICA_PASS", long = "replica-pass", requires = "replica_user")]
+	replica_pass: Option<String>,
+	#[arg(
+		help = "The interval at which the changes of the primary server are fetched",
+		help_heading = "Replication"
+	)]
+	#[arg(env = "SURREAL_REPLICA_INTERVAL", long = "replica-interval", value_parser = super::validator::duration)]
+	#[arg(default_value = "1s")]
+	replica_interval: Duration,
+
 	//
 	// Database options
 	//
@@ -144,16 +212,29 @@ pub async fn init(
 		dbs,
 		web,
 		log,
+		key: encryption_key,
+		encrypt_keys,
 		tick_interval,
 		no_banner,
 		no_identification_headers,
+		prometheus_metrics,
+		rate_limit,
+		user_rate_limit,
+		auth_rate_limit,
+		auth_access_rate_limit,
+		replica_of,
+		replica_ns,
+		replica_db,
+		replica_user,
+		replica_pass,
+		replica_interval,
 		..
 	}: StartCommandArguments,
 ) -> Result<(), Error> {
 	// Initialize opentelemetry and logging
 	crate::telemetry::builder().with_filter(log).init();
 	// Start metrics subsystem
-	crate::telemetry::metrics::init().expect("failed to initialize metrics");
+	crate::telemetry::metrics::init(prometheus_metrics).expect("failed to initialize metrics");
 
 	// Check if we should output a banner
 	if !no_banner {
@@ -172,6 +253,18 @@ pub async fn init(
 	} else {
 		(None, None)
 	};
+	// Setup the primary server of a replica
+	let replica = match (replica_of, replica_ns, replica_db) {
+		(Some(primary), Some(ns), Some(db)) => Some(Replica {
+			primary,
+			ns,
+			db,
+			user: replica_user,
+			pass: replica_pass,
+			interval: replica_interval,
+		}),
+		_ => None,
+	};
 	// Setup the command-line options
 	let _ = config::CF.set(Config {
 		bind: listen_addresses.first().cloned().unwrap(),
@@ -180,9 +273,17 @@ pub async fn init(
 		user,
 		pass,
 		no_identification_headers,
+		prometheus_metrics,
+		rate_limit,
+		user_rate_limit,
+		auth_rate_limit,
+		auth_access_rate_limit,
+		replica,
 		engine: Some(EngineOptions::default().with_tick_interval(tick_interval)),
 		crt,
 		key,
+		encryption_key,
+		encrypt_keys,
 	});
 	// This is the cancellation token propagated down to
 	// all the async functions that needs to be stopped gracefully.
@@ -5,6 +5,7 @@ use std::{
 	time::Duration,
 };
 
+use crate::net::limit::RateLimit;
 use surrealdb::dbs::capabilities::{FuncTarget, NetTarget, Targets};
 
 pub(crate) mod parser;
@@ -77,6 +78,10 @@ pub(crate) fn duration(v: &str) -> Result<Duration, String> {
 	surrealdb::sql::Duration::from_str(v).map(|d| d.0).map_err(|_| String::from("invalid duration"))
 }
 
+pub(crate) fn rate_limit(v: &str) -> Result<RateLimit, String> {
+	RateLimit::from_str(v)
+}
+
 pub(crate) fn net_targets(value: &str) -> Result<Targets<NetTarget>, String> {
 	if ["*", ""].contains(&value) {
 		return Ok(Targets::All);
@@ -149,4 +154,27 @@ mod tests {
 		assert!(net_targets("127777.0.0.1").is_err());
 		assert!(net_targets("127.0.0.1,127777.0.0.1").is_err());
 	}
+
+	#[test]
+	fn test_rate_limit() {
+		assert_eq!(
+			rate_limit("100/1m").unwrap(),
+			RateLimit {
+				requests: 100,
+				period: Duration::from_secs(60)
+			}
+		);
+		assert_eq!(
+			rate_limit("5 / 30s").unwrap(),
+			RateLimit {
+				requests: 5,
+				period: Duration::from_secs(30)
+			}
+		);
+
+		assert!(rate_limit("100").is_err());
+		assert!(rate_limit("0/1s").is_err());
+		assert!(rate_limit("10/0s").is_err());
+		assert!(rate_limit("ten/1s").is_err());
+	}
 }
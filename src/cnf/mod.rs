@@ -43,6 +43,10 @@ pub static WEBSOCKET_MAX_MESSAGE_SIZE: Lazy<usize> =
 pub static WEBSOCKET_MAX_CONCURRENT_REQUESTS: Lazy<usize> =
 	lazy_env_parse!("SURREAL_WEBSOCKET_MAX_CONCURRENT_REQUESTS", usize, 24);
 
+/// How long an interactive transaction can stay idle, in seconds (defaults to 30 seconds)
+pub static WEBSOCKET_TRANSACTION_TIMEOUT: Lazy<u64> =
+	lazy_env_parse!("SURREAL_WEBSOCKET_TRANSACTION_TIMEOUT", u64, 30);
+
 /// What is the runtime thread memory stack size (defaults to 10MiB)
 pub static RUNTIME_STACK_SIZE: Lazy<usize> =
 	lazy_env_parse_or_else!("SURREAL_RUNTIME_STACK_SIZE", usize, |_| {
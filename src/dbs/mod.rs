@@ -227,16 +227,22 @@ pub async fn init(
 	if unauthenticated {
 		warn!("❌🔒 IMPORTANT: Authentication is disabled. This is not recommended for production use. 🔒❌");
 	}
+	// Log whether this is a replica of another server
+	if let Some(replica) = &opt.replica {
+		info!("Starting as a read only replica of {}", replica.primary);
+	}
 	// Log the specified server capabilities
 	debug!("Server capabilities: {capabilities}");
 	// Parse and setup the desired kv datastore
-	let dbs = Datastore::new(&opt.path)
+	let key = opt.encryption_key.as_deref();
+	let dbs = Datastore::new_with_encryption(&opt.path, key, opt.encrypt_keys)
 		.await?
 		.with_notifications()
 		.with_strict_mode(strict_mode)
 		.with_query_timeout(query_timeout)
 		.with_transaction_timeout(transaction_timeout)
 		.with_auth_enabled(!unauthenticated)
+		.with_read_only(opt.replica.is_some())
 		.with_temporary_directory(temporary_directory)
 		.with_capabilities(capabilities);
 	// Ensure the storage version is up-to-date to prevent corruption
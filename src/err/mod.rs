@@ -4,6 +4,7 @@ use axum::Error as AxumError;
 use axum::Json;
 use axum_extra::typed_header::TypedHeaderRejection;
 use base64::DecodeError as Base64Error;
+use http::header::RETRY_AFTER;
 use http::{HeaderName, StatusCode};
 use reqwest::Error as ReqwestError;
 use serde::Serialize;
@@ -67,6 +68,9 @@ pub enum Error {
 	#[error("There was an error with the node agent")]
 	NodeAgent,
 
+	#[error("Too many requests, try again in {0} seconds")]
+	TooManyRequests(u64),
+
 	/// Statement has been deprecated
 	#[error("{0}")]
 	Other(String),
@@ -167,6 +171,23 @@ pub(super) struct Message {
 
 impl IntoResponse for Error {
 	fn into_response(self) -> Response {
+		// Tell rate limited clients when they can retry
+		if let Error::TooManyRequests(secs) = self {
+			return (
+				StatusCode::TOO_MANY_REQUESTS,
+				[(RETRY_AFTER, secs.to_string())],
+				Json(Message {
+					code: StatusCode::TOO_MANY_REQUESTS.as_u16(),
+					details: Some("Too many requests".to_string()),
+					description: Some(
+						"The rate limit of the server was exceeded. Retry the request later."
+							.to_string(),
+					),
+					information: Some(self.to_string()),
+				}),
+			)
+				.into_response();
+		}
 		match self {
 			err @ Error::InvalidAuth | err @ Error::Db(SurrealError::Db(SurrealDbError::InvalidAuth)) => (
 				StatusCode::UNAUTHORIZED,
@@ -186,6 +207,15 @@ impl IntoResponse for Error {
 					information: Some(err.to_string()),
 				})
 			),
+			err @ Error::Db(SurrealError::Db(SurrealDbError::ChangefeedGap(_))) => (
+				StatusCode::GONE,
+				Json(Message {
+					code: StatusCode::GONE.as_u16(),
+					details: Some("Changes unavailable".to_string()),
+					description: Some("The requested changes have been removed from the change feed. Take a new snapshot of the database.".to_string()),
+					information: Some(err.to_string()),
+				})
+			),
 			Error::InvalidType => (
 				StatusCode::UNSUPPORTED_MEDIA_TYPE,
 				Json(Message {
@@ -16,6 +16,7 @@ extern crate tracing;
 #[macro_use]
 mod mac;
 
+mod changes;
 mod cli;
 mod cnf;
 mod dbs;
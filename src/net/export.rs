@@ -3,26 +3,45 @@ use crate::err::Error;
 use axum::body::Body;
 use axum::response::IntoResponse;
 use axum::routing::get;
+use axum::routing::post;
 use axum::Router;
 use axum::{response::Response, Extension};
 use bytes::Bytes;
 use http::StatusCode;
 use surrealdb::dbs::Session;
 use surrealdb::iam::check::check_ns_db;
-use surrealdb::iam::Action::View;
+use surrealdb::iam::Action::Export;
 use surrealdb::iam::ResourceKind::Any;
+use surrealdb::kvs::export::Config;
 
 pub(super) fn router<S>() -> Router<S>
 where
 	S: Clone + Send + Sync + 'static,
 {
-	Router::new().route("/export", get(handler))
+	Router::new().route("/export", get(get_handler)).route("/export", post(post_handler))
 }
 
-async fn handler(
+async fn get_handler(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
 ) -> Result<impl IntoResponse, Error> {
+	handler(state, session, Config::default()).await
+}
+
+async fn post_handler(
+	Extension(state): Extension<AppState>,
+	Extension(session): Extension<Session>,
+	body: Bytes,
+) -> Result<impl IntoResponse, Error> {
+	// Parse the export configuration
+	let cfg = match body.is_empty() {
+		true => Config::default(),
+		false => serde_json::from_slice(&body).map_err(|e| Error::Json(e.to_string()))?,
+	};
+	handler(state, session, cfg).await
+}
+
+async fn handler(state: AppState, session: Session, cfg: Config) -> Result<Response, Error> {
 	// Get the datastore reference
 	let db = &state.datastore;
 	// Create a chunked response
@@ -31,11 +50,11 @@ async fn handler(
 	// Ensure a NS and DB are set
 	let (nsv, dbv) = check_ns_db(&session)?;
 	// Check the permissions level
-	db.check(&session, View, Any.on_db(&nsv, &dbv))?;
+	db.check(&session, Export, Any.on_db(&nsv, &dbv))?;
 	// Create a new bounded channel
 	let (snd, rcv) = surrealdb::channel::bounded(1);
 	// Start the export task
-	let task = db.export(&session, snd).await?;
+	let task = db.export_with_config(&session, snd, cfg).await?;
 	// Spawn a new database export job
 	tokio::spawn(task);
 	// Process all chunk values
@@ -4,14 +4,15 @@ use std::sync::Arc;
 use axum::routing::post_service;
 
 use surrealdb::gql::cache::Pessimistic;
+use surrealdb::gql::Subscriptions;
 use surrealdb::kvs::Datastore;
 
 use crate::gql::GraphQL;
 
-pub(super) async fn router<S>(ds: Arc<Datastore>) -> Router<S>
+pub(super) async fn router<S>(ds: Arc<Datastore>, subscriptions: Subscriptions) -> Router<S>
 where
 	S: Clone + Send + Sync + 'static,
 {
-	let service = GraphQL::new(Pessimistic, ds);
-	Router::new().route("/graphql", post_service(service))
+	let service = GraphQL::new(Pessimistic, ds, subscriptions);
+	Router::new().route("/graphql", post_service(service.clone()).get_service(service))
 }
@@ -1,7 +1,6 @@
 use super::headers::Accept;
 use super::AppState;
 use crate::err::Error;
-use crate::net::input::bytes_to_utf8;
 use crate::net::output;
 use axum::extract::DefaultBodyLimit;
 use axum::response::IntoResponse;
@@ -11,7 +10,7 @@ use axum::Router;
 use axum_extra::TypedHeader;
 use bytes::Bytes;
 use surrealdb::dbs::Session;
-use surrealdb::iam::Action::Edit;
+use surrealdb::iam::Action::Import;
 use surrealdb::iam::ResourceKind::Any;
 use tower_http::limit::RequestBodyLimitLayer;
 
@@ -31,16 +30,14 @@ async fn handler(
 	Extension(state): Extension<AppState>,
 	Extension(session): Extension<Session>,
 	accept: Option<TypedHeader<Accept>>,
-	sql: Bytes,
+	body: Bytes,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
 	// Get the datastore reference
 	let db = &state.datastore;
-	// Convert the body to a byte slice
-	let sql = bytes_to_utf8(&sql)?;
 	// Check the permissions level
-	db.check(&session, Edit, Any.on_level(session.au.level().to_owned()))?;
-	// Execute the sql query in the database
-	match db.import(sql, &session).await {
+	db.check(&session, Import, Any.on_level(session.au.level().to_owned()))?;
+	// Import the SurrealQL, NDJSON or CBOR export into the database
+	match db.import_bytes(&body, &session).await {
 		Ok(res) => match accept.as_deref() {
 			// Simple serialization
 			Some(Accept::ApplicationJson) => Ok(output::json(&output::simplify(res))),
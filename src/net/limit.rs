@@ -0,0 +1,369 @@
+use super::client_ip::ExtractClientIP;
+use super::AppState;
+use crate::err::Error;
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use surrealdb::dbs::Session;
+use surrealdb::sql::{Object, Value};
+
+/// The maximum number of keys which a limiter keeps track of
+const MAX_KEYS: usize = 50_000;
+
+/// A number of requests which are allowed in a period of time
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+	/// The number of requests which can be made at once
+	pub requests: u32,
+	/// The period in which the requests are replenished
+	pub period: Duration,
+}
+
+impl FromStr for RateLimit {
+	type Err = String;
+	/// Parses a rate limit in the form `<requests>/<duration>`, such as `100/1m`
+	fn from_str(v: &str) -> Result<Self, Self::Err> {
+		let invalid = || format!("Invalid rate limit {v}, expected <requests>/<duration>");
+		let (requests, period) = v.split_once('/').ok_or_else(invalid)?;
+		let requests = requests.trim().parse::<u32>().map_err(|_| invalid())?;
+		let period = surrealdb::sql::Duration::from_str(period.trim()).map_err(|_| invalid())?.0;
+		if requests == 0 || period.is_zero() {
+			return Err(invalid());
+		}
+		Ok(RateLimit {
+			requests,
+			period,
+		})
+	}
+}
+
+impl fmt::Display for RateLimit {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}/{}", self.requests, surrealdb::sql::Duration::from(self.period))
+	}
+}
+
+/// The remaining requests of a key
+struct Bucket {
+	tokens: f64,
+	updated: Instant,
+}
+
+/// The token buckets of a limiter, ordered by when they were last used
+#[derive(Default)]
+struct Buckets {
+	buckets: HashMap<String, Bucket>,
+	used: BTreeSet<(Instant, String)>,
+}
+
+impl Buckets {
+	fn len(&self) -> usize {
+		self.buckets.len()
+	}
+
+	fn contains_key(&self, key: &str) -> bool {
+		self.buckets.contains_key(key)
+	}
+
+	/// The time at which the least recently used bucket was last used
+	fn oldest(&self) -> Option<Instant> {
+		self.used.first().map(|(used, _)| *used)
+	}
+
+	/// Removes the least recently used bucket
+	fn pop_oldest(&mut self) {
+		if let Some((_, key)) = self.used.pop_first() {
+			self.buckets.remove(&key);
+		}
+	}
+}
+
+/// Limits the rate of the requests of each key, with a token bucket per key
+struct Limiter {
+	limit: RateLimit,
+	max_keys: usize,
+	buckets: Mutex<Buckets>,
+}
+
+impl Limiter {
+	fn new(limit: RateLimit) -> Self {
+		Self {
+			limit,
+			max_keys: MAX_KEYS,
+			buckets: Mutex::new(Buckets::default()),
+		}
+	}
+
+	/// The number of requests which are replenished every second
+	fn rate(&self) -> f64 {
+		self.limit.requests as f64 / self.limit.period.as_secs_f64()
+	}
+
+	/// Takes a request from the bucket of a key, or returns the seconds to wait for one
+	fn check(&self, key: &str) -> Result<(), Error> {
+		let now = Instant::now();
+		let capacity = self.limit.requests as f64;
+		let rate = self.rate();
+		let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+		// Remove the buckets which have been idle for a whole period, as they are full again
+		if let Some(idle) = now.checked_sub(self.limit.period) {
+			while buckets.oldest().is_some_and(|used| used < idle) {
+				buckets.pop_oldest();
+			}
+		}
+		// Evict the least recently used bucket to make room for a new key
+		if buckets.len() >= self.max_keys && !buckets.contains_key(key) {
+			buckets.pop_oldest();
+		}
+		let Buckets {
+			buckets,
+			used,
+		} = &mut *buckets;
+		let bucket = buckets.entry(key.to_owned()).or_insert(Bucket {
+			tokens: capacity,
+			updated: now,
+		});
+		// Replenish the bucket since the last request
+		used.remove(&(bucket.updated, key.to_owned()));
+		let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+		bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+		bucket.updated = now;
+		used.insert((now, key.to_owned()));
+		if bucket.tokens >= 1.0 {
+			bucket.tokens -= 1.0;
+			Ok(())
+		} else {
+			let wait = (1.0 - bucket.tokens) / rate;
+			Err(Error::TooManyRequests(wait.ceil() as u64))
+		}
+	}
+}
+
+/// The rate limits of the server
+#[derive(Default)]
+pub struct RateLimits {
+	/// Limits the requests of each client IP address
+	ip: Option<Limiter>,
+	/// Limits the requests of each authenticated user
+	user: Option<Limiter>,
+	/// Limits the signin and signup attempts of each client IP address
+	auth_ip: Option<Limiter>,
+	/// Limits the signin and signup attempts for each identity of each access method
+	auth_access: Option<Limiter>,
+}
+
+impl RateLimits {
+	pub fn new(
+		ip: Option<RateLimit>,
+		user: Option<RateLimit>,
+		auth_ip: Option<RateLimit>,
+		auth_access: Option<RateLimit>,
+	) -> Self {
+		Self {
+			ip: ip.map(Limiter::new),
+			user: user.map(Limiter::new),
+			auth_ip: auth_ip.map(Limiter::new),
+			auth_access: auth_access.map(Limiter::new),
+		}
+	}
+
+	/// Checks the limit of the client IP address of a request
+	pub fn check_ip(&self, ip: Option<&str>) -> Result<(), Error> {
+		match (&self.ip, ip) {
+			(Some(limiter), Some(ip)) => limiter.check(ip),
+			_ => Ok(()),
+		}
+	}
+
+	/// Checks the limit of the authenticated user of a request
+	pub fn check_user(&self, sess: &Session) -> Result<(), Error> {
+		match &self.user {
+			Some(limiter) if !sess.au.is_anon() => {
+				limiter.check(&format!("{}{}", sess.au.level(), sess.au.id()))
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Checks the limits of a request, for each client IP address and each authenticated user
+	pub fn check(&self, sess: &Session) -> Result<(), Error> {
+		self.check_ip(sess.ip.as_deref())?;
+		self.check_user(sess)
+	}
+
+	/// Checks the limits of a signin, signup or refresh attempt with the specified variables
+	pub fn check_auth(&self, sess: &Session, vars: &Object) -> Result<(), Error> {
+		if let (Some(limiter), Some(ip)) = (&self.auth_ip, sess.ip.as_deref()) {
+			limiter.check(ip)?;
+		}
+		if let Some(limiter) = &self.auth_access {
+			limiter.check(&access_method(vars))?;
+		}
+		Ok(())
+	}
+}
+
+/// Describes the access method, or the system user, which a signin or signup attempt targets.
+/// Only the system user and the identifier of a bearer key or refresh token are part of the
+/// identity, as any other variable can be chosen freely by the client to get a new limit.
+fn access_method(vars: &Object) -> String {
+	let get = |upper: &str, lower: &str| {
+		vars.get(upper).or_else(|| vars.get(lower)).map(Value::to_raw_string)
+	};
+	let mut key = String::from("/");
+	if let Some(ns) = get("NS", "ns") {
+		key.push_str(&format!("ns:{ns}/"));
+	}
+	if let Some(db) = get("DB", "db") {
+		key.push_str(&format!("db:{db}/"));
+	}
+	if let Some(ac) = get("AC", "ac") {
+		key.push_str(&format!("ac:{ac}/"));
+	}
+	// System users are signed in with a username
+	if let Some(user) = vars.get("user").map(Value::to_raw_string) {
+		key.push_str(&format!("user:{user}/"));
+	}
+	// Keep the prefix and key identifier, without the secret
+	for name in ["key", "refresh"] {
+		if let Some(value) = vars.get(name).map(Value::to_raw_string) {
+			let id = value.rsplit_once('-').map(|(id, _)| id).unwrap_or_default();
+			key.push_str(&format!("{name}:{id}/"));
+		}
+	}
+	key
+}
+
+/// Limits the requests of each client IP address, before they are authenticated
+pub(super) async fn limit_ip(
+	Extension(state): Extension<AppState>,
+	ip: Option<Extension<ExtractClientIP>>,
+	request: Request,
+	next: Next,
+) -> Response {
+	let ip = ip.and_then(|Extension(ExtractClientIP(ip))| ip);
+	match state.limits.check_ip(ip.as_deref()) {
+		Ok(_) => next.run(request).await,
+		Err(err) => err.into_response(),
+	}
+}
+
+/// Limits the requests of each authenticated user
+pub(super) async fn limit_user(
+	Extension(state): Extension<AppState>,
+	session: Option<Extension<Session>>,
+	request: Request,
+	next: Next,
+) -> Response {
+	if let Some(Extension(sess)) = session {
+		if let Err(err) = state.limits.check_user(&sess) {
+			return err.into_response();
+		}
+	}
+	next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::BTreeMap;
+
+	fn vars(vars: &[(&str, &str)]) -> Object {
+		vars.iter()
+			.map(|(k, v)| (k.to_string(), Value::from(*v)))
+			.collect::<BTreeMap<_, _>>()
+			.into()
+	}
+
+	#[test]
+	fn access_method_identity() {
+		// The variables chosen by the client do not get a new limit
+		let a = access_method(&vars(&[("ns", "a"), ("db", "b"), ("ac", "c"), ("email", "x")]));
+		let b = access_method(&vars(&[("ns", "a"), ("db", "b"), ("ac", "c"), ("email", "y")]));
+		assert_eq!(a, b);
+		let b = access_method(&vars(&[("ns", "a"), ("db", "b"), ("ac", "c"), ("other", "1")]));
+		assert_eq!(a, b);
+		// The password is not part of the identity
+		let c = access_method(&vars(&[
+			("NS", "a"),
+			("DB", "b"),
+			("AC", "c"),
+			("email", "x"),
+			("pass", "1"),
+		]));
+		let d = access_method(&vars(&[
+			("NS", "a"),
+			("DB", "b"),
+			("AC", "c"),
+			("email", "x"),
+			("pass", "2"),
+		]));
+		assert_eq!(a, c);
+		assert_eq!(c, d);
+		// Only the identifier of a key is part of the identity
+		let e =
+			access_method(&vars(&[("ns", "a"), ("ac", "c"), ("key", "surreal-bearer-id-secret1")]));
+		let f =
+			access_method(&vars(&[("ns", "a"), ("ac", "c"), ("key", "surreal-bearer-id-secret2")]));
+		assert_eq!(e, f);
+		// System users are limited for each user
+		let g = access_method(&vars(&[("user", "root"), ("pass", "root")]));
+		let h = access_method(&vars(&[("user", "admin"), ("pass", "root")]));
+		assert_ne!(g, h);
+	}
+
+	#[test]
+	fn auth_limits_each_ip() {
+		let limits = RateLimits::new(
+			None,
+			None,
+			Some(RateLimit {
+				requests: 2,
+				period: Duration::from_secs(3600),
+			}),
+			Some(RateLimit {
+				requests: 100,
+				period: Duration::from_secs(3600),
+			}),
+		);
+		let attempt = vars(&[("ns", "a"), ("db", "b"), ("ac", "c"), ("email", "x")]);
+		let session = |ip: &str| {
+			let mut sess = Session::default();
+			sess.ip = Some(ip.to_owned());
+			sess
+		};
+		// One client uses up its own limit
+		let attacker = session("10.0.0.1");
+		limits.check_auth(&attacker, &attempt).unwrap();
+		limits.check_auth(&attacker, &attempt).unwrap();
+		assert!(limits.check_auth(&attacker, &attempt).is_err());
+		// Signins to the same access method from other clients are not blocked
+		for i in 2..50 {
+			limits.check_auth(&session(&format!("10.0.0.{i}")), &attempt).unwrap();
+		}
+	}
+
+	#[test]
+	fn limiter_evicts_keys() {
+		let mut limiter = Limiter::new(RateLimit {
+			requests: 1,
+			period: Duration::from_secs(3600),
+		});
+		limiter.max_keys = 100;
+		limiter.check("first").unwrap();
+		assert!(limiter.check("first").is_err());
+		for i in 0..limiter.max_keys {
+			let _ = limiter.check(&i.to_string());
+		}
+		// The number of keys is bounded, evicting the least recently used
+		let buckets = limiter.buckets.lock().unwrap();
+		assert_eq!(buckets.len(), limiter.max_keys);
+		assert!(!buckets.contains_key("first"));
+	}
+}
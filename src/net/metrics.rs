@@ -0,0 +1,35 @@
+use super::AppState;
+use crate::err::Error;
+use crate::telemetry::metrics;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Extension;
+use axum::Router;
+use http::header::CONTENT_TYPE;
+use surrealdb::dbs::Session;
+use surrealdb::iam::Action::View;
+use surrealdb::iam::ResourceKind::Any;
+
+pub(super) fn router<S>() -> Router<S>
+where
+	S: Clone + Send + Sync + 'static,
+{
+	Router::new().route("/metrics", get(handler))
+}
+
+async fn handler(
+	Extension(state): Extension<AppState>,
+	Extension(session): Extension<Session>,
+) -> Result<impl IntoResponse, Error> {
+	// Get the datastore reference
+	let db = &state.datastore;
+	// The metrics describe every namespace and database
+	db.check(&session, View, Any.on_root())?;
+	// Read the metrics which are not counted as they change
+	metrics::ds::observe(db).await?;
+	// Encode the metrics for Prometheus
+	match metrics::encode() {
+		Ok(body) => Ok(([(CONTENT_TYPE, prometheus::TEXT_FORMAT)], body)),
+		Err(err) => Err(Error::Other(err.to_string())),
+	}
+}
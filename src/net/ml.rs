@@ -105,7 +105,7 @@ async fn export(
 	// Attempt to get the model definition
 	let info = tx.get_db_model(&nsv, &dbv, &name, &version).await?;
 	// Calculate the path of the model file
-	let path = format!("ml/{nsv}/{dbv}/{name}-{version}-{}.surml", info.hash);
+	let path = info.path(&nsv, &dbv);
 	// Export the file data in to the store
 	let mut data = surrealdb::obs::stream(path).await?;
 	// Create a chunked response
@@ -8,14 +8,17 @@ mod health;
 mod import;
 mod input;
 mod key;
+pub mod limit;
+mod metrics;
 pub(crate) mod output;
 mod params;
+mod refresh;
 mod rpc;
 mod signals;
 mod signin;
 mod signup;
 mod sql;
-mod sync;
+pub mod sync;
 mod tracer;
 mod version;
 
@@ -64,6 +67,7 @@ const LOG: &str = "surrealdb::net";
 pub struct AppState {
 	pub client_ip: client_ip::ClientIp,
 	pub datastore: Arc<Datastore>,
+	pub limits: Arc<limit::RateLimits>,
 }
 
 pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error> {
@@ -73,6 +77,12 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 	let app_state = AppState {
 		client_ip: opt.client_ip,
 		datastore: ds.clone(),
+		limits: Arc::new(limit::RateLimits::new(
+			opt.rate_limit,
+			opt.user_rate_limit,
+			opt.auth_rate_limit,
+			opt.auth_access_rate_limit,
+		)),
 	};
 
 	// Specify headers to be obfuscated from all requests/responses
@@ -131,6 +141,7 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 	let service = service
 		.layer(AddExtensionLayer::new(app_state))
 		.layer(middleware::from_fn(client_ip::client_ip_middleware))
+		.layer(middleware::from_fn(limit::limit_ip))
 		.layer(SetSensitiveRequestHeadersLayer::from_shared(Arc::clone(&headers)))
 		.layer(
 			TraceLayer::new_for_http()
@@ -142,6 +153,7 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 		.layer(HttpMetricsLayer)
 		.layer(SetSensitiveResponseHeadersLayer::from_shared(headers))
 		.layer(AsyncRequireAuthorizationLayer::new(auth::SurrealAuth))
+		.layer(middleware::from_fn(limit::limit_user))
 		.layer(headers::add_server_header(!opt.no_identification_headers))
 		.layer(headers::add_version_header(!opt.no_identification_headers))
 		.layer(
@@ -173,13 +185,16 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 		.merge(sql::router())
 		.merge(signin::router())
 		.merge(signup::router())
+		.merge(refresh::router())
 		.merge(key::router());
 
+	let rpc_state = Arc::new(RpcState::new());
+
 	let axum_app = if *GRAPHQL_ENABLE {
 		#[cfg(surrealdb_unstable)]
 		{
 			warn!("❌🔒IMPORTANT: GraphQL is a pre-release feature with known security flaws. This is not recommended for production use.🔒❌");
-			axum_app.merge(gql::router(ds.clone()).await)
+			axum_app.merge(gql::router(ds.clone(), rpc_state.subscriptions.clone()).await)
 		}
 		#[cfg(not(surrealdb_unstable))]
 		{
@@ -193,18 +208,28 @@ pub async fn init(ds: Arc<Datastore>, ct: CancellationToken) -> Result<(), Error
 	#[cfg(feature = "ml")]
 	let axum_app = axum_app.merge(ml::router());
 
+	let axum_app = if opt.prometheus_metrics {
+		axum_app.merge(metrics::router())
+	} else {
+		axum_app
+	};
+
 	let axum_app = axum_app.layer(service);
 
 	// Get a new server handler
 	let handle = Handle::new();
 
-	let rpc_state = Arc::new(RpcState::new());
-
 	// Setup the graceful shutdown handler
 	let shutdown_handler = graceful_shutdown(rpc_state.clone(), ct.clone(), handle.clone());
 
 	let axum_app = axum_app.with_state(rpc_state.clone());
 
+	// Spawn a task to follow the primary server of a replica
+	if let Some(replica) = opt.replica.clone() {
+		tokio::spawn(sync::follow(ds.clone(), replica, ct.clone()));
+	}
+	// Spawn a task to refresh the datastore metrics
+	tokio::spawn(crate::telemetry::metrics::ds::refresh(ds.clone(), ct.clone()));
 	// Spawn a task to handle notifications
 	tokio::spawn(async move { notifications(ds, rpc_state, ct.clone()).await });
 	// If a certificate and key are specified then setup TLS
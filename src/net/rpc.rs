@@ -3,6 +3,7 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use super::headers::SurrealId;
+use super::limit::RateLimits;
 use crate::cnf;
 use crate::err::Error;
 use crate::rpc::connection::Connection;
@@ -28,6 +29,7 @@ use surrealdb::kvs::Datastore;
 use surrealdb::rpc::format::Format;
 use surrealdb::rpc::format::PROTOCOLS;
 use surrealdb::rpc::method::Method;
+use surrealdb::sql::Value;
 use tower_http::request_id::RequestId;
 use uuid::Uuid;
 
@@ -102,12 +104,20 @@ async fn get_handler(
 		.max_message_size(*cnf::WEBSOCKET_MAX_MESSAGE_SIZE)
 		// Handle the WebSocket upgrade and process messages
 		.on_upgrade(move |socket| {
-			handle_socket(state.datastore.clone(), rpc_state, socket, sess, id)
+			handle_socket(
+				state.datastore.clone(),
+				state.limits.clone(),
+				rpc_state,
+				socket,
+				sess,
+				id,
+			)
 		}))
 }
 
 async fn handle_socket(
 	datastore: Arc<Datastore>,
+	limits: Arc<RateLimits>,
 	state: Arc<RpcState>,
 	ws: WebSocket,
 	sess: Session,
@@ -122,7 +132,7 @@ async fn handle_socket(
 	};
 	// Format::Unsupported is not in the PROTOCOLS list so cannot be the value of format here
 	// Create a new connection instance
-	let rpc = Connection::new(datastore, state, id, sess, format);
+	let rpc = Connection::new(datastore, limits, state, id, sess, format);
 	// Serve the socket connection requests
 	Connection::serve(rpc, ws).await;
 }
@@ -145,11 +155,17 @@ async fn post_handler(
 		return Err(Error::InvalidType);
 	}
 
-	let mut rpc_ctx = PostRpcContext::new(&state.datastore, session, BTreeMap::new());
-
 	match fmt.req_http(body) {
 		Ok(req) => {
-			let res = rpc_ctx.execute(Method::parse(req.method), req.params).await;
+			let method = Method::parse(req.method);
+			// Check the rate limits of the signin, signup and refresh attempts
+			if let (Method::Signin | Method::Signup | Method::Refresh, Some(Value::Object(vars))) =
+				(&method, req.params.first())
+			{
+				state.limits.check_auth(&session, vars)?;
+			}
+			let mut rpc_ctx = PostRpcContext::new(&state.datastore, session, BTreeMap::new());
+			let res = rpc_ctx.execute(method, req.params, req.txn).await;
 			fmt.res_http(res.into_response(None)).map_err(Error::from)
 		}
 		Err(err) => Err(Error::from(err)),
@@ -10,6 +10,7 @@ use axum_extra::TypedHeader;
 use bytes::Bytes;
 use serde::Serialize;
 use surrealdb::dbs::Session;
+use surrealdb::iam::token::Token;
 use surrealdb::sql::Value;
 use tower_http::limit::RequestBodyLimitLayer;
 
@@ -23,12 +24,15 @@ struct Success {
 	code: u16,
 	details: String,
 	token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	refresh: Option<String>,
 }
 
 impl Success {
-	fn new(token: String) -> Success {
+	fn new(token: Token) -> Success {
 		Success {
-			token: Some(token),
+			token: Some(token.access().to_owned()),
+			refresh: token.refresh().map(str::to_owned),
 			code: 200,
 			details: String::from("Authentication succeeded"),
 		}
@@ -59,6 +63,8 @@ async fn handler(
 	match surrealdb::sql::json(data) {
 		// The provided value was an object
 		Ok(Value::Object(vars)) => {
+			// Check the rate limits of the signin attempts
+			state.limits.check_auth(&session, &vars)?;
 			match surrealdb::iam::signin::signin(kvs, &mut session, vars).await.map_err(Error::from)
 			{
 				// Authentication was successful
@@ -68,7 +74,7 @@ async fn handler(
 					Some(Accept::ApplicationCbor) => Ok(output::cbor(&Success::new(v))),
 					Some(Accept::ApplicationPack) => Ok(output::pack(&Success::new(v))),
 					// Text serialization
-					Some(Accept::TextPlain) => Ok(output::text(v)),
+					Some(Accept::TextPlain) => Ok(output::text(v.access().to_owned())),
 					// Internal serialization
 					Some(Accept::Surrealdb) => Ok(output::full(&Success::new(v))),
 					// Return nothing
@@ -9,6 +9,7 @@ use axum_extra::TypedHeader;
 use bytes::Bytes;
 use serde::Serialize;
 use surrealdb::dbs::Session;
+use surrealdb::iam::token::Token;
 use surrealdb::sql::Value;
 use tower_http::limit::RequestBodyLimitLayer;
 
@@ -22,12 +23,15 @@ struct Success {
 	code: u16,
 	details: String,
 	token: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	refresh: Option<String>,
 }
 
 impl Success {
-	fn new(token: Option<String>) -> Success {
+	fn new(token: Option<Token>) -> Success {
 		Success {
-			token,
+			token: token.as_ref().map(|v| v.access().to_owned()),
+			refresh: token.as_ref().and_then(|v| v.refresh()).map(str::to_owned),
 			code: 200,
 			details: String::from("Authentication succeeded"),
 		}
@@ -58,6 +62,8 @@ async fn handler(
 	match surrealdb::sql::json(data) {
 		// The provided value was an object
 		Ok(Value::Object(vars)) => {
+			// Check the rate limits of the signup attempts
+			state.limits.check_auth(&session, &vars)?;
 			match surrealdb::iam::signup::signup(kvs, &mut session, vars).await.map_err(Error::from)
 			{
 				// Authentication was successful
@@ -67,7 +73,9 @@ async fn handler(
 					Some(Accept::ApplicationCbor) => Ok(output::cbor(&Success::new(v))),
 					Some(Accept::ApplicationPack) => Ok(output::pack(&Success::new(v))),
 					// Text serialization
-					Some(Accept::TextPlain) => Ok(output::text(v.unwrap_or_default())),
+					Some(Accept::TextPlain) => {
+						Ok(output::text(v.map(|v| v.access().to_owned()).unwrap_or_default()))
+					}
 					// Internal serialization
 					Some(Accept::Surrealdb) => Ok(output::full(&Success::new(v))),
 					// Return nothing
@@ -1,18 +1,363 @@
-use axum::response::IntoResponse;
+use super::AppState;
+use crate::changes::{replay, ChangeSet};
+use crate::err::Error;
+use axum::body::Body;
+use axum::response::Response;
 use axum::routing::get;
+use axum::Extension;
 use axum::Router;
+use axum_extra::extract::Query;
+use bytes::Bytes;
+use http::header::{HeaderName, CONTENT_TYPE};
+use http::StatusCode;
+use serde::Deserialize;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use surrealdb::dbs::Session;
+use surrealdb::headers::{DB, NS, SCHEMA, VERSIONSTAMP};
+use surrealdb::iam::check::check_ns_db;
+use surrealdb::iam::Action::Export;
+use surrealdb::iam::ResourceKind::Any;
+use surrealdb::kvs::export::Config;
+use surrealdb::kvs::replica::State;
+use surrealdb::kvs::Datastore;
+use surrealdb::sql::{Ident, Value};
+use tokio_util::sync::CancellationToken;
+
+/// The number of table changes which are read from the change feed at once
+const CHANGES_BATCH_SIZE: u32 = 1000;
+
+/// The longest time a replica waits before retrying after repeated failures
+const MAX_RETRY_INTERVAL: Duration = Duration::from_secs(300);
+
+/// The primary server of a read only replica
+#[derive(Clone, Debug)]
+pub struct Replica {
+	/// The address of the primary server
+	pub primary: String,
+	/// The namespace of the replicated database
+	pub ns: String,
+	/// The replicated database
+	pub db: String,
+	/// The credentials of a root user of the primary server
+	pub user: Option<String>,
+	pub pass: Option<String>,
+	/// The interval at which the changes of the primary server are fetched
+	pub interval: Duration,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+struct SyncOptions {
+	/// Streams the changes after this versionstamp, instead of a snapshot
+	pub since: Option<String>,
+}
 
 pub(super) fn router<S>() -> Router<S>
 where
 	S: Clone + Send + Sync + 'static,
 {
-	Router::new().route("/sync", get(save).post(load))
+	Router::new().route("/sync", get(handler))
+}
+
+async fn handler(
+	Extension(state): Extension<AppState>,
+	Extension(session): Extension<Session>,
+	Query(query): Query<SyncOptions>,
+) -> Result<Response, Error> {
+	// Ensure a NS and DB are set
+	let (nsv, dbv) = check_ns_db(&session)?;
+	// Check the permissions level
+	state.datastore.check(&session, Export, Any.on_db(&nsv, &dbv))?;
+	// Stream a snapshot, or the changes since a versionstamp
+	match query.since {
+		None => snapshot(state, session).await,
+		Some(since) => {
+			let since = since.parse::<u128>().map_err(|_| Error::Request)?;
+			changes(state, session, since).await
+		}
+	}
+}
+
+/// Streams a snapshot of the database, along with the replication state at which it was taken
+async fn snapshot(state: AppState, session: Session) -> Result<Response, Error> {
+	// Get the datastore reference
+	let db = &state.datastore;
+	// Create a chunked response
+	let (chn, body_stream) = surrealdb::channel::bounded::<Result<Bytes, Error>>(1);
+	let body = Body::from_stream(body_stream);
+	// Create a new bounded channel
+	let (snd, rcv) = surrealdb::channel::bounded(1);
+	// Start the export task
+	let (replica, task) = db.export_snapshot(&session, snd, Config::default()).await?;
+	// Spawn a new database export job
+	tokio::spawn(task);
+	// Process all chunk values
+	tokio::spawn(async move {
+		while let Ok(v) = rcv.recv().await {
+			let _ = chn.send(Ok(Bytes::from(v))).await;
+		}
+	});
+	// Return the chunked body
+	Ok(Response::builder()
+		.status(StatusCode::OK)
+		.header(&VERSIONSTAMP, replica.versionstamp.to_string())
+		.header(&SCHEMA, replica.schema.to_string())
+		.body(body)
+		.unwrap())
+}
+
+/// Streams the change sets after a versionstamp, as newline delimited JSON
+async fn changes(state: AppState, session: Session, since: u128) -> Result<Response, Error> {
+	// The schema changes are not recorded in the change feed
+	let schema = state.datastore.schema_fingerprint(&session).await?;
+	// Read the first batch before responding, so that errors are returned
+	let batch = state.datastore.changes_since(&session, since, CHANGES_BATCH_SIZE).await?;
+	// Create a chunked response
+	let (chn, body_stream) = surrealdb::channel::bounded::<Result<Bytes, Error>>(1);
+	let body = Body::from_stream(body_stream);
+	// Stream the batches until the changes are caught up
+	tokio::spawn(async move {
+		let mut batch = batch;
+		let mut since = since;
+		while !batch.is_empty() {
+			for change in batch {
+				match encode(change) {
+					Ok((versionstamp, line)) => {
+						since = versionstamp;
+						// Stop if the client went away
+						if chn.send(Ok(line)).await.is_err() {
+							return;
+						}
+					}
+					Err(e) => {
+						let _ = chn.send(Err(e)).await;
+						return;
+					}
+				}
+			}
+			batch = match state.datastore.changes_since(&session, since, CHANGES_BATCH_SIZE).await {
+				Ok(v) => v,
+				Err(e) => {
+					let _ = chn.send(Err(e.into())).await;
+					return;
+				}
+			};
+		}
+	});
+	// Return the chunked body
+	Ok(Response::builder()
+		.status(StatusCode::OK)
+		.header(CONTENT_TYPE, "application/x-ndjson")
+		.header(&SCHEMA, schema.to_string())
+		.body(body)
+		.unwrap())
+}
+
+/// Encodes a change set as a line of newline delimited JSON
+fn encode(change: Value) -> Result<(u128, Bytes), Error> {
+	let mut change = match change {
+		Value::Object(v) => v,
+		v => return Err(Error::Other(format!("Unexpected change feed entry {v}"))),
+	};
+	let versionstamp = match change.remove("versionstamp") {
+		Some(Value::Number(v)) => u128::try_from(v).map_err(|e| Error::Other(e.to_string()))?,
+		_ => return Err(Error::Other("Missing change feed versionstamp".to_string())),
+	};
+	let changes = change.remove("changes").unwrap_or_default().to_string();
+	let mut line = serde_json::to_vec(&ChangeSet {
+		versionstamp,
+		changes,
+	})?;
+	line.push(b'\n');
+	Ok((versionstamp, Bytes::from(line)))
+}
+
+/// Follows a primary server, applying the changes of the replicated database
+pub(super) async fn follow(ds: Arc<Datastore>, replica: Replica, ct: CancellationToken) {
+	let client = reqwest::Client::new();
+	let sess = Session::owner().with_ns(&replica.ns).with_db(&replica.db);
+	// Resume from the changes which were already applied before a restart
+	let mut state = match ds.replica_state(&replica.ns, &replica.db).await {
+		Ok(Some(v)) => {
+			info!(
+				"Resuming the replication of {} after versionstamp {}",
+				replica.primary, v.versionstamp
+			);
+			Some(v)
+		}
+		Ok(None) => None,
+		Err(e) => {
+			error!("Failed to read the state of the replica: {e}");
+			None
+		}
+	};
+	let mut failures = 0;
+	loop {
+		let failed = match state {
+			// Bootstrap the replica from a snapshot
+			None => match bootstrap(&ds, &client, &replica, &sess).await {
+				Ok(v) => {
+					state = Some(v);
+					false
+				}
+				Err(e) => {
+					error!("Failed to bootstrap the replica from {}: {e}", replica.primary);
+					true
+				}
+			},
+			// Catch up with the changes of the primary
+			Some(mut v) => {
+				let res = catch_up(&ds, &client, &replica, &sess, &mut v).await;
+				state = Some(v);
+				match res {
+					Ok(true) => false,
+					Ok(false) => {
+						state = None;
+						false
+					}
+					Err(e) => {
+						error!("Failed to replicate the changes of {}: {e}", replica.primary);
+						true
+					}
+				}
+			}
+		};
+		let wait = match failed {
+			// Wait for longer after each consecutive failure
+			true => {
+				failures += 1;
+				Some(retry_interval(replica.interval, failures))
+			}
+			// Bootstrap again straight away when the replica is out of sync
+			false => {
+				failures = 0;
+				state.is_some().then_some(replica.interval)
+			}
+		};
+		match wait {
+			Some(wait) => tokio::select! {
+				_ = ct.cancelled() => return,
+				_ = tokio::time::sleep(wait) => {}
+			},
+			None if ct.is_cancelled() => return,
+			None => {}
+		}
+	}
+}
+
+/// The time to wait before retrying, doubling the interval after each consecutive failure
+fn retry_interval(interval: Duration, failures: u32) -> Duration {
+	let backoff = interval.saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)));
+	backoff.min(MAX_RETRY_INTERVAL.max(interval))
+}
+
+/// Replaces the replicated database with a snapshot of the primary server
+///
+/// The snapshot is applied as it is streamed, in a single transaction, so
+/// that the previous contents are kept if the bootstrap fails.
+async fn bootstrap(
+	ds: &Datastore,
+	client: &reqwest::Client,
+	replica: &Replica,
+	sess: &Session,
+) -> Result<State, Error> {
+	let mut res = request(client, replica).send().await?.error_for_status()?;
+	let versionstamp = header(&res, &VERSIONSTAMP)?;
+	let schema = header(&res, &SCHEMA)?;
+	let mut txn = ds.replication(sess).await?;
+	let imported: Result<(), Error> = async {
+		// Replace the previous contents of the database
+		let ns = Ident::from(replica.ns.as_str());
+		let db = Ident::from(replica.db.as_str());
+		let sql = format!(
+			"DEFINE NAMESPACE IF NOT EXISTS {ns}; REMOVE DATABASE IF EXISTS {db}; DEFINE DATABASE {db};"
+		);
+		txn.apply(surrealdb::sql::parse(&sql)?).await?;
+		// Import the snapshot as it is received
+		while let Some(chunk) = res.chunk().await? {
+			txn.apply_sql(&chunk).await?;
+		}
+		Ok(())
+	}
+	.await;
+	if let Err(e) = imported {
+		let _ = txn.cancel().await;
+		return Err(e);
+	}
+	let state = State::new(versionstamp, schema);
+	txn.commit(state).await?;
+	info!("Bootstrapped the replica from {} at versionstamp {versionstamp}", replica.primary);
+	Ok(state)
+}
+
+/// Applies the changes of the primary server since the last applied versionstamp
+///
+/// Returns false if the replica can't catch up with the change feed, and
+/// needs to be bootstrapped again.
+async fn catch_up(
+	ds: &Datastore,
+	client: &reqwest::Client,
+	replica: &Replica,
+	sess: &Session,
+	state: &mut State,
+) -> Result<bool, Error> {
+	let res =
+		request(client, replica).query(&[("since", state.versionstamp.to_string())]).send().await?;
+	// The changes have been removed from the change feed of the primary
+	if res.status() == StatusCode::GONE {
+		warn!("The changes after versionstamp {} are no longer available on {}, bootstrapping the replica again", state.versionstamp, replica.primary);
+		return Ok(false);
+	}
+	let mut res = res.error_for_status()?;
+	// The definitions are not replicated through the change feed
+	let schema = header(&res, &SCHEMA)?;
+	if schema != state.schema {
+		info!("The schema of {} has changed, bootstrapping the replica again", replica.primary);
+		return Ok(false);
+	}
+	// Apply every complete line, as the changes are streamed
+	let mut buf = Vec::new();
+	while let Some(chunk) = res.chunk().await? {
+		buf.extend_from_slice(&chunk);
+		while let Some(pos) = buf.iter().position(|b| *b == b'\n') {
+			let line: Vec<u8> = buf.drain(..=pos).collect();
+			let change: ChangeSet = serde_json::from_slice(&line)?;
+			debug!("Replicating the changes at versionstamp {}", change.versionstamp);
+			let txn = ds.replication(sess).await?;
+			let ast = match surrealdb::sql::parse(&replay(&change)?) {
+				Ok(v) => v,
+				Err(e) => {
+					let _ = txn.cancel().await;
+					return Err(e.into());
+				}
+			};
+			if let Err(e) = txn.apply(ast).await {
+				let _ = txn.cancel().await;
+				return Err(e.into());
+			}
+			// Store the versionstamp along with the changes
+			let next = State::new(change.versionstamp, schema);
+			txn.commit(next).await?;
+			*state = next;
+		}
+	}
+	Ok(true)
 }
 
-async fn load() -> impl IntoResponse {
-	"Load"
+/// Creates a request to the /sync endpoint of the primary server
+fn request(client: &reqwest::Client, replica: &Replica) -> reqwest::RequestBuilder {
+	let url = format!("{}/sync", replica.primary.trim_end_matches('/'));
+	let req = client.get(url).header(&NS, &replica.ns).header(&DB, &replica.db);
+	match &replica.user {
+		Some(user) => req.basic_auth(user, replica.pass.as_ref()),
+		None => req,
+	}
 }
 
-async fn save() -> impl IntoResponse {
-	"Save"
+/// Parses a header of a response of the primary server
+fn header<T: FromStr>(res: &reqwest::Response, name: &HeaderName) -> Result<T, Error> {
+	res.headers().get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok()).ok_or_else(
+		|| Error::Other(format!("The primary server did not return a valid {name} header")),
+	)
 }
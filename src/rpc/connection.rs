@@ -1,6 +1,8 @@
 use crate::cnf::{
 	PKG_NAME, PKG_VERSION, WEBSOCKET_MAX_CONCURRENT_REQUESTS, WEBSOCKET_PING_FREQUENCY,
+	WEBSOCKET_TRANSACTION_TIMEOUT,
 };
+use crate::net::limit::RateLimits;
 use crate::rpc::failure::Failure;
 use crate::rpc::format::WsFormat;
 use crate::rpc::response::{failure, IntoRpcResponse};
@@ -15,6 +17,7 @@ use opentelemetry::trace::FutureExt;
 use opentelemetry::Context as TelemetryContext;
 use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 use surrealdb::channel::{self, Receiver, Sender};
 use surrealdb::dbs::Session;
 #[cfg(surrealdb_unstable)]
@@ -24,6 +27,7 @@ use surrealdb::rpc::format::Format;
 use surrealdb::rpc::method::Method;
 use surrealdb::rpc::Data;
 use surrealdb::rpc::RpcContext;
+use surrealdb::rpc::Transactions;
 use surrealdb::sql::Array;
 use surrealdb::sql::Value;
 use tokio::sync::{RwLock, Semaphore};
@@ -45,6 +49,8 @@ pub struct Connection {
 	pub(crate) channels: (Sender<Message>, Receiver<Message>),
 	pub(crate) state: Arc<RpcState>,
 	pub(crate) datastore: Arc<Datastore>,
+	pub(crate) limits: Arc<RateLimits>,
+	pub(crate) transactions: Transactions,
 	#[cfg(surrealdb_unstable)]
 	pub(crate) gql_schema: SchemaCache<Pessimistic>,
 }
@@ -53,6 +59,7 @@ impl Connection {
 	/// Instantiate a new RPC
 	pub fn new(
 		datastore: Arc<Datastore>,
+		limits: Arc<RateLimits>,
 		state: Arc<RpcState>,
 		id: Uuid,
 		mut session: Session,
@@ -69,10 +76,12 @@ impl Connection {
 			limiter: Arc::new(Semaphore::new(*WEBSOCKET_MAX_CONCURRENT_REQUESTS)),
 			canceller: CancellationToken::new(),
 			channels: channel::bounded(*WEBSOCKET_MAX_CONCURRENT_REQUESTS),
-			state,
 			#[cfg(surrealdb_unstable)]
-			gql_schema: SchemaCache::new(datastore.clone()),
+			gql_schema: SchemaCache::new(datastore.clone(), state.subscriptions.clone()),
+			state,
+			transactions: Transactions::new(Duration::from_secs(*WEBSOCKET_TRANSACTION_TIMEOUT)),
 			datastore,
+			limits,
 		}))
 	}
 
@@ -138,6 +147,9 @@ impl Connection {
 			error!("Error handling RPC connection: {}", err);
 		}
 
+		// Cancel all interactive transactions
+		rpc.read().await.transactions.clear().await;
+
 		if let Err(err) = telemetry::metrics::ws::on_disconnect() {
 			error!("Error running metrics::ws::on_disconnect hook: {}", err);
 		}
@@ -167,6 +179,8 @@ impl Connection {
 						// Exit out of the loop
 						break;
 					}
+					// Cancel any idle interactive transactions
+					rpc.read().await.transactions.expire().await;
 				},
 			}
 		}
@@ -332,7 +346,8 @@ impl Connection {
 					));
 					// Process the message
 					let res =
-						Connection::process_message(rpc.clone(), &req.method, req.params).await;
+						Connection::process_message(rpc.clone(), &req.method, req.params, req.txn)
+							.await;
 					// Process the response
 					res.into_response(req.id)
 						.send(otel_cx.clone(), fmt, &chn)
@@ -358,18 +373,29 @@ impl Connection {
 		rpc: Arc<RwLock<Connection>>,
 		method: &str,
 		params: Array,
+		txn: Option<Uuid>,
 	) -> Result<Data, Failure> {
 		debug!("Process RPC request");
 		let method = Method::parse(method);
 		if !method.is_valid() {
 			return Err(Failure::METHOD_NOT_FOUND);
 		}
+		// Check the rate limits of the request
+		{
+			let rpc = rpc.read().await;
+			rpc.limits.check(&rpc.session)?;
+			if let (Method::Signin | Method::Signup | Method::Refresh, Some(Value::Object(vars))) =
+				(&method, params.first())
+			{
+				rpc.limits.check_auth(&rpc.session, vars)?;
+			}
+		}
 
 		// if the write lock is a bottleneck then execute could be refactored into execute_mut and execute
 		// rpc.write().await.execute(method, params).await.map_err(Into::into)
 		match method.needs_mut() {
-			true => rpc.write().await.execute(method, params).await.map_err(Into::into),
-			false => rpc.read().await.execute_immut(method, params).await.map_err(Into::into),
+			true => rpc.write().await.execute(method, params, txn).await.map_err(Into::into),
+			false => rpc.read().await.execute_immut(method, params, txn).await.map_err(Into::into),
 		}
 	}
 }
@@ -412,6 +438,12 @@ impl RpcContext for Connection {
 		}
 	}
 
+	const TXN_SUPPORT: bool = true;
+
+	fn transactions(&self) -> &Transactions {
+		&self.transactions
+	}
+
 	#[cfg(surrealdb_unstable)]
 	const GQL_SUPPORT: bool = true;
 	#[cfg(surrealdb_unstable)]
@@ -48,7 +48,10 @@ impl From<&str> for Failure {
 
 impl From<Error> for Failure {
 	fn from(err: Error) -> Self {
-		Failure::custom(err.to_string())
+		match err {
+			Error::TooManyRequests(_) => Failure::TOO_MANY_REQUESTS,
+			err => Failure::custom(err.to_string()),
+		}
 	}
 }
 
@@ -103,6 +106,11 @@ impl Failure {
 		message: Cow::Borrowed("Internal error"),
 	};
 
+	pub const TOO_MANY_REQUESTS: Failure = Failure {
+		code: -32029,
+		message: Cow::Borrowed("Too many requests"),
+	};
+
 	pub fn custom<S>(message: S) -> Failure
 	where
 		Cow<'static, str>: From<S>,
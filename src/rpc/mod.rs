@@ -11,6 +11,8 @@ use opentelemetry::Context as TelemetryContext;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+#[cfg(surrealdb_unstable)]
+use surrealdb::gql::Subscriptions;
 use surrealdb::kvs::Datastore;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
@@ -29,6 +31,9 @@ pub struct RpcState {
 	pub web_sockets: WebSockets,
 	/// Stores the currently initiated LIVE queries
 	pub live_queries: LiveQueries,
+	/// Routes live query notifications to GraphQL subscriptions
+	#[cfg(surrealdb_unstable)]
+	pub subscriptions: Subscriptions,
 }
 
 impl RpcState {
@@ -36,11 +41,13 @@ impl RpcState {
 		RpcState {
 			web_sockets: WebSockets::default(),
 			live_queries: LiveQueries::default(),
+			#[cfg(surrealdb_unstable)]
+			subscriptions: Subscriptions::default(),
 		}
 	}
 }
 
-/// Performs notification delivery to the WebSockets
+/// Performs notification delivery to the WebSockets and GraphQL subscriptions
 pub(crate) async fn notifications(
 	ds: Arc<Datastore>,
 	state: Arc<RpcState>,
@@ -57,6 +64,11 @@ pub(crate) async fn notifications(
 				_ = canceller.cancelled() => break,
 				// Receive a notification on the channel
 				Ok(notification) = channel.recv() => {
+					// Check if the notification belongs to a GraphQL subscription
+					#[cfg(surrealdb_unstable)]
+					let Some(notification) = state.subscriptions.deliver(notification).await else {
+						continue;
+					};
 					// Find which WebSocket the notification belongs to
 					let found_ws = {
 						// We remove the lock asap
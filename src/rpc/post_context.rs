@@ -28,7 +28,7 @@ impl PostRpcContext {
 			session,
 			vars,
 			#[cfg(surrealdb_unstable)]
-			gql_schema: SchemaCache::new(kvs.clone()),
+			gql_schema: SchemaCache::new(kvs.clone(), Default::default()),
 		}
 	}
 }
@@ -0,0 +1,141 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use opentelemetry::metrics::{Meter, MetricsError, ObservableCounter, ObservableGauge};
+use opentelemetry::{global, KeyValue};
+use surrealdb::error::Db as Error;
+use surrealdb::kvs::metrics::{IndexBuild, Metrics};
+use surrealdb::kvs::Datastore;
+use tokio_util::sync::CancellationToken;
+
+static METER: Lazy<Meter> = Lazy::new(|| global::meter("surrealdb.kvs"));
+
+/// The metrics which are read from the datastore, rather than from the counters of the process
+#[derive(Default)]
+struct Snapshot {
+	live_queries: usize,
+	index_builds: Vec<IndexBuild>,
+}
+
+static SNAPSHOT: Lazy<RwLock<Snapshot>> = Lazy::new(Default::default);
+
+/// The interval at which the snapshot is refreshed, for the metrics which are exported periodically
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(15);
+
+pub static KVS_TRANSACTION_COMMITS: Lazy<ObservableCounter<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_counter("kvs.transaction.commits")
+		.with_description("The number of committed transactions.")
+		.with_callback(|observer| observer.observe(Metrics::counters().transaction_commits, &[]))
+		.init()
+});
+
+pub static KVS_TRANSACTION_CONFLICTS: Lazy<ObservableCounter<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_counter("kvs.transaction.conflicts")
+		.with_description("The number of transactions which failed to commit due to a conflict.")
+		.with_callback(|observer| observer.observe(Metrics::counters().transaction_conflicts, &[]))
+		.init()
+});
+
+pub static KVS_TRANSACTION_FAILURES: Lazy<ObservableCounter<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_counter("kvs.transaction.failures")
+		.with_description("The number of transactions which failed to commit for another reason.")
+		.with_callback(|observer| observer.observe(Metrics::counters().transaction_failures, &[]))
+		.init()
+});
+
+pub static KVS_CACHE_HITS: Lazy<ObservableCounter<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_counter("kvs.cache.hits")
+		.with_description("The number of lookups which were answered by a cache.")
+		.with_callback(|observer| {
+			let metrics = Metrics::counters();
+			observer.observe(metrics.cache_hits, &[KeyValue::new("cache", "definitions")]);
+			observer.observe(metrics.index_cache_hits, &[KeyValue::new("cache", "index")]);
+		})
+		.init()
+});
+
+pub static KVS_CACHE_MISSES: Lazy<ObservableCounter<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_counter("kvs.cache.misses")
+		.with_description("The number of lookups which were not answered by a cache.")
+		.with_callback(|observer| {
+			let metrics = Metrics::counters();
+			observer.observe(metrics.cache_misses, &[KeyValue::new("cache", "definitions")]);
+			observer.observe(metrics.index_cache_misses, &[KeyValue::new("cache", "index")]);
+		})
+		.init()
+});
+
+pub static KVS_LIVE_QUERIES: Lazy<ObservableGauge<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_gauge("kvs.live_queries")
+		.with_description("The number of live queries registered on this node.")
+		.with_callback(|observer| {
+			if let Ok(snapshot) = SNAPSHOT.read() {
+				observer.observe(snapshot.live_queries as u64, &[]);
+			}
+		})
+		.init()
+});
+
+pub static KVS_INDEX_BUILD_RECORDS: Lazy<ObservableGauge<u64>> = Lazy::new(|| {
+	METER
+		.u64_observable_gauge("kvs.index_build.records")
+		.with_description("The number of records indexed by an index build.")
+		.with_callback(|observer| {
+			if let Ok(snapshot) = SNAPSHOT.read() {
+				for build in snapshot.index_builds.iter() {
+					let attrs = [
+						KeyValue::new("ns", build.ns.clone()),
+						KeyValue::new("db", build.db.clone()),
+						KeyValue::new("tb", build.tb.clone()),
+						KeyValue::new("ix", build.ix.clone()),
+						KeyValue::new("status", build.status),
+					];
+					observer.observe(build.count as u64, &attrs);
+				}
+			}
+		})
+		.init()
+});
+
+/// Registers the callbacks which observe the datastore metrics.
+pub fn init() -> Result<(), MetricsError> {
+	Lazy::force(&KVS_TRANSACTION_COMMITS);
+	Lazy::force(&KVS_TRANSACTION_CONFLICTS);
+	Lazy::force(&KVS_TRANSACTION_FAILURES);
+	Lazy::force(&KVS_CACHE_HITS);
+	Lazy::force(&KVS_CACHE_MISSES);
+	Lazy::force(&KVS_LIVE_QUERIES);
+	Lazy::force(&KVS_INDEX_BUILD_RECORDS);
+	Ok(())
+}
+
+/// Reads the live queries and index builds from the datastore, for the next collection.
+pub async fn observe(ds: &Datastore) -> Result<(), Error> {
+	let metrics = ds.metrics().await?;
+	if let Ok(mut snapshot) = SNAPSHOT.write() {
+		snapshot.live_queries = metrics.live_queries;
+		snapshot.index_builds = metrics.index_builds;
+	}
+	Ok(())
+}
+
+/// Refreshes the live queries and index builds periodically, until the server is shut down.
+pub async fn refresh(ds: Arc<Datastore>, ct: CancellationToken) {
+	let mut interval = tokio::time::interval(SNAPSHOT_INTERVAL);
+	loop {
+		tokio::select! {
+			_ = ct.cancelled() => return,
+			_ = interval.tick() => {}
+		}
+		if let Err(e) = observe(&ds).await {
+			warn!("Failed to read the datastore metrics: {e}");
+		}
+	}
+}
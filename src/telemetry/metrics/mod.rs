@@ -1,3 +1,4 @@
+pub mod ds;
 pub mod http;
 pub mod ws;
 
@@ -9,10 +10,15 @@ use opentelemetry_sdk::metrics::{
 	Aggregation, Instrument, PeriodicReader, SdkMeterProvider, Stream,
 };
 use opentelemetry_sdk::runtime;
+use prometheus::{Registry, TextEncoder};
 
 pub use self::http::tower_layer::HttpMetricsLayer;
 
 use super::OTEL_DEFAULT_RESOURCE;
+use once_cell::sync::Lazy;
+
+// The registry which is served in the Prometheus format on `/metrics`
+static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
 
 // Histogram buckets in milliseconds
 static HISTOGRAM_BUCKETS_MS: &[f64] = &[
@@ -39,7 +45,7 @@ const HISTOGRAM_BUCKETS_BYTES: &[f64] = &[
 	100.0 * MB, // 100 MB
 ];
 
-fn build_controller() -> Result<SdkMeterProvider, MetricsError> {
+fn build_controller(prometheus: bool) -> Result<SdkMeterProvider, MetricsError> {
 	let exporter = MetricsExporterBuilder::from(opentelemetry_otlp::new_exporter().tonic())
 		.build_metrics_exporter(
 			Box::new(DefaultTemporalitySelector::new()),
@@ -66,21 +72,38 @@ fn build_controller() -> Result<SdkMeterProvider, MetricsError> {
 		opentelemetry_sdk::metrics::new_view(criteria, mask)?
 	};
 
-	Ok(SdkMeterProvider::builder()
+	let builder = SdkMeterProvider::builder()
 		.with_reader(reader)
 		.with_resource(OTEL_DEFAULT_RESOURCE.clone())
 		.with_view(histo_duration_view)
-		.with_view(histo_size_view)
-		.build())
+		.with_view(histo_size_view);
+
+	// Also collect the metrics when they are scraped by Prometheus
+	let builder = if prometheus {
+		let exporter =
+			opentelemetry_prometheus::exporter().with_registry(REGISTRY.clone()).build()?;
+		builder.with_reader(exporter)
+	} else {
+		builder
+	};
+
+	Ok(builder.build())
 }
 
-// Initialize the metrics subsystem
+// Initialize the metrics subsystem, optionally collecting the metrics for Prometheus
 // Panics if initialization fails
-pub fn init() -> Result<(), MetricsError> {
-	let meter_provider = build_controller()?;
+pub fn init(prometheus: bool) -> Result<(), MetricsError> {
+	let meter_provider = build_controller(prometheus)?;
 
 	global::set_meter_provider(meter_provider);
-	Ok(())
+	ds::init()
+}
+
+// Encode the collected metrics in the Prometheus text format
+pub fn encode() -> Result<String, prometheus::Error> {
+	let mut buffer = String::new();
+	TextEncoder::new().encode_utf8(&REGISTRY.gather(), &mut buffer)?;
+	Ok(buffer)
 }
 
 //
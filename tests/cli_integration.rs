@@ -242,6 +242,94 @@ mod cli_integration {
 		}
 	}
 
+	#[test(tokio::test)]
+	async fn backup_restore() {
+		let (addr, _server) = common::start_server(StartServerArguments {
+			auth: false,
+			args: "--allow-all".to_string(),
+			..Default::default()
+		})
+		.await
+		.unwrap();
+		let ns = Ulid::new();
+		let db = Ulid::new();
+		let sql_args =
+			format!("sql --conn http://{addr} --ns {ns} --db {db} --multi --hide-welcome");
+		let backup = common::tmp_file("backup");
+		let backup_args = format!("backup --conn http://{addr} --ns {ns} --db {db} {backup}");
+
+		info!("* Create records with and without a change feed");
+		{
+			let input = "DEFINE TABLE thing CHANGEFEED 1h; CREATE thing:one; CREATE thing:two; CREATE note:one;\n";
+			common::run(&sql_args).input(input).output().unwrap();
+		}
+
+		info!("* Back up the base snapshot");
+		{
+			let output = common::run(&backup_args).output().expect("failed to run base backup");
+			assert!(output.contains("base snapshot was backed up"), "unexpected output: {output}");
+		}
+
+		info!("* Back up the changes in a segment");
+		{
+			let input = "DELETE thing:one; CREATE thing:three; CREATE note:two;\n";
+			common::run(&sql_args).input(input).output().unwrap();
+			let output = common::run(&backup_args).output().expect("failed to run backup");
+			assert!(output.contains("segment-000001.ndjson"), "unexpected output: {output}");
+		}
+
+		info!("* Back up a snapshot when the schema changes");
+		{
+			let input = "DEFINE FIELD name ON thing TYPE option<string>; UPDATE thing:two SET name = 'two';\n";
+			common::run(&sql_args).input(input).output().unwrap();
+			let output = common::run(&backup_args).output().expect("failed to run backup");
+			assert!(output.contains("schema has changed"), "unexpected output: {output}");
+			assert!(output.contains("segment-000002.ndjson"), "unexpected output: {output}");
+		}
+
+		info!("* Back up the changes after the snapshot");
+		{
+			let input = "CREATE thing:four; DELETE note:one;\n";
+			common::run(&sql_args).input(input).output().unwrap();
+			let output = common::run(&backup_args).output().expect("failed to run backup");
+			assert!(output.contains("segment-000003.ndjson"), "unexpected output: {output}");
+		}
+
+		info!("* Restore the backup");
+		let db2 = Ulid::new();
+		{
+			let args = format!("restore --conn http://{addr} --ns {ns} --db {db2} {backup}");
+			common::run(&args).output().expect("failed to run restore");
+			let args =
+				format!("sql --conn http://{addr} --ns {ns} --db {db2} --multi --hide-welcome");
+			let output =
+				common::run(&args).input("SELECT VALUE id FROM thing;\n").output().unwrap();
+			assert!(
+				output.contains("[[thing:four, thing:three, thing:two]]"),
+				"unexpected output: {output}"
+			);
+			let output = common::run(&args)
+				.input("SELECT VALUE name FROM thing WHERE name;\n")
+				.output()
+				.unwrap();
+			assert!(output.contains("[['two']]"), "unexpected output: {output}");
+			let output = common::run(&args).input("SELECT VALUE id FROM note;\n").output().unwrap();
+			assert!(output.contains("[[note:two]]"), "unexpected output: {output}");
+		}
+
+		info!("* Restore fails when a segment is missing");
+		{
+			std::fs::rename(
+				format!("{backup}/segment-000003.ndjson"),
+				format!("{backup}/segment-000004.ndjson"),
+			)
+			.unwrap();
+			let args =
+				format!("restore --conn http://{addr} --ns {ns} --db {} {backup}", Ulid::new());
+			common::run(&args).output().expect_err("restore should check the segments");
+		}
+	}
+
 	#[test(tokio::test)]
 	async fn start_tls() {
 		let (_, server) = common::start_server(StartServerArguments {
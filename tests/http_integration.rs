@@ -404,6 +404,42 @@ mod http_integration {
 		Ok(())
 	}
 
+	#[test(tokio::test)]
+	async fn metrics_endpoint() -> Result<(), Box<dyn std::error::Error>> {
+		// the endpoint is only served when it is enabled
+		{
+			let (addr, _server) = common::start_server_with_defaults().await.unwrap();
+			let url = &format!("http://{addr}/metrics");
+
+			let res = Client::default().get(url).send().await?;
+			assert_eq!(res.status(), 404, "response: {res:#?}");
+		}
+
+		{
+			let mut start_server_arguments = StartServerArguments::default();
+			start_server_arguments.args.push_str(" --prometheus-metrics");
+			let (addr, _server) = common::start_server(start_server_arguments).await.unwrap();
+			// Run a request, so that the HTTP metrics are recorded
+			let res = Client::default().get(format!("http://{addr}/health")).send().await?;
+			assert_eq!(res.status(), 200, "response: {res:#?}");
+
+			let url = &format!("http://{addr}/metrics");
+			// the metrics are only served to root users
+			let res = Client::default().get(url).send().await?;
+			assert_eq!(res.status(), 403, "response: {res:#?}");
+
+			let res = Client::default().get(url).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "response: {res:#?}");
+			let body = res.text().await?;
+			assert!(body.contains("http_server_duration"), "body: {body}");
+			assert!(body.contains("kvs_transaction_commits"), "body: {body}");
+			assert!(body.contains("kvs_transaction_failures"), "body: {body}");
+			assert!(body.contains("kvs_live_queries"), "body: {body}");
+		}
+
+		Ok(())
+	}
+
 	#[test(tokio::test)]
 	async fn no_server_id_headers() -> Result<(), Box<dyn std::error::Error>> {
 		// default server has the id headers
@@ -503,6 +539,62 @@ mod http_integration {
 		Ok(())
 	}
 
+	#[test(tokio::test)]
+	async fn rate_limits() -> Result<(), Box<dyn std::error::Error>> {
+		// Requests from the same client IP address are limited
+		{
+			let mut start_server_arguments = StartServerArguments::default();
+			start_server_arguments.args.push_str(" --rate-limit 3/1h");
+			let (addr, _server) = common::start_server(start_server_arguments).await.unwrap();
+			let url = &format!("http://{addr}/health");
+
+			for _ in 0..3 {
+				let res = Client::default().get(url).send().await?;
+				assert_eq!(res.status(), 200, "response: {res:#?}");
+			}
+			let res = Client::default().get(url).send().await?;
+			assert_eq!(res.status(), 429, "response: {res:#?}");
+			assert!(res.headers().contains_key("retry-after"), "response: {res:#?}");
+		}
+
+		// Signin attempts are limited separately
+		{
+			let mut start_server_arguments = StartServerArguments::default();
+			start_server_arguments.args.push_str(" --auth-rate-limit 1/1h");
+			let (addr, _server) = common::start_server(start_server_arguments).await.unwrap();
+			let url = &format!("http://{addr}/signin");
+			let body = r#"{"user":"root","pass":"invalid"}"#;
+
+			let res = Client::default().post(url).body(body).send().await?;
+			assert_eq!(res.status(), 401, "response: {res:#?}");
+			let res = Client::default().post(url).body(body).send().await?;
+			assert_eq!(res.status(), 429, "response: {res:#?}");
+			// Other requests are not affected by the signin limits
+			let res = Client::default().get(format!("http://{addr}/health")).send().await?;
+			assert_eq!(res.status(), 200, "response: {res:#?}");
+		}
+
+		// Signin attempts for each system user are limited separately
+		{
+			let mut start_server_arguments = StartServerArguments::default();
+			start_server_arguments.args.push_str(" --auth-access-rate-limit 1/1h");
+			let (addr, _server) = common::start_server(start_server_arguments).await.unwrap();
+			let url = &format!("http://{addr}/signin");
+
+			let body = r#"{"user":"root","pass":"invalid"}"#;
+			let res = Client::default().post(url).body(body).send().await?;
+			assert_eq!(res.status(), 401, "response: {res:#?}");
+			let res = Client::default().post(url).body(body).send().await?;
+			assert_eq!(res.status(), 429, "response: {res:#?}");
+			// Other system users are not affected
+			let body = r#"{"user":"admin","pass":"invalid"}"#;
+			let res = Client::default().post(url).body(body).send().await?;
+			assert_eq!(res.status(), 401, "response: {res:#?}");
+		}
+
+		Ok(())
+	}
+
 	#[test(tokio::test)]
 	async fn rpc_endpoint() -> Result<(), Box<dyn std::error::Error>> {
 		let (addr, _server) = common::start_server_with_defaults().await.unwrap();
@@ -979,27 +1071,139 @@ mod http_integration {
 
 		// Prepare HTTP client
 		let mut headers = reqwest::header::HeaderMap::new();
-		headers.insert("surreal-ns", Ulid::new().to_string().parse()?);
-		headers.insert("surreal-db", Ulid::new().to_string().parse()?);
+		headers.insert("surreal-ns", "sync".parse()?);
+		headers.insert("surreal-db", "sync".parse()?);
 		headers.insert(header::ACCEPT, "application/json".parse()?);
 		let client = reqwest::Client::builder()
 			.connect_timeout(Duration::from_millis(10))
 			.default_headers(headers)
 			.build()?;
 
-		// GET
+		// Create some data in a database with a change feed
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("DEFINE DATABASE sync CHANGEFEED 1h; CREATE foo:1 SET name = 'one';")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+		}
+
+		// When no auth is provided, the endpoint returns a 403
 		{
 			let res = client.get(url).send().await?;
+			assert_eq!(res.status(), 403, "body: {}", res.text().await?);
+		}
+
+		// A snapshot is returned along with its versionstamp
+		let versionstamp = {
+			let res = client.get(url).basic_auth(USER, Some(PASS)).send().await?;
+			assert_eq!(res.status(), 200, "response: {res:#?}");
+			let versionstamp: u128 = res.headers()["surreal-versionstamp"].to_str()?.parse()?;
+			assert!(versionstamp > 0);
+			let body = res.text().await?;
+			assert!(body.contains("DEFINE TABLE foo"), "body: {body}");
+			assert!(body.contains("'one'"), "body: {body}");
+			versionstamp
+		};
+
+		// The changes after the snapshot are streamed as NDJSON
+		{
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("CREATE foo:2 SET name = 'two';")
+				.send()
+				.await?;
 			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			let res = client
+				.get(format!("{url}?since={versionstamp}"))
+				.basic_auth(USER, Some(PASS))
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "response: {res:#?}");
 			let body = res.text().await?;
-			assert_eq!(body, r#"Save"#, "body: {body}");
+			let lines: Vec<serde_json::Value> =
+				body.lines().map(serde_json::from_str).collect::<Result<_, _>>()?;
+			assert_eq!(lines.len(), 1, "body: {body}");
+			assert!(lines[0]["changes"].as_str().unwrap().contains("'two'"), "body: {body}");
 		}
-		// POST
+
+		// A replica follows the changes of the primary server
 		{
-			let res = client.post(url).body("").send().await?;
+			let mut start_server_arguments = StartServerArguments::default();
+			start_server_arguments.args.push_str(&format!(
+				" --replica-of http://{addr} --replica-ns sync --replica-db sync \
+				--replica-user {USER} --replica-pass {PASS} --replica-interval 100ms"
+			));
+			let (replica, _replica) = common::start_server(start_server_arguments).await.unwrap();
+
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("CREATE foo:3 SET name = 'three'; DELETE foo:1;")
+				.send()
+				.await?;
 			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			// Wait for the replica to catch up
+			let mut body = serde_json::Value::Null;
+			for _ in 0..50 {
+				let res = client
+					.post(format!("http://{replica}/sql"))
+					.basic_auth(USER, Some(PASS))
+					.body("SELECT VALUE name FROM foo ORDER BY name")
+					.send()
+					.await?;
+				assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+				body = serde_json::from_str(&res.text().await?)?;
+				if body[0]["result"] == json!(["three", "two"]) {
+					break;
+				}
+				tokio::time::sleep(Duration::from_millis(100)).await;
+			}
+			assert_eq!(body[0]["result"], json!(["three", "two"]), "body: {body}");
+
+			// Writes are rejected by the replica
+			let res = client
+				.post(format!("http://{replica}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("CREATE foo:4")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 400, "response: {res:#?}");
 			let body = res.text().await?;
-			assert_eq!(body, r#"Load"#, "body: {body}");
+			assert!(body.contains("read only replica"), "body: {body}");
+
+			// The definitions of the primary server are replicated
+			let res = client
+				.post(format!("http://{addr}/sql"))
+				.basic_auth(USER, Some(PASS))
+				.body("DEFINE FIELD age ON foo TYPE option<int>; CREATE foo:5 SET name = 'five', age = 5;")
+				.send()
+				.await?;
+			assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+
+			// Wait for the replica to catch up
+			let mut body = serde_json::Value::Null;
+			for _ in 0..50 {
+				let res = client
+					.post(format!("http://{replica}/sql"))
+					.basic_auth(USER, Some(PASS))
+					.body("INFO FOR TABLE foo; SELECT VALUE name FROM foo ORDER BY name")
+					.send()
+					.await?;
+				assert_eq!(res.status(), 200, "body: {}", res.text().await?);
+				body = serde_json::from_str(&res.text().await?)?;
+				if body[1]["result"] == json!(["five", "three", "two"]) {
+					break;
+				}
+				tokio::time::sleep(Duration::from_millis(100)).await;
+			}
+			assert_eq!(body[1]["result"], json!(["five", "three", "two"]), "body: {body}");
+			assert!(body[0]["result"]["fields"]["age"].is_string(), "body: {body}");
 		}
 
 		Ok(())